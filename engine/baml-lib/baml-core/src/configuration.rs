@@ -39,6 +39,9 @@ pub struct CodegenGenerator {
     pub output_type: GeneratorOutputType,
     default_client_mode: Option<GeneratorDefaultClientMode>,
     pub on_generate: Vec<String>,
+    /// Also generate Zod schemas (TypeScript only).
+    #[builder(default)]
+    pub zod: bool,
     output_dir: PathBuf,
     pub version: String,
 
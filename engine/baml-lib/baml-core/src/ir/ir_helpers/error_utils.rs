@@ -53,8 +53,7 @@ where
 #[macro_export]
 macro_rules! error_not_found {
     ($type:expr, $name:expr, $candidates:expr) => {{
-        let suggestions =
-            $crate::ir::ir_helpers::error_utils::sort_by_match($name, $candidates, Some(5));
+        let suggestions = $crate::ir::sort_by_match($name, $candidates, Some(5));
         match suggestions.len() {
             0 => anyhow::bail!("{} `{}` not found.", $type, $name),
             1 => {
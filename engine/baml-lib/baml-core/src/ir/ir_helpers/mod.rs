@@ -17,6 +17,7 @@ use baml_types::{
     BamlMap, BamlValue, BamlValueWithMeta, Constraint, ConstraintLevel, FieldType, LiteralValue,
     TypeValue,
 };
+pub use error_utils::sort_by_match;
 pub use to_baml_arg::ArgCoercer;
 
 use super::repr;
@@ -215,10 +216,18 @@ impl IRHelper for IntermediateRepr {
         match value {
             BamlValue::String(s) => {
                 let literal_type = FieldType::Literal(LiteralValue::String(s.clone()));
-                let primitive_type = FieldType::Primitive(TypeValue::String);
+                // Dates and durations are held as ISO 8601 strings.
+                let primitive_types = [
+                    TypeValue::String,
+                    TypeValue::Date,
+                    TypeValue::DateTime,
+                    TypeValue::Duration,
+                ];
 
                 if literal_type.is_subtype_of(&field_type)
-                    || primitive_type.is_subtype_of(&field_type)
+                    || primitive_types
+                        .into_iter()
+                        .any(|t| FieldType::Primitive(t).is_subtype_of(&field_type))
                 {
                     return Ok(BamlValueWithMeta::String(s, field_type));
                 }
@@ -1,6 +1,6 @@
 use baml_types::{
-    BamlMap, BamlValue, BamlValueWithMeta, Constraint, ConstraintLevel, FieldType, LiteralValue,
-    TypeValue,
+    iso8601, BamlMap, BamlValue, BamlValueWithMeta, Constraint, ConstraintLevel, FieldType,
+    LiteralValue, TypeValue,
 };
 use core::result::Result;
 use std::path::PathBuf;
@@ -68,6 +68,33 @@ impl ArgCoercer {
                 },
                 TypeValue::Bool if matches!(value, BamlValue::Bool(_)) => Ok(value.clone()),
                 TypeValue::Null if matches!(value, BamlValue::Null) => Ok(value.clone()),
+                TypeValue::Date | TypeValue::DateTime | TypeValue::Duration => {
+                    let canonical = match (t, value) {
+                        // Datetimes, like JS `Date`s, are accepted for their date
+                        // at their own UTC offset, which for `Date`s is the
+                        // local one of the caller.
+                        (TypeValue::Date, BamlValue::String(s)) => iso8601::canonical_date(s)
+                            .or_else(|| {
+                                iso8601::parse_datetime(s)
+                                    .map(|(dt, _)| iso8601::format_date(dt.date()))
+                            }),
+                        (TypeValue::DateTime, BamlValue::String(s)) => {
+                            iso8601::canonical_datetime(s)
+                        }
+                        (TypeValue::Duration, BamlValue::String(s)) => {
+                            iso8601::canonical_duration(s)
+                        }
+                        _ => None,
+                    };
+                    match canonical {
+                        Some(s) => Ok(BamlValue::String(s)),
+                        None => {
+                            scope
+                                .push_error(format!("Expected an ISO 8601 {}, got `{}`", t, value));
+                            Err(())
+                        }
+                    }
+                }
                 TypeValue::Media(media_type) => match value {
                     BamlValue::Media(v) => Ok(BamlValue::Media(v.clone())),
                     BamlValue::Map(kv) => {
@@ -1,242 +1,661 @@
-// JSON Schema
+//! JSON Schema (2020-12) for BAML types.
+//!
+//! Classes and enums are referenced as `#/$defs/<Name>`, so a schema is only
+//! complete together with the `$defs` of the types it uses. [`type_document`]
+//! and the function documents include them.
 
-use baml_types::TypeValue;
-use serde_json::json;
+use anyhow::{Context, Result};
+use baml_types::{Constraint, ConstraintLevel, LiteralValue, TypeValue};
+use indexmap::IndexSet;
+use serde_json::{json, Map, Value};
 
 use super::{
-    repr::{self},
-    Class, Enum, FieldType, FunctionArgs, FunctionNode, IntermediateRepr, Walker,
+    repr::{Docstring, Expression, NodeAttributes},
+    ClassWalker, EnumWalker, FieldType, FunctionWalker, IRHelper, IntermediateRepr,
 };
 
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
 pub trait WithJsonSchema {
-    fn json_schema(&self) -> serde_json::Value;
+    fn json_schema(&self) -> Value;
 }
 
+/// A single document defining every class and enum, and the input and output
+/// of every function (as `$defs/<Function>.input` and `$defs/<Function>.output`).
 impl WithJsonSchema for IntermediateRepr {
-    fn json_schema(&self) -> serde_json::Value {
-        let enums = self
-            .walk_enums()
-            .map(|e| (e.elem().name.clone(), e.json_schema()));
-        let classes = self
-            .walk_classes()
-            .map(|c| (c.elem().name.clone(), c.json_schema()));
-        let function_inputs = self
-            .walk_functions()
-            .map(|f| (format!("{}_input", f.name()), (f.item, true).json_schema()));
-        let function_outputs = self.walk_functions().map(|f| {
-            (
-                format!("{}_output", f.name()),
-                (f.item, false).json_schema(),
-            )
-        });
-
-        // Combine all the definitions into one object of key-value pairs
-        let definitions = enums
-            .chain(classes)
-            .chain(function_inputs)
-            .chain(function_outputs)
-            .collect::<serde_json::Map<_, _>>();
-
-        json!({
-            "definitions": definitions,
-        })
+    fn json_schema(&self) -> Value {
+        let mut defs = Map::new();
+        for c in self.walk_classes() {
+            defs.insert(c.name().to_string(), c.json_schema());
+        }
+        for e in self.walk_enums() {
+            defs.insert(e.name().to_string(), e.json_schema());
+        }
+        for f in self.walk_functions() {
+            defs.insert(format!("{}.input", f.name()), function_input(&f));
+            defs.insert(
+                format!("{}.output", f.name()),
+                f.elem().output().json_schema(),
+            );
+        }
+        header("BAML", Map::new(), defs)
     }
 }
 
-impl WithJsonSchema for (&FunctionNode, bool) {
-    fn json_schema(&self) -> serde_json::Value {
-        let (f, is_input) = self;
+impl WithJsonSchema for ClassWalker<'_> {
+    fn json_schema(&self) -> Value {
+        let fields = &self.item.elem.static_fields;
+        let mut schema = Map::new();
+        schema.insert("type".into(), "object".into());
+        insert_description(
+            &mut schema,
+            &self.item.attributes,
+            self.item.elem.docstring.as_ref(),
+        );
+        schema.insert(
+            "properties".into(),
+            fields
+                .iter()
+                .map(|f| {
+                    let mut property = into_object(f.elem.r#type.elem.json_schema());
+                    insert_description(
+                        &mut property,
+                        &f.elem.r#type.attributes,
+                        f.elem.docstring.as_ref(),
+                    );
+                    insert_alias(&mut property, &f.elem.r#type.attributes);
+                    (f.elem.name.clone(), Value::Object(property))
+                })
+                .collect::<Map<_, _>>()
+                .into(),
+        );
+        schema.insert(
+            "required".into(),
+            fields
+                .iter()
+                .filter(|f| !f.elem.r#type.elem.is_optional())
+                .map(|f| Value::from(f.elem.name.as_str()))
+                .collect(),
+        );
+        // Fields of @@dynamic classes are only known at runtime.
+        schema.insert(
+            "additionalProperties".into(),
+            self.item.attributes.get("dynamic_type").is_some().into(),
+        );
+        let asserts = self
+            .item
+            .attributes
+            .constraints
+            .iter()
+            .filter(|c| c.level == ConstraintLevel::Assert);
+        apply_asserts(&mut schema, asserts, Some("Properties"));
+        Value::Object(schema)
+    }
+}
 
-        let mut res = if *is_input {
-            f.elem.inputs.json_schema()
+impl WithJsonSchema for EnumWalker<'_> {
+    fn json_schema(&self) -> Value {
+        let values = self
+            .item
+            .elem
+            .values
+            .iter()
+            .filter(|(v, _)| !matches!(v.attributes.get("skip"), Some(Expression::Bool(true))))
+            .collect::<Vec<_>>();
+        let mut schema = Map::new();
+        schema.insert("type".into(), "string".into());
+        insert_description(
+            &mut schema,
+            &self.item.attributes,
+            self.item.elem.docstring.as_ref(),
+        );
+        let annotated = values.iter().any(|(v, docstring)| {
+            docstring.is_some()
+                || v.attributes.get("description").is_some()
+                || v.attributes.get("alias").is_some()
+        });
+        let key = if self.item.attributes.get("dynamic_type").is_some() {
+            // Values of @@dynamic enums are only known at runtime.
+            "examples"
+        } else if annotated {
+            "oneOf"
         } else {
-            f.elem.output.json_schema()
+            "enum"
         };
-
-        // Add a title field to the schema
-        if let serde_json::Value::Object(res) = &mut res {
-            res.insert(
-                "title".to_string(),
-                json!(format!(
-                    "{} {}",
-                    f.elem.name(),
-                    if *is_input { "input" } else { "output" }
-                )),
-            );
-        }
-
-        res
+        schema.insert(
+            key.into(),
+            values
+                .iter()
+                .map(|(v, docstring)| {
+                    if key != "oneOf" {
+                        return Value::from(v.elem.0.as_str());
+                    }
+                    let mut value = Map::new();
+                    value.insert("const".into(), v.elem.0.as_str().into());
+                    insert_description(&mut value, &v.attributes, docstring.as_ref());
+                    insert_alias(&mut value, &v.attributes);
+                    Value::Object(value)
+                })
+                .collect(),
+        );
+        Value::Object(schema)
     }
 }
 
-impl WithJsonSchema for FunctionArgs {
-    fn json_schema(&self) -> serde_json::Value {
+impl WithJsonSchema for FieldType {
+    fn json_schema(&self) -> Value {
         match self {
-            FunctionArgs::UnnamedArg(t) => t.json_schema(),
-            FunctionArgs::NamedArgList(args) => {
-                let mut properties = json!({});
-                let mut required_props = vec![];
-                for (name, t) in args.iter() {
-                    properties[name] = t.json_schema();
-                    match t {
-                        FieldType::Optional(_) => {
-                            required_props.push(name.clone());
-                        }
-                        _ => {}
-                    }
-                }
-                json!({
+            FieldType::Primitive(v) => match v {
+                TypeValue::String => json!({ "type": "string" }),
+                TypeValue::Int => json!({ "type": "integer" }),
+                TypeValue::Float => json!({ "type": "number" }),
+                TypeValue::Bool => json!({ "type": "boolean" }),
+                TypeValue::Null => json!({ "type": "null" }),
+                TypeValue::Date => json!({ "type": "string", "format": "date" }),
+                TypeValue::DateTime => json!({ "type": "string", "format": "date-time" }),
+                TypeValue::Duration => json!({ "type": "string", "format": "duration" }),
+                TypeValue::Media(_) => json!({
                     "type": "object",
-                    "properties": properties,
-                    "required": required_props,
-                })
+                    "properties": {
+                        "url": { "type": "string" },
+                        "base64": { "type": "string" },
+                        "media_type": { "type": "string" },
+                    },
+                    "anyOf": [{ "required": ["url"] }, { "required": ["base64"] }],
+                }),
+            },
+            FieldType::Enum(name) | FieldType::Class(name) => def_ref(name),
+            FieldType::Literal(v) => match v {
+                LiteralValue::String(s) => json!({ "const": s }),
+                LiteralValue::Int(i) => json!({ "const": i }),
+                LiteralValue::Bool(b) => json!({ "const": b }),
+            },
+            FieldType::List(inner) => json!({ "type": "array", "items": inner.json_schema() }),
+            FieldType::Map(key, value) => {
+                let mut schema = json!({
+                    "type": "object",
+                    "additionalProperties": value.json_schema(),
+                });
+                // Keys may be restricted to an enum or to literal strings.
+                if !matches!(**key, FieldType::Primitive(TypeValue::String)) {
+                    schema["propertyNames"] = key.json_schema();
+                }
+                schema
+            }
+            FieldType::Optional(inner) => json!({
+                "anyOf": [inner.json_schema(), { "type": "null" }],
+            }),
+            FieldType::Tuple(inner) => json!({
+                "type": "array",
+                "prefixItems": inner.iter().map(|t| t.json_schema()).collect::<Vec<_>>(),
+                "minItems": inner.len(),
+                "maxItems": inner.len(),
+            }),
+            FieldType::Union(inner) => json!({
+                "anyOf": inner.iter().map(|t| t.json_schema()).collect::<Vec<_>>(),
+            }),
+            FieldType::Constrained { base, constraints } => {
+                let mut schema = into_object(base.json_schema());
+                let asserts = constraints
+                    .iter()
+                    .filter(|c| c.level == ConstraintLevel::Assert);
+                apply_asserts(&mut schema, asserts, length_kind(base));
+                let checks = constraints
+                    .iter()
+                    .filter_map(|c| match c.level {
+                        ConstraintLevel::Check => c.label.as_deref(),
+                        ConstraintLevel::Assert => None,
+                    })
+                    .collect::<Vec<_>>();
+                if checks.is_empty() {
+                    Value::Object(schema)
+                } else {
+                    checked(Value::Object(schema), &checks)
+                }
             }
         }
     }
 }
 
-impl WithJsonSchema for Vec<(String, FieldType)> {
-    fn json_schema(&self) -> serde_json::Value {
-        let mut properties = json!({});
-        let mut required_props = vec![];
-        for (name, t) in self.iter() {
-            properties[name.clone()] = t.json_schema();
-            match t {
-                FieldType::Optional(_) => {}
-                _ => {
-                    required_props.push(name.clone());
+/// A standalone document for the class or enum `name`.
+pub fn type_document(ir: &IntermediateRepr, name: &str) -> Result<Value> {
+    let (root, deps) = if let Ok(c) = ir.find_class(name) {
+        let fields = c
+            .item
+            .elem
+            .static_fields
+            .iter()
+            .map(|f| &f.elem.r#type.elem);
+        (c.json_schema(), dependencies(ir, fields))
+    } else if let Ok(e) = ir.find_enum(name) {
+        (e.json_schema(), IndexSet::new())
+    } else {
+        anyhow::bail!("No class or enum named {name}");
+    };
+    Ok(header(name, into_object(root), defs(ir, &deps)))
+}
+
+/// A document for the arguments of the function `name`, as an object.
+pub fn function_input_document(ir: &IntermediateRepr, name: &str) -> Result<Value> {
+    let f = ir
+        .find_function(name)
+        .context(format!("No function named {name}"))?;
+    let deps = dependencies(ir, f.inputs().iter().map(|(_, t)| t));
+    Ok(header(
+        &format!("{name}.input"),
+        into_object(function_input(&f)),
+        defs(ir, &deps),
+    ))
+}
+
+/// A document for the result of the function `name`.
+pub fn function_output_document(ir: &IntermediateRepr, name: &str) -> Result<Value> {
+    let f = ir
+        .find_function(name)
+        .context(format!("No function named {name}"))?;
+    let output = f.elem().output();
+    let deps = dependencies(ir, std::iter::once(output));
+    Ok(header(
+        &format!("{name}.output"),
+        into_object(output.json_schema()),
+        defs(ir, &deps),
+    ))
+}
+
+fn header(title: &str, root: Map<String, Value>, defs: Map<String, Value>) -> Value {
+    let mut document = Map::new();
+    document.insert("$schema".into(), DIALECT.into());
+    document.insert("title".into(), title.into());
+    document.extend(root);
+    if !defs.is_empty() {
+        document.insert("$defs".into(), Value::Object(defs));
+    }
+    Value::Object(document)
+}
+
+fn into_object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        // Only objects are produced for types.
+        other => Map::from_iter([("allOf".to_string(), json!([other]))]),
+    }
+}
+
+fn def_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+fn function_input(f: &FunctionWalker<'_>) -> Value {
+    let inputs = f.inputs();
+    json!({
+        "type": "object",
+        "properties": inputs
+            .iter()
+            .map(|(name, t)| (name.to_string(), t.json_schema()))
+            .collect::<Map<_, _>>(),
+        "required": inputs
+            .iter()
+            .filter(|(_, t)| !t.is_optional())
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        "additionalProperties": false,
+    })
+}
+
+/// The classes and enums `types` refer to, directly or indirectly.
+fn dependencies<'a>(
+    ir: &'a IntermediateRepr,
+    types: impl Iterator<Item = &'a FieldType>,
+) -> IndexSet<String> {
+    let mut deps = IndexSet::new();
+    let mut pending = types.collect::<Vec<_>>();
+    while let Some(t) = pending.pop() {
+        match t {
+            FieldType::Class(name) => {
+                if deps.insert(name.clone()) {
+                    if let Ok(c) = ir.find_class(name) {
+                        pending.extend(
+                            c.item
+                                .elem
+                                .static_fields
+                                .iter()
+                                .map(|f| &f.elem.r#type.elem),
+                        );
+                    }
                 }
             }
+            FieldType::Enum(name) => {
+                deps.insert(name.clone());
+            }
+            FieldType::List(inner) | FieldType::Optional(inner) => pending.push(inner),
+            FieldType::Map(key, value) => {
+                pending.push(key);
+                pending.push(value);
+            }
+            FieldType::Tuple(inner) | FieldType::Union(inner) => pending.extend(inner),
+            FieldType::Constrained { base, .. } => pending.push(base),
+            FieldType::Primitive(_) | FieldType::Literal(_) => {}
         }
-        json!({
-            "type": "object",
-            "properties": properties,
-            "required": required_props,
-        })
     }
+    deps.sort();
+    deps
+}
+
+fn defs(ir: &IntermediateRepr, names: &IndexSet<String>) -> Map<String, Value> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let schema = if let Ok(c) = ir.find_class(name) {
+                c.json_schema()
+            } else {
+                ir.find_enum(name).ok()?.json_schema()
+            };
+            Some((name.clone(), schema))
+        })
+        .collect()
 }
 
-impl WithJsonSchema for Walker<'_, &Enum> {
-    fn json_schema(&self) -> serde_json::Value {
-        json!({
-                "title": self.elem().name,
-                "enum": self.elem().values
+/// The shape of a value with `@check`s, as returned by the BAML clients.
+fn checked(value: Value, checks: &[&str]) -> Value {
+    let check = json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "expression": { "type": "string" },
+            "status": { "enum": ["succeeded", "failed"] },
+        },
+        "required": ["name", "expression", "status"],
+    });
+    json!({
+        "type": "object",
+        "properties": {
+            "value": value,
+            "checks": {
+                "type": "object",
+                "properties": checks
                     .iter()
-                    .map(|v| json!({
-                        "const": v.0.elem.0.clone()
-                    }))
-                    .collect::<Vec<_>>(),
+                    .map(|name| (name.to_string(), check.clone()))
+                    .collect::<Map<_, _>>(),
+                "required": checks,
+                "additionalProperties": false,
+            },
+        },
+        "required": ["value", "checks"],
+        "additionalProperties": false,
+    })
+}
 
-        })
+fn insert_description(
+    schema: &mut Map<String, Value>,
+    attributes: &NodeAttributes,
+    docstring: Option<&Docstring>,
+) {
+    let description = match attributes.get("description") {
+        Some(Expression::String(s)) | Some(Expression::RawString(s)) => Some(s.clone()),
+        _ => docstring.map(|d| d.0.clone()),
+    };
+    if let Some(description) = description {
+        schema.insert("description".into(), description.into());
     }
 }
 
-impl WithJsonSchema for Walker<'_, &Class> {
-    fn json_schema(&self) -> serde_json::Value {
-        let mut properties = json!({});
-        let mut required_props = vec![];
-        for field in self.elem().static_fields.iter() {
-            properties[field.elem.name.clone()] = field.elem.r#type.elem.json_schema();
-            match field.elem.r#type.elem {
-                FieldType::Optional(_) => {}
-                _ => {
-                    required_props.push(field.elem.name.clone());
-                }
-            }
+/// JSON Schema has no notion of aliases, so they're kept as an annotation.
+fn insert_alias(schema: &mut Map<String, Value>, attributes: &NodeAttributes) {
+    if let Some(Expression::String(alias)) = attributes.get("alias") {
+        schema.insert("x-baml-alias".into(), alias.as_str().into());
+    }
+}
+
+/// The suffix of the keywords constraining the length of values of type
+/// `t`, e.g. `Length` for `minLength`.
+pub fn length_kind(t: &FieldType) -> Option<&'static str> {
+    match t {
+        FieldType::Primitive(TypeValue::String) => Some("Length"),
+        FieldType::List(_) | FieldType::Tuple(_) => Some("Items"),
+        FieldType::Map(..) | FieldType::Class(_) => Some("Properties"),
+        FieldType::Optional(inner) | FieldType::Constrained { base: inner, .. } => {
+            length_kind(inner)
         }
-        json!({
-                "title": self.elem().name,
-                "type": "object",
-                "properties": properties,
-                "required": required_props,
-        })
+        _ => None,
     }
 }
 
-impl<'db> WithJsonSchema for FieldType {
-    fn json_schema(&self) -> serde_json::Value {
-        match self {
-            FieldType::Class(name) | FieldType::Enum(name) => json!({
-                "$ref": format!("#/definitions/{}", name),
-            }),
-            FieldType::Literal(v) => json!({
-                "const": v.to_string(),
-            }),
-            FieldType::Primitive(t) => match t {
-                TypeValue::String => json!({
-                    "type": "string",
-                }),
-                TypeValue::Int => json!({
-                    "type": "integer",
-                }),
-                TypeValue::Float => json!({
-                    "type": "number",
-                }),
-                TypeValue::Bool => json!({
-                    "type": "boolean",
-                }),
-                TypeValue::Null => json!({
-                    "type": "null",
-                }),
-                TypeValue::Media(_) => json!({
-                    // anyOf either an object that has a uri, or it has a base64 string
-                    "type": "object",
-                    "properties": {
-                        "url": {
-                            "type": "string",
+/// Adds the keywords equivalent to `asserts` to `schema`.
+///
+/// Only simple comparisons of `this`, `this|length` and `this|regex_match`
+/// can be expressed; other asserts are listed in `$comment`.
+fn apply_asserts<'a>(
+    schema: &mut Map<String, Value>,
+    asserts: impl Iterator<Item = &'a Constraint>,
+    length_kind: Option<&str>,
+) {
+    let mut untranslated = vec![];
+    for assert in asserts {
+        let expression = assert.expression.0.trim();
+        match assert_keywords(expression, length_kind) {
+            Some(keywords) => {
+                for (keyword, value) in keywords {
+                    if schema.contains_key(&keyword) {
+                        let all_of = schema.entry("allOf").or_insert_with(|| json!([]));
+                        if let Value::Array(all_of) = all_of {
+                            all_of.push(json!({ keyword: value }));
                         }
-                    },
-                    "required": ["url"],
-                }),
-            },
-            FieldType::List(item) => json!({
-                "type": "array",
-                "items": (*item).json_schema()
-            }),
-            FieldType::Map(_k, v) => json!({
-                "type": "object",
-                "additionalProperties": {
-                    "type": v.json_schema(),
-                }
-            }),
-            FieldType::Union(options) => json!({
-                "anyOf": options.iter().map(|t| {
-                    let mut res = t.json_schema();
-                    // if res is a map, add a "title" field
-                    if let serde_json::Value::Object(r) = &mut res {
-                        r.insert("title".to_string(), json!(t.to_string()));
-                    }
-                    res
-                }
-            ).collect::<Vec<_>>(),
-            }),
-            FieldType::Tuple(options) => json!({
-                "type": "array",
-                "prefixItems": options.iter().map(|t| t.json_schema()).collect::<Vec<_>>(),
-            }),
-            // The caller object is responsible for adding the "null" type
-            FieldType::Optional(inner) => {
-                match **inner {
-                    FieldType::Primitive(_) => {
-                        let mut res = inner.json_schema();
-                        res["type"] = json!([res["type"], "null"]);
-                        res["default"] = serde_json::Value::Null;
-                        res
-                    }
-                    _ => {
-                        let mut res = inner.json_schema();
-                        // if res is a map, add a "title" field
-                        if let serde_json::Value::Object(r) = &mut res {
-                            r.insert("title".to_string(), json!(inner.to_string()));
-                        }
-                        json!({
-                            "anyOf": [res, json!({"type": "null", "title": "null"})],
-                            "default": serde_json::Value::Null,
-                        })
+                    } else {
+                        schema.insert(keyword, value);
                     }
                 }
             }
-            FieldType::Constrained { base, .. } => base.json_schema(),
+            None => untranslated.push(format!("@assert({{{{ {expression} }}}})")),
         }
     }
+    if !untranslated.is_empty() {
+        schema.insert(
+            "$comment".into(),
+            format!(
+                "Not expressible in JSON Schema: {}",
+                untranslated.join(", ")
+            )
+            .into(),
+        );
+    }
+}
+
+pub fn assert_keywords(
+    expression: &str,
+    length_kind: Option<&str>,
+) -> Option<Vec<(String, Value)>> {
+    if expression.contains(" or ") || expression.contains("not ") {
+        return None;
+    }
+    let mut keywords = vec![];
+    for clause in expression.split(" and ") {
+        keywords.extend(clause_keywords(clause.trim(), length_kind)?);
+    }
+    Some(keywords)
+}
+
+fn clause_keywords(clause: &str, length_kind: Option<&str>) -> Option<Vec<(String, Value)>> {
+    let compact = clause.replace(' ', "");
+    if let Some(pattern) = compact
+        .strip_prefix("this|regex_match(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        // Take the pattern from the original clause, since it may have spaces.
+        let start = clause.find('(')? + 1;
+        let pattern = clause[start..clause.rfind(')')?].trim();
+        // Jinja unescapes `\\` and `\"` like JSON does. Other escapes, like
+        // `\d`, are kept as they are.
+        let pattern = serde_json::from_str::<String>(pattern).ok().or_else(|| {
+            pattern
+                .strip_prefix('"')
+                .and_then(|p| p.strip_suffix('"'))
+                .or_else(|| {
+                    pattern
+                        .strip_prefix('\'')
+                        .and_then(|p| p.strip_suffix('\''))
+                })
+                .map(str::to_string)
+        })?;
+        return Some(vec![("pattern".into(), pattern.into())]);
+    }
+    if let Some(values) = clause.strip_prefix("this in ") {
+        let values: Value = serde_json::from_str(values.trim()).ok()?;
+        return values.is_array().then(|| vec![("enum".into(), values)]);
+    }
+
+    let (op_index, op) = [">=", "<=", "==", "!=", ">", "<"]
+        .iter()
+        .filter_map(|op| clause.find(op).map(|i| (i, *op)))
+        .min_by_key(|(i, op)| (*i, std::cmp::Reverse(op.len())))?;
+    let subject = clause[..op_index].replace(' ', "");
+    let operand: Value = serde_json::from_str(clause[op_index + op.len()..].trim()).ok()?;
+
+    match subject.as_str() {
+        "this" => Some(match (op, &operand) {
+            ("==", _) => vec![("const".into(), operand)],
+            ("!=", _) => vec![("not".into(), json!({ "const": operand }))],
+            (">", Value::Number(_)) => vec![("exclusiveMinimum".into(), operand)],
+            (">=", Value::Number(_)) => vec![("minimum".into(), operand)],
+            ("<", Value::Number(_)) => vec![("exclusiveMaximum".into(), operand)],
+            ("<=", Value::Number(_)) => vec![("maximum".into(), operand)],
+            _ => return None,
+        }),
+        "this|length" => {
+            let kind = length_kind?;
+            let n = operand.as_i64()?;
+            let (min, max) = (format!("min{kind}"), format!("max{kind}"));
+            Some(match op {
+                ">" => vec![(min, (n + 1).into())],
+                ">=" => vec![(min, n.into())],
+                "<" => vec![(max, (n - 1).into())],
+                "<=" => vec![(max, n.into())],
+                "==" => vec![(min, n.into()), (max, n.into())],
+                _ => return None,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ir::repr::make_test_ir;
+
+    use super::*;
+
+    #[test]
+    fn test_assert_keywords() {
+        assert_eq!(
+            assert_keywords("this > 0 and this <= 100", None),
+            Some(vec![
+                ("exclusiveMinimum".to_string(), json!(0)),
+                ("maximum".to_string(), json!(100)),
+            ])
+        );
+        assert_eq!(
+            assert_keywords("this|length > 0", Some("Items")),
+            Some(vec![("minItems".to_string(), json!(1))])
+        );
+        assert_eq!(
+            assert_keywords(r#"this|regex_match("^[a-z]+$")"#, Some("Length")),
+            Some(vec![("pattern".to_string(), json!("^[a-z]+$"))])
+        );
+        assert_eq!(
+            assert_keywords(r#"this|regex_match("^\\d+$")"#, Some("Length")),
+            Some(vec![("pattern".to_string(), json!(r"^\d+$"))])
+        );
+        assert_eq!(
+            assert_keywords(r#"this in ["free", "pro"]"#, Some("Length")),
+            Some(vec![("enum".to_string(), json!(["free", "pro"]))])
+        );
+        assert_eq!(assert_keywords("this.age > 10", None), None);
+        assert_eq!(assert_keywords("this > 0 or this < -10", None), None);
+    }
+
+    #[test]
+    fn test_bundle() {
+        let ir = make_test_ir(
+            r##"
+class Resume {
+  name string
+}
+
+client<llm> GPT4 {
+  provider openai
+  options {
+    model gpt-4o
+  }
+}
+
+function ExtractResume(text: string, page: int?) -> Resume {
+  client GPT4
+  prompt #"{{ text }}"#
+}
+"##,
+        )
+        .expect("Valid source");
+
+        let bundle = ir.json_schema();
+        assert_eq!(bundle["title"], "BAML");
+        assert_eq!(
+            bundle["$defs"]["ExtractResume.input"]["required"],
+            json!(["text"])
+        );
+        assert_eq!(
+            bundle["$defs"]["ExtractResume.output"],
+            json!({ "$ref": "#/$defs/Resume" })
+        );
+        assert_eq!(bundle["$defs"]["Resume"]["required"], json!(["name"]));
+    }
+
+    #[test]
+    fn test_type_document() {
+        let ir = make_test_ir(
+            r##"
+enum Level {
+  Junior
+  Senior @description("Ten or more years")
+}
+
+class Employee {
+  name string @alias("full_name")
+  age int @assert(adult, {{ this >= 18 }})
+  level Level?
+  manager Employee?
+  tags map<string, int> @description("Counts by tag")
+}
+"##,
+        )
+        .expect("Valid source");
+
+        let document = type_document(&ir, "Employee").unwrap();
+        assert_eq!(document["$schema"], DIALECT);
+        assert_eq!(document["title"], "Employee");
+        assert_eq!(document["required"], json!(["name", "age", "tags"]));
+        assert_eq!(
+            document["properties"]["name"],
+            json!({ "type": "string", "x-baml-alias": "full_name" })
+        );
+        assert_eq!(
+            document["properties"]["age"],
+            json!({ "type": "integer", "minimum": 18 })
+        );
+        assert_eq!(
+            document["properties"]["tags"]["description"],
+            "Counts by tag"
+        );
+        // Recursive classes are defined in $defs, next to their dependencies.
+        assert_eq!(
+            document["$defs"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["Employee", "Level"]
+        );
+        assert_eq!(
+            document["$defs"]["Level"]["oneOf"][1],
+            json!({ "const": "Senior", "description": "Ten or more years" })
+        );
+    }
 }
@@ -1,12 +1,13 @@
 mod ir_helpers;
 pub mod jinja_helpers;
-mod json_schema;
+pub mod json_schema;
 pub mod repr;
 mod walker;
 
 pub use ir_helpers::{
-    scope_diagnostics, ArgCoercer, ClassFieldWalker, ClassWalker, ClientWalker, EnumValueWalker,
-    EnumWalker, FunctionWalker, IRHelper, RetryPolicyWalker, TemplateStringWalker, TestCaseWalker,
+    scope_diagnostics, sort_by_match, ArgCoercer, ClassFieldWalker, ClassWalker, ClientWalker,
+    EnumValueWalker, EnumWalker, FunctionWalker, IRHelper, RetryPolicyWalker, TemplateStringWalker,
+    TestCaseWalker,
 };
 
 pub(super) use repr::IntermediateRepr;
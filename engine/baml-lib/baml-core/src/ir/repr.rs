@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use anyhow::{anyhow, Result};
-use baml_types::{Constraint, ConstraintLevel, FieldType};
+use baml_types::{Constraint, ConstraintLevel, FieldType, ParserMode};
 use either::Either;
 use indexmap::{IndexMap, IndexSet};
 use internal_baml_parser_database::{
@@ -99,6 +99,31 @@ impl IntermediateRepr {
         &self.finite_recursive_cycles
     }
 
+    /// The field a union is discriminated by, when every option but `null` is
+    /// a class tagged with `@@tag` on that same field. Returns the field, and
+    /// the tag value and name of each class.
+    pub fn union_tag<'a>(
+        &'a self,
+        options: &[FieldType],
+    ) -> Option<(&'a str, Vec<(&'a str, &'a str)>)> {
+        let mut field = None;
+        let mut variants = vec![];
+        for option in options.iter().filter(|o| !o.is_null()) {
+            let FieldType::Class(name) = option else {
+                return None;
+            };
+            let class = self.walk_classes().find(|c| c.name() == name)?;
+            let (tag, value) = class.tag()?;
+            if *field.get_or_insert(tag) != tag {
+                return None;
+            }
+            variants.push((value, class.name()));
+        }
+        // A single class needs no telling apart.
+        let field = field?;
+        (variants.len() > 1).then_some((field, variants))
+    }
+
     pub fn walk_enums<'a>(&'a self) -> impl ExactSizeIterator<Item = Walker<'a, &'a Node<Enum>>> {
         self.enums.iter().map(|e| Walker { db: self, item: e })
     }
@@ -231,6 +256,7 @@ pub struct NodeAttributes {
     ///
     ///   - @skip becomes ("skip", bool)
     ///   - @alias(...) becomes ("alias", ...)
+    ///   - @default(...) becomes ("default", ...)
     #[serde(with = "indexmap::map::serde_seq")]
     meta: IndexMap<String, Expression>,
 
@@ -269,6 +295,9 @@ fn to_ir_attributes(
             dynamic_type,
             skip,
             constraints,
+            default_value,
+            value_constraints,
+            tag,
         } = attributes;
         let description = description.as_ref().and_then(|d| {
             let name = "description".to_string();
@@ -304,9 +333,23 @@ fn to_ir_attributes(
             }
         });
 
-        let meta = vec![description, alias, dynamic_type, skip]
+        let default_value = default_value
+            .as_ref()
+            .and_then(|v| v.repr(db).ok())
+            .map(|v| ("default".to_string(), v));
+
+        let value_constraints = value_constraints
+            .iter()
+            .filter_map(|(name, v)| v.repr(db).ok().map(|v| (name.clone(), v)));
+
+        let tag = tag
+            .as_ref()
+            .map(|v| ("tag".to_string(), Expression::String(db[*v].to_string())));
+
+        let meta = vec![description, alias, dynamic_type, skip, default_value, tag]
             .into_iter()
             .filter_map(|s| s)
+            .chain(value_constraints)
             .collect();
         (meta, constraints.clone())
     })
@@ -712,25 +755,80 @@ impl WithRepr<Field> for FieldWalker<'_> {
     }
 
     fn repr(&self, db: &ParserDatabase) -> Result<Field> {
+        let attributes = self.attributes(db);
+        let r#type = self
+            .ast_field()
+            .expr
+            .clone()
+            .ok_or(anyhow!(
+                "Internal error occurred while resolving repr of field {:?}",
+                self.name(),
+            ))?
+            .repr(db)?;
         Ok(Field {
             name: self.name().to_string(),
             r#type: Node {
-                elem: self
-                    .ast_field()
-                    .expr
-                    .clone()
-                    .ok_or(anyhow!(
-                        "Internal error occurred while resolving repr of field {:?}",
-                        self.name(),
-                    ))?
-                    .repr(db)?,
-                attributes: self.attributes(db),
+                elem: with_asserts(r#type, value_constraint_asserts(&attributes.meta)),
+                attributes,
             },
             docstring: self.get_documentation().map(|s| Docstring(s)),
         })
     }
 }
 
+/// The asserts enforcing a field's `@min`, `@max`, `@min_length`,
+/// `@max_length`, `@pattern` and `@one_of`, labeled with the attribute name.
+fn value_constraint_asserts(meta: &IndexMap<String, Expression>) -> Vec<Constraint> {
+    let literal = |name: &str| {
+        meta.get(name)
+            .and_then(|v| v.normalize(&Default::default()).ok())
+            .and_then(|v| serde_json::to_string(&v).ok())
+    };
+    [
+        ("min", "this >= "),
+        ("max", "this <= "),
+        ("min_length", "this|length >= "),
+        ("max_length", "this|length <= "),
+        ("one_of", "this in "),
+    ]
+    .into_iter()
+    .filter_map(|(name, prefix)| Some((name, format!("{prefix}{}", literal(name)?))))
+    .chain(literal("pattern").map(|pattern| ("pattern", format!("this|regex_match({pattern})"))))
+    .map(|(name, expression)| Constraint {
+        level: ConstraintLevel::Assert,
+        expression: JinjaExpression(expression),
+        label: Some(name.to_string()),
+    })
+    .collect()
+}
+
+/// Adds `asserts` to a type. Optional values are only checked when present.
+fn with_asserts(r#type: FieldType, asserts: Vec<Constraint>) -> FieldType {
+    if asserts.is_empty() {
+        return r#type;
+    }
+    match r#type {
+        FieldType::Optional(inner) => FieldType::Optional(Box::new(with_asserts(*inner, asserts))),
+        FieldType::Constrained { base, constraints } if base.is_optional() => {
+            FieldType::Constrained {
+                base: Box::new(with_asserts(*base, asserts)),
+                constraints,
+            }
+        }
+        FieldType::Constrained {
+            base,
+            mut constraints,
+        } => {
+            constraints.extend(asserts);
+            FieldType::Constrained { base, constraints }
+        }
+        r#type => FieldType::Constrained {
+            base: Box::new(r#type),
+            constraints: asserts,
+        },
+    }
+}
+
 type ClassId = String;
 
 /// A BAML Class.
@@ -865,8 +963,29 @@ impl Function {
     pub fn configs(&self) -> Option<&Vec<FunctionConfig>> {
         Some(&self.configs)
     }
+
+    pub fn find_config(&self, name: &str) -> Option<&FunctionConfig> {
+        self.configs.iter().find(|c| c.name == name)
+    }
+
+    /// Picks the config for a call that doesn't select one, given a roll in
+    /// `0..100`. Each config takes its `percent` of the rolls.
+    pub fn config_for_roll(&self, roll: u32) -> Option<&FunctionConfig> {
+        let mut upper = 0;
+        self.configs
+            .iter()
+            .find(|c| {
+                upper += c.percent;
+                roll < upper
+            })
+            .or_else(|| self.find_config(&self.default_config))
+    }
 }
 
+/// The name of the config made from a function's own prompt and client. The
+/// function's named variants make up the rest of its configs.
+pub const DEFAULT_VARIANT: &str = "default_config";
+
 #[derive(serde::Serialize, Debug)]
 pub struct Function {
     pub name: FunctionId,
@@ -875,6 +994,8 @@ pub struct Function {
     pub tests: Vec<Node<TestCase>>,
     pub configs: Vec<FunctionConfig>,
     pub default_config: String,
+    /// How strictly the output of the function is parsed.
+    pub parser_mode: ParserMode,
 }
 
 #[derive(serde::Serialize, Debug)]
@@ -884,6 +1005,8 @@ pub struct FunctionConfig {
     #[serde(skip)]
     pub prompt_span: ast::Span,
     pub client: ClientSpec,
+    /// The share of calls that don't select a config which go to this one.
+    pub percent: u32,
 }
 
 // NB(sam): we used to use this to bridge the wasm layer, but
@@ -1008,16 +1131,9 @@ impl WithRepr<Function> for FunctionWalker<'_> {
                 .expect("need block arg")
                 .field_type
                 .repr(db)?,
-            configs: vec![FunctionConfig {
-                name: "default_config".to_string(),
-                prompt_template: self.jinja_prompt().to_string(),
-                prompt_span: self.ast_function().span().clone(),
-                client: match self.client_spec() {
-                    Ok(spec) => ClientSpec::from(spec),
-                    Err(e) => anyhow::bail!("{}", e.message()),
-                },
-            }],
-            default_config: "default_config".to_string(),
+            configs: function_configs(self)?,
+            default_config: DEFAULT_VARIANT.to_string(),
+            parser_mode: self.parser_mode(),
             tests: self
                 .walk_tests()
                 .map(|e| e.node(db))
@@ -1026,6 +1142,44 @@ impl WithRepr<Function> for FunctionWalker<'_> {
     }
 }
 
+/// The function's own prompt and client, followed by one config for each of
+/// its variants.
+fn function_configs(function: &FunctionWalker<'_>) -> Result<Vec<FunctionConfig>> {
+    let client = |spec: Result<_, internal_baml_diagnostics::DatamodelError>| match spec {
+        Ok(spec) => Ok(ClientSpec::from(spec)),
+        Err(e) => anyhow::bail!("{}", e.message()),
+    };
+    let variants_percent = function
+        .variants()
+        .iter()
+        .filter_map(|v| v.percent.as_ref().map(|(percent, _)| *percent))
+        .sum::<u32>();
+
+    let mut configs = vec![FunctionConfig {
+        name: DEFAULT_VARIANT.to_string(),
+        prompt_template: function.jinja_prompt().to_string(),
+        prompt_span: function.ast_function().span().clone(),
+        client: client(function.client_spec())?,
+        percent: 100u32.saturating_sub(variants_percent),
+    }];
+    for variant in function.variants() {
+        configs.push(FunctionConfig {
+            name: variant.name.0.clone(),
+            prompt_template: match &variant.prompt {
+                Some(prompt) => prompt.value().to_string(),
+                None => function.jinja_prompt().to_string(),
+            },
+            prompt_span: match &variant.prompt {
+                Some(prompt) => prompt.span().clone(),
+                None => function.ast_function().span().clone(),
+            },
+            client: client(function.variant_client_spec(variant))?,
+            percent: variant.percent.as_ref().map_or(0, |(percent, _)| *percent),
+        });
+    }
+    Ok(configs)
+}
+
 type ClientId = String;
 
 #[derive(serde::Serialize, Debug)]
@@ -1310,6 +1464,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_union_tag() {
+        let ir = make_test_ir(
+            r#"
+            class Circle {
+              kind "circle"
+              radius float
+              @@tag("kind")
+            }
+
+            class Square {
+              kind "square"
+              size float
+              @@tag("kind")
+            }
+
+            class Point {
+              x int
+            }
+        "#,
+        )
+        .unwrap();
+
+        let circle = ir.find_class("Circle").unwrap();
+        assert_eq!(circle.tag(), Some(("kind", "circle")));
+        assert_eq!(
+            ir.union_tag(&[
+                FieldType::class("Circle"),
+                FieldType::class("Square"),
+                FieldType::null(),
+            ]),
+            Some(("kind", vec![("circle", "Circle"), ("square", "Square")]))
+        );
+        assert_eq!(
+            ir.union_tag(&[FieldType::class("Circle"), FieldType::class("Point")]),
+            None
+        );
+        assert_eq!(ir.union_tag(&[FieldType::class("Circle")]), None);
+    }
+
     #[test]
     fn test_block_attributes() {
         let ir = make_test_ir(
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use baml_types::BamlValue;
+use baml_types::{BamlValue, LiteralValue};
 use indexmap::IndexMap;
 
 use internal_baml_parser_database::RetryPolicyStrategy;
@@ -60,20 +60,25 @@ impl<'a> Walker<'a, &'a FunctionNode> {
     }
 
     pub fn required_env_vars(&'a self) -> Result<HashSet<String>> {
-        if let Some(c) = self.elem().configs.first() {
+        if self.elem().configs.is_empty() {
+            anyhow::bail!("Function {} has no client", self.name())
+        }
+        // Every variant may be called, so the env vars of all their clients
+        // are needed.
+        let mut env_vars = HashSet::new();
+        for c in self.elem().configs.iter() {
             match &c.client {
                 repr::ClientSpec::Named(n) => {
                     let client: super::ClientWalker<'a> = self.db.find_client(n)?;
-                    Ok(client.required_env_vars())
+                    env_vars.extend(client.required_env_vars());
                 }
                 repr::ClientSpec::Shorthand(provider, _) => {
-                    let env_vars = provider_to_env_vars(provider);
-                    Ok(env_vars.into_iter().map(|(_, v)| v.to_string()).collect())
+                    let provider_env_vars = provider_to_env_vars(provider);
+                    env_vars.extend(provider_env_vars.into_iter().map(|(_, v)| v.to_string()));
                 }
             }
-        } else {
-            anyhow::bail!("Function {} has no client", self.name())
         }
+        Ok(env_vars)
     }
 
     pub fn walk_impls(
@@ -285,6 +290,14 @@ impl<'a> Walker<'a, (&'a FunctionNode, &'a Impl)> {
     }
 }
 
+impl<'a> Walker<'a, (&'a repr::Function, &'a FunctionConfig)> {
+    /// Whether this is the function's own prompt and client rather than one
+    /// of its named variants.
+    pub fn is_default(&self) -> bool {
+        self.item.1.name == self.item.0.default_config
+    }
+}
+
 impl<'a> Walker<'a, (&'a FunctionNode, &'a TestCase)> {
     pub fn matches(&self, function_name: &str, test_name: &str) -> bool {
         self.item.0.elem.name() == function_name && self.item.1.elem.name == test_name
@@ -337,6 +350,25 @@ impl<'a> Walker<'a, &'a Class> {
             .transpose()
     }
 
+    /// The field from `@@tag`, and its string literal value.
+    pub fn tag(&self) -> Option<(&'a str, &'a str)> {
+        let Some(Expression::String(tag)) = self.item.attributes.get("tag") else {
+            return None;
+        };
+        let field = self
+            .item
+            .elem
+            .static_fields
+            .iter()
+            .find(|f| &f.elem.name == tag)?;
+        match &field.elem.r#type.elem {
+            baml_types::FieldType::Literal(LiteralValue::String(value)) => {
+                Some((tag.as_str(), value.as_str()))
+            }
+            _ => None,
+        }
+    }
+
     pub fn walk_fields(&'a self) -> impl Iterator<Item = Walker<'a, &'a Field>> {
         self.item.elem.static_fields.iter().map(|f| Walker {
             db: self.db,
@@ -488,6 +520,53 @@ impl<'a> Walker<'a, &'a Field> {
             .transpose()
     }
 
+    /// The value of the field's `@default`, used when the LLM doesn't return
+    /// the field.
+    pub fn default_value(&self, env_values: &HashMap<String, String>) -> Result<Option<BamlValue>> {
+        self.item
+            .attributes
+            .get("default")
+            .map(|v| v.normalize(env_values))
+            .transpose()
+    }
+
+    /// Describes the field's `@min`, `@max`, `@min_length`, `@max_length`,
+    /// `@pattern` and `@one_of` to the LLM, e.g. `between 1 and 10`.
+    pub fn value_constraints_description(&self) -> Option<String> {
+        let value = |name: &str| {
+            self.item
+                .attributes
+                .get(name)
+                .and_then(|v| v.normalize(&Default::default()).ok())
+        };
+        let literal = |name: &str| value(name).and_then(|v| serde_json::to_string(&v).ok());
+        let range = |min: Option<String>, max: Option<String>| match (min, max) {
+            (Some(min), Some(max)) if min == max => Some(format!("exactly {min}")),
+            (Some(min), Some(max)) => Some(format!("between {min} and {max}")),
+            (Some(min), None) => Some(format!("at least {min}")),
+            (None, Some(max)) => Some(format!("at most {max}")),
+            (None, None) => None,
+        };
+
+        let mut parts = vec![];
+        parts.extend(range(literal("min"), literal("max")));
+        parts.extend(
+            range(literal("min_length"), literal("max_length"))
+                .map(|range| format!("length {range}")),
+        );
+        if let Some(BamlValue::String(pattern)) = value("pattern") {
+            parts.push(format!("matching the regex {pattern}"));
+        }
+        if let Some(BamlValue::List(values)) = value("one_of") {
+            let values = values
+                .iter()
+                .filter_map(|v| serde_json::to_string(v).ok())
+                .collect::<Vec<_>>();
+            parts.push(format!("one of {}", values.join(", ")));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
     pub fn span(&self) -> Option<&crate::Span> {
         self.item.attributes.span.as_ref()
     }
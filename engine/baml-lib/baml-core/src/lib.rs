@@ -10,6 +10,7 @@ pub use internal_baml_schema_ast::{self, ast};
 use rayon::prelude::*;
 use std::{path::PathBuf, sync::Mutex};
 
+use ast::{WithName, WithSpan};
 use internal_baml_diagnostics::{DatamodelError, Diagnostics, SourceFile, Span};
 
 mod common;
@@ -62,6 +63,149 @@ pub fn validate(root_path: &PathBuf, files: Vec<SourceFile>) -> ValidatedSchema
         });
     }
 
+    validate_db(root_path, db, diagnostics)
+}
+
+/// Validates `snippet`, a set of class and enum declarations added at runtime
+/// through a TypeBuilder, together with the rest of the schema. A declaration
+/// that shares its name with a `@@dynamic` class or enum extends it instead of
+/// declaring a new type.
+///
+/// Along with the validated schema, returns the name of every type the
+/// snippet declares or extends and the fields or values it declares for it.
+pub fn validate_type_builder_snippet(
+    root_path: &PathBuf,
+    files: Vec<SourceFile>,
+    snippet: &SourceFile,
+) -> (ValidatedSchema, Vec<(String, Vec<String>)>) {
+    let mut diagnostics = Diagnostics::new(root_path.clone());
+    let mut tops = Vec::new();
+
+    for file in files.iter() {
+        match internal_baml_schema_ast::parse_schema(root_path, file) {
+            Ok((ast, err)) => {
+                diagnostics.push(err);
+                tops.extend(ast.tops);
+            }
+            Err(err) => diagnostics.push(err),
+        }
+    }
+
+    let mut declared = Vec::new();
+    match internal_baml_schema_ast::parse_schema(root_path, snippet) {
+        Ok((ast, err)) => {
+            diagnostics.push(err);
+            for top in ast.tops {
+                let is_class = matches!(top, ast::Top::Class(_));
+                let block = match top {
+                    ast::Top::Class(block) | ast::Top::Enum(block) => block,
+                    other => {
+                        diagnostics.push_error(DatamodelError::new_validation_error(
+                            &format!(
+                                "Only classes and enums can be added through a TypeBuilder, found a {}.",
+                                other.get_type()
+                            ),
+                            other.span().clone(),
+                        ));
+                        continue;
+                    }
+                };
+
+                // The overrides a TypeBuilder produces only carry types,
+                // aliases, descriptions and skips.
+                let unsupported = block
+                    .attributes
+                    .iter()
+                    .filter(|a| matches!(a.name(), "tag" | "assert" | "check"))
+                    .map(|a| (format!("@@{}", a.name()), a))
+                    .chain(
+                        block
+                            .fields
+                            .iter()
+                            .flat_map(|f| f.attributes.iter())
+                            .filter(|a| {
+                                matches!(
+                                    a.name(),
+                                    "default"
+                                        | "min"
+                                        | "max"
+                                        | "min_length"
+                                        | "max_length"
+                                        | "pattern"
+                                        | "one_of"
+                                )
+                            })
+                            .map(|a| (format!("@{}", a.name()), a)),
+                    );
+                for (name, attribute) in unsupported {
+                    diagnostics.push_error(DatamodelError::new_validation_error(
+                        &format!("`{}` can't be added through a TypeBuilder.", name),
+                        attribute.span.clone(),
+                    ));
+                }
+
+                declared.push((
+                    block.name().to_string(),
+                    block.fields.iter().map(|f| f.name().to_string()).collect(),
+                ));
+
+                let existing = tops.iter_mut().find_map(|top| match top {
+                    ast::Top::Class(existing) if is_class && existing.name() == block.name() => {
+                        Some(existing)
+                    }
+                    ast::Top::Enum(existing) if !is_class && existing.name() == block.name() => {
+                        Some(existing)
+                    }
+                    _ => None,
+                });
+
+                let Some(existing) = existing else {
+                    tops.push(match is_class {
+                        true => ast::Top::Class(block),
+                        false => ast::Top::Enum(block),
+                    });
+                    continue;
+                };
+
+                if !existing.attributes.iter().any(|a| a.name() == "dynamic") {
+                    diagnostics.push_error(DatamodelError::new_validation_error(
+                        &format!(
+                            "`{}` is not marked `@@dynamic`, so it can't be changed at runtime.",
+                            block.name()
+                        ),
+                        block.name.span().clone(),
+                    ));
+                    continue;
+                }
+                if let Some(attribute) = block.attributes.first() {
+                    diagnostics.push_error(DatamodelError::new_validation_error(
+                        &format!(
+                            "Block attributes can't be added to the existing type `{}`.",
+                            block.name()
+                        ),
+                        attribute.span.clone(),
+                    ));
+                    continue;
+                }
+                existing.fields.extend(block.fields);
+            }
+        }
+        Err(err) => diagnostics.push(err),
+    }
+
+    let mut db = internal_baml_parser_database::ParserDatabase::new();
+    db.add_ast(ast::SchemaAst { tops });
+
+    (validate_db(root_path, db, diagnostics), declared)
+}
+
+/// Runs name and type resolution, configuration loading and the validation
+/// pipeline over the ASTs already added to `db`.
+fn validate_db(
+    root_path: &PathBuf,
+    mut db: internal_baml_parser_database::ParserDatabase,
+    mut diagnostics: Diagnostics,
+) -> ValidatedSchema {
     if let Err(d) = db.validate(&mut diagnostics) {
         return ValidatedSchema {
             db,
@@ -55,6 +55,20 @@ fn parse_optional_key<'a>(
     }
 }
 
+fn parse_optional_bool_key(
+    map: &HashMap<&str, &ast::Expression>,
+    key: &str,
+) -> Result<Option<bool>, DatamodelError> {
+    match map.get(key) {
+        Some(ast::Expression::BoolValue(value, _)) => Ok(Some(*value)),
+        Some(expr) => Err(DatamodelError::new_validation_error(
+            &format!("`{}` must be true or false.", key),
+            expr.span().clone(),
+        )),
+        None => Ok(None),
+    }
+}
+
 pub(crate) fn parse_generator(
     ast_generator: &ast::ValueExprBlock,
     baml_src: &PathBuf,
@@ -70,11 +84,13 @@ pub(crate) fn parse_generator(
 
     let args = check_property_allowlist(generator_name, ast_generator)?;
     let mut errors = vec![];
+    let mut output_type = None;
 
     match parse_required_key(&args, "output_type", ast_generator.span()) {
         Ok((name, name_span)) => match GeneratorOutputType::from_str(name) {
             Ok(lang) => {
                 builder.output_type(lang);
+                output_type = Some(lang);
             }
             Err(_) => {
                 const BOUNDARY_CLOUD_OUTPUT_TYPE: &str = "boundary-cloud";
@@ -183,6 +199,25 @@ pub(crate) fn parse_generator(
         }
     }
 
+    match parse_optional_bool_key(&args, "zod") {
+        Ok(Some(zod)) => {
+            if zod && !matches!(output_type, Some(GeneratorOutputType::Typescript)) {
+                errors.push(DatamodelError::new_validation_error(
+                    "`zod` is only supported by the typescript generator.",
+                    args.get("zod")
+                        .map(|arg| arg.span())
+                        .unwrap_or_else(|| ast_generator.span())
+                        .clone(),
+                ));
+            }
+            builder.zod(zod);
+        }
+        Ok(None) => {}
+        Err(err) => {
+            errors.push(err);
+        }
+    }
+
     if !errors.is_empty() {
         return Err(errors);
     }
@@ -269,6 +304,7 @@ fn check_property_allowlist<'ir>(
         "default_client_mode",
         "on_generate",
         "project",
+        "zod",
     ];
 
     let mut errors = vec![];
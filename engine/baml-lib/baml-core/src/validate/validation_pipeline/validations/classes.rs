@@ -1,8 +1,9 @@
-use baml_types::GeneratorOutputType;
+use baml_types::{iso8601, BamlValue, GeneratorOutputType, LiteralValue, TypeValue};
+use internal_baml_parser_database::walkers::FieldWalker;
 use internal_baml_schema_ast::ast::{Field, FieldType, WithName, WithSpan};
 
 use super::types::validate_type;
-use crate::validate::validation_pipeline::context::Context;
+use crate::{ir::repr::WithRepr, validate::validation_pipeline::context::Context};
 use internal_baml_diagnostics::DatamodelError;
 
 use itertools::join;
@@ -19,6 +20,7 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             if let Some(ft) = &field.expr {
                 validate_type(ctx, &ft);
             }
+            validate_default_value(ctx, c);
         }
 
         for args in cls.walk_input_args() {
@@ -50,6 +52,88 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
     }
 }
 
+/// Checks that the `@default` of a field is a value of the field's type.
+fn validate_default_value(ctx: &mut Context<'_>, field: FieldWalker<'_>) {
+    let Some(value) = field
+        .get_default_attributes()
+        .and_then(|attributes| attributes.default_value().as_ref())
+    else {
+        return;
+    };
+    let Some(field_type) = field.ast_field().expr.as_ref() else {
+        return;
+    };
+
+    // Defaults are resolved when the schema is loaded, so they can't read
+    // environment variables.
+    let resolved_type = field_type.repr(ctx.db);
+    let is_valid = match (
+        value
+            .repr(ctx.db)
+            .and_then(|value| value.normalize(&HashMap::new())),
+        &resolved_type,
+    ) {
+        (Ok(value), Ok(field_type)) => is_value_of_type(ctx, &value, field_type),
+        _ => false,
+    };
+    if !is_valid {
+        let type_name = match &resolved_type {
+            Ok(field_type) => field_type.to_string(),
+            Err(_) => field_type.name(),
+        };
+        ctx.push_error(DatamodelError::new_validation_error(
+            &format!(
+                "The default value `{}` is not a valid `{}`",
+                value, type_name
+            ),
+            value.span().clone(),
+        ));
+    }
+}
+
+fn is_value_of_type(
+    ctx: &Context<'_>,
+    value: &BamlValue,
+    field_type: &baml_types::FieldType,
+) -> bool {
+    use baml_types::FieldType as T;
+
+    match (field_type, value) {
+        (T::Constrained { base, .. }, _) => is_value_of_type(ctx, value, base),
+        (T::Optional(_), BamlValue::Null) => true,
+        (T::Optional(inner), _) => is_value_of_type(ctx, value, inner),
+        (T::Union(options), _) => options.iter().any(|t| is_value_of_type(ctx, value, t)),
+        (T::Primitive(TypeValue::String), BamlValue::String(_)) => true,
+        (T::Primitive(TypeValue::Int), BamlValue::Int(_)) => true,
+        (T::Primitive(TypeValue::Float), BamlValue::Int(_) | BamlValue::Float(_)) => true,
+        (T::Primitive(TypeValue::Bool), BamlValue::Bool(_)) => true,
+        (T::Primitive(TypeValue::Null), BamlValue::Null) => true,
+        (T::Primitive(TypeValue::Date), BamlValue::String(s)) => iso8601::parse_date(s).is_some(),
+        (T::Primitive(TypeValue::DateTime), BamlValue::String(s)) => {
+            iso8601::parse_datetime(s).is_some()
+        }
+        (T::Primitive(TypeValue::Duration), BamlValue::String(s)) => {
+            iso8601::Duration::parse(s).is_some()
+        }
+        (T::Literal(LiteralValue::String(a)), BamlValue::String(b)) => a == b,
+        (T::Literal(LiteralValue::Int(a)), BamlValue::Int(b)) => a == b,
+        (T::Literal(LiteralValue::Bool(a)), BamlValue::Bool(b)) => a == b,
+        (T::Enum(name), BamlValue::String(v)) => ctx
+            .db
+            .find_type_by_str(name)
+            .and_then(|t| t.right())
+            .is_some_and(|e| e.values().any(|value| value.name() == v)),
+        (T::List(inner), BamlValue::List(items)) => {
+            items.iter().all(|item| is_value_of_type(ctx, item, inner))
+        }
+        (T::Map(_, inner), BamlValue::Map(items)) => items
+            .values()
+            .all(|item| is_value_of_type(ctx, item, inner)),
+        // Class, media and tuple defaults can't be written down yet.
+        _ => false,
+    }
+}
+
 /// Enforce that keywords in the user's requested target languages
 /// do not appear as field names in BAML classes, and that field
 /// names are not equal to type names when using Pydantic.
@@ -130,6 +214,14 @@ fn reserved_names(
         } else {
             Vec::new()
         },
+        if generator_output_types.contains(&GeneratorOutputType::Rust) {
+            RESERVED_NAMES_RUST
+                .into_iter()
+                .map(|name| (*name, GeneratorOutputType::Rust))
+                .collect()
+        } else {
+            Vec::new()
+        },
     ]
     .iter()
     .flatten()
@@ -160,3 +252,7 @@ const RESERVED_NAMES_PYTHON: &[&str] = &[
 
 // Typescript is much more flexible in the key names it allows.
 const RESERVED_NAMES_TYPESCRIPT: &[&str] = &[];
+
+// Other Rust keywords are generated as raw identifiers (`r#type`), which
+// these can't be.
+const RESERVED_NAMES_RUST: &[&str] = &["crate", "self", "Self", "super"];
@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 
-use crate::validate::validation_pipeline::context::Context;
+use crate::{ir::repr::DEFAULT_VARIANT, validate::validation_pipeline::context::Context};
 
 use either::Either;
 use internal_baml_diagnostics::{DatamodelError, DatamodelWarning, Span};
 
-use internal_baml_schema_ast::ast::{FieldType, TypeExpId, WithIdentifier, WithName, WithSpan};
+use internal_baml_parser_database::walkers::FunctionWalker;
+use internal_baml_schema_ast::ast::{
+    FieldType, RawString, TypeExpId, WithIdentifier, WithName, WithSpan,
+};
 
 use super::types::validate_type;
 
@@ -138,6 +141,8 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             }
         }
 
+        validate_variants(ctx, func);
+
         let prompt = match func.metadata().prompt.as_ref() {
             Some(prompt) => prompt,
             None => {
@@ -166,55 +171,107 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
 
             defined_types.add_variable(&name, field_type);
         });
-        match internal_baml_jinja_types::validate_template(
-            func.name(),
-            prompt.raw_value(),
-            &mut defined_types,
-        ) {
-            Ok(_) => {}
-            Err(e) => {
-                let pspan = prompt.span();
-                if let Some(e) = e.parsing_errors {
-                    let range = match e.range() {
-                        Some(range) => range,
-                        None => {
-                            ctx.push_error(DatamodelError::new_validation_error(
-                                &format!("Error parsing jinja template: {}", e),
-                                pspan.clone(),
-                            ));
-                            continue;
-                        }
-                    };
+        validate_prompt(ctx, func.name(), prompt, &mut defined_types);
+        for variant in func.variants() {
+            if let Some(prompt) = &variant.prompt {
+                validate_prompt(ctx, func.name(), prompt, &mut defined_types);
+            }
+        }
+        defined_types.end_scope();
+        defined_types.errors_mut().clear();
+    }
+}
 
-                    let start_offset = pspan.start + range.start;
-                    let end_offset = pspan.start + range.end;
+fn validate_variants(ctx: &mut Context<'_>, func: FunctionWalker<'_>) {
+    let mut names = HashSet::new();
+    let mut total_percent = 0;
+    for variant in func.variants() {
+        let (name, span) = &variant.name;
+        if name == DEFAULT_VARIANT {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "`{}` is the name of the function's own prompt and client",
+                    DEFAULT_VARIANT
+                ),
+                span.clone(),
+            ));
+        } else if !names.insert(name.as_str()) {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!("Duplicate variant `{}` in function `{}`", name, func.name()),
+                span.clone(),
+            ));
+        }
 
-                    let span = Span::new(
-                        pspan.file.clone(),
-                        start_offset as usize,
-                        end_offset as usize,
-                    );
+        if variant.client.is_some() {
+            if let Err(e) = func.variant_client_spec(variant) {
+                ctx.push_error(e);
+            }
+        }
+
+        if let Some((percent, span)) = &variant.percent {
+            total_percent += percent;
+            if total_percent > 100 {
+                ctx.push_error(DatamodelError::new_validation_error(
+                    &format!(
+                        "The variants of `{}` add up to {}%, but can take at most 100% of calls",
+                        func.name(),
+                        total_percent
+                    ),
+                    span.clone(),
+                ));
+            }
+        }
+    }
+}
 
+fn validate_prompt(
+    ctx: &mut Context<'_>,
+    name: &str,
+    prompt: &RawString,
+    defined_types: &mut internal_baml_jinja_types::PredefinedTypes,
+) {
+    if let Err(e) =
+        internal_baml_jinja_types::validate_template(name, prompt.raw_value(), defined_types)
+    {
+        let pspan = prompt.span();
+        if let Some(e) = e.parsing_errors {
+            let range = match e.range() {
+                Some(range) => range,
+                None => {
                     ctx.push_error(DatamodelError::new_validation_error(
                         &format!("Error parsing jinja template: {}", e),
-                        span,
-                    ))
-                } else {
-                    e.errors.iter().for_each(|t| {
-                        let span = t.span();
-                        let span = Span::new(
-                            pspan.file.clone(),
-                            pspan.start + span.start_offset as usize,
-                            pspan.start + span.end_offset as usize,
-                        );
-                        ctx.push_warning(DatamodelWarning::new(t.message().to_string(), span))
-                    })
+                        pspan.clone(),
+                    ));
+                    return;
                 }
-            }
+            };
+
+            let start_offset = pspan.start + range.start;
+            let end_offset = pspan.start + range.end;
+
+            let span = Span::new(
+                pspan.file.clone(),
+                start_offset as usize,
+                end_offset as usize,
+            );
+
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!("Error parsing jinja template: {}", e),
+                span,
+            ))
+        } else {
+            e.errors.iter().for_each(|t| {
+                let span = t.span();
+                let span = Span::new(
+                    pspan.file.clone(),
+                    pspan.start + span.start_offset as usize,
+                    pspan.start + span.end_offset as usize,
+                );
+                ctx.push_warning(DatamodelWarning::new(t.message().to_string(), span))
+            })
         }
-        defined_types.end_scope();
-        defined_types.errors_mut().clear();
     }
+    defined_types.errors_mut().clear();
 }
 
 /// Just syntactic sugar for the recursive check.
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use baml_types::{LiteralValue, TypeValue};
 use either::Either;
@@ -126,11 +126,44 @@ fn validate_type_allowed(ctx: &mut Context<'_>, field_type: &FieldType) {
             }
             validate_type_allowed(ctx, field_type)
         }
-        FieldType::Tuple(_, field_types, ..) | FieldType::Union(_, field_types, ..) => {
+        FieldType::Union(_, field_types, ..) => {
+            validate_union_tags(ctx, field_types);
             for field_type in field_types {
                 validate_type_allowed(ctx, field_type);
             }
         }
+        FieldType::Tuple(_, field_types, ..) => {
+            for field_type in field_types {
+                validate_type_allowed(ctx, field_type);
+            }
+        }
+    }
+}
+
+/// Classes in a union that are tagged on the same field need different tag
+/// values, or the tag can't tell them apart.
+fn validate_union_tags(ctx: &mut Context<'_>, options: &[FieldType]) {
+    let db = ctx.db;
+    let mut tagged = HashMap::new();
+    for option in options {
+        let FieldType::Symbol(_, identifier, _) = option else {
+            continue;
+        };
+        let Some(Either::Left(class)) = db.find_type(identifier) else {
+            continue;
+        };
+        let Some((field, value)) = class.tag() else {
+            continue;
+        };
+        if let Some(other) = tagged.insert((field, value), class.name().to_string()) {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "`{other}` and `{}` are both tagged `{field} \"{value}\"`, so this union can't tell them apart.",
+                    class.name()
+                ),
+                option.span().clone(),
+            ));
+        }
     }
 }
 
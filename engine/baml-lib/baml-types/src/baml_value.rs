@@ -101,6 +101,8 @@ impl BamlValue {
             BamlValue::Media(m) => match m.media_type {
                 BamlMediaType::Image => "image",
                 BamlMediaType::Audio => "audio",
+                BamlMediaType::Pdf => "pdf",
+                BamlMediaType::Video => "video",
             }
             .into(),
             BamlValue::Enum(e, _) => format!("enum {}", e),
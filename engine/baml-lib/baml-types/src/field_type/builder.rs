@@ -33,6 +33,18 @@ impl FieldType {
         FieldType::Primitive(TypeValue::Null)
     }
 
+    pub fn date() -> Self {
+        FieldType::Primitive(TypeValue::Date)
+    }
+
+    pub fn datetime() -> Self {
+        FieldType::Primitive(TypeValue::DateTime)
+    }
+
+    pub fn duration() -> Self {
+        FieldType::Primitive(TypeValue::Duration)
+    }
+
     pub fn image() -> Self {
         FieldType::Primitive(TypeValue::Media(BamlMediaType::Image))
     }
@@ -12,6 +12,12 @@ pub enum TypeValue {
     // Char,
     Null,
     Media(BamlMediaType),
+    /// A calendar date, canonically `YYYY-MM-DD`.
+    Date,
+    /// A point in time, canonically RFC 3339 (`YYYY-MM-DDTHH:MM:SSZ`).
+    DateTime,
+    /// A length of time, canonically ISO 8601 (`PT2H30M`).
+    Duration,
 }
 impl TypeValue {
     pub fn from_str(s: &str) -> Option<TypeValue> {
@@ -23,6 +29,11 @@ impl TypeValue {
             "null" => Some(TypeValue::Null),
             "image" => Some(TypeValue::Media(BamlMediaType::Image)),
             "audio" => Some(TypeValue::Media(BamlMediaType::Audio)),
+            "pdf" => Some(TypeValue::Media(BamlMediaType::Pdf)),
+            "video" => Some(TypeValue::Media(BamlMediaType::Video)),
+            "date" => Some(TypeValue::Date),
+            "datetime" => Some(TypeValue::DateTime),
+            "duration" => Some(TypeValue::Duration),
             _ => None,
         }
     }
@@ -38,6 +49,11 @@ impl std::fmt::Display for TypeValue {
             TypeValue::Null => write!(f, "null"),
             TypeValue::Media(BamlMediaType::Image) => write!(f, "image"),
             TypeValue::Media(BamlMediaType::Audio) => write!(f, "audio"),
+            TypeValue::Media(BamlMediaType::Pdf) => write!(f, "pdf"),
+            TypeValue::Media(BamlMediaType::Video) => write!(f, "video"),
+            TypeValue::Date => write!(f, "date"),
+            TypeValue::DateTime => write!(f, "datetime"),
+            TypeValue::Duration => write!(f, "duration"),
         }
     }
 }
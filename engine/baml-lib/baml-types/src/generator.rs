@@ -22,6 +22,15 @@ pub enum GeneratorOutputType {
 
     #[strum(serialize = "ruby/sorbet")]
     RubySorbet,
+
+    #[strum(serialize = "rust")]
+    Rust,
+
+    #[strum(serialize = "go")]
+    Go,
+
+    #[strum(serialize = "json-schema")]
+    JsonSchema,
 }
 
 impl std::hash::Hash for GeneratorOutputType {
@@ -40,6 +49,11 @@ impl GeneratorOutputType {
             Self::PythonPydantic => GeneratorDefaultClientMode::Async,
             Self::Typescript => GeneratorDefaultClientMode::Async,
             Self::RubySorbet => GeneratorDefaultClientMode::Sync,
+            // Only async functions are generated for Rust.
+            Self::Rust => GeneratorDefaultClientMode::Async,
+            // Go calls are blocking; concurrency is left to goroutines.
+            Self::Go => GeneratorDefaultClientMode::Sync,
+            Self::JsonSchema => GeneratorDefaultClientMode::Sync,
         }
     }
 
@@ -50,6 +64,9 @@ impl GeneratorOutputType {
             Self::PythonPydantic => GeneratorDefaultClientMode::Sync,
             Self::Typescript => GeneratorDefaultClientMode::Async,
             Self::RubySorbet => GeneratorDefaultClientMode::Sync,
+            Self::Rust => GeneratorDefaultClientMode::Async,
+            Self::Go => GeneratorDefaultClientMode::Sync,
+            Self::JsonSchema => GeneratorDefaultClientMode::Sync,
         }
     }
 }
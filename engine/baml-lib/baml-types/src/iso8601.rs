@@ -0,0 +1,217 @@
+//! Canonical ISO 8601 forms of the `date`, `datetime` and `duration` types.
+//!
+//! Values of these types are strings in their canonical form everywhere in
+//! the runtime, and only become native types in the generated clients.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat};
+
+/// Parses a `YYYY-MM-DD` date.
+pub fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Parses an ISO 8601 date and time, with an optional UTC offset. A space may
+/// be used instead of the `T`, and the seconds may be left out.
+pub fn parse_datetime(s: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+    let s = s.replacen(' ', "T", 1);
+    for fmt in ["%Y-%m-%dT%H:%M:%S%.f%#z", "%Y-%m-%dT%H:%M%#z"] {
+        if let Ok(dt) = DateTime::parse_from_str(&s, fmt) {
+            return Some((dt.naive_local(), Some(*dt.offset())));
+        }
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(&s, fmt).ok())
+        .map(|dt| (dt, None))
+}
+
+/// Formats a date and time as RFC 3339, using `Z` for UTC. Times without an
+/// offset are kept without one.
+pub fn format_datetime(dt: NaiveDateTime, offset: Option<FixedOffset>) -> String {
+    match offset.and_then(|offset| dt.and_local_timezone(offset).single()) {
+        Some(dt) => dt.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        None => dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string(),
+    }
+}
+
+/// Formats milliseconds since the Unix epoch as an RFC 3339 datetime at a
+/// UTC offset of `offset_seconds` east, using `Z` for UTC. Keeping the offset
+/// of the caller's time zone keeps the date it sees: local midnight is not
+/// the day before in UTC.
+pub fn format_timestamp_millis(millis: i64, offset_seconds: i32) -> Option<String> {
+    let offset = FixedOffset::east_opt(offset_seconds)?;
+    DateTime::from_timestamp_millis(millis).map(|dt| {
+        dt.with_timezone(&offset)
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    })
+}
+
+/// A length of time. Months and days are kept apart from the time, since
+/// their length depends on the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Duration {
+    pub months: u64,
+    pub days: u64,
+    pub seconds: f64,
+}
+
+impl Duration {
+    /// Adds `amount` of `unit`, which is one of `Y`, `M`, `W`, `D`, `h`, `m`
+    /// or `s`. Fractions carry into the next smaller unit that has a fixed
+    /// length, so fractions of a month are rejected.
+    pub fn add(&mut self, amount: f64, unit: char) -> Option<()> {
+        if !amount.is_finite() || amount < 0.0 {
+            return None;
+        }
+        let whole = |n: f64| (n.fract() == 0.0).then_some(n as u64);
+        match unit {
+            'Y' => self.months += whole(amount * 12.0)?,
+            'M' => self.months += whole(amount)?,
+            'W' => return self.add(amount * 7.0, 'D'),
+            'D' => {
+                self.days += amount.trunc() as u64;
+                self.seconds += amount.fract() * 86400.0;
+            }
+            'h' => self.seconds += amount * 3600.0,
+            'm' => self.seconds += amount * 60.0,
+            's' => self.seconds += amount,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// Parses an ISO 8601 duration like `P1DT2H30M`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let rest = s.trim().strip_prefix('P')?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if !time.is_empty() => (date, Some(time)),
+            Some(_) => return None,
+            None => (rest, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return None;
+        }
+
+        let mut duration = Duration::default();
+        for (part, units) in [(date, "YMWD"), (time.unwrap_or_default(), "HMS")] {
+            let mut rest = part;
+            let mut allowed = units;
+            while !rest.is_empty() {
+                let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+                let amount = rest[..end].replace(',', ".").parse::<f64>().ok()?;
+                let unit = rest[end..].chars().next()?;
+                // Units must come in order, and at most once each.
+                allowed = &allowed[allowed.find(unit)? + 1..];
+                let unit = match (units, unit) {
+                    ("HMS", 'H') => 'h',
+                    ("HMS", 'M') => 'm',
+                    ("HMS", 'S') => 's',
+                    (_, unit) => unit,
+                };
+                duration.add(amount, unit)?;
+                rest = &rest[end + 1..];
+            }
+        }
+        Some(duration)
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "P")?;
+        let (years, months) = (self.months / 12, self.months % 12);
+        for (amount, unit) in [(years, 'Y'), (months, 'M'), (self.days, 'D')] {
+            if amount > 0 {
+                write!(f, "{amount}{unit}")?;
+            }
+        }
+
+        // Round away float noise from fractions of larger units.
+        let seconds = (self.seconds * 1e6).round() / 1e6;
+        let hours = (seconds / 3600.0).trunc();
+        let minutes = ((seconds - hours * 3600.0) / 60.0).trunc();
+        let seconds = seconds - hours * 3600.0 - minutes * 60.0;
+        if hours > 0.0 || minutes > 0.0 || seconds > 0.0 {
+            write!(f, "T")?;
+            if hours > 0.0 {
+                write!(f, "{hours}H")?;
+            }
+            if minutes > 0.0 {
+                write!(f, "{minutes}M")?;
+            }
+            if seconds > 0.0 {
+                write!(f, "{}S", (seconds * 1e6).round() / 1e6)?;
+            }
+        } else if self.months == 0 && self.days == 0 {
+            write!(f, "T0S")?;
+        }
+        Ok(())
+    }
+}
+
+/// The canonical form of a `date`, or `None` if `s` isn't `YYYY-MM-DD`.
+pub fn canonical_date(s: &str) -> Option<String> {
+    parse_date(s).map(format_date)
+}
+
+/// The canonical form of a `datetime`, or `None` if `s` isn't ISO 8601.
+pub fn canonical_datetime(s: &str) -> Option<String> {
+    parse_datetime(s).map(|(dt, offset)| format_datetime(dt, offset))
+}
+
+/// The canonical form of a `duration`, or `None` if `s` isn't ISO 8601.
+pub fn canonical_duration(s: &str) -> Option<String> {
+    Duration::parse(s).map(|d| d.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_forms() {
+        assert_eq!(canonical_date("2024-03-03"), Some("2024-03-03".into()));
+        assert_eq!(canonical_date("2024-02-30"), None);
+        assert_eq!(canonical_date("March 3, 2024"), None);
+
+        assert_eq!(
+            canonical_datetime("2024-03-03T10:30:00Z"),
+            Some("2024-03-03T10:30:00Z".into())
+        );
+        assert_eq!(
+            canonical_datetime("2024-03-03 10:30+02:00"),
+            Some("2024-03-03T10:30:00+02:00".into())
+        );
+        assert_eq!(
+            canonical_datetime("2024-03-03T10:30:00.5"),
+            Some("2024-03-03T10:30:00.500".into())
+        );
+        assert_eq!(canonical_datetime("2024-03-03"), None);
+        assert_eq!(
+            format_timestamp_millis(1709461800000, 0),
+            Some("2024-03-03T10:30:00Z".into())
+        );
+        assert_eq!(
+            format_timestamp_millis(1709416800000, 2 * 3600),
+            Some("2024-03-03T00:00:00+02:00".into())
+        );
+
+        assert_eq!(canonical_duration("PT2H"), Some("PT2H".into()));
+        assert_eq!(canonical_duration("PT90M"), Some("PT1H30M".into()));
+        assert_eq!(canonical_duration("P1Y14M2W"), Some("P2Y2M14D".into()));
+        assert_eq!(canonical_duration("P1.5D"), Some("P1DT12H".into()));
+        assert_eq!(canonical_duration("PT0,5S"), Some("PT0.5S".into()));
+        assert_eq!(canonical_duration("P0D"), Some("PT0S".into()));
+        assert_eq!(canonical_duration("PT"), None);
+        assert_eq!(canonical_duration("PT2M1H"), None);
+        assert_eq!(canonical_duration("2 hours"), None);
+    }
+}
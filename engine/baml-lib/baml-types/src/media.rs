@@ -7,6 +7,8 @@ use std::{borrow::Cow, fmt, path::PathBuf};
 pub enum BamlMediaType {
     Image,
     Audio,
+    Pdf,
+    Video,
 }
 
 impl fmt::Display for BamlMediaType {
@@ -14,6 +16,19 @@ impl fmt::Display for BamlMediaType {
         match *self {
             BamlMediaType::Image => write!(f, "image"),
             BamlMediaType::Audio => write!(f, "audio"),
+            BamlMediaType::Pdf => write!(f, "pdf"),
+            BamlMediaType::Video => write!(f, "video"),
+        }
+    }
+}
+
+impl BamlMediaType {
+    /// Best-effort mime type for a file with the given extension, used when
+    /// the user did not specify one.
+    pub fn mime_type_for_extension(&self, ext: &str) -> String {
+        match self {
+            BamlMediaType::Pdf => "application/pdf".to_string(),
+            _ => format!("{}/{}", self, ext),
         }
     }
 }
@@ -0,0 +1,35 @@
+/// How leniently LLM output is parsed into a function's return type.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    strum::VariantNames,
+    serde::Serialize,
+)]
+pub enum ParserMode {
+    /// Only valid JSON, and values must match the types exactly.
+    #[strum(serialize = "strict")]
+    #[serde(rename = "strict")]
+    Strict,
+    /// JSON may be wrapped in markdown or other text, but is not repaired.
+    #[strum(serialize = "no_fixes")]
+    #[serde(rename = "no_fixes")]
+    NoFixes,
+    /// Every heuristic of the parser is used.
+    #[default]
+    #[strum(serialize = "lenient")]
+    #[serde(rename = "lenient")]
+    Lenient,
+}
+
+impl ParserMode {
+    /// The names of all modes, as written in BAML files.
+    pub fn names() -> &'static [&'static str] {
+        <Self as strum::VariantNames>::VARIANTS
+    }
+}
@@ -415,8 +415,13 @@ impl DatamodelError {
             "float".to_string(),
             "bool".to_string(),
             "string".to_string(),
+            "date".to_string(),
+            "datetime".to_string(),
+            "duration".to_string(),
             "image".to_string(),
             "audio".to_string(),
+            "pdf".to_string(),
+            "video".to_string(),
             "null".to_string(),
         ];
         names.extend(primitives);
@@ -441,6 +441,7 @@ mod render_tests {
 
     use super::*;
 
+    use crate::types::ResponseFormat;
     use baml_types::{BamlMap, BamlMediaType};
     use env_logger;
     use indexmap::IndexMap;
@@ -1017,6 +1018,53 @@ mod render_tests {
         Ok(())
     }
 
+    #[test]
+    fn render_records_the_output_format_used() -> anyhow::Result<()> {
+        setup_logging();
+
+        let args: BamlValue = BamlValue::Map(BamlMap::from([(
+            "fmt".to_string(),
+            BamlValue::String("yaml".to_string()),
+        )]));
+        let ir = make_test_ir("class C {\n}")?;
+        let client = RenderContext_Client {
+            name: "gpt4".to_string(),
+            provider: "openai".to_string(),
+            default_role: "system".to_string(),
+        };
+
+        for (template, format) in [
+            ("{{ ctx.output_format }}", Some(ResponseFormat::Json)),
+            ("{{ Schema() }}", Some(ResponseFormat::Yaml)),
+            (
+                "{{ ctx.output_format(format=fmt) }}",
+                Some(ResponseFormat::Yaml),
+            ),
+            ("No schema (see output_format(format=\"xml\"))", None),
+        ] {
+            let output_format = OutputFormatContent::new_array();
+            render_prompt(
+                template,
+                &args,
+                RenderContext {
+                    client: client.clone(),
+                    output_format: output_format.clone(),
+                    tags: HashMap::new(),
+                },
+                &[TemplateStringMacro {
+                    name: "Schema".to_string(),
+                    args: vec![],
+                    template: "{{ ctx.output_format(format=\"yaml\") }}".to_string(),
+                }],
+                &ir,
+                &HashMap::new(),
+            )?;
+            assert_eq!(output_format.rendered_format(), format, "{template}");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn render_with_kwargs() -> anyhow::Result<()> {
         setup_logging();
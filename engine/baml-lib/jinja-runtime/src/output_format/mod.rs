@@ -162,10 +162,41 @@ impl minijinja::value::Object for OutputFormat {
             None
         };
 
+        let format = if kwargs.has("format") {
+            match kwargs
+                .get::<String>("format")
+                .map(|s| types::ResponseFormat::from_str(s.as_str()))
+            {
+                Ok(Ok(format)) => Some(format),
+                Ok(Err(e)) => {
+                    return Err(Error::new(
+                        ErrorKind::SyntaxError,
+                        format!(
+                            "Invalid value for format (expected one of {}): {}",
+                            types::ResponseFormat::VARIANTS.join(", "),
+                            e
+                        ),
+                    ))
+                }
+                Err(e) => {
+                    return Err(Error::new(
+                        ErrorKind::SyntaxError,
+                        format!(
+                            "Invalid value for format (expected one of {}): {}",
+                            types::ResponseFormat::VARIANTS.join(", "),
+                            e
+                        ),
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+
         let Ok(_) = kwargs.assert_all_used() else {
             return Err(Error::new(
                 ErrorKind::TooManyArguments,
-                "output_format() got an unexpected keyword argument (only 'prefix', 'always_host_enums', 'enum_value_prefix', and 'or_splitter' are allowed)",
+                "output_format() got an unexpected keyword argument (only 'prefix', 'always_host_enums', 'enum_value_prefix', 'or_splitter', and 'format' are allowed)",
             ));
         };
 
@@ -176,6 +207,7 @@ impl minijinja::value::Object for OutputFormat {
             always_hoist_enums,
             map_style,
             hoisted_class_prefix,
+            format,
         ))?;
 
         match content {
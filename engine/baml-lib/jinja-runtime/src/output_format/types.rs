@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
-use baml_types::{Constraint, FieldType, TypeValue};
+use baml_types::{BamlValue, Constraint, FieldType, TypeValue};
 use indexmap::{IndexMap, IndexSet};
 
 #[derive(Debug)]
@@ -51,6 +51,11 @@ pub struct Class {
     // fields have name, type and description.
     pub fields: Vec<(Name, FieldType, Option<String>)>,
     pub constraints: Vec<Constraint>,
+    /// The `@default` of each field that has one, by field name.
+    pub defaults: IndexMap<String, BamlValue>,
+    /// The field from `@@tag`, whose literal value picks this class out of a
+    /// union of classes.
+    pub tag: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +64,12 @@ pub struct OutputFormatContent {
     pub classes: Arc<IndexMap<String, Class>>,
     recursive_classes: Arc<IndexSet<String>>,
     pub target: FieldType,
+    /// The format the parser expects the LLM to answer in.
+    pub format: ResponseFormat,
+    /// The format the last rendered `ctx.output_format` asked for. Clones
+    /// share it, so it can be read back from the content a prompt was
+    /// rendered with.
+    rendered_format: Arc<Mutex<Option<ResponseFormat>>>,
 }
 
 /// Builder for [`OutputFormatContent`].
@@ -68,6 +79,7 @@ pub struct Builder {
     /// Order matters for this one.
     recursive_classes: IndexSet<String>,
     target: FieldType,
+    format: ResponseFormat,
 }
 
 impl Builder {
@@ -77,6 +89,7 @@ impl Builder {
             classes: vec![],
             recursive_classes: IndexSet::new(),
             target,
+            format: ResponseFormat::Json,
         }
     }
 
@@ -100,6 +113,11 @@ impl Builder {
         self
     }
 
+    pub fn format(mut self, format: ResponseFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     pub fn build(self) -> OutputFormatContent {
         OutputFormatContent {
             enums: Arc::new(
@@ -116,6 +134,8 @@ impl Builder {
             ),
             recursive_classes: Arc::new(self.recursive_classes.into_iter().collect()),
             target: self.target,
+            format: self.format,
+            rendered_format: Default::default(),
         }
     }
 }
@@ -141,6 +161,31 @@ pub(crate) enum MapStyle {
     ObjectLiteral,
 }
 
+/// The syntax the LLM is asked to answer in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, strum::AsRefStr, strum::EnumString, strum::VariantNames,
+)]
+pub enum ResponseFormat {
+    #[strum(serialize = "json")]
+    Json,
+
+    #[strum(serialize = "yaml")]
+    Yaml,
+
+    #[strum(serialize = "xml")]
+    Xml,
+}
+
+impl ResponseFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            ResponseFormat::Json => "JSON",
+            ResponseFormat::Yaml => "YAML",
+            ResponseFormat::Xml => "XML",
+        }
+    }
+}
+
 pub(crate) struct RenderOptions {
     prefix: RenderSetting<String>,
     pub(crate) or_splitter: String,
@@ -148,6 +193,7 @@ pub(crate) struct RenderOptions {
     hoisted_class_prefix: RenderSetting<String>,
     always_hoist_enums: RenderSetting<bool>,
     map_style: MapStyle,
+    format: ResponseFormat,
 }
 
 impl Default for RenderOptions {
@@ -159,6 +205,7 @@ impl Default for RenderOptions {
             hoisted_class_prefix: RenderSetting::Auto,
             always_hoist_enums: RenderSetting::Auto,
             map_style: MapStyle::TypeParameters,
+            format: ResponseFormat::Json,
         }
     }
 }
@@ -174,6 +221,7 @@ impl RenderOptions {
         always_hoist_enums: Option<bool>,
         map_style: Option<MapStyle>,
         hoisted_class_prefix: Option<Option<String>>,
+        format: Option<ResponseFormat>,
     ) -> Self {
         Self {
             prefix: prefix.map_or(RenderSetting::Auto, |p| {
@@ -189,6 +237,7 @@ impl RenderOptions {
             hoisted_class_prefix: hoisted_class_prefix.map_or(RenderSetting::Auto, |p| {
                 p.map_or(RenderSetting::Never, RenderSetting::Always)
             }),
+            format: format.unwrap_or(ResponseFormat::Json),
         }
     }
 
@@ -199,6 +248,13 @@ impl RenderOptions {
 
         render_options
     }
+
+    pub(crate) fn with_format(format: ResponseFormat) -> Self {
+        let mut render_options = Self::default();
+        render_options.format = format;
+
+        render_options
+    }
 }
 
 struct Attribute {
@@ -270,6 +326,57 @@ impl std::fmt::Display for ClassRender {
     }
 }
 
+impl ClassRender {
+    fn render(&self, format: ResponseFormat) -> String {
+        match format {
+            ResponseFormat::Json => self.to_string(),
+            ResponseFormat::Yaml => self
+                .values
+                .iter()
+                .map(|value| {
+                    let mut field = String::new();
+                    if let Some(desc) = &value.description {
+                        field.push_str(&format!("# {}\n", desc.replace("\n", "\n# ")));
+                    }
+                    if value.r#type.contains('\n') {
+                        field.push_str(&format!(
+                            "{}:\n  {}",
+                            value.name,
+                            value.r#type.replace('\n', "\n  ")
+                        ));
+                    } else {
+                        field.push_str(&format!("{}: {}", value.name, value.r#type));
+                    }
+                    field
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ResponseFormat::Xml => self
+                .values
+                .iter()
+                .map(|value| {
+                    let mut field = String::new();
+                    if let Some(desc) = &value.description {
+                        field.push_str(&format!("<!-- {} -->\n", desc.replace("\n", "\n     ")));
+                    }
+                    field.push_str(&xml_element(&value.name, &value.r#type));
+                    field
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// `<name>content</name>`, with multi-line content indented on its own lines.
+fn xml_element(name: &str, content: &str) -> String {
+    if content.contains('\n') {
+        format!("<{name}>\n  {}\n</{name}>", content.replace('\n', "\n  "))
+    } else {
+        format!("<{name}>{content}</{name}>")
+    }
+}
+
 struct MapRender<'s> {
     style: &'s MapStyle,
     key_type: String,
@@ -331,16 +438,28 @@ impl OutputFormatContent {
                         "\n"
                     };
 
-                    Some(format!("Answer in JSON using this {type_prefix}:{end}"))
+                    Some(format!(
+                        "Answer in {} using this {type_prefix}:{end}",
+                        options.format.name()
+                    ))
                 }
-                FieldType::List(_) => Some(String::from(
-                    "Answer with a JSON Array using this schema:\n",
+                FieldType::List(_) => Some(match options.format {
+                    ResponseFormat::Json => {
+                        String::from("Answer with a JSON Array using this schema:\n")
+                    }
+                    ResponseFormat::Yaml => {
+                        String::from("Answer with a YAML list using this schema:\n")
+                    }
+                    ResponseFormat::Xml => String::from("Answer in XML using this schema:\n"),
+                }),
+                FieldType::Union(_) => Some(format!(
+                    "Answer in {} using any of these schemas:\n",
+                    options.format.name()
+                )),
+                FieldType::Optional(_) | FieldType::Map(_, _) => Some(format!(
+                    "Answer in {} using this schema:\n",
+                    options.format.name()
                 )),
-                FieldType::Union(_) => {
-                    Some(String::from("Answer in JSON using any of these schemas:\n"))
-                }
-                FieldType::Optional(_) => Some(String::from("Answer in JSON using this schema:\n")),
-                FieldType::Map(_, _) => Some(String::from("Answer in JSON using this schema:\n")),
                 FieldType::Tuple(_) => None,
                 FieldType::Constrained { base, .. } => {
                     auto_prefix(base, options, output_format_content)
@@ -415,6 +534,9 @@ impl OutputFormatContent {
                 TypeValue::Float => "float".to_string(),
                 TypeValue::Bool => "bool".to_string(),
                 TypeValue::Null => "null".to_string(),
+                TypeValue::Date => "date (YYYY-MM-DD)".to_string(),
+                TypeValue::DateTime => "datetime (YYYY-MM-DDTHH:MM:SSZ)".to_string(),
+                TypeValue::Duration => "duration (ISO 8601, like PT2H30M)".to_string(),
                 TypeValue::Media(media_type) => {
                     return Err(minijinja::Error::new(
                         minijinja::ErrorKind::BadSerialization,
@@ -460,12 +582,24 @@ impl OutputFormatContent {
                     ));
                 };
 
+                // The tag of a class goes first, so the LLM commits to a variant
+                // of a tagged union before the rest of its fields.
+                let is_tag = |name: &Name| class.tag.as_deref() == Some(name.real_name());
+                let fields = class.fields.iter().filter(|(name, ..)| is_tag(name));
+                let fields = fields.chain(class.fields.iter().filter(|(name, ..)| !is_tag(name)));
+
                 ClassRender {
                     name: class.name.rendered_name().to_string(),
-                    values: class
-                        .fields
-                        .iter()
+                    values: fields
                         .map(|(name, field_type, description)| {
+                            // The LLM may leave out fields that have a default.
+                            let optional;
+                            let field_type = if class.defaults.contains_key(name.real_name()) {
+                                optional = FieldType::optional(field_type.clone());
+                                &optional
+                            } else {
+                                field_type
+                            };
                             Ok(ClassFieldRender {
                                 name: name.rendered_name().to_string(),
                                 description: description.clone(),
@@ -479,7 +613,7 @@ impl OutputFormatContent {
                         })
                         .collect::<Result<_, minijinja::Error>>()?,
                 }
-                .to_string()
+                .render(options.format)
             }
             FieldType::List(inner) => {
                 let is_recursive = match inner.as_ref() {
@@ -490,7 +624,9 @@ impl OutputFormatContent {
                 let inner_str =
                     self.render_possibly_recursive_type(options, inner, render_state, false)?;
 
-                if !is_recursive
+                if options.format == ResponseFormat::Xml {
+                    format!("{}\n...", xml_element("item", &inner_str))
+                } else if !is_recursive
                     && match inner.as_ref() {
                         FieldType::Primitive(_) => false,
                         FieldType::Optional(t) => !t.is_primitive(),
@@ -498,7 +634,10 @@ impl OutputFormatContent {
                         _ => true,
                     }
                 {
-                    format!("[\n  {}\n]", inner_str.replace('\n', "\n  "))
+                    match options.format {
+                        ResponseFormat::Yaml => format!("- {}", inner_str.replace('\n', "\n  ")),
+                        _ => format!("[\n  {}\n]", inner_str.replace('\n', "\n  ")),
+                    }
                 } else if matches!(inner.as_ref(), FieldType::Optional(_)) {
                     format!("({})[]", inner_str)
                 } else {
@@ -545,6 +684,7 @@ impl OutputFormatContent {
         &self,
         options: RenderOptions,
     ) -> Result<Option<String>, minijinja::Error> {
+        self.record_format(options.format);
         let prefix = self.prefix(&options);
 
         let mut render_state = RenderState {
@@ -574,6 +714,28 @@ impl OutputFormatContent {
             }
         }
 
+        // XML needs a single root element around the fields or items.
+        if options.format == ResponseFormat::Xml {
+            let mut target = &self.target;
+            while let FieldType::Constrained { base, .. } = target {
+                target = base;
+            }
+            let root = match target {
+                FieldType::Class(class) if !self.recursive_classes.contains(class) => self
+                    .classes
+                    .get(class)
+                    .map(|c| c.name.rendered_name().to_string()),
+                FieldType::List(_)
+                | FieldType::Map(_, _)
+                | FieldType::Union(_)
+                | FieldType::Optional(_) => Some("answer".to_string()),
+                _ => None,
+            };
+            if let (Some(root), Some(m)) = (root, &message) {
+                message = Some(xml_element(&root, m));
+            }
+        }
+
         let enum_definitions = Vec::from_iter(render_state.hoisted_enums.iter().map(|e| {
             let enm = self.enums.get(e).expect("Enum not found"); // TODO: Jinja Err
             self.enum_to_string(enm, &options)
@@ -593,11 +755,19 @@ impl OutputFormatContent {
                 false,
             )?;
 
+            let definition = match options.format {
+                ResponseFormat::Json => format!("{class_name} {schema}"),
+                ResponseFormat::Yaml => {
+                    format!("{class_name}:\n  {}", schema.replace('\n', "\n  "))
+                }
+                ResponseFormat::Xml => xml_element(class_name, &schema),
+            };
+
             class_definitions.push(match &options.hoisted_class_prefix {
                 RenderSetting::Always(prefix) if !prefix.is_empty() => {
-                    format!("{prefix} {class_name} {schema}")
+                    format!("{prefix} {definition}")
                 }
-                _ => format!("{class_name} {schema}"),
+                _ => definition,
             });
         }
 
@@ -646,6 +816,18 @@ impl OutputFormatContent {
 }
 
 impl OutputFormatContent {
+    /// The format a prompt rendered with this content asked for, if it
+    /// rendered `ctx.output_format` at all.
+    pub fn rendered_format(&self) -> Option<ResponseFormat> {
+        *self.rendered_format.lock().unwrap()
+    }
+
+    /// Records `format` as the one the prompt asked for, for prompts that
+    /// are not rendered again, like those of a batch job.
+    pub fn record_format(&self, format: ResponseFormat) {
+        *self.rendered_format.lock().unwrap() = Some(format);
+    }
+
     pub fn find_enum(&self, name: &str) -> Result<&Enum> {
         self.enums
             .get(name)
@@ -686,6 +868,13 @@ mod tests {
         assert_eq!(rendered, Some("Answer as a float".into()));
     }
 
+    #[test]
+    fn render_date() {
+        let content = OutputFormatContent::target(FieldType::date()).build();
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(rendered, Some("Answer as a date (YYYY-MM-DD)".into()));
+    }
+
     #[test]
     fn render_array() {
         let content = OutputFormatContent::new_array();
@@ -737,6 +926,8 @@ mod tests {
                 ),
             ],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::class("Person"))
@@ -751,6 +942,154 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_class_with_defaults() {
+        let classes = vec![Class {
+            name: Name::new("Person".to_string()),
+            fields: vec![
+                (Name::new("name".to_string()), FieldType::string(), None),
+                (Name::new("age".to_string()), FieldType::int(), None),
+            ],
+            constraints: Vec::new(),
+            defaults: IndexMap::from([("age".to_string(), BamlValue::Int(0))]),
+            tag: None,
+        }];
+
+        let content = OutputFormatContent::target(FieldType::class("Person"))
+            .classes(classes)
+            .build();
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(String::from(
+                "Answer in JSON using this schema:\n{\n  name: string,\n  age: int or null,\n}"
+            ))
+        );
+    }
+
+    #[test]
+    fn render_tagged_class() {
+        let classes = vec![Class {
+            name: Name::new("Circle".to_string()),
+            fields: vec![
+                (Name::new("radius".to_string()), FieldType::float(), None),
+                (
+                    Name::new("kind".to_string()),
+                    FieldType::literal_string("circle".to_string()),
+                    None,
+                ),
+            ],
+            constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: Some("kind".to_string()),
+        }];
+
+        let content = OutputFormatContent::target(FieldType::class("Circle"))
+            .classes(classes)
+            .build();
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(String::from(
+                "Answer in JSON using this schema:\n{\n  kind: \"circle\",\n  radius: float,\n}"
+            ))
+        );
+    }
+
+    fn person_with_jobs() -> OutputFormatContent {
+        let classes = vec![
+            Class {
+                name: Name::new("Person".to_string()),
+                fields: vec![
+                    (
+                        Name::new("name".to_string()),
+                        FieldType::string(),
+                        Some("The person's name".to_string()),
+                    ),
+                    (
+                        Name::new("jobs".to_string()),
+                        FieldType::list(FieldType::class("Job")),
+                        None,
+                    ),
+                ],
+                constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
+            },
+            Class {
+                name: Name::new("Job".to_string()),
+                fields: vec![
+                    (Name::new("title".to_string()), FieldType::string(), None),
+                    (Name::new("years".to_string()), FieldType::int(), None),
+                ],
+                constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
+            },
+        ];
+
+        OutputFormatContent::target(FieldType::class("Person"))
+            .classes(classes)
+            .build()
+    }
+
+    #[test]
+    fn clones_share_the_rendered_format() {
+        let content = person_with_jobs();
+        assert_eq!(content.rendered_format(), None);
+
+        let rendered_with = content.clone();
+        rendered_with
+            .render(RenderOptions::with_format(ResponseFormat::Yaml))
+            .unwrap();
+        assert_eq!(content.rendered_format(), Some(ResponseFormat::Yaml));
+        assert_eq!(person_with_jobs().rendered_format(), None);
+    }
+
+    #[test]
+    fn render_class_as_yaml() {
+        let rendered = person_with_jobs()
+            .render(RenderOptions::with_format(ResponseFormat::Yaml))
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(
+            rendered,
+            Some(String::from(
+r#"Answer in YAML using this schema:
+# The person's name
+name: string
+jobs:
+  - title: string
+    years: int"#
+            ))
+        );
+    }
+
+    #[test]
+    fn render_class_as_xml() {
+        let rendered = person_with_jobs()
+            .render(RenderOptions::with_format(ResponseFormat::Xml))
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(
+            rendered,
+            Some(String::from(
+r#"Answer in XML using this schema:
+<Person>
+  <!-- The person's name -->
+  <name>string</name>
+  <jobs>
+    <item>
+      <title>string</title>
+      <years>int</years>
+    </item>
+    ...
+  </jobs>
+</Person>"#
+            ))
+        );
+    }
+
     #[test]
     fn render_class_with_multiline_descriptions() {
         let classes = vec![Class {
@@ -769,6 +1108,8 @@ mod tests {
                 (Name::new("year".to_string()), FieldType::int(), None),
             ],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::class("Education"))
@@ -797,6 +1138,8 @@ mod tests {
                     (Name::new("severity".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Enhancement".to_string()),
@@ -809,6 +1152,8 @@ mod tests {
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Documentation".to_string()),
@@ -817,6 +1162,8 @@ mod tests {
                     (Name::new("format".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -865,6 +1212,8 @@ r#"Answer in JSON using any of these schemas:
                     (Name::new("date".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Bug".to_string()),
@@ -877,6 +1226,8 @@ r#"Answer in JSON using any of these schemas:
                     (Name::new("severity".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Enhancement".to_string()),
@@ -889,6 +1240,8 @@ r#"Answer in JSON using any of these schemas:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Documentation".to_string()),
@@ -897,6 +1250,8 @@ r#"Answer in JSON using any of these schemas:
                     (Name::new("format".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -939,6 +1294,8 @@ r#"Answer in JSON using this schema:
                 ),
             ],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::class("Node"))
@@ -974,6 +1331,8 @@ Answer in JSON using this schema: Node"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("LinkedList".to_string()),
@@ -986,6 +1345,8 @@ Answer in JSON using this schema: Node"#
                     (Name::new("len".to_string()), FieldType::int(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1023,6 +1384,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("B".to_string()),
@@ -1032,6 +1395,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("C".to_string()),
@@ -1041,6 +1406,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1083,6 +1450,8 @@ Answer in JSON using this schema: A"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("B".to_string()),
@@ -1092,6 +1461,8 @@ Answer in JSON using this schema: A"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("C".to_string()),
@@ -1101,6 +1472,8 @@ Answer in JSON using this schema: A"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1114,6 +1487,8 @@ Answer in JSON using this schema: A"#
                     (Name::new("field".to_string()), FieldType::bool(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1168,6 +1543,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("B".to_string()),
@@ -1177,6 +1554,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("C".to_string()),
@@ -1186,6 +1565,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1199,6 +1580,8 @@ Answer in JSON using this schema:
                     (Name::new("field".to_string()), FieldType::bool(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Nested".to_string()),
@@ -1207,6 +1590,8 @@ Answer in JSON using this schema:
                     (Name::new("field".to_string()), FieldType::bool(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1261,6 +1646,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Forest".to_string()),
@@ -1270,6 +1657,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1312,6 +1701,8 @@ Answer in JSON using this schema: Tree"#
                 None,
             )],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::class("SelfReferential"))
@@ -1348,6 +1739,8 @@ Answer in JSON using this schema: SelfReferential"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1360,6 +1753,8 @@ Answer in JSON using this schema: SelfReferential"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1412,6 +1807,8 @@ Node or Tree"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Node".to_string()),
@@ -1424,6 +1821,8 @@ Node or Tree"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1436,6 +1835,8 @@ Node or Tree"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1484,6 +1885,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1496,6 +1899,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1504,6 +1909,8 @@ Answer in JSON using this schema:
                     (Name::new("tag".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1564,6 +1971,8 @@ Node or Tree or {
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Node".to_string()),
@@ -1576,6 +1985,8 @@ Node or Tree or {
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1588,6 +1999,8 @@ Node or Tree or {
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1596,6 +2009,8 @@ Node or Tree or {
                     (Name::new("tag".to_string()), FieldType::string(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1644,6 +2059,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("B".to_string()),
@@ -1653,6 +2070,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("C".to_string()),
@@ -1662,6 +2081,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1675,6 +2096,8 @@ Answer in JSON using this schema:
                     (Name::new("field".to_string()), FieldType::bool(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1727,6 +2150,8 @@ Answer in JSON using this interface:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1739,6 +2164,8 @@ Answer in JSON using this interface:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1786,6 +2213,8 @@ Node or int or string or Tree"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Tree".to_string()),
@@ -1798,6 +2227,8 @@ Node or int or string or Tree"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1814,6 +2245,8 @@ Node or int or string or Tree"#
                     (Name::new("field".to_string()), FieldType::bool(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1861,6 +2294,8 @@ Answer in JSON using this schema:
                 ),
             ],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::list(FieldType::class("Node")))
@@ -1893,6 +2328,8 @@ Node[]"#
                 None,
             )],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::class("RecursiveMap"))
@@ -1924,6 +2361,8 @@ Answer in JSON using this schema: RecursiveMap"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -1933,6 +2372,8 @@ Answer in JSON using this schema: RecursiveMap"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -1970,6 +2411,8 @@ Answer in JSON using this schema:
                 ),
             ],
             constraints: Vec::new(),
+            defaults: IndexMap::new(),
+            tag: None,
         }];
 
         let content = OutputFormatContent::target(FieldType::map(
@@ -2006,6 +2449,8 @@ map<string, Node>"#
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Node".to_string()),
@@ -2018,6 +2463,8 @@ map<string, Node>"#
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -2057,6 +2504,8 @@ Answer in JSON using this schema:
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Node".to_string()),
@@ -2069,6 +2518,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -2108,6 +2559,8 @@ Answer in JSON using this schema:
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -2116,6 +2569,8 @@ Answer in JSON using this schema:
                     (Name::new("data".to_string()), FieldType::int(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -2167,6 +2622,8 @@ map<string, Node or int or {
                     None,
                 )],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("Node".to_string()),
@@ -2179,6 +2636,8 @@ map<string, Node or int or {
                     ),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
             Class {
                 name: Name::new("NonRecursive".to_string()),
@@ -2187,6 +2646,8 @@ map<string, Node or int or {
                     (Name::new("data".to_string()), FieldType::int(), None),
                 ],
                 constraints: Vec::new(),
+                defaults: IndexMap::new(),
+                tag: None,
             },
         ];
 
@@ -17,6 +17,23 @@ fn parse_as_function_call<'a>(
     t: &Type,
 ) -> (Type, Vec<TypeError>) {
     match t {
+        // A variable that could be anything, e.g. `caller()` in a macro, or a macro argument.
+        Type::Unknown if matches!(&expr.expr, ast::Expr::Var(var) if types.resolve(var.id).is_some()) =>
+        {
+            for arg in &expr.args {
+                match arg {
+                    ast::Expr::Kwargs(kwargs) => {
+                        for (_, v) in &kwargs.pairs {
+                            tracker_visit_expr(v, state, types);
+                        }
+                    }
+                    _ => {
+                        tracker_visit_expr(arg, state, types);
+                    }
+                }
+            }
+            (Type::Unknown, vec![])
+        }
         Type::FunctionRef(name) => {
             let mut positional_args = Vec::new();
             let mut kwargs = HashMap::new();
@@ -125,7 +142,18 @@ fn tracker_visit_expr<'a>(
         }
         ast::Expr::BinOp(expr) => {
             let lhs = tracker_visit_expr(&expr.left, state, types);
-            let rhs = tracker_visit_expr(&expr.right, state, types);
+            // The right side of `and` / `or` only runs if the left side was truthy / falsy.
+            let rhs = match expr.op {
+                ast::BinOpKind::ScAnd => {
+                    let narrowed = narrow(&expr.left, types).if_true;
+                    visit_narrowed(&expr.right, state, types, narrowed)
+                }
+                ast::BinOpKind::ScOr => {
+                    let narrowed = narrow(&expr.left, types).if_false;
+                    visit_narrowed(&expr.right, state, types, narrowed)
+                }
+                _ => tracker_visit_expr(&expr.right, state, types),
+            };
             // TODO: Check for type compatibility
 
             match expr.op {
@@ -156,26 +184,33 @@ fn tracker_visit_expr<'a>(
         }
         ast::Expr::IfExpr(expr) => {
             let _test = tracker_visit_expr(&expr.test_expr, state, types);
+            let narrowing = narrow(&expr.test_expr, types);
 
-            let true_expr = tracker_visit_expr(&expr.true_expr, state, types);
+            let true_expr = visit_narrowed(&expr.true_expr, state, types, narrowing.if_true);
             let false_expr = expr
                 .false_expr
                 .as_ref()
-                .map(|x| tracker_visit_expr(x, state, types))
+                .map(|x| visit_narrowed(x, state, types, narrowing.if_false))
                 .unwrap_or(Type::Unknown);
             Type::merge([true_expr, false_expr])
         }
         ast::Expr::Filter(expr) => {
-            // Filters have a name
-            let inner = tracker_visit_expr(expr.expr.as_ref().unwrap(), state, types);
+            // Filters have a name. In `{% filter %}` blocks and `{% set x | filter %}`,
+            // there is no expression: the filter is applied to the rendered block.
+            let inner = match expr.expr.as_ref() {
+                Some(inner) => tracker_visit_expr(inner, state, types),
+                None => Type::String,
+            };
 
             let mut ensure_type = |error_string: &str| {
-                state.errors.push(TypeError::new_invalid_type(
-                    expr.expr.as_ref().unwrap(),
-                    &inner,
-                    error_string,
-                    expr.span(),
-                ));
+                if let Some(inner_expr) = expr.expr.as_ref() {
+                    state.errors.push(TypeError::new_invalid_type(
+                        inner_expr,
+                        &inner,
+                        error_string,
+                        expr.span(),
+                    ));
+                }
             };
 
             let valid_filters = vec![
@@ -328,6 +363,12 @@ fn tracker_visit_expr<'a>(
             Type::Bool
         }
         ast::Expr::GetAttr(expr) => {
+            let narrowed = narrowable_path(&expr.expr)
+                .and_then(|path| types.as_variable(&format!("{}.{}", path, expr.name)));
+            if let Some(t) = narrowed {
+                return t.clone();
+            }
+
             let parent = tracker_visit_expr(&expr.expr, state, types);
 
             match &parent {
@@ -443,6 +484,130 @@ fn infer_const_type(v: &minijinja::value::Value) -> Type {
     }
 }
 
+fn visit_narrowed(
+    expr: &ast::Expr,
+    state: &mut ScopeTracker,
+    types: &PredefinedTypes,
+    narrowed: HashMap<String, Type>,
+) -> Type {
+    if narrowed.is_empty() {
+        tracker_visit_expr(expr, state, types)
+    } else {
+        tracker_visit_expr(expr, state, &types.with_narrowed(narrowed))
+    }
+}
+
+/// The name that narrowed types are tracked under: `x` for variables and
+/// `x.y.z` for attributes.
+fn narrowable_path(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Var(var) => Some(var.id.to_string()),
+        ast::Expr::GetAttr(expr) => {
+            narrowable_path(&expr.expr).map(|path| format!("{}.{}", path, expr.name))
+        }
+        _ => None,
+    }
+}
+
+fn is_none(expr: &ast::Expr) -> bool {
+    matches!(expr, ast::Expr::Const(c) if c.value.is_none())
+}
+
+/// The types that a condition narrows variables (and attributes) to.
+#[derive(Debug, Default)]
+pub(super) struct Narrowing {
+    /// Types when the condition is true.
+    pub if_true: HashMap<String, Type>,
+    /// Types when the condition is false.
+    pub if_false: HashMap<String, Type>,
+}
+
+impl Narrowing {
+    fn swap(self) -> Self {
+        Narrowing {
+            if_true: self.if_false,
+            if_false: self.if_true,
+        }
+    }
+}
+
+/// Narrows optional variables in `if` conditions, so that `x` is not none in
+/// `{% if x %}` or `{% if x is not none %}`.
+pub(super) fn narrow(expr: &ast::Expr, types: &PredefinedTypes) -> Narrowing {
+    let mut narrowing = Narrowing::default();
+    // Returns the path of `expr` and its type without none, if it is optional.
+    let optional = |expr: &ast::Expr| {
+        let path = narrowable_path(expr)?;
+        let t = evaluate_type(expr, types).ok()?;
+        let without_none = t.without_none();
+        (without_none != t).then_some((path, without_none))
+    };
+
+    match expr {
+        ast::Expr::Var(_) | ast::Expr::GetAttr(_) => {
+            narrowing.if_true.extend(optional(expr));
+        }
+        ast::Expr::Test(test) if test.name == "none" => {
+            if let Some((path, t)) = optional(&test.expr) {
+                narrowing.if_true.insert(path.clone(), Type::None);
+                narrowing.if_false.insert(path, t);
+            }
+        }
+        ast::Expr::BinOp(op) if matches!(op.op, ast::BinOpKind::Eq | ast::BinOpKind::Ne) => {
+            let value = if is_none(&op.right) {
+                &op.left
+            } else if is_none(&op.left) {
+                &op.right
+            } else {
+                return narrowing;
+            };
+            if let Some((path, t)) = optional(value) {
+                narrowing.if_true.insert(path.clone(), Type::None);
+                narrowing.if_false.insert(path, t);
+            }
+            if matches!(op.op, ast::BinOpKind::Ne) {
+                return narrowing.swap();
+            }
+        }
+        ast::Expr::UnaryOp(op) if matches!(op.op, ast::UnaryOpKind::Not) => {
+            return narrow(&op.expr, types).swap();
+        }
+        ast::Expr::BinOp(op) if matches!(op.op, ast::BinOpKind::ScAnd) => {
+            // Both sides are true; the right side is checked with the left side narrowed.
+            let left = narrow(&op.left, types).if_true;
+            let right = narrow(&op.right, &types.with_narrowed(left.clone())).if_true;
+            narrowing.if_true.extend(left);
+            narrowing.if_true.extend(right);
+        }
+        ast::Expr::BinOp(op) if matches!(op.op, ast::BinOpKind::ScOr) => {
+            // Both sides are false; the right side is checked with the left side narrowed.
+            let left = narrow(&op.left, types).if_false;
+            let right = narrow(&op.right, &types.with_narrowed(left.clone())).if_false;
+            narrowing.if_false.extend(left);
+            narrowing.if_false.extend(right);
+        }
+        _ => {}
+    }
+    narrowing
+}
+
+/// Evaluates the call in a `{% call %}` block or a `{% do %}` statement.
+pub(super) fn evaluate_call_type(
+    call: &ast::Spanned<ast::Call>,
+    types: &PredefinedTypes,
+) -> Result<Type, Vec<TypeError>> {
+    let mut state = ScopeTracker::new();
+    let func = tracker_visit_expr(&call.expr, &mut state, types);
+    let (result, errors) = parse_as_function_call(call, &mut state, types, &func);
+    state.errors.extend(errors);
+
+    if state.errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(state.errors)
+    }
+}
+
 pub fn evaluate_type(expr: &ast::Expr, types: &PredefinedTypes) -> Result<Type, Vec<TypeError>> {
     let mut state = ScopeTracker::new();
     let result = tracker_visit_expr(expr, &mut state, types);
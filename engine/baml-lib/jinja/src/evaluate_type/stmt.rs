@@ -2,7 +2,52 @@ use minijinja::machinery::ast::{self, Stmt};
 
 use crate::evaluate_type::types::Type;
 
-use super::{expr::evaluate_type, types::PredefinedTypes, TypeError};
+use super::{
+    expr::{evaluate_call_type, evaluate_type, narrow},
+    types::PredefinedTypes,
+    TypeError,
+};
+
+/// Records the errors of an expression and returns its type, or
+/// `Type::Unknown` if it has errors.
+fn visit_expr(expr: &ast::Expr, state: &mut PredefinedTypes) -> Type {
+    match evaluate_type(expr, state) {
+        Ok(t) => t,
+        Err(errors) => {
+            state.errors_mut().extend(errors);
+            Type::Unknown
+        }
+    }
+}
+
+fn visit_call(call: &ast::Spanned<ast::Call>, state: &mut PredefinedTypes) -> Type {
+    match evaluate_call_type(call, state) {
+        Ok(t) => t,
+        Err(errors) => {
+            state.errors_mut().extend(errors);
+            Type::Unknown
+        }
+    }
+}
+
+/// Walks the body of a macro (or a call block) with its arguments and the
+/// implicit `varargs`, `kwargs` and `caller` in scope.
+fn track_macro_body(stmt: &ast::Macro, state: &mut PredefinedTypes) {
+    state.start_scope();
+    for arg in &stmt.args {
+        if let ast::Expr::Var(var) = arg {
+            state.add_variable(var.id, Type::Unknown);
+        }
+    }
+    state.add_variable("varargs", Type::List(Box::new(Type::Unknown)));
+    state.add_variable(
+        "kwargs",
+        Type::Map(Box::new(Type::String), Box::new(Type::Unknown)),
+    );
+    state.add_variable("caller", Type::Unknown);
+    stmt.body.iter().for_each(|x| track_walk(x, state));
+    state.end_scope();
+}
 
 fn track_walk<'a>(node: &ast::Stmt<'a>, state: &mut PredefinedTypes) {
     match node {
@@ -91,15 +136,37 @@ fn track_walk<'a>(node: &ast::Stmt<'a>, state: &mut PredefinedTypes) {
         }
         ast::Stmt::IfCond(stmt) => {
             let _expr_type = evaluate_type(&stmt.expr, state);
+            let narrowing = narrow(&stmt.expr, state);
 
             // Record variables in each branch and their types (fuse them if they are the same)
             state.start_branch();
+            state.start_narrowed_scope(narrowing.if_true);
             stmt.true_body.iter().for_each(|x| track_walk(x, state));
+            state.end_scope();
             state.start_else_branch();
+            state.start_narrowed_scope(narrowing.if_false);
             stmt.false_body.iter().for_each(|x| track_walk(x, state));
+            state.end_scope();
             state.resolve_branch();
         }
-        ast::Stmt::WithBlock(_) => todo!(),
+        ast::Stmt::WithBlock(stmt) => {
+            state.start_scope();
+            // Each assignment can use the ones before it.
+            for (target, expr) in &stmt.assignments {
+                let expr_type = visit_expr(expr, state);
+                match target {
+                    ast::Expr::Var(var) => state.add_variable(var.id, expr_type),
+                    ast::Expr::List(list) => list.items.iter().for_each(|x| {
+                        if let ast::Expr::Var(var) = x {
+                            state.add_variable(var.id, Type::Unknown);
+                        }
+                    }),
+                    _ => {}
+                }
+            }
+            stmt.body.iter().for_each(|x| track_walk(x, state));
+            state.end_scope();
+        }
         ast::Stmt::Set(stmt) => {
             let expr_type = evaluate_type(&stmt.expr, state);
 
@@ -116,15 +183,54 @@ fn track_walk<'a>(node: &ast::Stmt<'a>, state: &mut PredefinedTypes) {
             }
         }
         ast::Stmt::SetBlock(stmt) => {
-            let _target_type = evaluate_type(&stmt.target, state);
-            let _filter_type = stmt.filter.as_ref().map(|x| evaluate_type(x, state));
+            if let Some(filter) = &stmt.filter {
+                visit_expr(filter, state);
+            }
+            stmt.body.iter().for_each(|x| track_walk(x, state));
+            // The target is set to the rendered block.
+            if let ast::Expr::Var(var) = &stmt.target {
+                state.add_variable(var.id, Type::String);
+            }
+        }
+        ast::Stmt::AutoEscape(stmt) => {
+            visit_expr(&stmt.enabled, state);
+            stmt.body.iter().for_each(|x| track_walk(x, state));
+        }
+        ast::Stmt::FilterBlock(stmt) => {
+            visit_expr(&stmt.filter, state);
             stmt.body.iter().for_each(|x| track_walk(x, state));
         }
-        ast::Stmt::AutoEscape(_) => todo!(),
-        ast::Stmt::FilterBlock(_) => todo!(),
-        ast::Stmt::Macro(_stmt) => {}
-        ast::Stmt::CallBlock(_) => todo!(),
-        ast::Stmt::Do(_) => todo!(),
+        ast::Stmt::Macro(stmt) => {
+            // Arguments with defaults are optional; the defaults come last.
+            let required = stmt.args.len() - stmt.defaults.len();
+            let args = stmt
+                .args
+                .iter()
+                .enumerate()
+                .filter_map(|(i, arg)| match arg {
+                    ast::Expr::Var(var) if i < required => {
+                        Some((var.id.to_string(), Type::Unknown))
+                    }
+                    ast::Expr::Var(var) => Some((
+                        var.id.to_string(),
+                        Type::Union(vec![Type::None, Type::Unknown]),
+                    )),
+                    _ => None,
+                })
+                .collect();
+            stmt.defaults.iter().for_each(|x| {
+                visit_expr(x, state);
+            });
+            state.add_function(stmt.name, Type::String, args);
+            track_macro_body(stmt, state);
+        }
+        ast::Stmt::CallBlock(stmt) => {
+            visit_call(&stmt.call, state);
+            track_macro_body(&stmt.macro_decl, state);
+        }
+        ast::Stmt::Do(stmt) => {
+            visit_call(&stmt.call, state);
+        }
     }
 }
 
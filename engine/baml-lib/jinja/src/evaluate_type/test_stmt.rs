@@ -267,3 +267,208 @@ fn if_else() {
         types
     );
 }
+
+#[test]
+fn with_block() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_variable("name", Type::String);
+    types.add_function("Foo", Type::Bool, vec![("arg".into(), Type::Int)]);
+    assert_evaluates_to!(
+        r#"
+{% with greeting = 'Hi ' ~ name, size = greeting|length %}
+    {{ greeting }} {{ Foo(size) }}
+{% endwith %}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"
+{% with greeting = name %}
+    {{ greeting.first }}
+{% endwith %}
+        "#
+        .trim(),
+        types,
+        vec!["'greeting' is a string, expected class"]
+    );
+}
+
+#[test]
+fn filter_and_autoescape_blocks() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_class(
+        "User",
+        vec![("name".into(), Type::String)].into_iter().collect(),
+    );
+    types.add_variable("user", Type::ClassRef("User".into()));
+    assert_evaluates_to!(
+        r#"
+{% filter upper %}Hello {{ user.name }}{% endfilter %}
+{% autoescape true %}{{ user.name }}{% endautoescape %}
+{% set greeting | trim %} Hi {{ user.name }} {% endset %}
+{{ greeting }}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"
+{% filter upper %}{{ user.age }}{% endfilter %}
+{% autoescape true %}{{ user.email }}{% endautoescape %}
+        "#
+        .trim(),
+        types,
+        vec![
+            "class User (user) does not have a property 'age'",
+            "class User (user) does not have a property 'email'"
+        ]
+    );
+}
+
+#[test]
+fn macros() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_class(
+        "User",
+        vec![("name".into(), Type::String)].into_iter().collect(),
+    );
+    types.add_variable("user", Type::ClassRef("User".into()));
+    assert_evaluates_to!(
+        r#"
+{% macro greet(person, greeting='Hello') %}{{ greeting }} {{ person }}{% endmacro %}
+{{ greet(user.name) }}
+{{ greet(user.name, greeting='Hi') }}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"
+{% macro greet(person, greeting='Hello') %}{{ greeting }} {{ user.age }}{% endmacro %}
+{{ greet() }}
+        "#
+        .trim(),
+        types,
+        vec![
+            "class User (user) does not have a property 'age'",
+            "Function 'greet' expects 2 arguments, but got 0"
+        ]
+    );
+}
+
+#[test]
+fn call_block() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_class(
+        "User",
+        vec![("name".into(), Type::String)].into_iter().collect(),
+    );
+    types.add_variable("user", Type::ClassRef("User".into()));
+    assert_evaluates_to!(
+        r#"
+{% macro card(title) %}{{ title }}: {{ caller() }}{% endmacro %}
+{% call card('Profile') %}{{ user.name }}{% endcall %}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"
+{% macro card(title) %}{{ title }}: {{ caller() }}{% endmacro %}
+{% call card('Profile', 'Extra') %}{{ user.age }}{% endcall %}
+        "#
+        .trim(),
+        types,
+        vec![
+            "Function 'card' expects 1 arguments, but got 2",
+            "class User (user) does not have a property 'age'"
+        ]
+    );
+}
+
+#[test]
+fn do_statement() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_variable("name", Type::String);
+    types.add_function("Foo", Type::Bool, vec![("arg".into(), Type::String)]);
+    assert_evaluates_to!(r#"{% do Foo(name) %}"#, types);
+
+    assert_fails_to!(
+        r#"{% do Foo(1) %}"#,
+        types,
+        vec!["Function 'Foo' expects argument 'arg' to be of type string, but got literal[1]"]
+    );
+}
+
+#[test]
+fn narrowing_optional_variables() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_class(
+        "User",
+        vec![("name".into(), Type::String)].into_iter().collect(),
+    );
+    types.add_variable("user", Type::None | Type::ClassRef("User".into()));
+    assert_evaluates_to!(
+        r#"
+{% if user %}{{ user.name }}{% endif %}
+{% if user is not none %}{{ user.name }}{% endif %}
+{% if user is none %}Anonymous{% else %}{{ user.name }}{% endif %}
+{% if not user %}Anonymous{% else %}{{ user.name }}{% endif %}
+{% if user != none and user.name %}{{ user.name }}{% endif %}
+{{ user.name if user }}
+{{ user and user.name }}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"
+{{ user.name }}
+{% if user is none %}{{ user.name }}{% endif %}
+{% if user %}{% set user = none %}{{ user.name }}{% endif %}
+        "#
+        .trim(),
+        types,
+        vec![
+            "'user' is a (none | class User), expected class",
+            "'user' is a none, expected class",
+            "'user' is a none, expected class"
+        ]
+    );
+}
+
+#[test]
+fn narrowing_optional_attributes() {
+    let mut types = PredefinedTypes::default(JinjaContext::Prompt);
+    types.add_class(
+        "User",
+        vec![("name".into(), Type::String)].into_iter().collect(),
+    );
+    types.add_class(
+        "Team",
+        vec![("owner".into(), Type::None | Type::ClassRef("User".into()))]
+            .into_iter()
+            .collect(),
+    );
+    types.add_variable("team", Type::ClassRef("Team".into()));
+    assert_evaluates_to!(
+        r#"
+{% if team.owner %}{{ team.owner.name }}{% endif %}
+{{ team.owner.name if team.owner is not none else 'nobody' }}
+        "#
+        .trim(),
+        types
+    );
+
+    assert_fails_to!(
+        r#"{{ team.owner.name }}"#,
+        types,
+        vec!["'team.owner' is a (none | class User), expected class"]
+    );
+}
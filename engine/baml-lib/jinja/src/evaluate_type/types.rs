@@ -160,6 +160,25 @@ impl Type {
         }
     }
 
+    /// The type without `none` and `undefined`, e.g. after an `{% if x %}` check.
+    pub fn without_none(&self) -> Type {
+        match self {
+            Type::Union(v) => {
+                let mut rest = v
+                    .iter()
+                    .filter(|t| !matches!(t, Type::None | Type::Undefined))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                match rest.len() {
+                    0 => self.clone(),
+                    1 => rest.remove(0),
+                    _ => Type::Union(rest),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
     pub fn merge<'a, I>(v: I) -> Type
     where
         I: IntoIterator<Item = Type>,
@@ -209,13 +228,16 @@ impl BitOr for Type {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Scope {
     CodeBlock(HashMap<String, Type>),
     Branch(HashMap<String, Type>, HashMap<String, Type>, bool),
+    // Types that variables (or attributes, as `x.y`) are narrowed to by a condition.
+    // Assignments go to the enclosing scope.
+    Narrowed(HashMap<String, Type>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PredefinedTypes {
     functions: HashMap<String, (Type, Vec<(String, Type)>)>,
     classes: HashMap<String, HashMap<String, Type>>,
@@ -233,7 +255,8 @@ pub enum JinjaContext {
 
 impl PredefinedTypes {
     pub fn variable_names(&self) -> Vec<String> {
-        self.variables
+        let mut names: Vec<String> = self
+            .variables
             .keys()
             .chain(self.scopes.iter().flat_map(|s| match s {
                 Scope::CodeBlock(vars) => vars.keys(),
@@ -244,9 +267,15 @@ impl PredefinedTypes {
                         on_false.keys()
                     }
                 }
+                Scope::Narrowed(vars) => vars.keys(),
             }))
+            // Narrowed attributes are not variables.
+            .filter(|k| !k.contains('.'))
             .map(|k| k.to_string())
-            .collect()
+            .collect();
+        names.sort();
+        names.dedup();
+        names
     }
 
     pub fn default(context: JinjaContext) -> Self {
@@ -349,6 +378,18 @@ impl PredefinedTypes {
         self.scopes.pop();
     }
 
+    pub fn start_narrowed_scope(&mut self, types: HashMap<String, Type>) {
+        self.scopes.push(Scope::Narrowed(types));
+    }
+
+    /// A copy of these types with `types` narrowed, for checking expressions
+    /// such as `x.name if x`.
+    pub fn with_narrowed(&self, types: HashMap<String, Type>) -> PredefinedTypes {
+        let mut narrowed = self.clone();
+        narrowed.start_narrowed_scope(types);
+        narrowed
+    }
+
     pub fn start_branch(&mut self) {
         self.scopes
             .push(Scope::Branch(HashMap::new(), HashMap::new(), true));
@@ -429,6 +470,7 @@ impl PredefinedTypes {
                         false_vars.get(name)
                     }
                 }
+                Scope::Narrowed(vars) => vars.get(name),
             })
             .or_else(|| self.variables.get(name))
     }
@@ -450,7 +492,20 @@ impl PredefinedTypes {
     }
 
     pub fn add_variable(&mut self, name: &str, t: Type) {
-        match self.scopes.last_mut() {
+        // Assigning to a narrowed variable undoes the narrowing.
+        let attributes = format!("{}.", name);
+        for scope in self.scopes.iter_mut() {
+            if let Scope::Narrowed(vars) = scope {
+                vars.retain(|k, _| k != name && !k.starts_with(&attributes));
+            }
+        }
+
+        match self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find(|scope| !matches!(scope, Scope::Narrowed(_)))
+        {
             Some(Scope::Branch(true_vars, false_vars, branch_cond)) => {
                 if *branch_cond {
                     true_vars.insert(name.to_string(), t);
@@ -461,6 +516,7 @@ impl PredefinedTypes {
             Some(Scope::CodeBlock(vars)) => {
                 vars.insert(name.to_string(), t);
             }
+            Some(Scope::Narrowed(_)) => unreachable!(),
             None => {
                 self.variables.insert(name.to_string(), t);
             }
@@ -40,6 +40,7 @@ pub(super) fn coerce_array(
                 }
             }
         }
+        Some(v) if ctx.strict => return Err(ctx.error_unexpected_type(list_target, v)),
         Some(v) => {
             flags.add_flag(Flag::SingleToArray);
             match inner.coerce(&ctx.enter_scope("<implied>"), inner, Some(v)) {
@@ -0,0 +1,280 @@
+use anyhow::Result;
+use baml_types::iso8601::{self, Duration};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use internal_baml_core::ir::FieldType;
+use regex::Regex;
+
+use crate::deserializer::{deserialize_flags::Flag, types::BamlValueWithFlags};
+
+use super::{array_helper::coerce_array_to_singular, ParsingContext, ParsingError};
+
+/// Coerces a string to the canonical ISO 8601 form of a date, datetime or
+/// duration. `iso` reads the canonical form, and `natural` the other formats
+/// LLMs tend to write, which are flagged with [`Flag::NonIsoFormat`].
+fn coerce_iso(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+    iso: fn(&str) -> Option<String>,
+    natural: fn(&str) -> Option<String>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    let Some(value) = value else {
+        return Err(ctx.error_unexpected_null(target));
+    };
+    match value {
+        crate::jsonish::Value::String(s) => {
+            let s = s.trim();
+            if let Some(canonical) = iso(s) {
+                Ok(BamlValueWithFlags::String(canonical.into()))
+            } else if let Some(canonical) = natural(s) {
+                Ok(BamlValueWithFlags::String(
+                    (canonical, Flag::NonIsoFormat(s.to_string())).into(),
+                ))
+            } else {
+                Err(ctx.error_unexpected_type(target, value))
+            }
+        }
+        crate::jsonish::Value::Null => Err(ctx.error_unexpected_null(target)),
+        crate::jsonish::Value::Array(items) => {
+            coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
+                coerce_iso(ctx, target, Some(value), iso, natural)
+            })
+        }
+        _ => Err(ctx.error_unexpected_type(target, value)),
+    }
+}
+
+pub(super) fn coerce_date(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    coerce_iso(ctx, target, value, iso8601::canonical_date, |s| {
+        natural_date(s).map(iso8601::format_date)
+    })
+}
+
+pub(super) fn coerce_datetime(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    coerce_iso(ctx, target, value, iso8601::canonical_datetime, |s| {
+        natural_datetime(s).map(|(dt, offset)| iso8601::format_datetime(dt, offset))
+    })
+}
+
+pub(super) fn coerce_duration(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    coerce_iso(ctx, target, value, iso8601::canonical_duration, |s| {
+        natural_duration(s).map(|d| d.to_string())
+    })
+}
+
+/// Reads dates like "March 3rd, 2024", "3 Mar 2024", "Sunday, March 3, 2024"
+/// and "03/03/2024". Numeric dates are read month first, unless that isn't a
+/// valid date. Years must have four digits, so "March 2024" isn't read as
+/// March 20th, 24 AD.
+fn natural_date(s: &str) -> Option<NaiveDate> {
+    let s = Regex::new(r"(?i)(\d)(st|nd|rd|th)\b")
+        .unwrap()
+        .replace_all(s, "$1");
+    let s = Regex::new(r"(?i)\bof\b|,|([a-z])\.")
+        .unwrap()
+        .replace_all(&s, "$1 ");
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    [
+        "%B %d %Y",
+        "%d %B %Y",
+        "%A %B %d %Y",
+        "%A %d %B %Y",
+        "%Y %B %d",
+        "%Y/%m/%d",
+        "%m/%d/%Y",
+        "%d/%m/%Y",
+        "%Y.%m.%d",
+        "%d.%m.%Y",
+    ]
+    .iter()
+    .find_map(|fmt| NaiveDate::parse_from_str(&s, fmt).ok())
+    .filter(|date| date.year() >= 1000)
+}
+
+/// Reads a date as in [`natural_date`] followed by a time like "10:30",
+/// "10:30:15 PM" or "at 9am", and optionally "UTC" or an offset. RFC 2822
+/// dates, like in emails, are read too.
+fn natural_datetime(s: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    if let Ok(dt) = DateTime::parse_from_rfc2822(s) {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+
+    let time = Regex::new(
+        r"(?i)\s*(?:\bat\s+)?\b(\d{1,2})(?::(\d{2})(?::(\d{2})(\.\d+)?)?)?\s*(?:([ap])\.?m\b\.?)?\s*(z|utc|gmt|[+-]\d{2}:?\d{2})?$",
+    )
+    .unwrap()
+    .captures(s)?;
+    let number = |i: usize| time.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+    let (minute, meridiem) = (number(2), time.get(5).map(|m| m.as_str()));
+    if minute.is_none() && meridiem.is_none() {
+        return None;
+    }
+    let hour = match (number(1)?, meridiem.map(str::to_lowercase).as_deref()) {
+        (12, Some("a")) => 0,
+        (h @ 1..=11, Some("p")) => h + 12,
+        (h @ 1..=12, Some(_)) => h,
+        (h, _) => h,
+    };
+    let nanos = time
+        .get(4)
+        .and_then(|m| m.as_str().parse::<f64>().ok())
+        .map_or(0, |f| (f * 1e9).round() as u32);
+    let time_of_day =
+        NaiveTime::from_hms_nano_opt(hour, minute.unwrap_or(0), number(3).unwrap_or(0), nanos)?;
+
+    let offset = match time.get(6).map(|m| m.as_str().to_lowercase()) {
+        None => None,
+        Some(tz) if matches!(tz.as_str(), "z" | "utc" | "gmt") => FixedOffset::east_opt(0),
+        Some(tz) => {
+            let digits = tz[1..].replace(':', "");
+            let seconds =
+                digits[..2].parse::<i32>().ok()? * 3600 + digits[2..].parse::<i32>().ok()? * 60;
+            FixedOffset::east_opt(if tz.starts_with('-') {
+                -seconds
+            } else {
+                seconds
+            })
+        }
+    };
+
+    let date = s[..time.get(0)?.start()]
+        .trim()
+        .trim_end_matches([',', 'T']);
+    let date = iso8601::parse_date(date).or_else(|| natural_date(date))?;
+    Some((date.and_time(time_of_day), offset))
+}
+
+/// Reads durations like "2 hours", "1h 30m", "1 year and 6 months", "a day"
+/// and "1:30:00".
+fn natural_duration(s: &str) -> Option<Duration> {
+    if let Some(clock) = Regex::new(r"^(\d+):(\d{2})(?::(\d{2}(?:\.\d+)?))?$")
+        .unwrap()
+        .captures(s)
+    {
+        let mut duration = Duration::default();
+        for (i, unit) in [(1, 'h'), (2, 'm'), (3, 's')] {
+            if let Some(amount) = clock.get(i) {
+                duration.add(amount.as_str().parse().ok()?, unit)?;
+            }
+        }
+        return Some(duration);
+    }
+
+    let separator = Regex::new(r"(?i)^(?:[\s,]|\band\b)+").unwrap();
+    let amount = Regex::new(
+        r"(?i)^(\d+(?:\.\d+)?|an?)\s*(years?|yrs?|y|months?|mos?|weeks?|wks?|w|days?|d|hours?|hrs?|h|minutes?|mins?|m|seconds?|secs?|s)\b",
+    )
+    .unwrap();
+
+    let mut duration = Duration::default();
+    let mut rest = s;
+    let mut found = false;
+    loop {
+        rest = &rest[separator.find(rest).map_or(0, |m| m.end())..];
+        if rest.is_empty() {
+            break;
+        }
+        let captures = amount.captures(rest)?;
+        let number = match captures[1].to_lowercase().as_str() {
+            "a" | "an" => 1.0,
+            n => n.parse::<f64>().ok()?,
+        };
+        let unit = match captures[2].to_lowercase().as_str() {
+            "mo" | "mos" | "month" | "months" => 'M',
+            u => match u.chars().next()? {
+                'y' => 'Y',
+                'w' => 'W',
+                'd' => 'D',
+                c => c,
+            },
+        };
+        duration.add(number, unit)?;
+        found = true;
+        rest = &rest[captures.get(0)?.end()..];
+    }
+    found.then_some(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 3);
+        for s in [
+            "March 3rd, 2024",
+            "march 3 2024",
+            "Mar. 3, 2024",
+            "3 March 2024",
+            "3rd of March, 2024",
+            "Sunday, March 3, 2024",
+            "2024/03/03",
+            "03/03/2024",
+            "3.3.2024",
+        ] {
+            assert_eq!(natural_date(s), date, "{s}");
+        }
+        assert_eq!(
+            natural_date("13/03/2024"),
+            NaiveDate::from_ymd_opt(2024, 3, 13)
+        );
+        assert_eq!(natural_date("March 2024"), None);
+        assert_eq!(natural_date("yesterday"), None);
+    }
+
+    #[test]
+    fn test_natural_datetime() {
+        let format = |s: &str| natural_datetime(s).map(|(dt, o)| iso8601::format_datetime(dt, o));
+        assert_eq!(
+            format("March 3rd, 2024 at 10:30 PM"),
+            Some("2024-03-03T22:30:00".into())
+        );
+        assert_eq!(
+            format("2024-03-03 9am UTC"),
+            Some("2024-03-03T09:00:00Z".into())
+        );
+        assert_eq!(
+            format("03/03/2024 12:15:30 a.m. -05:00"),
+            Some("2024-03-03T00:15:30-05:00".into())
+        );
+        assert_eq!(
+            format("Sun, 03 Mar 2024 10:30:00 +0000"),
+            Some("2024-03-03T10:30:00Z".into())
+        );
+        assert_eq!(format("March 3rd, 2024"), None);
+        assert_eq!(format("10:30"), None);
+    }
+
+    #[test]
+    fn test_natural_duration() {
+        let format = |s: &str| natural_duration(s).map(|d| d.to_string());
+        assert_eq!(format("2 hours"), Some("PT2H".into()));
+        assert_eq!(format("1h 30m"), Some("PT1H30M".into()));
+        assert_eq!(format("1.5 hours"), Some("PT1H30M".into()));
+        assert_eq!(format("90 minutes"), Some("PT1H30M".into()));
+        assert_eq!(
+            format("1 year, 2 months and 3 days"),
+            Some("P1Y2M3D".into())
+        );
+        assert_eq!(format("a week"), Some("P7D".into()));
+        assert_eq!(format("45 secs"), Some("PT45S".into()));
+        assert_eq!(format("1:30:00"), Some("PT1H30M".into()));
+        assert_eq!(format("2 hours ago"), None);
+        assert_eq!(format("soon"), None);
+        assert_eq!(format(""), None);
+    }
+}
@@ -9,7 +9,11 @@ use crate::deserializer::{
 };
 use regex::Regex;
 
-use super::{array_helper::coerce_array_to_singular, ParsingContext, ParsingError};
+use super::{
+    array_helper::coerce_array_to_singular,
+    coerce_date::{coerce_date, coerce_datetime, coerce_duration},
+    ParsingContext, ParsingError,
+};
 
 impl TypeCoercer for TypeValue {
     fn coerce(
@@ -34,13 +38,58 @@ impl TypeCoercer for TypeValue {
 
         match self {
             TypeValue::String => coerce_string(ctx, target, value),
-            TypeValue::Int => coerce_int(ctx, target, value),
-            TypeValue::Float => coerce_float(ctx, target, value),
-            TypeValue::Bool => coerce_bool(ctx, target, value),
+            TypeValue::Int => {
+                reject_conversions(ctx, target, value, coerce_int(ctx, target, value))
+            }
+            TypeValue::Float => {
+                reject_conversions(ctx, target, value, coerce_float(ctx, target, value))
+            }
+            TypeValue::Bool => {
+                reject_conversions(ctx, target, value, coerce_bool(ctx, target, value))
+            }
             TypeValue::Null => coerce_null(ctx, target, value),
+            TypeValue::Date => {
+                reject_conversions(ctx, target, value, coerce_date(ctx, target, value))
+            }
+            TypeValue::DateTime => {
+                reject_conversions(ctx, target, value, coerce_datetime(ctx, target, value))
+            }
+            TypeValue::Duration => {
+                reject_conversions(ctx, target, value, coerce_duration(ctx, target, value))
+            }
             TypeValue::Media(BamlMediaType::Image) => Err(ctx.error_image_not_supported()),
             TypeValue::Media(BamlMediaType::Audio) => Err(ctx.error_audio_not_supported()),
+            TypeValue::Media(BamlMediaType::Pdf) => Err(ctx.error_pdf_not_supported()),
+            TypeValue::Media(BamlMediaType::Video) => Err(ctx.error_video_not_supported()),
+        }
+    }
+}
+
+/// Strict parsing rejects values that only fit the type after a conversion,
+/// e.g. a float rounded to an int, a string read as a bool, a date that isn't
+/// in ISO 8601 format or a single value taken out of a list.
+fn reject_conversions(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+    result: Result<BamlValueWithFlags, ParsingError>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    match (result, value) {
+        (Ok(_), Some(value @ crate::jsonish::Value::Array(_))) if ctx.strict => {
+            Err(ctx.error_unexpected_type(target, value))
+        }
+        (Ok(v), Some(value))
+            if ctx.strict
+                && v.conditions().flags().iter().any(|f| {
+                    matches!(
+                        f,
+                        Flag::FloatToInt(_) | Flag::StringToBool(_) | Flag::NonIsoFormat(_)
+                    )
+                }) =>
+        {
+            Err(ctx.error_unexpected_type(target, value))
         }
+        (result, _) => result,
     }
 }
 
@@ -70,6 +119,7 @@ fn coerce_string(
                 Ok(BamlValueWithFlags::String(s.to_string().into()))
             }
             crate::jsonish::Value::Null => Err(ctx.error_unexpected_null(target)),
+            v if ctx.strict => Err(ctx.error_unexpected_type(target, v)),
             v => Ok(BamlValueWithFlags::String(
                 (v.to_string(), Flag::JsonToString(v.clone())).into(),
             )),
@@ -99,6 +149,10 @@ pub(super) fn coerce_int(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
+            // Strict parsing doesn't read numbers out of strings.
+            crate::jsonish::Value::String(_) if ctx.strict => {
+                Err(ctx.error_unexpected_type(target, value))
+            }
             crate::jsonish::Value::String(s) => {
                 let s = s.trim();
                 // Trim trailing commas
@@ -185,6 +239,10 @@ fn coerce_float(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
+            // Strict parsing doesn't read numbers out of strings.
+            crate::jsonish::Value::String(_) if ctx.strict => {
+                Err(ctx.error_unexpected_type(target, value))
+            }
             crate::jsonish::Value::String(s) => {
                 let s = s.trim();
                 // Trim trailing commas
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use baml_types::LiteralValue;
 use internal_baml_core::ir::FieldType;
 
 use crate::deserializer::{coercer::array_helper, types::BamlValueWithFlags};
@@ -23,6 +24,13 @@ pub(super) fn coerce_union(
         _ => unreachable!(),
     };
 
+    // Unions of classes tagged with `@@tag` only try the classes whose tag
+    // matches the one in the value.
+    let options = match tagged_options(ctx, options, value) {
+        Some(tagged) => tagged,
+        None => options.iter().collect(),
+    };
+
     let parsed = options
         .iter()
         .map(|option| option.coerce(ctx, option, value))
@@ -30,3 +38,54 @@ pub(super) fn coerce_union(
 
     array_helper::pick_best(ctx, union_target, &parsed)
 }
+
+/// The options of a tagged union whose tag matches `value`, or `None` if the
+/// union isn't tagged or `value` has no known tag.
+fn tagged_options<'a>(
+    ctx: &ParsingContext,
+    options: &'a [FieldType],
+    value: Option<&crate::jsonish::Value>,
+) -> Option<Vec<&'a FieldType>> {
+    let mut key = None;
+    let mut tags = vec![];
+    for option in options.iter().filter(|o| !o.is_null()) {
+        let FieldType::Class(name) = option else {
+            return None;
+        };
+        let class = ctx.of.find_class(name).ok()?;
+        let tag = class.tag.as_deref()?;
+        let (field, tag_value) = class.fields.iter().find_map(|(field, t, _)| match t {
+            FieldType::Literal(LiteralValue::String(v)) if field.real_name() == tag => {
+                Some((field.rendered_name(), v.as_str()))
+            }
+            _ => None,
+        })?;
+        if *key.get_or_insert(field) != field {
+            return None;
+        }
+        tags.push((option, tag_value));
+    }
+
+    let found = find_tag(value?, key?)?;
+    let matching = tags
+        .into_iter()
+        .filter(|(_, tag)| tag.eq_ignore_ascii_case(found.trim()))
+        .map(|(option, _)| option)
+        .collect::<Vec<_>>();
+    (!matching.is_empty()).then_some(matching)
+}
+
+/// The string under `key` in `value`, looking through fixed and markdown JSON.
+fn find_tag<'v>(value: &'v crate::jsonish::Value, key: &str) -> Option<&'v str> {
+    use crate::jsonish::Value;
+
+    match value {
+        Value::Object(fields) => fields.iter().find_map(|(k, v)| match v {
+            Value::String(s) if k.trim() == key => Some(s.as_str()),
+            _ => None,
+        }),
+        Value::Markdown(_, inner) | Value::FixedJson(inner, _) => find_tag(inner, key),
+        Value::AnyOf(values, _) => values.iter().find_map(|v| find_tag(v, key)),
+        _ => None,
+    }
+}
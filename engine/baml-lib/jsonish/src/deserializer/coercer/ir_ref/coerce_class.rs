@@ -99,7 +99,7 @@ impl TypeCoercer for Class {
                     }
                 });
 
-                if !found_keys && !extra_keys.is_empty() && self.fields.len() == 1 {
+                if !ctx.strict && !found_keys && !extra_keys.is_empty() && self.fields.len() == 1 {
                     // Try to coerce the object into the single field
                     let field = &self.fields[0];
                     let scope = ctx.enter_scope(&format!("<implied:{}>", field.0.real_name()));
@@ -133,6 +133,8 @@ impl TypeCoercer for Class {
                     });
                 }
             }
+            // Strict parsing only builds classes out of objects.
+            Some(v) if ctx.strict => return Err(ctx.error_unexpected_type(target, v)),
             Some(crate::jsonish::Value::Array(items)) => {
                 if self.fields.len() == 1 {
                     let field = &self.fields[0];
@@ -177,6 +179,21 @@ impl TypeCoercer for Class {
             }
         }
 
+        // Strict mode never swaps a value it could not parse for a default.
+        if ctx.strict {
+            let unparsed_fields = required_values
+                .iter()
+                .chain(optional_values.iter())
+                .filter_map(|(k, v)| match v {
+                    Some(Err(e)) => Some((k.clone(), e)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if !unparsed_fields.is_empty() {
+                return Err(ctx.error_missing_required_field(unparsed_fields, vec![], value));
+            }
+        }
+
         // Check what we have / what we need
         {
             self.fields.iter().for_each(|(field_name, t, ..)| {
@@ -193,10 +210,12 @@ impl TypeCoercer for Class {
                                 t.default_value(Some(e))
                             }
                             // If we're missing a field, thats ok!
-                            None => Some(BamlValueWithFlags::Null(
-                                DeserializerConditions::new()
-                                    .with_flag(Flag::OptionalDefaultFromNoValue),
-                            )),
+                            None => declared_default(ctx, self, field_name, t).or_else(|| {
+                                Some(BamlValueWithFlags::Null(
+                                    DeserializerConditions::new()
+                                        .with_flag(Flag::OptionalDefaultFromNoValue),
+                                ))
+                            }),
                         };
 
                         if let Some(next) = next {
@@ -208,26 +227,34 @@ impl TypeCoercer for Class {
                     if let Some(v) = required_values.get(field_name.real_name()) {
                         let next = match v {
                             Some(Ok(_)) => None,
-                            Some(Err(e)) => t.default_value(Some(e)).or_else(|| {
-                                if ctx.allow_partials {
-                                    Some(BamlValueWithFlags::Null(
-                                        DeserializerConditions::new()
-                                            .with_flag(Flag::OptionalDefaultFromNoValue),
-                                    ))
-                                } else {
-                                    None
-                                }
-                            }),
-                            None => t.default_value(None).or_else(|| {
-                                if ctx.allow_partials {
-                                    Some(BamlValueWithFlags::Null(
-                                        DeserializerConditions::new()
-                                            .with_flag(Flag::OptionalDefaultFromNoValue),
-                                    ))
-                                } else {
-                                    None
-                                }
-                            }),
+                            Some(Err(e)) => declared_default(ctx, self, field_name, t)
+                                .map(|mut v| {
+                                    v.add_flag(Flag::DefaultButHadUnparseableValue(e.clone()));
+                                    v
+                                })
+                                .or_else(|| t.default_value(Some(e)))
+                                .or_else(|| {
+                                    if ctx.allow_partials {
+                                        Some(BamlValueWithFlags::Null(
+                                            DeserializerConditions::new()
+                                                .with_flag(Flag::OptionalDefaultFromNoValue),
+                                        ))
+                                    } else {
+                                        None
+                                    }
+                                }),
+                            None => declared_default(ctx, self, field_name, t)
+                                .or_else(|| t.default_value(None))
+                                .or_else(|| {
+                                    if ctx.allow_partials {
+                                        Some(BamlValueWithFlags::Null(
+                                            DeserializerConditions::new()
+                                                .with_flag(Flag::OptionalDefaultFromNoValue),
+                                        ))
+                                    } else {
+                                        None
+                                    }
+                                }),
                         };
 
                         if let Some(next) = next {
@@ -345,6 +372,24 @@ impl TypeCoercer for Class {
     }
 }
 
+/// The `@default` of a field, coerced to the field's type as if the LLM had
+/// returned it.
+fn declared_default(
+    ctx: &ParsingContext,
+    class: &Class,
+    field_name: &Name,
+    field_type: &FieldType,
+) -> Option<BamlValueWithFlags> {
+    let default = class.defaults.get(field_name.real_name())?;
+    let value = serde_json::to_value(default)
+        .and_then(serde_json::from_value::<crate::jsonish::Value>)
+        .ok()?;
+    let scope = ctx.enter_scope(field_name.real_name());
+    let mut parsed = field_type.coerce(&scope, field_type, Some(&value)).ok()?;
+    parsed.add_flag(Flag::DefaultFromNoValue);
+    Some(parsed)
+}
+
 pub fn apply_constraints(
     class_type: &FieldType,
     scope: Vec<String>,
@@ -52,6 +52,19 @@ pub(super) fn match_string(
     // Trim whitespaces.
     let match_context = jsonish_string.trim();
 
+    // Strict parsing only takes exact matches of strings.
+    if parsing_context.strict {
+        return match candidates
+            .iter()
+            .find(|(_, valid_values)| valid_values.iter().any(|v| v == match_context))
+        {
+            Some((candidate, _)) if matches!(value, jsonish::Value::String(_)) => {
+                Ok((candidate.to_string(), flags).into())
+            }
+            _ => Err(parsing_context.error_unexpected_type(target, &value)),
+        };
+    }
+
     // First attempt, case sensitive match ignoring possible pucntuation.
     if let Some(string_match) = string_match_strategy(&match_context, &candidates, &mut flags) {
         return try_match_only_once(parsing_context, target, string_match, flags);
@@ -1,5 +1,6 @@
 mod array_helper;
 mod coerce_array;
+mod coerce_date;
 mod coerce_literal;
 mod coerce_map;
 mod coerce_optional;
@@ -27,6 +28,9 @@ pub struct ParsingContext<'a> {
     visited: HashSet<(String, jsonish::Value)>,
     pub of: &'a OutputFormatContent,
     pub allow_partials: bool,
+    /// Disables coercions that guess at the value, such as substring matches
+    /// of enum values and rounding floats to ints.
+    pub strict: bool,
 }
 
 impl ParsingContext<'_> {
@@ -37,12 +41,17 @@ impl ParsingContext<'_> {
         self.scope.join(".")
     }
 
-    pub(crate) fn new<'a>(of: &'a OutputFormatContent, allow_partials: bool) -> ParsingContext<'a> {
+    pub(crate) fn new<'a>(
+        of: &'a OutputFormatContent,
+        allow_partials: bool,
+        strict: bool,
+    ) -> ParsingContext<'a> {
         ParsingContext {
             scope: Vec::new(),
             visited: HashSet::new(),
             of,
             allow_partials,
+            strict,
         }
     }
 
@@ -54,6 +63,7 @@ impl ParsingContext<'_> {
             visited: self.visited.clone(),
             of: self.of,
             allow_partials: self.allow_partials,
+            strict: self.strict,
         }
     }
 
@@ -71,6 +81,7 @@ impl ParsingContext<'_> {
             visited: new_visited,
             of: self.of,
             allow_partials: self.allow_partials,
+            strict: self.strict,
         }
     }
 
@@ -139,6 +150,22 @@ impl ParsingContext<'_> {
         }
     }
 
+    pub(crate) fn error_pdf_not_supported(&self) -> ParsingError {
+        ParsingError {
+            reason: "Pdf type is not supported here".to_string(),
+            scope: self.scope.clone(),
+            causes: vec![],
+        }
+    }
+
+    pub(crate) fn error_video_not_supported(&self) -> ParsingError {
+        ParsingError {
+            reason: "Video type is not supported here".to_string(),
+            scope: self.scope.clone(),
+            causes: vec![],
+        }
+    }
+
     pub(crate) fn error_map_must_have_supported_key(&self, key_type: &FieldType) -> ParsingError {
         ParsingError {
             reason: format!(
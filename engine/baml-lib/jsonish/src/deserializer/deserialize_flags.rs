@@ -38,6 +38,8 @@ pub enum Flag {
     StringToBool(String),
     StringToNull(String),
     StringToChar(String),
+    /// A date, datetime or duration that wasn't in ISO 8601 format.
+    NonIsoFormat(String),
 
     // Number -> X convertions.
     FloatToInt(f64),
@@ -91,6 +93,7 @@ impl DeserializerConditions {
                 Flag::StringToBool(_) => None,
                 Flag::StringToNull(_) => None,
                 Flag::StringToChar(_) => None,
+                Flag::NonIsoFormat(_) => None,
                 Flag::FloatToInt(_) => None,
                 Flag::NoFields(_) => None,
                 Flag::UnionMatch(_idx, _) => None,
@@ -145,9 +148,11 @@ impl std::fmt::Display for Flag {
             Flag::DefaultFromNoValue => {
                 write!(f, "Default value")?;
             }
-            Flag::ObjectFromFixedJson(fixes) => {
-                write!(f, "JSON (Fixed {} mistakes)", fixes.len())?;
-            }
+            Flag::ObjectFromFixedJson(fixes) => match fixes.as_slice() {
+                [crate::jsonish::Fixes::FromYaml] => write!(f, "Object from YAML")?,
+                [crate::jsonish::Fixes::FromXml] => write!(f, "Object from XML")?,
+                _ => write!(f, "JSON (Fixed {} mistakes)", fixes.len())?,
+            },
             Flag::ObjectFromMarkdown(_) => {
                 write!(f, "Object from markdown")?;
             }
@@ -235,6 +240,9 @@ impl std::fmt::Display for Flag {
             Flag::StringToChar(value) => {
                 write!(f, "String to char: {}", value)?;
             }
+            Flag::NonIsoFormat(value) => {
+                write!(f, "Non ISO 8601 format: {}", value)?;
+            }
             Flag::FloatToInt(value) => {
                 write!(f, "Float to int: {}", value)?;
             }
@@ -261,6 +269,110 @@ impl std::fmt::Display for Flag {
     }
 }
 
+impl Flag {
+    /// The kind of the flag, e.g. `ObjectFromMarkdown`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Flag::ObjectFromMarkdown(_) => "ObjectFromMarkdown",
+            Flag::ObjectFromFixedJson(_) => "ObjectFromFixedJson",
+            Flag::DefaultButHadUnparseableValue(_) => "DefaultButHadUnparseableValue",
+            Flag::ObjectToString(_) => "ObjectToString",
+            Flag::ObjectToPrimitive(_) => "ObjectToPrimitive",
+            Flag::ObjectToMap(_) => "ObjectToMap",
+            Flag::ExtraKey(_, _) => "ExtraKey",
+            Flag::StrippedNonAlphaNumeric(_) => "StrippedNonAlphaNumeric",
+            Flag::SubstringMatch(_) => "SubstringMatch",
+            Flag::SingleToArray => "SingleToArray",
+            Flag::ArrayItemParseError(_, _) => "ArrayItemParseError",
+            Flag::MapKeyParseError(_, _) => "MapKeyParseError",
+            Flag::MapValueParseError(_, _) => "MapValueParseError",
+            Flag::JsonToString(_) => "JsonToString",
+            Flag::ImpliedKey(_) => "ImpliedKey",
+            Flag::InferedObject(_) => "InferedObject",
+            Flag::FirstMatch(_, _) => "FirstMatch",
+            Flag::UnionMatch(_, _) => "UnionMatch",
+            Flag::StrMatchOneFromMany(_) => "StrMatchOneFromMany",
+            Flag::DefaultFromNoValue => "DefaultFromNoValue",
+            Flag::DefaultButHadValue(_) => "DefaultButHadValue",
+            Flag::OptionalDefaultFromNoValue => "OptionalDefaultFromNoValue",
+            Flag::StringToBool(_) => "StringToBool",
+            Flag::StringToNull(_) => "StringToNull",
+            Flag::StringToChar(_) => "StringToChar",
+            Flag::NonIsoFormat(_) => "NonIsoFormat",
+            Flag::FloatToInt(_) => "FloatToInt",
+            Flag::NoFields(_) => "NoFields",
+            Flag::ConstraintResults(_) => "ConstraintResults",
+        }
+    }
+
+    /// The text from the LLM output that the flag was recorded for, if the
+    /// flag keeps it.
+    pub fn original(&self) -> Option<String> {
+        match self {
+            Flag::ObjectToString(v)
+            | Flag::ObjectToPrimitive(v)
+            | Flag::ObjectToMap(v)
+            | Flag::ExtraKey(_, v)
+            | Flag::JsonToString(v)
+            | Flag::InferedObject(v)
+            | Flag::DefaultButHadValue(v)
+            | Flag::NoFields(Some(v)) => Some(original_text(v)),
+            Flag::StrippedNonAlphaNumeric(s)
+            | Flag::SubstringMatch(s)
+            | Flag::StringToBool(s)
+            | Flag::StringToNull(s)
+            | Flag::StringToChar(s)
+            | Flag::NonIsoFormat(s) => Some(s.clone()),
+            Flag::FloatToInt(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The names of all flags, as returned by [`Flag::name`].
+pub const FLAG_NAMES: &[&str] = &[
+    "ObjectFromMarkdown",
+    "ObjectFromFixedJson",
+    "DefaultButHadUnparseableValue",
+    "ObjectToString",
+    "ObjectToPrimitive",
+    "ObjectToMap",
+    "ExtraKey",
+    "StrippedNonAlphaNumeric",
+    "SubstringMatch",
+    "SingleToArray",
+    "ArrayItemParseError",
+    "MapKeyParseError",
+    "MapValueParseError",
+    "JsonToString",
+    "ImpliedKey",
+    "InferedObject",
+    "FirstMatch",
+    "UnionMatch",
+    "StrMatchOneFromMany",
+    "DefaultFromNoValue",
+    "DefaultButHadValue",
+    "OptionalDefaultFromNoValue",
+    "StringToBool",
+    "StringToNull",
+    "StringToChar",
+    "NonIsoFormat",
+    "FloatToInt",
+    "NoFields",
+    "ConstraintResults",
+];
+
+fn original_text(value: &crate::jsonish::Value) -> String {
+    match value {
+        // Values the parser had several readings of keep the raw text.
+        crate::jsonish::Value::AnyOf(_, raw) => raw.clone(),
+        crate::jsonish::Value::Markdown(_, inner) | crate::jsonish::Value::FixedJson(inner, _) => {
+            original_text(inner)
+        }
+        other => other.to_string(),
+    }
+}
+
 impl DeserializerConditions {
     pub fn add_flag(&mut self, flag: Flag) {
         self.flags.push(flag);
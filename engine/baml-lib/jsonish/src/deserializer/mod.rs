@@ -1,5 +1,6 @@
 pub mod coercer;
 pub mod deserialize_flags;
+pub mod report;
 // pub mod schema;
 mod score;
 pub mod types;
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use super::{
+    deserialize_flags::{DeserializerConditions, Flag, FLAG_NAMES},
+    score::WithScore,
+    types::BamlValueWithFlags,
+};
+
+/// What the parser had to do to coerce an LLM output into the target type.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseReport {
+    /// The score the parser ranks candidate parses by (see `WithScore`), so
+    /// flags on nested values weigh more than the sum of `flags` suggests.
+    /// Lower is better; an output that was valid JSON of the right shape
+    /// scores 0.
+    pub score: i32,
+    pub flags: Vec<ParseFlag>,
+}
+
+/// A single coercion the parser applied.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseFlag {
+    /// The value the flag was recorded on, e.g. `$.items[0].name`.
+    pub path: String,
+    /// The kind of the flag, e.g. `ObjectFromMarkdown`.
+    pub flag: &'static str,
+    pub description: String,
+    /// The text from the LLM output the flag applied to, if known.
+    pub original: Option<String>,
+    pub score: i32,
+}
+
+impl BamlValueWithFlags {
+    /// Every coercion flag in the value, with the path of the value it was
+    /// recorded on. Check results are not included.
+    pub fn report(&self) -> ParseReport {
+        let mut flags = vec![];
+        self.report_impl("$", &mut flags);
+        ParseReport {
+            score: WithScore::score(self),
+            flags,
+        }
+    }
+
+    fn report_impl(&self, path: &str, out: &mut Vec<ParseFlag>) {
+        push_flags(path, self.conditions(), out);
+        match self {
+            BamlValueWithFlags::List(_, items) => {
+                for (i, item) in items.iter().enumerate() {
+                    item.report_impl(&format!("{path}[{i}]"), out);
+                }
+            }
+            BamlValueWithFlags::Map(_, kv) => {
+                for (k, (flags, v)) in kv.iter() {
+                    let path = format!("{path}.{k}");
+                    push_flags(&path, flags, out);
+                    v.report_impl(&path, out);
+                }
+            }
+            BamlValueWithFlags::Class(_, _, fields) => {
+                for (k, v) in fields.iter() {
+                    v.report_impl(&format!("{path}.{k}"), out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn push_flags(path: &str, conditions: &DeserializerConditions, out: &mut Vec<ParseFlag>) {
+    for flag in conditions.flags() {
+        let description = match flag {
+            Flag::ConstraintResults(_) => continue,
+            // The candidates of a union are only useful when debugging the parser.
+            Flag::FirstMatch(idx, options) | Flag::UnionMatch(idx, options) => {
+                format!("Picked option {} of {}", idx, options.len())
+            }
+            _ => flag.to_string().trim().to_string(),
+        };
+        out.push(ParseFlag {
+            path: path.to_string(),
+            flag: flag.name(),
+            description,
+            original: flag.original(),
+            score: flag.score(),
+        });
+    }
+}
+
+/// Limits on a parse above which the output is rejected instead of returned.
+#[derive(Debug, Clone, Default)]
+pub struct ParseThresholds {
+    pub max_score: Option<i32>,
+    pub forbidden_flags: Vec<String>,
+}
+
+impl ParseThresholds {
+    pub fn new(max_score: Option<i32>, forbidden_flags: Vec<String>) -> Result<Self> {
+        if let Some(unknown) = forbidden_flags
+            .iter()
+            .find(|f| !FLAG_NAMES.contains(&f.as_str()))
+        {
+            anyhow::bail!(
+                "Unknown parser flag: {}. Valid flags are: {}",
+                unknown,
+                FLAG_NAMES.join(", ")
+            );
+        }
+        Ok(Self {
+            max_score,
+            forbidden_flags,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max_score.is_none() && self.forbidden_flags.is_empty()
+    }
+
+    /// Fails if `report` has a forbidden flag or scores above the maximum.
+    pub fn check(&self, report: &ParseReport) -> Result<()> {
+        if let Some(flag) = report
+            .flags
+            .iter()
+            .find(|f| self.forbidden_flags.iter().any(|name| name == f.flag))
+        {
+            anyhow::bail!(
+                "Parser applied forbidden flag {} at {}: {}",
+                flag.flag,
+                flag.path,
+                flag.description
+            );
+        }
+        match self.max_score {
+            Some(max_score) if report.score > max_score => anyhow::bail!(
+                "Parse score {} is above the maximum of {} ({})",
+                report.score,
+                max_score,
+                report
+                    .flags
+                    .iter()
+                    .filter(|f| f.score > 0)
+                    .map(|f| format!("{} at {}", f.flag, f.path))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            _ => Ok(()),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::jsonish::{
     parser::{
         fixing_parser,
         markdown_parser::{self, MarkdownResult},
-        multi_json_parser,
+        multi_json_parser, xml_parser, yaml_parser,
     },
     value::Fixes,
     Value,
@@ -102,20 +102,51 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
         }
     }
 
+    if options.allow_xml {
+        match xml_parser::parse(str, &options) {
+            Ok(v) => {
+                return Ok(Value::AnyOf(
+                    vec![Value::FixedJson(v.into(), vec![Fixes::FromXml])],
+                    str.to_string(),
+                ))
+            }
+            Err(e) => {
+                log::debug!("Error parsing XML: {:?}", e);
+            }
+        }
+    }
+
+    // YAML can have flow collections like `tags: [a, b]`, which are found as
+    // JSON objects too, so both are candidates.
+    let yaml = if options.allow_yaml {
+        match yaml_parser::parse(str, &options) {
+            Ok(v) if has_several_entries(&v) => Some(v),
+            Ok(_) => None,
+            Err(e) => {
+                log::debug!("Error parsing YAML: {:?}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     if options.all_finding_all_json_objects {
         match multi_json_parser::parse(str, &options) {
             Ok(items) => match items.len() {
                 0 => {}
                 1 => {
                     return Ok(Value::AnyOf(
-                        vec![Value::FixedJson(
+                        std::iter::once(Value::FixedJson(
                             items
                                 .into_iter()
                                 .next()
                                 .ok_or_else(|| anyhow::anyhow!("Expected 1 item"))?
                                 .into(),
                             vec![Fixes::GreppedForJSON],
-                        )],
+                        ))
+                        .chain(yaml.map(from_yaml))
+                        .collect(),
                         str.to_string(),
                     ))
                 }
@@ -125,6 +156,7 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                         .into_iter()
                         .chain(std::iter::once(items_clone))
                         .map(|v| Value::FixedJson(v.into(), vec![Fixes::GreppedForJSON]))
+                        .chain(yaml.map(from_yaml))
                         .collect::<Vec<_>>();
                     return Ok(Value::AnyOf(items, str.to_string()));
                 }
@@ -135,6 +167,10 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
         }
     }
 
+    if let Some(yaml) = yaml {
+        return Ok(Value::AnyOf(vec![from_yaml(yaml)], str.to_string()));
+    }
+
     if options.allow_fixes {
         match fixing_parser::parse(str, &options) {
             Ok(items) => {
@@ -185,3 +221,16 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
 
     Err(anyhow::anyhow!("Failed to parse JSON"))
 }
+
+fn from_yaml(value: Value) -> Value {
+    Value::FixedJson(value.into(), vec![Fixes::FromYaml])
+}
+
+/// Whether YAML is more than a line of text in front of a value, like
+/// `Answer: true` or `Answer: {"a": 1}`.
+fn has_several_entries(value: &Value) -> bool {
+    match value {
+        Value::Object(fields) => fields.len() > 1,
+        _ => true,
+    }
+}
@@ -2,6 +2,13 @@ mod entry;
 mod fixing_parser;
 mod markdown_parser;
 mod multi_json_parser;
+mod xml_parser;
+mod yaml_parser;
+
+use baml_types::ParserMode;
+use internal_baml_jinja::types::ResponseFormat;
+
+use crate::jsonish::Value;
 
 pub use entry::parse;
 
@@ -10,6 +17,8 @@ pub struct ParseOptions {
     all_finding_all_json_objects: bool,
     allow_markdown_json: bool,
     allow_fixes: bool,
+    allow_yaml: bool,
+    allow_xml: bool,
     allow_as_string: bool,
     depth: usize,
 }
@@ -20,12 +29,35 @@ impl Default for ParseOptions {
             all_finding_all_json_objects: true,
             allow_markdown_json: true,
             allow_fixes: true,
+            allow_yaml: false,
+            allow_xml: false,
             allow_as_string: true,
             depth: 0,
         }
     }
 }
 
+impl From<ParserMode> for ParseOptions {
+    fn from(mode: ParserMode) -> Self {
+        match mode {
+            ParserMode::Strict => Self {
+                all_finding_all_json_objects: false,
+                allow_markdown_json: false,
+                allow_fixes: false,
+                allow_yaml: false,
+                allow_xml: false,
+                allow_as_string: false,
+                depth: 0,
+            },
+            ParserMode::NoFixes => Self {
+                allow_fixes: false,
+                ..Default::default()
+            },
+            ParserMode::Lenient => Self::default(),
+        }
+    }
+}
+
 pub(super) enum ParsingMode {
     JsonMarkdown,
     JsonMarkdownString,
@@ -33,6 +65,19 @@ pub(super) enum ParsingMode {
 }
 
 impl ParseOptions {
+    pub fn with_fixes(mut self) -> Self {
+        self.allow_fixes = true;
+        self
+    }
+
+    /// YAML and XML are only read when the prompt asked for them, so JSON
+    /// answers are parsed the same as before they were supported.
+    pub fn with_format(mut self, format: ResponseFormat) -> Self {
+        self.allow_yaml = format == ResponseFormat::Yaml;
+        self.allow_xml = format == ResponseFormat::Xml;
+        self
+    }
+
     pub(super) fn next_from_mode(&self, curr_mode: ParsingMode) -> Self {
         let mut new = self.clone();
         match curr_mode {
@@ -46,9 +91,27 @@ impl ParseOptions {
             }
             ParsingMode::AllJsonObjects => {
                 new.all_finding_all_json_objects = false;
+                new.allow_yaml = false;
+                new.allow_xml = false;
                 new.allow_as_string = false;
             }
         }
         new
     }
 }
+
+/// A scalar from YAML or XML, which have no quotes to tell strings apart from
+/// numbers, booleans and null. Numbers and booleans keep their text, so string
+/// fields get it unchanged.
+fn scalar(text: &str) -> Value {
+    let value = match text {
+        "~" | "null" | "Null" | "NULL" => return Value::Null,
+        "true" | "True" | "TRUE" => Value::Boolean(true),
+        "false" | "False" | "FALSE" => Value::Boolean(false),
+        _ => match text.parse::<serde_json::Number>() {
+            Ok(n) => Value::Number(n),
+            Err(_) => return Value::String(text.to_string()),
+        },
+    };
+    Value::AnyOf(vec![value], text.to_string())
+}
@@ -0,0 +1,278 @@
+use crate::jsonish::Value;
+
+use super::{scalar, ParseOptions};
+use anyhow::Result;
+
+/// Parses the first XML element with child elements in `str` into the value
+/// of its content.
+///
+/// Elements that only contain text become scalars, and elements with child
+/// elements become objects keyed by tag name, or lists when every child is
+/// an `<item>` or shares one tag name. The name of the outermost element is
+/// dropped. Elements still open at the end of the input, like in a streamed
+/// response, are closed.
+pub fn parse(str: &str, _options: &ParseOptions) -> Result<Value> {
+    let mut from = 0;
+    while let Some(start) = find_tag_start(str, from) {
+        if str[..start].contains(['{', '[']) {
+            anyhow::bail!("XML after JSON is parsed as JSON");
+        }
+        match parse_element(str, start) {
+            (Some(value @ Value::AnyOf(..)), _) => return Ok(value),
+            // Skip elements that only have text, like `<thinking>` blocks.
+            (_, end) => from = end.max(start + 1),
+        }
+    }
+    anyhow::bail!("No XML elements with child elements found")
+}
+
+/// Parses the element that starts at `start`, and returns its value and where
+/// it ends.
+fn parse_element(str: &str, start: usize) -> (Option<Value>, usize) {
+    let mut stack: Vec<Element> = vec![];
+    let mut root = None;
+    let mut pos = start;
+    while pos < str.len() && root.is_none() {
+        let rest = &str[pos..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            pos += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").unwrap_or(cdata.len());
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&cdata[..end]);
+            }
+            pos += 9 + (end + 3).min(cdata.len());
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            pos += rest.find('>').map_or(rest.len(), |end| end + 1);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let Some(end) = tag.find('>') else {
+                break;
+            };
+            let name = tag[..end].trim();
+            // Closing tags without a matching open tag are ignored.
+            if let Some(idx) = stack.iter().rposition(|e| e.name == name) {
+                while stack.len() > idx {
+                    let element = stack.pop().expect("stack is not empty");
+                    let value = element.into_value(&str[..pos]);
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(value),
+                        None => root = Some(value.1),
+                    }
+                }
+            }
+            pos += 2 + end + 1;
+        } else if find_tag_start(rest, 0) == Some(0) {
+            let Some(end) = tag_end(rest) else {
+                break;
+            };
+            let tag = &rest[1..end];
+            let self_closing = tag.ends_with('/');
+            let name = tag
+                .trim_end_matches('/')
+                .split(|c: char| c.is_whitespace())
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            pos += end + 1;
+            if self_closing {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push((name, Value::Null)),
+                    None => root = Some(Value::Null),
+                }
+            } else {
+                stack.push(Element {
+                    name,
+                    content_start: pos,
+                    children: vec![],
+                    text: String::new(),
+                });
+            }
+        } else {
+            let skip = rest.chars().next().map_or(1, char::len_utf8);
+            let end = find_tag_start(rest, skip)
+                .into_iter()
+                .chain(
+                    ["</", "<!", "<?"]
+                        .iter()
+                        .filter_map(|p| rest[skip..].find(p).map(|i| i + skip)),
+                )
+                .min()
+                .unwrap_or(rest.len());
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&decode_entities(&rest[..end]));
+            }
+            pos += end;
+        }
+    }
+
+    // Close the elements of a streamed response that hasn't finished.
+    while let Some(element) = stack.pop() {
+        let value = element.into_value(str);
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(value),
+            None => root = Some(value.1),
+        }
+    }
+
+    (root, pos)
+}
+
+struct Element {
+    name: String,
+    /// Where the content of the element starts in the input.
+    content_start: usize,
+    children: Vec<(String, Value)>,
+    text: String,
+}
+
+impl Element {
+    /// The value of the element, given the input up to where its content ends.
+    fn into_value(self, input: &str) -> (String, Value) {
+        if self.children.is_empty() {
+            let text = self.text.trim();
+            let value = if text.is_empty() {
+                Value::Null
+            } else {
+                scalar(text)
+            };
+            return (self.name, value);
+        }
+
+        let first = &self.children[0].0;
+        let is_list = self.children.iter().all(|(name, _)| name == first)
+            && (first == "item" || self.children.len() > 1);
+        let value = if is_list {
+            Value::Array(self.children.into_iter().map(|(_, v)| v).collect())
+        } else {
+            Value::Object(self.children)
+        };
+        // Keep the markup, for string fields that contain tags.
+        let raw = input.get(self.content_start..).unwrap_or_default().trim();
+        (self.name, Value::AnyOf(vec![value], raw.to_string()))
+    }
+}
+
+/// The index of the first `<` at or after `from` that opens a tag.
+fn find_tag_start(str: &str, from: usize) -> Option<usize> {
+    str.get(from..)?
+        .match_indices('<')
+        .map(|(i, _)| i + from)
+        .find(|&i| {
+            str[i + 1..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        })
+}
+
+/// The index of the `>` that ends the tag at the start of `str`.
+fn tag_end(str: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in str.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('>', None) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                code => code
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| code.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_xml(str: &str) -> Value {
+        match parse(str, &ParseOptions::default()).unwrap() {
+            Value::AnyOf(mut values, _) => values.remove(0),
+            value => value,
+        }
+    }
+
+    #[test]
+    fn test_elements() {
+        let value = parse_xml(
+            "Here you go:\n<Person>\n  <name>Jane &amp; co</name>\n  <bio><![CDATA[Likes <b>bold</b>]]></bio>\n  <jobs>\n    <item><title>Engineer</title></item>\n  </jobs>\n  <tags><tag>a</tag><tag>b</tag></tags>\n  <nickname/>\n</Person>",
+        );
+        let Value::Object(fields) = value else {
+            panic!("Expected an object: {value:?}");
+        };
+        let names = fields.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["name", "bio", "jobs", "tags", "nickname"]);
+        assert_eq!(fields[0].1, Value::String("Jane & co".to_string()));
+        assert_eq!(fields[1].1, Value::String("Likes <b>bold</b>".to_string()));
+        assert!(
+            matches!(&fields[2].1, Value::AnyOf(v, _) if matches!(&v[0], Value::Array(items) if items.len() == 1))
+        );
+        assert!(
+            matches!(&fields[3].1, Value::AnyOf(v, _) if matches!(&v[0], Value::Array(items) if items.len() == 2))
+        );
+        assert_eq!(fields[4].1, Value::Null);
+    }
+
+    #[test]
+    fn test_partial() {
+        let value = parse_xml("<Person>\n  <name>Jane</name>\n  <bio>Grew up in");
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                ("name".to_string(), Value::String("Jane".to_string())),
+                ("bio".to_string(), Value::String("Grew up in".to_string())),
+            ])
+        );
+
+        let value = parse_xml("<Person>\n  <name>Jane</name>\n  <bi");
+        assert_eq!(
+            value,
+            Value::Object(vec![(
+                "name".to_string(),
+                Value::String("Jane".to_string())
+            )])
+        );
+    }
+
+    #[test]
+    fn test_not_xml() {
+        let options = ParseOptions::default();
+        assert!(parse("1 < 2", &options).is_err());
+        assert!(parse("<b>bold</b>", &options).is_err());
+        assert!(parse("{\"html\": \"<div><p>hi", &options).is_err());
+    }
+}
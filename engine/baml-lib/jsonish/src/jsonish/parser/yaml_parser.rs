@@ -0,0 +1,463 @@
+use crate::jsonish::Value;
+
+use super::{fixing_parser, scalar, ParseOptions};
+use anyhow::Result;
+
+/// Parses the block mappings and sequences of a YAML document.
+///
+/// Only the subset of YAML that LLMs write is supported: block mappings and
+/// sequences, plain, quoted and block (`|` and `>`) scalars, and flow
+/// collections on a single line. Input that ends mid-document, like a
+/// streamed response, is parsed up to where it stops.
+pub fn parse(str: &str, options: &ParseOptions) -> Result<Value> {
+    let lines = str
+        .lines()
+        .map(|line| {
+            let text = line.trim_start_matches(' ');
+            Line {
+                indent: line.len() - text.len(),
+                text: text.trim_end(),
+                raw: line,
+            }
+        })
+        .filter(|line| !matches!(line.text, "---" | "..."))
+        .collect::<Vec<_>>();
+
+    let mut parser = YamlParser {
+        lines,
+        pos: 0,
+        options,
+    };
+
+    // Text around JSON is parsed as JSON, even when it looks like YAML.
+    if parser
+        .lines
+        .iter()
+        .any(|l| l.indent == 0 && l.text.starts_with(['{', '[']))
+    {
+        anyhow::bail!("Flow collections are parsed as JSON");
+    }
+    if parser.peek().is_none() {
+        anyhow::bail!("No YAML found");
+    }
+
+    let value = parser.parse_node(None)?;
+    if let Some(line) = parser.peek() {
+        anyhow::bail!("Unexpected YAML: {}", line.text);
+    }
+
+    match value {
+        Value::Object(_) | Value::Array(_) => Ok(value),
+        _ => anyhow::bail!("YAML is not a mapping or a sequence"),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Line<'a> {
+    indent: usize,
+    /// The line without its indentation and trailing whitespace.
+    text: &'a str,
+    raw: &'a str,
+}
+
+impl Line<'_> {
+    fn is_content(&self) -> bool {
+        !self.text.is_empty() && !self.text.starts_with('#')
+    }
+}
+
+struct YamlParser<'a, 'o> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+    options: &'o ParseOptions,
+}
+
+impl<'a> YamlParser<'a, '_> {
+    /// The next line that isn't blank or a comment.
+    fn peek(&mut self) -> Option<Line<'a>> {
+        while let Some(line) = self.lines.get(self.pos) {
+            if line.is_content() {
+                return Some(*line);
+            }
+            self.pos += 1;
+        }
+        None
+    }
+
+    fn is_last_line(&self) -> bool {
+        self.lines[self.pos + 1..].iter().all(|l| !l.is_content())
+    }
+
+    /// Parses the node on the next line, if it's indented more than `parent`.
+    fn parse_node(&mut self, parent: Option<usize>) -> Result<Value> {
+        let Some(line) = self.peek() else {
+            return Ok(Value::Null);
+        };
+        if parent.is_some_and(|p| line.indent <= p) {
+            return Ok(Value::Null);
+        }
+
+        if is_sequence_item(line.text) {
+            self.parse_sequence(line.indent)
+        } else if split_key(line.text).is_some() {
+            self.parse_mapping(line.indent)
+        } else {
+            self.pos += 1;
+            self.parse_inline(line.text, parent)
+        }
+    }
+
+    fn parse_mapping(&mut self, indent: usize) -> Result<Value> {
+        let mut fields = vec![];
+        while let Some(line) = self.peek() {
+            if line.indent < indent {
+                break;
+            }
+            if line.indent > indent {
+                anyhow::bail!("Unexpected indentation: {}", line.text);
+            }
+            let Some((key, rest)) = split_key(line.text) else {
+                // The key of a streamed response may not have its colon yet.
+                if self.is_last_line() && line.text.chars().all(|c| c.is_alphanumeric() || c == '_')
+                {
+                    self.pos += 1;
+                    break;
+                }
+                anyhow::bail!("Expected a key: {}", line.text);
+            };
+            self.pos += 1;
+
+            let value = if rest.is_empty() {
+                match self.peek() {
+                    // Sequences may be indented as much as their key.
+                    Some(next) if next.indent == indent && is_sequence_item(next.text) => {
+                        self.parse_sequence(indent)?
+                    }
+                    _ => self.parse_node(Some(indent))?,
+                }
+            } else {
+                self.parse_inline(rest, Some(indent))?
+            };
+            fields.push((key, value));
+        }
+        Ok(Value::Object(fields))
+    }
+
+    fn parse_sequence(&mut self, indent: usize) -> Result<Value> {
+        let mut items = vec![];
+        while let Some(line) = self.peek() {
+            if line.indent != indent || !is_sequence_item(line.text) {
+                break;
+            }
+            let rest = line.text[1..].trim_start();
+            let item = if rest.is_empty() {
+                self.pos += 1;
+                self.parse_node(Some(indent))?
+            } else if is_sequence_item(rest) || split_key(rest).is_some() {
+                // `- key: value` starts a mapping at the column of `key`.
+                self.lines[self.pos] = Line {
+                    indent: indent + line.text.len() - rest.len(),
+                    text: rest,
+                    raw: line.raw,
+                };
+                self.parse_node(Some(indent))?
+            } else {
+                self.pos += 1;
+                self.parse_inline(rest, Some(indent))?
+            };
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+
+    /// Parses a value that starts on the same line as its key or dash.
+    fn parse_inline(&mut self, text: &str, parent: Option<usize>) -> Result<Value> {
+        if text.starts_with(['|', '>']) {
+            return Ok(Value::String(self.parse_block_scalar(text, parent)));
+        }
+        if text.starts_with(['"', '\'']) {
+            return Ok(Value::String(self.parse_quoted(text)));
+        }
+        if text.starts_with(['[', '{']) {
+            return Ok(self.parse_flow(text));
+        }
+
+        // Plain scalars continue on lines indented more than their parent.
+        let mut plain = strip_comment(text).to_string();
+        while let Some(line) = self.peek() {
+            if parent.is_some_and(|p| line.indent <= p)
+                || is_sequence_item(line.text)
+                || split_key(line.text).is_some()
+            {
+                break;
+            }
+            plain.push(' ');
+            plain.push_str(strip_comment(line.text));
+            self.pos += 1;
+        }
+        Ok(scalar(&plain))
+    }
+
+    fn parse_block_scalar(&mut self, header: &str, parent: Option<usize>) -> String {
+        let folded = header.starts_with('>');
+        let chomping = header[1..].trim_start_matches(|c: char| c.is_ascii_digit());
+
+        let mut lines = vec![];
+        while let Some(line) = self.lines.get(self.pos) {
+            if !line.text.is_empty() && parent.is_some_and(|p| line.indent <= p) {
+                break;
+            }
+            lines.push(*line);
+            self.pos += 1;
+        }
+        while lines.last().is_some_and(|l| l.text.is_empty()) {
+            lines.pop();
+        }
+
+        let block_indent = lines
+            .iter()
+            .find(|l| !l.text.is_empty())
+            .map_or(0, |l| l.indent);
+        let lines = lines
+            .iter()
+            .map(|l| {
+                l.raw
+                    .get(block_indent..)
+                    .unwrap_or("")
+                    .trim_end_matches('\r')
+            })
+            .collect::<Vec<_>>();
+
+        let mut content = if folded {
+            let mut content = String::new();
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    content.push(if line.is_empty() || lines[i - 1].is_empty() {
+                        '\n'
+                    } else {
+                        ' '
+                    });
+                }
+                content.push_str(line);
+            }
+            content
+        } else {
+            lines.join("\n")
+        };
+        if !chomping.starts_with('-') {
+            content.push('\n');
+        }
+        content
+    }
+
+    fn parse_quoted(&mut self, text: &str) -> String {
+        let quote = text.chars().next().unwrap_or('"');
+
+        // Quoted scalars may continue on the next lines.
+        let mut quoted = text.to_string();
+        while closing_quote(&quoted, quote).is_none() {
+            match self.lines.get(self.pos) {
+                Some(line) => {
+                    quoted.push(' ');
+                    quoted.push_str(line.text);
+                    self.pos += 1;
+                }
+                None => break,
+            }
+        }
+
+        let inner = match closing_quote(&quoted, quote) {
+            Some(end) => &quoted[1..end],
+            // A streamed response may end inside the quotes.
+            None => &quoted[1..],
+        };
+        if quote == '\'' {
+            inner.replace("''", "'")
+        } else {
+            serde_json::from_str(&format!("\"{inner}\"")).unwrap_or_else(|_| inner.to_string())
+        }
+    }
+
+    fn parse_flow(&mut self, text: &str) -> Value {
+        let text = strip_comment(text);
+        if let Ok(value) = serde_json::from_str(text) {
+            return value;
+        }
+        match fixing_parser::parse(text, self.options) {
+            Ok(items) if items.len() == 1 => items
+                .into_iter()
+                .next()
+                .map_or(Value::Null, |(value, _fixes)| value),
+            _ => Value::String(text.to_string()),
+        }
+    }
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Splits `key: rest` into the key and the rest of the line.
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if text.starts_with(['-', '[', '{', '#', '|', '>']) {
+        return None;
+    }
+
+    if let Some(quote) = text.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+        let end = closing_quote(text, quote)?;
+        let rest = text[end + 1..].trim_start().strip_prefix(':')?;
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return None;
+        }
+        return Some((text[1..end].to_string(), rest.trim()));
+    }
+
+    let bytes = text.as_bytes();
+    let colon = (0..bytes.len())
+        .find(|&i| bytes[i] == b':' && bytes.get(i + 1).map_or(true, |c| *c == b' '))?;
+    let key = text[..colon].trim();
+    if key.is_empty() {
+        return None;
+    }
+    Some((key.to_string(), text[colon + 1..].trim()))
+}
+
+/// The index of the quote that closes the scalar opened at the start of `text`.
+fn closing_quote(text: &str, quote: char) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1).peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if quote == '"' => {
+                chars.next();
+            }
+            // Single quotes are escaped by doubling them.
+            '\'' if quote == '\'' && chars.peek().is_some_and(|(_, c)| *c == '\'') => {
+                chars.next();
+            }
+            c if c == quote => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn strip_comment(text: &str) -> &str {
+    match text.find(" #") {
+        Some(i) => text[..i].trim_end(),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_yaml(str: &str) -> Value {
+        parse(str, &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_nested_mappings_and_sequences() {
+        let value = parse_yaml(
+            "name: Jane\njobs:\n  - title: Engineer\n    years: 3\n  - title: Manager\ntags:\n- a\n- b\n",
+        );
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                ("name".to_string(), Value::String("Jane".to_string())),
+                (
+                    "jobs".to_string(),
+                    Value::Array(vec![
+                        Value::Object(vec![
+                            ("title".to_string(), Value::String("Engineer".to_string())),
+                            ("years".to_string(), scalar("3")),
+                        ]),
+                        Value::Object(vec![(
+                            "title".to_string(),
+                            Value::String("Manager".to_string())
+                        )]),
+                    ])
+                ),
+                (
+                    "tags".to_string(),
+                    Value::Array(vec![
+                        Value::String("a".to_string()),
+                        Value::String("b".to_string())
+                    ])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_block_scalars() {
+        let value = parse_yaml(
+            "body: |\n  Line one: \"quoted\"\n\n  # not a comment\nsummary: >-\n  folded\n  text\n",
+        );
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                (
+                    "body".to_string(),
+                    Value::String("Line one: \"quoted\"\n\n# not a comment\n".to_string())
+                ),
+                (
+                    "summary".to_string(),
+                    Value::String("folded text".to_string())
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_quoted_and_flow_values() {
+        let value = parse_yaml("a: \"x: \\\"y\\\"\"\nb: 'it''s'\nc: [1, two]\n");
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                ("a".to_string(), Value::String("x: \"y\"".to_string())),
+                ("b".to_string(), Value::String("it's".to_string())),
+                (
+                    "c".to_string(),
+                    Value::Array(vec![
+                        Value::Number(1.into()),
+                        Value::String("two".to_string())
+                    ])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_partial() {
+        let value = parse_yaml("name: Jane\nbio: \"Grew up in");
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                ("name".to_string(), Value::String("Jane".to_string())),
+                ("bio".to_string(), Value::String("Grew up in".to_string())),
+            ])
+        );
+
+        let value = parse_yaml("name: Jane\nag");
+        assert_eq!(
+            value,
+            Value::Object(vec![(
+                "name".to_string(),
+                Value::String("Jane".to_string())
+            )])
+        );
+    }
+
+    #[test]
+    fn test_not_yaml() {
+        let options = ParseOptions::default();
+        assert!(parse("Just some text.", &options).is_err());
+        assert!(parse(
+            "Note: this is prose.\nIt keeps going.\nAnd going.",
+            &options
+        )
+        .is_err());
+        assert!(parse("{\"a\": 1", &options).is_err());
+    }
+}
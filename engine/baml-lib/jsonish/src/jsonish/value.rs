@@ -9,6 +9,10 @@ use baml_types::BamlMap;
 pub enum Fixes {
     GreppedForJSON,
     InferredArray,
+    /// The value was written as YAML instead of JSON.
+    FromYaml,
+    /// The value was written as XML instead of JSON.
+    FromXml,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
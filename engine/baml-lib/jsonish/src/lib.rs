@@ -5,9 +5,10 @@ use anyhow::Result;
 pub mod deserializer;
 mod jsonish;
 
-use baml_types::FieldType;
+use baml_types::{FieldType, ParserMode};
 use deserializer::coercer::{ParsingContext, TypeCoercer};
 
+pub use deserializer::report::{ParseFlag, ParseReport, ParseThresholds};
 pub use deserializer::types::BamlValueWithFlags;
 use internal_baml_core::ir::TypeValue;
 use internal_baml_jinja::types::OutputFormatContent;
@@ -20,13 +21,40 @@ pub fn from_str(
     target: &FieldType,
     raw_string: &str,
     allow_partials: bool,
+) -> Result<BamlValueWithFlags> {
+    from_str_with_mode(
+        of,
+        target,
+        raw_string,
+        allow_partials,
+        ParserMode::default(),
+    )
+}
+
+/// Like [`from_str`], with the heuristics allowed by `mode`.
+pub fn from_str_with_mode(
+    of: &OutputFormatContent,
+    target: &FieldType,
+    raw_string: &str,
+    allow_partials: bool,
+    mode: ParserMode,
 ) -> Result<BamlValueWithFlags> {
     if matches!(target, FieldType::Primitive(TypeValue::String)) {
         return Ok(BamlValueWithFlags::String(raw_string.to_string().into()));
     }
 
     // When the schema is just a string, i should really just return the raw_string w/o parsing it.
-    let mut value = jsonish::parse(raw_string, jsonish::ParseOptions::default())?;
+    let mut options = jsonish::ParseOptions::from(mode);
+    // Strict parsing only accepts JSON.
+    if mode != ParserMode::Strict {
+        options = options.with_format(of.format);
+    }
+    // Partial outputs are unfinished JSON, which only the fixing parser reads.
+    // The final output is still parsed with the options of the mode.
+    if allow_partials {
+        options = options.with_fixes();
+    }
+    let mut value = jsonish::parse(raw_string, options)?;
     // let schema = deserializer::schema::from_jsonish_value(&value, None);
 
     // See Note [Streaming Number Invalidation]
@@ -37,7 +65,7 @@ pub fn from_str(
     // Pick the schema that is the most specific.
     // log::info!("Parsed: {}", schema);
     log::debug!("Parsed JSONish (step 1 of parsing): {:#?}", value);
-    let ctx = ParsingContext::new(of, allow_partials);
+    let ctx = ParsingContext::new(of, allow_partials, mode == ParserMode::Strict);
     // let res = schema.cast_to(target);
     // log::info!("Casted: {:?}", res);
 
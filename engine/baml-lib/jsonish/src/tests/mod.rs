@@ -9,14 +9,17 @@ mod test_class;
 mod test_class_2;
 mod test_code;
 mod test_constraints;
+mod test_dates;
 mod test_enum;
+mod test_formats;
 mod test_lists;
 mod test_literals;
 mod test_maps;
+mod test_parser_modes;
 mod test_partials;
 mod test_unions;
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
@@ -30,7 +33,7 @@ use internal_baml_core::{
 };
 use serde_json::json;
 
-use crate::from_str;
+use crate::{from_str, ParseThresholds};
 
 fn load_test_ir(file_content: &str) -> IntermediateRepr {
     let mut schema = validate(
@@ -224,10 +227,26 @@ fn relevant_data_models<'a>(
                         }
                     }
 
+                    let mut defaults = IndexMap::new();
+                    if let Ok(walker) = &walker {
+                        for field in walker.walk_fields() {
+                            if let Some(value) = field.default_value(env_values)? {
+                                defaults.insert(field.name().to_string(), value);
+                            }
+                        }
+                    }
+                    let tag = walker
+                        .as_ref()
+                        .ok()
+                        .and_then(|walker| walker.tag())
+                        .map(|(field, _)| field.to_string());
+
                     classes.push(Class {
                         name: Name::new_with_alias(cls.to_string(), walker?.alias(env_values)?),
                         fields,
                         constraints,
+                        defaults,
+                        tag,
                     });
                 }
             }
@@ -1489,3 +1489,177 @@ test_deserializer!(
     },
   }
 );
+
+const DEFAULTS_FILE: &str = r#"
+enum Priority {
+  LOW
+  HIGH
+}
+
+class Task {
+  title string
+  done bool @default(false)
+  priority Priority @default(LOW)
+  tags string[] @default([])
+  note string? @default("none")
+}
+"#;
+
+test_deserializer!(
+    test_class_defaults_missing_fields,
+    DEFAULTS_FILE,
+    r#"{"title": "Write docs"}"#,
+    FieldType::Class("Task".to_string()),
+    {"title": "Write docs", "done": false, "priority": "LOW", "tags": [], "note": "none"}
+);
+
+test_deserializer!(
+    test_class_defaults_given_fields,
+    DEFAULTS_FILE,
+    r#"{"title": "Write docs", "done": true, "priority": "HIGH", "tags": ["docs"], "note": null}"#,
+    FieldType::Class("Task".to_string()),
+    {"title": "Write docs", "done": true, "priority": "HIGH", "tags": ["docs"], "note": null}
+);
+
+test_deserializer!(
+    test_class_defaults_unparseable_field,
+    DEFAULTS_FILE,
+    r#"{"title": "Write docs", "done": "perhaps"}"#,
+    FieldType::Class("Task".to_string()),
+    {"title": "Write docs", "done": false, "priority": "LOW", "tags": [], "note": "none"}
+);
+
+#[test_log::test]
+fn test_class_defaults_flags() {
+    let ir = load_test_ir(DEFAULTS_FILE);
+    let target_type = FieldType::Class("Task".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let result = from_str(
+        &target,
+        &target_type,
+        "```json\n{\"title\": \"Write docs\", \"done\": \"perhaps\"}\n```",
+        false,
+    )
+    .unwrap();
+
+    let report = result.report();
+    assert!(report.flags.iter().any(|f| f.flag == "ObjectFromMarkdown"));
+    assert!(report
+        .flags
+        .iter()
+        .any(|f| f.path == "$.done" && f.flag == "DefaultButHadUnparseableValue"));
+    assert!(report
+        .flags
+        .iter()
+        .any(|f| f.path == "$.priority" && f.flag == "DefaultFromNoValue"));
+    // Flags on fields weigh ten times as much as flags on the class itself.
+    let weighted = report
+        .flags
+        .iter()
+        .map(|f| if f.path == "$" { f.score } else { 10 * f.score })
+        .sum::<i32>();
+    assert_eq!(report.score, weighted);
+
+    assert!(ParseThresholds::default().check(&report).is_ok());
+    assert!(ParseThresholds::new(Some(report.score), vec![])
+        .unwrap()
+        .check(&report)
+        .is_ok());
+    assert!(ParseThresholds::new(Some(report.score - 1), vec![])
+        .unwrap()
+        .check(&report)
+        .is_err());
+    assert!(
+        ParseThresholds::new(None, vec!["DefaultFromNoValue".into()])
+            .unwrap()
+            .check(&report)
+            .is_err()
+    );
+    assert!(ParseThresholds::new(None, vec!["NotAFlag".into()]).is_err());
+}
+
+#[test]
+fn test_flag_names_cover_every_flag() {
+    use crate::deserializer::{
+        coercer::ParsingError,
+        deserialize_flags::{Flag, FLAG_NAMES},
+    };
+    use crate::jsonish::Value;
+
+    let error = || ParsingError {
+        scope: vec![],
+        reason: String::new(),
+        causes: vec![],
+    };
+    let flags = [
+        Flag::ObjectFromMarkdown(0),
+        Flag::ObjectFromFixedJson(vec![]),
+        Flag::DefaultButHadUnparseableValue(error()),
+        Flag::ObjectToString(Value::Null),
+        Flag::ObjectToPrimitive(Value::Null),
+        Flag::ObjectToMap(Value::Null),
+        Flag::ExtraKey(String::new(), Value::Null),
+        Flag::StrippedNonAlphaNumeric(String::new()),
+        Flag::SubstringMatch(String::new()),
+        Flag::SingleToArray,
+        Flag::ArrayItemParseError(0, error()),
+        Flag::MapKeyParseError(0, error()),
+        Flag::MapValueParseError(String::new(), error()),
+        Flag::JsonToString(Value::Null),
+        Flag::ImpliedKey(String::new()),
+        Flag::InferedObject(Value::Null),
+        Flag::FirstMatch(0, vec![]),
+        Flag::UnionMatch(0, vec![]),
+        Flag::StrMatchOneFromMany(vec![]),
+        Flag::DefaultFromNoValue,
+        Flag::DefaultButHadValue(Value::Null),
+        Flag::OptionalDefaultFromNoValue,
+        Flag::StringToBool(String::new()),
+        Flag::StringToNull(String::new()),
+        Flag::StringToChar(String::new()),
+        Flag::NonIsoFormat(String::new()),
+        Flag::FloatToInt(0.0),
+        Flag::NoFields(None),
+        Flag::ConstraintResults(vec![]),
+    ];
+    // Fails to compile when a variant is added: add it to `flags` above and
+    // its name to `FLAG_NAMES`.
+    for flag in flags.iter() {
+        match flag {
+            Flag::ObjectFromMarkdown(_)
+            | Flag::ObjectFromFixedJson(_)
+            | Flag::DefaultButHadUnparseableValue(_)
+            | Flag::ObjectToString(_)
+            | Flag::ObjectToPrimitive(_)
+            | Flag::ObjectToMap(_)
+            | Flag::ExtraKey(_, _)
+            | Flag::StrippedNonAlphaNumeric(_)
+            | Flag::SubstringMatch(_)
+            | Flag::SingleToArray
+            | Flag::ArrayItemParseError(_, _)
+            | Flag::MapKeyParseError(_, _)
+            | Flag::MapValueParseError(_, _)
+            | Flag::JsonToString(_)
+            | Flag::ImpliedKey(_)
+            | Flag::InferedObject(_)
+            | Flag::FirstMatch(_, _)
+            | Flag::UnionMatch(_, _)
+            | Flag::StrMatchOneFromMany(_)
+            | Flag::DefaultFromNoValue
+            | Flag::DefaultButHadValue(_)
+            | Flag::OptionalDefaultFromNoValue
+            | Flag::StringToBool(_)
+            | Flag::StringToNull(_)
+            | Flag::StringToChar(_)
+            | Flag::NonIsoFormat(_)
+            | Flag::FloatToInt(_)
+            | Flag::NoFields(_)
+            | Flag::ConstraintResults(_) => {}
+        }
+    }
+
+    let names = flags.iter().map(Flag::name).collect::<IndexSet<_>>();
+    assert_eq!(names.len(), flags.len(), "flag names must be unique");
+    assert_eq!(names, FLAG_NAMES.iter().copied().collect::<IndexSet<_>>());
+}
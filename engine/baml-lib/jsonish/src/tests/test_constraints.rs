@@ -156,3 +156,49 @@ test_failing_deserializer!(
     r#"THREE"#,
     FieldType::Enum("MyEnum".to_string())
 );
+
+const VALUE_CONSTRAINTS: &str = r#"
+class Signup {
+  age int @min(13) @max(120)
+  username string @min_length(3) @max_length(16) @pattern("^[a-z0-9_]+$")
+  plan string @one_of(["free", "pro"])
+  tags string[] @max_length(2)
+  referrer string? @min_length(1)
+}
+"#;
+
+test_deserializer!(
+    test_value_constraints_pass,
+    VALUE_CONSTRAINTS,
+    r#"{"age": 30, "username": "jane_doe", "plan": "pro", "tags": ["a"], "referrer": null}"#,
+    FieldType::Class("Signup".to_string()),
+    {"age": 30, "username": "jane_doe", "plan": "pro", "tags": ["a"], "referrer": null}
+);
+
+test_failing_deserializer!(
+    test_value_constraints_min,
+    VALUE_CONSTRAINTS,
+    r#"{"age": 12, "username": "jane_doe", "plan": "pro", "tags": []}"#,
+    FieldType::Class("Signup".to_string())
+);
+
+test_failing_deserializer!(
+    test_value_constraints_pattern,
+    VALUE_CONSTRAINTS,
+    r#"{"age": 30, "username": "Jane Doe", "plan": "pro", "tags": []}"#,
+    FieldType::Class("Signup".to_string())
+);
+
+test_failing_deserializer!(
+    test_value_constraints_one_of,
+    VALUE_CONSTRAINTS,
+    r#"{"age": 30, "username": "jane_doe", "plan": "enterprise", "tags": []}"#,
+    FieldType::Class("Signup".to_string())
+);
+
+test_failing_deserializer!(
+    test_value_constraints_list_length,
+    VALUE_CONSTRAINTS,
+    r#"{"age": 30, "username": "jane_doe", "plan": "free", "tags": ["a", "b", "c"]}"#,
+    FieldType::Class("Signup".to_string())
+);
@@ -0,0 +1,71 @@
+use super::*;
+
+use crate::from_str_with_mode;
+use baml_types::ParserMode;
+
+const EVENT_FILE: &str = r#"
+class Event {
+  name string
+  day date
+  starts datetime
+  length duration
+}
+"#;
+
+test_deserializer!(
+    test_iso_dates,
+    EVENT_FILE,
+    r#"{"name": "Launch", "day": "2024-03-03", "starts": "2024-03-03 10:30Z", "length": "PT90M"}"#,
+    FieldType::Class("Event".to_string()),
+    {
+      "name": "Launch",
+      "day": "2024-03-03",
+      "starts": "2024-03-03T10:30:00Z",
+      "length": "PT1H30M"
+    }
+);
+
+test_deserializer!(
+    test_natural_dates,
+    EVENT_FILE,
+    r#"{"name": "Launch", "day": "March 3rd, 2024", "starts": "March 3rd, 2024 at 10:30 AM UTC", "length": "1 hour and 30 minutes"}"#,
+    FieldType::Class("Event".to_string()),
+    {
+      "name": "Launch",
+      "day": "2024-03-03",
+      "starts": "2024-03-03T10:30:00Z",
+      "length": "PT1H30M"
+    }
+);
+
+test_failing_deserializer!(
+    test_invalid_date,
+    EVENT_FILE,
+    r#"{"name": "Launch", "day": "sometime soon", "starts": "2024-03-03T10:30:00Z", "length": "PT1H"}"#,
+    FieldType::Class("Event".to_string())
+);
+
+test_deserializer!(
+    test_date_in_union_with_string,
+    EVENT_FILE,
+    r#"next tuesday"#,
+    FieldType::union(vec![FieldType::date(), FieldType::string()]),
+    "next tuesday"
+);
+
+#[test]
+fn test_strict_rejects_natural_dates() {
+    let ir = load_test_ir(EVENT_FILE);
+    let target_type = FieldType::Class("Event".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let raw = r#"{"name": "Launch", "day": "March 3, 2024", "starts": "2024-03-03T10:30:00Z", "length": "PT1H"}"#;
+    assert!(from_str_with_mode(&target, &target_type, raw, false, ParserMode::Strict).is_err());
+
+    let value = from_str_with_mode(&target, &target_type, raw, false, ParserMode::Lenient).unwrap();
+    assert!(value
+        .report()
+        .flags
+        .iter()
+        .any(|f| f.description == "Non ISO 8601 format: March 3, 2024"));
+}
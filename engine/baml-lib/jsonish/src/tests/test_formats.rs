@@ -0,0 +1,223 @@
+use super::*;
+
+use internal_baml_jinja::types::ResponseFormat;
+
+const PERSON_FILE: &str = r#"
+class Person {
+  name string
+  bio string
+  jobs Job[]
+}
+
+class Job {
+  title string
+  years int
+}
+"#;
+
+fn parse_person(format: ResponseFormat, raw: &str, allow_partials: bool) -> serde_json::Value {
+    let ir = load_test_ir(PERSON_FILE);
+    let target_type = FieldType::Class("Person".to_string());
+    let mut target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+    target.format = format;
+
+    let result = from_str(&target, &target_type, raw, allow_partials);
+    assert!(result.is_ok(), "Failed to parse: {:?}", result);
+    let value: BamlValue = result.unwrap().into();
+    json!(value)
+}
+
+#[test]
+fn test_yaml() {
+    let value = parse_person(
+        ResponseFormat::Yaml,
+        r#"
+name: Jane Doe
+bio: |
+  Line one.
+  Line two: with a colon.
+jobs:
+  - title: Engineer
+    years: 3
+  - title: "Manager # of one"
+    years: 1
+"#
+        .trim(),
+        false,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({
+          "name": "Jane Doe",
+          "bio": "Line one.\nLine two: with a colon.\n",
+          "jobs": [
+            {"title": "Engineer", "years": 3},
+            {"title": "Manager # of one", "years": 1}
+          ]
+        })
+    );
+}
+
+#[test]
+fn test_yaml_in_markdown() {
+    let value = parse_person(
+        ResponseFormat::Yaml,
+        r#"
+Here is the person:
+
+```yaml
+name: Jane Doe
+bio: 'It''s me.'
+jobs: []
+```
+"#,
+        false,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({"name": "Jane Doe", "bio": "It's me.", "jobs": []})
+    );
+}
+
+#[test]
+fn test_xml() {
+    let value = parse_person(
+        ResponseFormat::Xml,
+        r#"
+<thinking>Let me write this as XML.</thinking>
+<Person>
+  <name>Jane Doe</name>
+  <bio>She said "hi" &amp; left & never came back.</bio>
+  <jobs>
+    <item>
+      <title>Engineer</title>
+      <years>3</years>
+    </item>
+  </jobs>
+</Person>
+"#,
+        false,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({
+          "name": "Jane Doe",
+          "bio": "She said \"hi\" & left & never came back.",
+          "jobs": [{"title": "Engineer", "years": 3}]
+        })
+    );
+}
+
+#[test]
+fn test_xml_keeps_markup_in_strings() {
+    let value = parse_person(
+        ResponseFormat::Xml,
+        r#"<Person><name>Jane</name><bio>Likes <b>bold</b> text</bio><jobs></jobs></Person>"#,
+        false,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({"name": "Jane", "bio": "Likes <b>bold</b> text", "jobs": []})
+    );
+}
+
+#[test]
+fn test_yaml_partial() {
+    let value = parse_person(
+        ResponseFormat::Yaml,
+        r#"
+name: Jane Doe
+bio: Grew up
+jobs:
+  - title: Engi"#,
+        true,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({
+          "name": "Jane Doe",
+          "bio": "Grew up",
+          "jobs": [{"title": "Engi", "years": null}]
+        })
+    );
+}
+
+#[test]
+fn test_xml_partial() {
+    let value = parse_person(
+        ResponseFormat::Xml,
+        r#"
+<Person>
+  <name>Jane Doe</name>
+  <jobs>
+    <item>
+      <title>Engi"#,
+        true,
+    );
+    assert_json_diff::assert_json_eq!(
+        value,
+        json!({
+          "name": "Jane Doe",
+          "bio": null,
+          "jobs": [{"title": "Engi", "years": null}]
+        })
+    );
+}
+
+#[test]
+fn test_formats_are_reported() {
+    let ir = load_test_ir(PERSON_FILE);
+    let target_type = FieldType::Class("Person".to_string());
+    let mut target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+    target.format = ResponseFormat::Yaml;
+
+    let value = from_str(
+        &target,
+        &target_type,
+        "name: Jane\nbio: Hi\njobs:\n  - title: Engineer\n    years: 3",
+        false,
+    )
+    .unwrap();
+    let report = value.report();
+    assert_eq!(report.score, 0);
+    assert!(report
+        .flags
+        .iter()
+        .any(|f| f.description == "Object from YAML"));
+}
+
+#[test]
+fn test_yaml_is_not_read_for_json_prompts() {
+    let ir = load_test_ir(PERSON_FILE);
+    let target_type = FieldType::Class("Person".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    assert!(from_str(
+        &target,
+        &target_type,
+        "name: Jane\nbio: Hi\njobs:\n  - title: Engineer\n    years: 3",
+        false,
+    )
+    .is_err());
+}
+
+// JSON answers parse the same whether or not they have XML or YAML-looking
+// text around them.
+test_deserializer!(
+    test_json_after_xml_tags,
+    PERSON_FILE,
+    r#"<thinking>The person is an engineer.</thinking>
+{"name": "Jane Doe", "bio": "Hi", "jobs": [{"title": "Engineer", "years": 3}]}"#,
+    FieldType::Class("Person".to_string()),
+    {"name": "Jane Doe", "bio": "Hi", "jobs": [{"title": "Engineer", "years": 3}]}
+);
+
+test_deserializer!(
+    test_json_after_prose_with_colons,
+    PERSON_FILE,
+    r#"Note: the years are rounded.
+Answer: here it is
+{"name": "Jane Doe", "bio": "Hi", "jobs": [{"title": "Engineer", "years": 3}]}"#,
+    FieldType::Class("Person".to_string()),
+    {"name": "Jane Doe", "bio": "Hi", "jobs": [{"title": "Engineer", "years": 3}]}
+);
@@ -0,0 +1,154 @@
+use super::*;
+
+use crate::from_str_with_mode;
+use baml_types::{LiteralValue, ParserMode};
+
+const INVOICE_FILE: &str = r#"
+enum Currency {
+  USD
+  EUR
+}
+
+class Invoice {
+  total int
+  currency Currency
+  paid bool
+}
+"#;
+
+fn parse(raw: &str, mode: ParserMode, allow_partials: bool) -> Result<serde_json::Value> {
+    let ir = load_test_ir(INVOICE_FILE);
+    let target_type = FieldType::Class("Invoice".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let value: BamlValue =
+        from_str_with_mode(&target, &target_type, raw, allow_partials, mode)?.into();
+    Ok(json!(value))
+}
+
+#[test]
+fn test_strict_accepts_exact_json() {
+    let value = parse(
+        r#"{"total": 12, "currency": "USD", "paid": true}"#,
+        ParserMode::Strict,
+        false,
+    )
+    .unwrap();
+    assert_eq!(value, json!({"total": 12, "currency": "USD", "paid": true}));
+}
+
+#[test]
+fn test_strict_rejects_markdown_and_fixes() {
+    let markdown = "```json\n{\"total\": 12, \"currency\": \"USD\", \"paid\": true}\n```";
+    assert!(parse(markdown, ParserMode::Strict, false).is_err());
+    assert!(parse(markdown, ParserMode::NoFixes, false).is_ok());
+
+    let broken = r#"{total: 12, currency: "USD", paid: true"#;
+    assert!(parse(broken, ParserMode::Strict, false).is_err());
+    assert!(parse(broken, ParserMode::NoFixes, false).is_err());
+    assert!(parse(broken, ParserMode::Lenient, false).is_ok());
+}
+
+#[test]
+fn test_strict_rejects_coercions() {
+    for raw in [
+        r#"{"total": 12.4, "currency": "USD", "paid": true}"#,
+        r#"{"total": 12, "currency": "usd", "paid": true}"#,
+        r#"{"total": 12, "currency": "The currency is USD", "paid": true}"#,
+        r#"{"total": 12, "currency": "USD", "paid": "true"}"#,
+    ] {
+        assert!(parse(raw, ParserMode::Strict, false).is_err(), "{raw}");
+        assert!(parse(raw, ParserMode::NoFixes, false).is_ok(), "{raw}");
+    }
+}
+
+const CONVERSIONS_FILE: &str = r#"
+class Item {
+  name string
+}
+
+class Order {
+  total int
+  price float
+  note string
+  tags string[]
+  item Item
+}
+"#;
+
+fn parse_order(raw: &str, mode: ParserMode) -> Result<serde_json::Value> {
+    let ir = load_test_ir(CONVERSIONS_FILE);
+    let target_type = FieldType::Class("Order".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let value: BamlValue = from_str_with_mode(&target, &target_type, raw, false, mode)?.into();
+    Ok(json!(value))
+}
+
+#[test]
+fn test_strict_accepts_exact_order() {
+    let raw = r#"{"total": 2, "price": 1.5, "note": "n", "tags": ["a"], "item": {"name": "pen"}}"#;
+    assert!(parse_order(raw, ParserMode::Strict).is_ok());
+}
+
+#[test]
+fn test_strict_rejects_string_encoded_numbers() {
+    for raw in [
+        r#"{"total": "2", "price": 1.5, "note": "n", "tags": ["a"], "item": {"name": "pen"}}"#,
+        r#"{"total": 2, "price": "1.5", "note": "n", "tags": ["a"], "item": {"name": "pen"}}"#,
+    ] {
+        assert!(parse_order(raw, ParserMode::Strict).is_err(), "{raw}");
+        assert!(parse_order(raw, ParserMode::NoFixes).is_ok(), "{raw}");
+    }
+}
+
+#[test]
+fn test_strict_rejects_json_to_string() {
+    let raw = r#"{"total": 2, "price": 1.5, "note": 7, "tags": ["a"], "item": {"name": "pen"}}"#;
+    assert!(parse_order(raw, ParserMode::Strict).is_err());
+    assert_eq!(parse_order(raw, ParserMode::NoFixes).unwrap()["note"], "7");
+}
+
+#[test]
+fn test_strict_rejects_single_to_array() {
+    let raw = r#"{"total": 2, "price": 1.5, "note": "n", "tags": "a", "item": {"name": "pen"}}"#;
+    assert!(parse_order(raw, ParserMode::Strict).is_err());
+    assert_eq!(
+        parse_order(raw, ParserMode::NoFixes).unwrap()["tags"],
+        json!(["a"])
+    );
+}
+
+#[test]
+fn test_strict_rejects_array_to_single() {
+    let raw =
+        r#"{"total": [2], "price": 1.5, "note": "n", "tags": ["a"], "item": {"name": "pen"}}"#;
+    assert!(parse_order(raw, ParserMode::Strict).is_err());
+    assert_eq!(parse_order(raw, ParserMode::NoFixes).unwrap()["total"], 2);
+}
+
+#[test]
+fn test_strict_rejects_implied_key() {
+    let raw = r#"{"total": 2, "price": 1.5, "note": "n", "tags": ["a"], "item": "pen"}"#;
+    assert!(parse_order(raw, ParserMode::Strict).is_err());
+    assert_eq!(
+        parse_order(raw, ParserMode::NoFixes).unwrap()["item"],
+        json!({"name": "pen"})
+    );
+}
+
+#[test]
+fn test_strict_rejects_object_to_string() {
+    let ir = load_test_ir(EMPTY_FILE);
+    let target_type = FieldType::Literal(LiteralValue::String("1".into()));
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    assert!(from_str_with_mode(&target, &target_type, "1", false, ParserMode::Strict).is_err());
+    assert!(from_str_with_mode(&target, &target_type, r#""1""#, false, ParserMode::Strict).is_ok());
+}
+
+#[test]
+fn test_strict_partials_close_unfinished_json() {
+    let value = parse(r#"{"total": 12, "paid": true"#, ParserMode::Strict, true).unwrap();
+    assert_eq!(value["total"], 12);
+}
@@ -277,3 +277,44 @@ test_deserializer!(
   FieldType::Class("ContactInfo".to_string()),
   {"primary": {"value": "help@boundaryml.com"}}
 );
+
+const TAGGED_SHAPES: &str = r#"
+class Circle {
+  radius float
+  kind "circle"
+  @@tag("kind")
+}
+
+class Square {
+  kind "square"
+  size float
+  @@tag("kind")
+}
+"#;
+
+test_deserializer!(
+  test_tagged_union,
+  TAGGED_SHAPES,
+  r#"{"kind": "square", "size": 2}"#,
+  FieldType::union(vec![FieldType::class("Circle"), FieldType::class("Square")]),
+  {"kind": "square", "size": 2.0}
+);
+
+test_deserializer!(
+  test_tagged_union_in_markdown_list,
+  TAGGED_SHAPES,
+  r#"Here you go:
+```json
+[{"kind": "circle", "radius": 1.5}, {"size": 3, "kind": "square"}]
+```"#,
+  FieldType::list(FieldType::union(vec![FieldType::class("Circle"), FieldType::class("Square")])),
+  [{"radius": 1.5, "kind": "circle"}, {"kind": "square", "size": 3.0}]
+);
+
+// The tag picks `Square`, which has no `size`, so `Circle` isn't tried.
+test_failing_deserializer!(
+  test_tagged_union_only_tries_tagged_class,
+  TAGGED_SHAPES,
+  r#"{"kind": "square", "radius": 2}"#,
+  FieldType::union(vec![FieldType::class("Circle"), FieldType::class("Square")])
+);
@@ -2,13 +2,37 @@ use internal_baml_schema_ast::ast::{Top, TopId, TypeExpId, TypeExpressionBlock};
 
 mod alias;
 pub mod constraint;
+mod default;
 mod description;
+mod tag;
 mod to_string_attribute;
+mod value_constraint;
 use crate::interner::StringId;
 use crate::{context::Context, types::ClassAttributes, types::EnumAttributes};
 use baml_types::Constraint;
+use indexmap::IndexMap;
 use internal_baml_schema_ast::ast::{Expression, SubType};
 
+/// Names of the attributes that a class field accepts, like `@alias`.
+pub const FIELD_ATTRIBUTES: &[&str] = &[
+    "alias",
+    "description",
+    "skip",
+    "assert",
+    "check",
+    "default",
+    "min",
+    "max",
+    "min_length",
+    "max_length",
+    "pattern",
+    "one_of",
+];
+
+/// Names of the attributes that a class or enum accepts, like `@@dynamic`.
+pub const BLOCK_ATTRIBUTES: &[&str] =
+    &["alias", "description", "dynamic", "assert", "check", "tag"];
+
 ///
 #[derive(Debug, Default)]
 pub struct Attributes {
@@ -26,6 +50,17 @@ pub struct Attributes {
 
     /// @check and @assert attributes attached to the node.
     pub constraints: Vec<Constraint>,
+
+    /// Value of a class field when the LLM doesn't return one.
+    pub default_value: Option<Expression>,
+
+    /// Declarative constraints like `@min(1)` or `@pattern("...")` on a class
+    /// field, by attribute name.
+    pub value_constraints: IndexMap<String, Expression>,
+
+    /// The field from `@@tag`, whose string literal value tells a class apart
+    /// from the others in a union.
+    pub tag: Option<StringId>,
 }
 
 impl Attributes {
@@ -68,6 +103,16 @@ impl Attributes {
     pub fn set_skip(&mut self) {
         self.skip.replace(true);
     }
+
+    /// Get the default value.
+    pub fn default_value(&self) -> &Option<Expression> {
+        &self.default_value
+    }
+
+    /// Set the default value.
+    pub fn set_default_value(&mut self, value: Expression) {
+        self.default_value.replace(value);
+    }
 }
 pub(super) fn resolve_attributes(ctx: &mut Context<'_>) {
     for top in ctx.ast.iter_tops() {
@@ -114,7 +159,23 @@ fn resolve_type_exp_block_attributes<'db>(
 
             for (field_idx, field) in ast_typexpr.iter_fields() {
                 ctx.assert_all_attributes_processed((type_id, field_idx).into());
-                if let Some(attrs) = to_string_attribute::visit(ctx, &field.span, false) {
+                let mut field_attributes = to_string_attribute::visit(ctx, &field.span, false);
+                // @default only applies to class fields, not enum values.
+                if ctx.visit_optional_single_attr("default") {
+                    default::visit_default_attribute(
+                        field_attributes.get_or_insert_with(Default::default),
+                        ctx,
+                    );
+                    ctx.validate_visited_arguments();
+                }
+                let value_constraints =
+                    value_constraint::visit_value_constraint_attributes(field.expr.as_ref(), ctx);
+                if !value_constraints.is_empty() {
+                    field_attributes
+                        .get_or_insert_with(Default::default)
+                        .value_constraints = value_constraints;
+                }
+                if let Some(attrs) = field_attributes {
                     class_attributes.field_serilizers.insert(field_idx, attrs);
                 }
                 ctx.validate_visited_attributes();
@@ -122,7 +183,17 @@ fn resolve_type_exp_block_attributes<'db>(
 
             // Now validate the class attributes.
             ctx.assert_all_attributes_processed(type_id.into());
-            class_attributes.serilizer = to_string_attribute::visit(ctx, &span, true);
+            let mut serilizer = to_string_attribute::visit(ctx, &span, true);
+            // @@tag only applies to classes, not enums.
+            if ctx.visit_optional_single_attr("tag") {
+                tag::visit_tag_attribute(
+                    serilizer.get_or_insert_with(Default::default),
+                    ast_typexpr,
+                    ctx,
+                );
+                ctx.validate_visited_arguments();
+            }
+            class_attributes.serilizer = serilizer;
             ctx.validate_visited_attributes();
 
             ctx.types.class_attributes.insert(type_id, class_attributes);
@@ -131,3 +202,15 @@ fn resolve_type_exp_block_attributes<'db>(
         _ => (),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_attributes_include_value_constraints() {
+        for name in value_constraint::VALUE_CONSTRAINTS {
+            assert!(FIELD_ATTRIBUTES.contains(&name), "missing @{name}");
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use baml_types::LiteralValue;
+use internal_baml_schema_ast::ast::{FieldArity, FieldType, TypeExpressionBlock, WithName};
+
+use crate::{coerce, context::Context, types::Attributes};
+
+/// Visits `@@tag("field")`, which names the field whose string literal value
+/// tells a class apart from the others in a union.
+pub(super) fn visit_tag_attribute(
+    attributes: &mut Attributes,
+    class: &TypeExpressionBlock,
+    ctx: &mut Context<'_>,
+) {
+    let name = match ctx
+        .visit_default_arg_with_idx("field")
+        .map(|(_, value)| coerce::string(value, ctx.diagnostics))
+    {
+        Ok(Some(name)) => name,
+        Err(err) => return ctx.push_error(err), // not flattened for error handing legacy reasons
+        Ok(None) => return,
+    };
+
+    let Some((_, field)) = class.iter_fields().find(|(_, field)| field.name() == name) else {
+        ctx.push_attribute_validation_error(
+            &format!("`{}` has no field named `{name}`.", class.name()),
+            true,
+        );
+        return;
+    };
+    if !matches!(
+        field.expr,
+        Some(FieldType::Literal(
+            FieldArity::Required,
+            LiteralValue::String(_),
+            ..
+        ))
+    ) {
+        ctx.push_attribute_validation_error(
+            &format!("The tag field `{name}` must have a string literal type, like `\"circle\"`."),
+            true,
+        );
+        return;
+    }
+    attributes.tag = Some(ctx.interner.intern(name));
+}
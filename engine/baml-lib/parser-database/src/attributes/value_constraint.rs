@@ -0,0 +1,146 @@
+use baml_types::TypeValue;
+use indexmap::IndexMap;
+use internal_baml_diagnostics::DatamodelError;
+use internal_baml_schema_ast::ast::{Expression, FieldType};
+
+use crate::context::Context;
+
+/// Field attributes that constrain the value of a field, like `@min(1)`.
+/// They are enforced as asserts, and exported to the generated clients.
+pub(super) const VALUE_CONSTRAINTS: [&str; 6] = [
+    "min",
+    "max",
+    "min_length",
+    "max_length",
+    "pattern",
+    "one_of",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueKind {
+    Number,
+    String,
+    Collection,
+}
+
+/// Visits the value constraints of a class field, returning the valid ones by
+/// attribute name.
+pub(super) fn visit_value_constraint_attributes(
+    field_type: Option<&FieldType>,
+    ctx: &mut Context<'_>,
+) -> IndexMap<String, Expression> {
+    let kind = field_type.and_then(value_kind);
+    let mut constraints = IndexMap::new();
+    for name in VALUE_CONSTRAINTS {
+        if !ctx.visit_optional_single_attr(name) {
+            continue;
+        }
+        match ctx.visit_default_arg_with_idx("value") {
+            Ok((_, value)) => match validate(name, value, kind) {
+                Ok(()) => {
+                    constraints.insert(name.to_string(), value.clone());
+                }
+                Err(message) => ctx.push_attribute_validation_error(&message, false),
+            },
+            Err(err) => ctx.push_error(err),
+        }
+        ctx.validate_visited_arguments();
+    }
+
+    for (min, max) in [("min", "max"), ("min_length", "max_length")] {
+        let bound = |name: &str| {
+            constraints
+                .get(name)
+                .and_then(|v| v.as_numeric_value())
+                .and_then(|(n, _)| n.parse::<f64>().ok())
+        };
+        if let (Some(lower), Some(upper)) = (bound(min), bound(max)) {
+            if lower > upper {
+                ctx.push_error(DatamodelError::new_attribute_validation_error(
+                    &format!("`@{min}` is greater than `@{max}`."),
+                    &format!("@{min}"),
+                    constraints[min].span().clone(),
+                ));
+            }
+        }
+    }
+    constraints
+}
+
+fn validate(name: &str, value: &Expression, kind: Option<ValueKind>) -> Result<(), String> {
+    let allowed: &[ValueKind] = match name {
+        "min" | "max" => &[ValueKind::Number],
+        "min_length" | "max_length" => &[ValueKind::String, ValueKind::Collection],
+        "pattern" => &[ValueKind::String],
+        _ => &[ValueKind::Number, ValueKind::String],
+    };
+    if !kind.is_some_and(|kind| allowed.contains(&kind)) {
+        let types = match name {
+            "min" | "max" => "`int` and `float`",
+            "min_length" | "max_length" => "`string`, list and map",
+            "pattern" => "`string`",
+            _ => "`string`, `int` and `float`",
+        };
+        return Err(format!("Only {types} fields can have `@{name}`."));
+    }
+
+    match name {
+        "min" | "max" => value
+            .as_numeric_value()
+            .filter(|(n, _)| n.parse::<f64>().is_ok())
+            .map(|_| ())
+            .ok_or_else(|| format!("`@{name}` takes a number.")),
+        "min_length" | "max_length" => value
+            .as_numeric_value()
+            .filter(|(n, _)| n.parse::<u64>().is_ok())
+            .map(|_| ())
+            .ok_or_else(|| format!("`@{name}` takes a non-negative integer.")),
+        "pattern" => {
+            let (pattern, _) = value
+                .as_string_value()
+                .ok_or_else(|| "`@pattern` takes a regex string.".to_string())?;
+            regex::Regex::new(pattern).map(|_| ()).map_err(|e| {
+                // Syntax errors span several lines, ending with the reason.
+                let e = e.to_string();
+                let reason = e.lines().last().unwrap_or_default();
+                format!(
+                    "`@pattern` is not a valid regex: {}",
+                    reason.trim_start_matches("error: ")
+                )
+            })
+        }
+        _ => {
+            let values = match value.as_array() {
+                Some((values, _)) if !values.is_empty() => values,
+                _ => return Err("`@one_of` takes a non-empty list of values.".to_string()),
+            };
+            let valid = |v: &Expression| match kind {
+                Some(ValueKind::Number) => v
+                    .as_numeric_value()
+                    .is_some_and(|(n, _)| n.parse::<f64>().is_ok()),
+                _ => v.as_string_value().is_some(),
+            };
+            if values.iter().all(valid) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "The values of `@one_of` must be {}.",
+                    match kind {
+                        Some(ValueKind::Number) => "numbers",
+                        _ => "strings",
+                    }
+                ))
+            }
+        }
+    }
+}
+
+/// What kind of value a field holds, ignoring whether it's optional.
+fn value_kind(field_type: &FieldType) -> Option<ValueKind> {
+    match field_type {
+        FieldType::Primitive(_, TypeValue::Int | TypeValue::Float, ..) => Some(ValueKind::Number),
+        FieldType::Primitive(_, TypeValue::String, ..) => Some(ValueKind::String),
+        FieldType::List(..) | FieldType::Map(..) => Some(ValueKind::Collection),
+        _ => None,
+    }
+}
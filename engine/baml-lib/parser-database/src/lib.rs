@@ -38,14 +38,15 @@ mod types;
 
 use std::collections::{HashMap, HashSet};
 
+pub use attributes::{BLOCK_ATTRIBUTES, FIELD_ATTRIBUTES};
 pub use coerce_expression::{coerce, coerce_array, coerce_opt};
 use either::Either;
 pub use internal_baml_schema_ast::ast;
 use internal_baml_schema_ast::ast::SchemaAst;
 pub use tarjan::Tarjan;
 pub use types::{
-    Attributes, ContantDelayStrategy, ExponentialBackoffStrategy, PrinterType, PromptAst,
-    PromptVariable, RetryPolicy, RetryPolicyStrategy, StaticType,
+    Attributes, ContantDelayStrategy, ExponentialBackoffStrategy, FunctionVariant, PrinterType,
+    PromptAst, PromptVariable, RetryPolicy, RetryPolicyStrategy, StaticType,
 };
 
 use self::{context::Context, interner::StringId, types::Types};
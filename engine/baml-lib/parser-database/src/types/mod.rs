@@ -5,7 +5,7 @@ use crate::coerce;
 use crate::types::configurations::visit_test_case;
 use crate::{context::Context, DatamodelError};
 
-use baml_types::Constraint;
+use baml_types::{Constraint, ParserMode};
 use indexmap::IndexMap;
 use internal_baml_diagnostics::Span;
 use internal_baml_prompt_parser::ast::{ChatBlock, PrinterBlock, Variable};
@@ -209,6 +209,24 @@ pub struct FunctionType {
     pub dependencies: (HashSet<String>, HashSet<String>),
     pub prompt: Option<RawString>,
     pub client: Option<(String, Span)>,
+    pub variants: Vec<FunctionVariant>,
+    /// How strictly the output of the function is parsed, if set.
+    pub parser: Option<(ParserMode, Span)>,
+}
+
+/// A named variant of a function, declared in its `variants` block. Fields
+/// that are not set fall back to the function's own.
+#[derive(Debug, Clone)]
+pub struct FunctionVariant {
+    /// The name of the variant.
+    pub name: (String, Span),
+    /// The prompt of the variant.
+    pub prompt: Option<RawString>,
+    /// The client of the variant.
+    pub client: Option<(String, Span)>,
+    /// The share of calls that go to this variant when the caller doesn't
+    /// pick one.
+    pub percent: Option<(u32, Span)>,
 }
 
 #[derive(Debug, Clone)]
@@ -365,6 +383,8 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
 
     let mut prompt = None;
     let mut client = None;
+    let mut variants = Vec::new();
+    let mut parser = None;
     function
         .iter_fields()
         .for_each(|(_idx, field)| match field.name() {
@@ -381,6 +401,25 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     None => None,
                 }
             }
+            "variants" => match &field.expr {
+                Some(ast::Expression::Map(map, _)) => {
+                    variants.extend(
+                        map.iter()
+                            .filter_map(|(key, value)| visit_function_variant(key, value, ctx)),
+                    );
+                }
+                Some(_) => ctx.push_error(DatamodelError::new_validation_error(
+                    "Expected a map of variants, e.g. `variants { Concise { prompt #\"...\"# } }`",
+                    field.span().clone(),
+                )),
+                None => {}
+            },
+            "parser" => {
+                parser = match &field.expr {
+                    Some(val) => visit_parser_mode(val, ctx),
+                    None => None,
+                }
+            }
             config => ctx.push_error(DatamodelError::new_validation_error(
                 &format!("Unknown field `{}` in function", config),
                 field.span().clone(),
@@ -395,6 +434,8 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     dependencies: (input_deps.clone(), output_deps),
                     prompt: Some(prompt.clone()),
                     client: Some(client),
+                    variants,
+                    parser,
                 },
             );
 
@@ -428,6 +469,79 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
     }
 }
 
+fn visit_parser_mode(value: &ast::Expression, ctx: &mut Context<'_>) -> Option<(ParserMode, Span)> {
+    let (mode, span) = coerce::string_with_span(value, ctx.diagnostics)?;
+    match mode.parse() {
+        Ok(mode) => Some((mode, span.clone())),
+        Err(_) => {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "Unknown parser mode `{}`. Valid modes are: {}",
+                    mode,
+                    ParserMode::names().join(", ")
+                ),
+                span.clone(),
+            ));
+            None
+        }
+    }
+}
+
+fn visit_function_variant(
+    key: &ast::Expression,
+    value: &ast::Expression,
+    ctx: &mut Context<'_>,
+) -> Option<FunctionVariant> {
+    let name = coerce::string_with_span(key, ctx.diagnostics)?;
+    let Some((fields, _)) = value.as_map() else {
+        ctx.push_error(DatamodelError::new_validation_error(
+            &format!(
+                "Expected a block for variant `{}`, e.g. `{} {{ prompt #\"...\"# }}`",
+                name.0, name.0
+            ),
+            value.span().clone(),
+        ));
+        return None;
+    };
+
+    let mut variant = FunctionVariant {
+        name: (name.0.to_string(), name.1.clone()),
+        prompt: None,
+        client: None,
+        percent: None,
+    };
+    for (key, value) in fields {
+        let Some(field) = coerce::string(key, ctx.diagnostics) else {
+            continue;
+        };
+        match field {
+            "prompt" => variant.prompt = coerce::template_string(value, ctx.diagnostics).cloned(),
+            "client" => {
+                variant.client = coerce::string_with_span(value, ctx.diagnostics)
+                    .map(|(v, span)| (v.to_string(), span.clone()))
+            }
+            "percent" => {
+                variant.percent = match coerce::integer(value, ctx.diagnostics) {
+                    Some(percent @ 0..=100) => Some((percent as u32, value.span().clone())),
+                    Some(_) => {
+                        ctx.push_error(DatamodelError::new_validation_error(
+                            "`percent` must be between 0 and 100",
+                            value.span().clone(),
+                        ));
+                        None
+                    }
+                    None => None,
+                }
+            }
+            other => ctx.push_error(DatamodelError::new_validation_error(
+                &format!("Unknown field `{}` in variant `{}`", other, variant.name.0),
+                key.span().clone(),
+            )),
+        }
+    }
+    Some(variant)
+}
+
 fn visit_client<'db>(idx: ValExpId, client: &'db ast::ValueExprBlock, ctx: &mut Context<'db>) {
     let mut provider = None;
     let mut retry_policy = None;
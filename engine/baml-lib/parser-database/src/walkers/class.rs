@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use super::{field::FieldWalker, EnumWalker};
 use crate::types::Attributes;
-use baml_types::Constraint;
+use baml_types::{Constraint, LiteralValue};
 use either::Either;
 use internal_baml_schema_ast::ast::Identifier;
 use internal_baml_schema_ast::ast::SubType;
@@ -96,6 +96,22 @@ impl<'db> ClassWalker<'db> {
         }
     }
 
+    /// The field from `@@tag`, and its string literal value.
+    pub fn tag(&self) -> Option<(&'db str, &'db str)> {
+        let tag = self.get_default_attributes(SubType::Class)?.tag?;
+        let field = self
+            .ast_type_block()
+            .iter_fields()
+            .find(|(_, field)| field.name() == &self.db[tag])?
+            .1;
+        match &field.expr {
+            Some(ast::FieldType::Literal(_, LiteralValue::String(value), ..)) => {
+                Some((field.name(), value.as_str()))
+            }
+            _ => None,
+        }
+    }
+
     /// Get the constraints of a class or an enum.
     pub fn get_constraints(&self, sub_type: SubType) -> Option<Vec<Constraint>> {
         self.get_default_attributes(sub_type).map(|attrs| attrs.constraints.clone())
@@ -1,10 +1,11 @@
+use baml_types::ParserMode;
 use either::Either;
-use internal_baml_diagnostics::DatamodelError;
+use internal_baml_diagnostics::{DatamodelError, Span};
 use internal_baml_schema_ast::ast::{ArgumentId, Identifier, WithIdentifier, WithSpan};
 
 use crate::{
     ast::{self, WithName},
-    types::FunctionType,
+    types::{FunctionType, FunctionVariant},
 };
 
 use super::{ClassWalker, ConfigurationWalker, EnumWalker, Walker};
@@ -111,6 +112,19 @@ impl<'db> FunctionWalker<'db> {
         !self.id.0
     }
 
+    /// The named variants of the function, in declaration order.
+    pub fn variants(self) -> &'db [FunctionVariant] {
+        &self.metadata().variants
+    }
+
+    /// How strictly the output of the function is parsed.
+    pub fn parser_mode(self) -> ParserMode {
+        self.metadata()
+            .parser
+            .as_ref()
+            .map_or(ParserMode::default(), |(mode, _)| *mode)
+    }
+
     /// The prompt for the function
     pub fn jinja_prompt(self) -> &'db str {
         assert!(self.id.0, "Only new functions have prompts");
@@ -140,6 +154,21 @@ impl<'db> FunctionWalker<'db> {
             ));
         };
 
+        self.resolve_client_spec(client)
+    }
+
+    /// The client of a variant. Variants without one use the function's.
+    pub fn variant_client_spec(
+        self,
+        variant: &FunctionVariant,
+    ) -> Result<ClientSpec, DatamodelError> {
+        match &variant.client {
+            Some(client) => self.resolve_client_spec(client),
+            None => self.client_spec(),
+        }
+    }
+
+    fn resolve_client_spec(self, client: &(String, Span)) -> Result<ClientSpec, DatamodelError> {
         match client.0.split_once("/") {
             // TODO: do this in a more robust way
             // actually validate which clients are and aren't allowed
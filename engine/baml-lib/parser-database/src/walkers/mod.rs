@@ -144,7 +144,9 @@ impl<'db> crate::ParserDatabase {
         names.extend(self.walk_enums().map(|e| e.name().to_string()));
         // Add primitive types
         names.extend(
-            vec!["string", "int", "float", "bool", "true", "false"]
+            vec![
+                "string", "int", "float", "bool", "date", "datetime", "duration", "true", "false",
+            ]
                 .into_iter()
                 .map(String::from),
         );
@@ -299,7 +301,11 @@ impl<'db> crate::ParserDatabase {
             }
             FieldType::Primitive(arity, t, ..) => {
                 let mut t = match &t {
-                    TypeValue::String => Type::String,
+                    // Dates and durations are ISO 8601 strings in templates.
+                    TypeValue::String
+                    | TypeValue::Date
+                    | TypeValue::DateTime
+                    | TypeValue::Duration => Type::String,
                     TypeValue::Int => Type::Int,
                     TypeValue::Float => Type::Float,
                     TypeValue::Bool => Type::Bool,
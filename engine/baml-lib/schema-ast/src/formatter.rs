@@ -0,0 +1,1061 @@
+//! The canonical formatter for `.baml` files, used by `baml-cli fmt`.
+//!
+//! Formatting only changes whitespace and layout: indentation, blank lines,
+//! the alignment of class fields and enum values, and where attributes go
+//! (field attributes on the field's line, block attributes at the end of the
+//! block). Comments are kept, and raw strings (e.g. prompts) are untouched.
+
+use pest::Parser;
+
+use crate::parser::{BAMLParser, Rule};
+
+type Pair<'a> = pest::iterators::Pair<'a, Rule>;
+
+const INDENT: &str = "  ";
+
+/// Why a file could not be formatted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatError {
+    /// The 1-based line on which the problem is.
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Formats the contents of a `.baml` file.
+///
+/// Files with syntax errors are not formatted, since their layout can't be
+/// changed without risking their meaning.
+pub fn format_schema(source: &str) -> Result<String, FormatError> {
+    let schema = BAMLParser::parse(Rule::schema, source)
+        .map_err(|e| FormatError {
+            line: match e.line_col {
+                pest::error::LineColLocation::Pos((line, _))
+                | pest::error::LineColLocation::Span((line, _), _) => line,
+            },
+            message: "This file could not be parsed".to_string(),
+        })?
+        .next()
+        .expect("the schema rule always produces a pair");
+
+    let formatter = Formatter::new(source);
+    let formatted = formatter.schema(schema)?;
+
+    // Formatting must not add, drop or change anything but layout.
+    if significant_chars(source) != significant_chars(&formatted) {
+        return Err(FormatError {
+            line: 1,
+            message: "Formatting this file would change its contents. Please report this as a bug."
+                .to_string(),
+        });
+    }
+    Ok(formatted)
+}
+
+/// The characters of a file, ignoring whitespace and the commas that
+/// formatting may add or remove, in sorted order (comments and block
+/// attributes may move).
+fn significant_chars(source: &str) -> Vec<char> {
+    let mut chars = source
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect::<Vec<_>>();
+    chars.sort_unstable();
+    chars
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    /// Byte offset of the start of each line, to find the line of a token.
+    line_starts: Vec<usize>,
+}
+
+/// A line of a block body, before indentation.
+enum Line {
+    Blank,
+    Comment(String),
+    Text(String),
+    /// A class field or enum value: `rest` is aligned across consecutive
+    /// fields.
+    Field {
+        name: String,
+        rest: String,
+    },
+}
+
+/// The lines of a `{ ... }` body.
+#[derive(Default)]
+struct Body {
+    lines: Vec<Line>,
+    /// Block attributes, with the comments right above them.
+    block_attributes: Vec<Line>,
+    /// The source line on which the last element ended, to keep comments
+    /// that follow it on the same line.
+    last_line: Option<usize>,
+}
+
+/// The comments found while rendering a field, split by whether they were on
+/// the same line as the code before them.
+#[derive(Default)]
+struct Comments {
+    last_line: usize,
+    trailing: Vec<String>,
+    own_line: Vec<String>,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Formatter {
+            source,
+            line_starts,
+        }
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= offset)
+    }
+
+    fn start_line(&self, pair: &Pair<'_>) -> usize {
+        self.line_of(pair.as_span().start())
+    }
+
+    fn end_line(&self, pair: &Pair<'_>) -> usize {
+        let span = pair.as_span();
+        self.line_of(span.end().saturating_sub(1).max(span.start()))
+    }
+
+    /// Whether `empty_lines` include a blank line, rather than just ending
+    /// the line before them.
+    fn has_blank_line(&self, pair: &Pair<'_>) -> bool {
+        let start = pair.as_span().start();
+        let line_start = self.line_starts[self.line_of(start) - 1];
+        let newlines = pair.as_str().matches('\n').count();
+        if self.source[line_start..start].trim().is_empty() {
+            newlines >= 1
+        } else {
+            newlines >= 2
+        }
+    }
+
+    fn error(&self, pair: &Pair<'_>, message: &str) -> FormatError {
+        FormatError {
+            line: self.start_line(pair),
+            message: message.to_string(),
+        }
+    }
+
+    fn invalid(&self, pair: &Pair<'_>) -> FormatError {
+        self.error(
+            pair,
+            "This line is invalid, so the file can't be formatted. Fix the syntax errors first.",
+        )
+    }
+
+    fn schema(&self, schema: Pair<'_>) -> Result<String, FormatError> {
+        let mut out: Vec<String> = vec![];
+        let mut blank_pending = false;
+        let mut after_block = false;
+        let mut last_line = None;
+
+        for pair in schema.into_inner() {
+            match pair.as_rule() {
+                Rule::empty_lines => blank_pending |= self.has_blank_line(&pair),
+                Rule::comment_block => {
+                    for comment in pair.into_inner() {
+                        let line = self.start_line(&comment);
+                        let text = comment_text(&comment);
+                        match out.last_mut() {
+                            Some(last) if Some(line) == last_line => {
+                                last.push(' ');
+                                last.push_str(&text);
+                            }
+                            _ => {
+                                if !out.is_empty() && (blank_pending || after_block) {
+                                    out.push(String::new());
+                                }
+                                out.push(text);
+                                after_block = false;
+                            }
+                        }
+                        blank_pending = false;
+                        last_line = None;
+                    }
+                }
+                Rule::type_expression_block
+                | Rule::value_expression_block
+                | Rule::template_declaration
+                | Rule::type_alias
+                | Rule::raw_string_literal => {
+                    if !out.is_empty() && (blank_pending || after_block) {
+                        out.push(String::new());
+                    }
+                    last_line = Some(self.end_line(&pair));
+                    out.push(match pair.as_rule() {
+                        Rule::type_expression_block => self.type_expression_block(pair)?,
+                        Rule::value_expression_block => self.value_expression_block(pair)?,
+                        Rule::template_declaration => self.template_declaration(pair)?,
+                        Rule::type_alias => self.type_alias(pair)?,
+                        _ => pair.as_str().trim().to_string(),
+                    });
+                    blank_pending = false;
+                    after_block = true;
+                }
+                Rule::EOI => {}
+                _ => return Err(self.invalid(&pair)),
+            }
+        }
+
+        if out.is_empty() {
+            return Ok(String::new());
+        }
+        Ok(out.join("\n") + "\n")
+    }
+
+    fn type_expression_block(&self, pair: Pair<'_>) -> Result<String, FormatError> {
+        let mut header = vec![];
+        let mut args = String::new();
+        let mut body = Body::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::identifier => header.push(child.as_str().to_string()),
+                Rule::named_argument_list => args = self.named_argument_list(child, 0)?,
+                Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE => {}
+                Rule::type_expression_contents => {
+                    for item in child.into_inner() {
+                        match item.as_rule() {
+                            Rule::type_expression => self.type_expression(item, &mut body)?,
+                            Rule::block_attribute => {
+                                let line = self.end_line(&item);
+                                body.block_attribute(self.attribute(item, 1)?, line);
+                            }
+                            Rule::comment_block => self.comment_block(item, &mut body),
+                            Rule::empty_lines => self.empty_lines(&item, &mut body),
+                            _ => return Err(self.invalid(&item)),
+                        }
+                    }
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(format!("{}{args} {}", header.join(" "), body.render(0)))
+    }
+
+    /// A class field or enum value.
+    fn type_expression(&self, pair: Pair<'_>, body: &mut Body) -> Result<(), FormatError> {
+        let mut name = String::new();
+        let mut r#type = String::new();
+        let mut attributes = vec![];
+        let mut comments = Comments::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::identifier => {
+                    comments.last_line = self.end_line(&child);
+                    name = child.as_str().to_string();
+                }
+                Rule::field_type_chain => {
+                    let (chain, field_attributes) =
+                        self.field_type_chain(child, 1, &mut comments)?;
+                    r#type = chain;
+                    attributes.extend(field_attributes);
+                }
+                Rule::field_attribute => {
+                    comments.last_line = self.end_line(&child);
+                    attributes.push(self.attribute(child, 1)?);
+                }
+                Rule::trailing_comment => self.comment(child, &mut comments),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+
+        let rest = std::iter::once(r#type)
+            .chain(attributes)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        body.element(Line::Field { name, rest }, comments.last_line);
+        body.comments(comments);
+        Ok(())
+    }
+
+    fn value_expression_block(&self, pair: Pair<'_>) -> Result<String, FormatError> {
+        let mut header = String::new();
+        let mut body = Body::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::value_expression_keyword => header.push_str(child.as_str()),
+                Rule::identifier => {
+                    header.push(' ');
+                    header.push_str(child.as_str());
+                }
+                Rule::named_argument_list => {
+                    header.push_str(&self.named_argument_list(child, 0)?);
+                }
+                Rule::ARROW => {
+                    for spacer in child.into_inner() {
+                        self.spacer_text(spacer, &mut body)?;
+                    }
+                    header.push_str(" ->");
+                }
+                Rule::field_type_chain => {
+                    let mut comments = Comments::default();
+                    let (chain, attributes) = self.field_type_chain(child, 0, &mut comments)?;
+                    header.push(' ');
+                    header.push_str(
+                        &std::iter::once(chain)
+                            .chain(attributes)
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    );
+                    // There's no room for comments in the header.
+                    body.comments(comments);
+                }
+                Rule::SPACER_TEXT => self.spacer_text(child, &mut body)?,
+                Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE => {}
+                Rule::value_expression_contents => {
+                    for item in child.into_inner() {
+                        match item.as_rule() {
+                            Rule::value_expression => self.value_expression(item, &mut body)?,
+                            Rule::block_attribute => {
+                                let line = self.end_line(&item);
+                                body.block_attribute(self.attribute(item, 1)?, line);
+                            }
+                            Rule::comment_block => self.comment_block(item, &mut body),
+                            Rule::empty_lines => self.empty_lines(&item, &mut body),
+                            _ => return Err(self.invalid(&item)),
+                        }
+                    }
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(format!("{header} {}", body.render(0)))
+    }
+
+    /// Comments between the header and the `{` of a block are moved into it.
+    fn spacer_text(&self, pair: Pair<'_>, body: &mut Body) -> Result<(), FormatError> {
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::comment_block => {
+                    for comment in child.into_inner() {
+                        body.lines.push(Line::Comment(comment_text(&comment)));
+                    }
+                }
+                Rule::empty_lines => {}
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(())
+    }
+
+    /// A `key value` line in a function, client, test, generator or
+    /// retry_policy.
+    fn value_expression(&self, pair: Pair<'_>, body: &mut Body) -> Result<(), FormatError> {
+        let mut parts = vec![];
+        let mut comments = Comments::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::identifier => {
+                    comments.last_line = self.end_line(&child);
+                    parts.push(child.as_str().to_string());
+                }
+                Rule::expression => {
+                    comments.last_line = self.end_line(&child);
+                    parts.push(self.expression(child, 1)?);
+                }
+                Rule::field_attribute => {
+                    comments.last_line = self.end_line(&child);
+                    parts.push(self.attribute(child, 1)?);
+                }
+                Rule::trailing_comment => self.comment(child, &mut comments),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        body.element(Line::Text(parts.join(" ")), comments.last_line);
+        body.comments(comments);
+        Ok(())
+    }
+
+    fn template_declaration(&self, pair: Pair<'_>) -> Result<String, FormatError> {
+        let mut out = String::new();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::TEMPLATE_KEYWORD => out.push_str(child.as_str()),
+                Rule::identifier => {
+                    out.push(' ');
+                    out.push_str(child.as_str());
+                }
+                Rule::assignment => out.push_str(" ="),
+                Rule::named_argument_list => out.push_str(&self.named_argument_list(child, 0)?),
+                Rule::raw_string_literal => {
+                    out.push(' ');
+                    out.push_str(child.as_str());
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(out)
+    }
+
+    fn type_alias(&self, pair: Pair<'_>) -> Result<String, FormatError> {
+        let mut parts = vec![];
+        let mut comments = Comments::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::TYPE_KEYWORD | Rule::identifier => parts.push(child.as_str().to_string()),
+                Rule::base_type => parts.push(self.base_type(child, 0, &mut comments)?),
+                Rule::field_attribute => parts.push(self.attribute(child, 0)?),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(parts.join(" "))
+    }
+
+    /// `(a: int, b: string)`, on one line unless it was written over several.
+    fn named_argument_list(&self, pair: Pair<'_>, indent: usize) -> Result<String, FormatError> {
+        let multiline = pair.as_str().contains('\n');
+        let mut body = Body::default();
+        let mut args = vec![];
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::openParan | Rule::closeParan => {}
+                Rule::SPACER_TEXT => {
+                    for spacer in child.into_inner() {
+                        match spacer.as_rule() {
+                            Rule::comment_block => self.comment_block(spacer, &mut body),
+                            Rule::empty_lines => {}
+                            _ => return Err(self.invalid(&spacer)),
+                        }
+                    }
+                }
+                Rule::named_argument => {
+                    let mut comments = Comments::default();
+                    let mut arg = String::new();
+                    for part in child.into_inner() {
+                        match part.as_rule() {
+                            Rule::identifier => {
+                                comments.last_line = self.end_line(&part);
+                                arg.push_str(part.as_str());
+                            }
+                            Rule::colon => arg.push(':'),
+                            Rule::field_type_chain => {
+                                let (chain, attributes) =
+                                    self.field_type_chain(part, indent + 1, &mut comments)?;
+                                arg.push_str(": ");
+                                arg.push_str(
+                                    &std::iter::once(chain)
+                                        .chain(attributes)
+                                        .collect::<Vec<_>>()
+                                        .join(" "),
+                                );
+                            }
+                            _ => return Err(self.invalid(&part)),
+                        }
+                    }
+                    args.push(arg.clone());
+                    body.element(Line::Text(format!("{arg},")), comments.last_line);
+                    body.comments(comments);
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+
+        let has_comments = body.lines.iter().any(|l| {
+            matches!(l, Line::Comment(_)) || matches!(l, Line::Text(t) if t.contains("//"))
+        });
+        if multiline || has_comments {
+            Ok(body.render_delimited(indent, "(", ")"))
+        } else {
+            Ok(format!("({})", args.join(", ")))
+        }
+    }
+
+    /// Renders a type, returning the attributes of its last member
+    /// separately: those belong to the field.
+    fn field_type_chain(
+        &self,
+        pair: Pair<'_>,
+        indent: usize,
+        comments: &mut Comments,
+    ) -> Result<(String, Vec<String>), FormatError> {
+        let mut members: Vec<(String, Vec<String>)> = vec![];
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::field_type_with_attr => {
+                    members.push(self.field_type_with_attr(child, indent, comments)?)
+                }
+                Rule::field_operator => {}
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        let (last, attributes) = members.pop().unwrap_or_default();
+        let chain = members
+            .into_iter()
+            .map(|(t, attributes)| {
+                std::iter::once(t)
+                    .chain(attributes)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .chain(std::iter::once(last))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        Ok((chain, attributes))
+    }
+
+    fn field_type_with_attr(
+        &self,
+        pair: Pair<'_>,
+        indent: usize,
+        comments: &mut Comments,
+    ) -> Result<(String, Vec<String>), FormatError> {
+        let mut r#type = String::new();
+        let mut attributes = vec![];
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::field_type => r#type = self.field_type(child, indent, comments)?,
+                Rule::field_attribute => {
+                    comments.last_line = self.end_line(&child);
+                    attributes.push(self.attribute(child, indent)?);
+                }
+                Rule::trailing_comment => self.comment(child, comments),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok((r#type, attributes))
+    }
+
+    fn field_type(
+        &self,
+        pair: Pair<'_>,
+        indent: usize,
+        comments: &mut Comments,
+    ) -> Result<String, FormatError> {
+        let mut out = String::new();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::union => {
+                    let mut members = vec![];
+                    for member in child.into_inner() {
+                        match member.as_rule() {
+                            Rule::base_type_with_attr => {
+                                let mut parts = vec![];
+                                for part in member.into_inner() {
+                                    match part.as_rule() {
+                                        Rule::base_type => {
+                                            parts.push(self.base_type(part, indent, comments)?)
+                                        }
+                                        Rule::field_attribute => {
+                                            comments.last_line = self.end_line(&part);
+                                            parts.push(self.attribute(part, indent)?)
+                                        }
+                                        _ => return Err(self.invalid(&part)),
+                                    }
+                                }
+                                members.push(parts.join(" "));
+                            }
+                            Rule::field_operator => {}
+                            _ => return Err(self.invalid(&member)),
+                        }
+                    }
+                    out.push_str(&members.join(" | "));
+                }
+                Rule::non_union => out.push_str(&self.base_type(child, indent, comments)?),
+                Rule::optional_token => out.push('?'),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Any of `base_type`, `non_union` and `base_type_without_array`, or the
+    /// type they wrap.
+    fn base_type(
+        &self,
+        pair: Pair<'_>,
+        indent: usize,
+        comments: &mut Comments,
+    ) -> Result<String, FormatError> {
+        comments.last_line = self.end_line(&pair);
+        match pair.as_rule() {
+            Rule::base_type | Rule::non_union | Rule::base_type_without_array => {
+                let mut out = String::new();
+                for child in pair.into_inner() {
+                    out.push_str(&self.base_type(child, indent, comments)?);
+                }
+                Ok(out)
+            }
+            Rule::identifier | Rule::literal_type => Ok(pair.as_str().trim().to_string()),
+            Rule::array_notation => {
+                let mut out = String::new();
+                for child in pair.into_inner() {
+                    match child.as_rule() {
+                        Rule::array_suffix => out.push_str("[]"),
+                        _ => out.push_str(&self.base_type(child, indent, comments)?),
+                    }
+                }
+                Ok(out)
+            }
+            Rule::map => {
+                let mut types = vec![];
+                for child in pair.into_inner() {
+                    types.push(self.field_type(child, indent, comments)?);
+                }
+                Ok(format!("map<{}>", types.join(", ")))
+            }
+            Rule::group | Rule::tuple | Rule::parenthesized_type => {
+                let mut members = vec![];
+                for child in pair.into_inner() {
+                    match child.as_rule() {
+                        Rule::openParan | Rule::closeParan => {}
+                        Rule::field_type => members.push(self.field_type(child, indent, comments)?),
+                        Rule::field_type_with_attr => {
+                            let (r#type, attributes) =
+                                self.field_type_with_attr(child, indent, comments)?;
+                            members.push(
+                                std::iter::once(r#type)
+                                    .chain(attributes)
+                                    .collect::<Vec<_>>()
+                                    .join(" "),
+                            );
+                        }
+                        // Attributes of a group follow its type.
+                        Rule::field_attribute => {
+                            let attribute = self.attribute(child, indent)?;
+                            match members.last_mut() {
+                                Some(last) => {
+                                    last.push(' ');
+                                    last.push_str(&attribute);
+                                }
+                                None => members.push(attribute),
+                            }
+                        }
+                        _ => return Err(self.invalid(&child)),
+                    }
+                }
+                Ok(format!("({})", members.join(", ")))
+            }
+            _ => Err(self.invalid(&pair)),
+        }
+    }
+
+    /// `@name(args)` or `@@name(args)`.
+    fn attribute(&self, pair: Pair<'_>, indent: usize) -> Result<String, FormatError> {
+        let prefix = if pair.as_rule() == Rule::block_attribute {
+            "@@"
+        } else {
+            "@"
+        };
+        let mut out = prefix.to_string();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::identifier => out.push_str(child.as_str()),
+                Rule::arguments_list => {
+                    let args = child
+                        .into_inner()
+                        .map(|arg| self.expression(arg, indent))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    out.push_str(&format!("({})", args.join(", ")));
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(out)
+    }
+
+    /// An expression starting on a line indented by `indent`. Multi-line
+    /// expressions are indented relative to it; raw strings are kept as-is.
+    fn expression(&self, pair: Pair<'_>, indent: usize) -> Result<String, FormatError> {
+        match pair.as_rule() {
+            Rule::expression | Rule::string_literal => {
+                let mut inner = pair.clone().into_inner();
+                match (inner.next(), inner.next()) {
+                    (Some(child), None) => self.expression(child, indent),
+                    _ => Err(self.invalid(&pair)),
+                }
+            }
+            Rule::jinja_expression
+            | Rule::numeric_literal
+            | Rule::identifier
+            | Rule::raw_string_literal
+            | Rule::quoted_string_literal => Ok(pair.as_str().to_string()),
+            Rule::unquoted_string_literal => Ok(pair.as_str().trim().to_string()),
+            Rule::map_expression => self.map_expression(pair, indent),
+            Rule::array_expression => self.array_expression(pair, indent),
+            _ => Err(self.invalid(&pair)),
+        }
+    }
+
+    fn map_expression(&self, pair: Pair<'_>, indent: usize) -> Result<String, FormatError> {
+        let mut body = Body::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::map_entry => {
+                    let mut parts = vec![];
+                    let mut comments = Comments::default();
+                    for part in child.into_inner() {
+                        match part.as_rule() {
+                            Rule::comment_block => self.comment_block(part, &mut body),
+                            Rule::empty_lines => self.empty_lines(&part, &mut body),
+                            Rule::map_key => {
+                                comments.last_line = self.end_line(&part);
+                                parts.push(part.as_str().to_string());
+                            }
+                            Rule::expression => {
+                                comments.last_line = self.end_line(&part);
+                                parts.push(self.expression(part, indent + 1)?);
+                            }
+                            Rule::trailing_comment => self.comment(part, &mut comments),
+                            _ => return Err(self.invalid(&part)),
+                        }
+                    }
+                    body.element(Line::Text(parts.join(" ")), comments.last_line);
+                    body.comments(comments);
+                }
+                Rule::comment_block => self.comment_block(child, &mut body),
+                Rule::empty_lines => self.empty_lines(&child, &mut body),
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        Ok(body.render(indent))
+    }
+
+    /// `[a, b]` stays on one line if it was written on one line, otherwise
+    /// each item gets its own line.
+    fn array_expression(&self, pair: Pair<'_>, indent: usize) -> Result<String, FormatError> {
+        let multiline = pair.as_str().contains('\n');
+        let mut body = Body::default();
+        let mut items = vec![];
+        let mut comments = Comments::default();
+        for child in pair.into_inner() {
+            match child.as_rule() {
+                Rule::expression => {
+                    body.comments(std::mem::take(&mut comments));
+                    let item = self.expression(child.clone(), indent + 1)?;
+                    comments.last_line = self.end_line(&child);
+                    body.element(Line::Text(format!("{item},")), comments.last_line);
+                    items.push(item);
+                }
+                Rule::trailing_comment => self.comment(child, &mut comments),
+                Rule::comment_block => {
+                    body.comments(std::mem::take(&mut comments));
+                    self.comment_block(child, &mut body);
+                }
+                Rule::empty_lines => {
+                    body.comments(std::mem::take(&mut comments));
+                    self.empty_lines(&child, &mut body);
+                }
+                _ => return Err(self.invalid(&child)),
+            }
+        }
+        body.comments(comments);
+
+        let has_comments = body.lines.iter().any(|l| {
+            matches!(l, Line::Comment(_)) || matches!(l, Line::Text(t) if t.contains("//"))
+        });
+        if multiline || has_comments {
+            Ok(body.render_delimited(indent, "[", "]"))
+        } else {
+            Ok(format!("[{}]", items.join(", ")))
+        }
+    }
+
+    fn comment(&self, pair: Pair<'_>, comments: &mut Comments) {
+        let text = comment_text(&pair);
+        if self.start_line(&pair) == comments.last_line && comments.own_line.is_empty() {
+            comments.trailing.push(text);
+        } else {
+            comments.own_line.push(text);
+        }
+    }
+
+    fn empty_lines(&self, pair: &Pair<'_>, body: &mut Body) {
+        if self.has_blank_line(pair) {
+            body.blank();
+        } else {
+            // The element before is over, but a comment may still follow
+            // on the next line.
+            body.last_line = None;
+        }
+    }
+
+    fn comment_block(&self, pair: Pair<'_>, body: &mut Body) {
+        for comment in pair.into_inner() {
+            let line = self.start_line(&comment);
+            body.comment(comment_text(&comment), line);
+        }
+    }
+}
+
+/// A comment without the whitespace around it. Block comments keep their
+/// contents as-is.
+fn comment_text(pair: &Pair<'_>) -> String {
+    pair.as_str().trim().to_string()
+}
+
+impl Body {
+    fn blank(&mut self) {
+        if matches!(self.lines.last(), Some(line) if !matches!(line, Line::Blank)) {
+            self.lines.push(Line::Blank);
+        }
+        self.last_line = None;
+    }
+
+    fn element(&mut self, line: Line, end_line: usize) {
+        self.lines.push(line);
+        self.last_line = Some(end_line);
+    }
+
+    fn block_attribute(&mut self, attribute: String, end_line: usize) {
+        // Comments right above a block attribute move with it.
+        let mut leading = vec![];
+        while matches!(self.lines.last(), Some(Line::Comment(_))) {
+            leading.extend(self.lines.pop());
+        }
+        self.block_attributes.extend(leading.into_iter().rev());
+        self.block_attributes.push(Line::Text(attribute));
+        self.last_line = Some(end_line);
+    }
+
+    /// A comment that starts on `line`: trailing if it follows an element
+    /// on the same line.
+    fn comment(&mut self, text: String, line: usize) {
+        if self.last_line == Some(line) {
+            let last = if self.block_attributes.is_empty() {
+                self.lines.last_mut()
+            } else {
+                self.block_attributes.last_mut()
+            };
+            match last {
+                Some(Line::Text(t)) => {
+                    t.push(' ');
+                    t.push_str(&text);
+                    self.last_line = None;
+                    return;
+                }
+                Some(Line::Field { rest, .. }) => {
+                    if !rest.is_empty() {
+                        rest.push(' ');
+                    }
+                    rest.push_str(&text);
+                    self.last_line = None;
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.lines.push(Line::Comment(text));
+        self.last_line = None;
+    }
+
+    fn comments(&mut self, comments: Comments) {
+        for text in comments.trailing {
+            let line = self.last_line.unwrap_or_default();
+            self.comment(text, line);
+            // Only the first comment fits at the end of the line.
+            self.last_line = None;
+        }
+        for text in comments.own_line {
+            self.lines.push(Line::Comment(text));
+        }
+    }
+
+    /// `{`, the lines indented by `indent + 1`, and `}` indented by `indent`.
+    fn render(self, indent: usize) -> String {
+        self.render_delimited(indent, "{", "}")
+    }
+
+    fn render_delimited(mut self, indent: usize, open: &str, close: &str) -> String {
+        if !self.block_attributes.is_empty() {
+            self.blank();
+            let attributes = std::mem::take(&mut self.block_attributes);
+            self.lines.extend(attributes);
+        }
+        while matches!(self.lines.last(), Some(Line::Blank)) {
+            self.lines.pop();
+        }
+        if self.lines.is_empty() {
+            return format!("{open}{close}");
+        }
+
+        // Align the type (or attributes) of consecutive fields.
+        let mut widths = vec![0; self.lines.len()];
+        let mut run_start = 0;
+        for i in 0..=self.lines.len() {
+            if i == self.lines.len() || matches!(self.lines[i], Line::Blank) {
+                let width = self.lines[run_start..i]
+                    .iter()
+                    .filter_map(|line| match line {
+                        Line::Field { name, rest } if !rest.is_empty() => Some(name.len()),
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                widths[run_start..i].fill(width);
+                run_start = i + 1;
+            }
+        }
+
+        let prefix = INDENT.repeat(indent + 1);
+        let mut out = format!("{open}\n");
+        for (line, width) in self.lines.iter().zip(widths) {
+            match line {
+                Line::Blank => {}
+                Line::Comment(text) | Line::Text(text) => {
+                    out.push_str(&prefix);
+                    out.push_str(text);
+                }
+                Line::Field { name, rest } if rest.is_empty() => {
+                    out.push_str(&prefix);
+                    out.push_str(name);
+                }
+                Line::Field { name, rest } => {
+                    out.push_str(&prefix);
+                    out.push_str(&format!("{name:width$} {rest}"));
+                }
+            }
+            out.push('\n');
+        }
+        out.push_str(&INDENT.repeat(indent));
+        out.push_str(close);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_formats(source: &str, expected: &str) {
+        let formatted = format_schema(source).unwrap();
+        assert_eq!(formatted, expected);
+        assert_eq!(
+            format_schema(&formatted).unwrap(),
+            formatted,
+            "formatting should be idempotent"
+        );
+    }
+
+    #[test]
+    fn test_format_class() {
+        assert_formats(
+            r#"
+// A person
+class Person{
+    name string   @description("their name") // required
+  age    int?
+      @alias("years")
+    @@dynamic
+
+  /// Where they live
+  address map<string,string>|null
+}
+"#,
+            r#"// A person
+class Person {
+  name string @description("their name") // required
+  age  int? @alias("years")
+
+  /// Where they live
+  address map<string, string> | null
+
+  @@dynamic
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_format_enum() {
+        assert_formats(
+            "enum Category {\n    Refund @alias(\"r\")\n  CancelOrder\n  TechnicalSupport   @description(#\"\n    Anything technical\n  \"#)\n}\nclass A {\n}",
+            "enum Category {\n  Refund           @alias(\"r\")\n  CancelOrder\n  TechnicalSupport @description(#\"\n    Anything technical\n  \"#)\n}\n\nclass A {}\n",
+        );
+    }
+
+    #[test]
+    fn test_format_function_keeps_prompt() {
+        let prompt =
+            "#\"\n      Extract from:\n  {{ resume }}\n\n    {{ ctx.output_format }}\n  \"#";
+        assert_formats(
+            &format!(
+                "function ExtractResume(resume:string,  other: int?)->Resume{{\nclient   GPT4\n    prompt {prompt}\n}}\n"
+            ),
+            &format!(
+                "function ExtractResume(resume: string, other: int?) -> Resume {{\n  client GPT4\n  prompt {prompt}\n}}\n"
+            ),
+        );
+    }
+
+    #[test]
+    fn test_format_client_and_test() {
+        assert_formats(
+            r#"client<llm> GPT4 {
+  provider openai
+  options {
+     model "gpt-4o" // the best one
+     api_key env.OPENAI_API_KEY
+
+     headers { "x-a" "b" }
+  }
+}
+test Foo {
+  functions [ExtractResume,   Other]
+  args {
+    items [
+      "a",
+      // the second one
+      "b"
+    ]
+  }
+}
+"#,
+            r#"client<llm> GPT4 {
+  provider openai
+  options {
+    model "gpt-4o" // the best one
+    api_key env.OPENAI_API_KEY
+
+    headers {
+      "x-a" "b"
+    }
+  }
+}
+
+test Foo {
+  functions [ExtractResume, Other]
+  args {
+    items [
+      "a",
+      // the second one
+      "b",
+    ]
+  }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_format_keeps_comment_attachment() {
+        assert_formats(
+            "/// docs\nclass A {\n  x int\n}\n// free floating\n\nclass B {\n  y int\n} // trailing\n",
+            "/// docs\nclass A {\n  x int\n}\n\n// free floating\n\nclass B {\n  y int\n} // trailing\n",
+        );
+    }
+
+    #[test]
+    fn test_format_rejects_invalid_files() {
+        let error = format_schema("class A {\n  x int\n}\n\nthis is not baml\n").unwrap_err();
+        assert_eq!(error.line, 5);
+    }
+}
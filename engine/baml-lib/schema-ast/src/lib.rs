@@ -5,6 +5,9 @@
 
 pub use self::parser::parse_schema;
 
+/// The canonical formatter for `.baml` files.
+pub mod formatter;
+
 /// The AST data structure. It aims to faithfully represent the syntax of a Prisma Schema, with
 /// source span information.
 pub mod ast;
@@ -0,0 +1,69 @@
+use crate::baml_src_files;
+use anyhow::{Context, Result};
+use internal_baml_core::internal_baml_schema_ast::formatter::format_schema;
+use std::path::PathBuf;
+
+#[derive(clap::Args, Debug)]
+pub struct FormatArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub from: PathBuf,
+    #[arg(help = "Files to format (defaults to every .baml file in baml_src)")]
+    pub paths: Vec<PathBuf>,
+    #[arg(
+        long,
+        help = "Fail if any file is not formatted, without changing it",
+        default_value_t = false
+    )]
+    pub(super) check: bool,
+}
+
+impl FormatArgs {
+    pub fn run(&self) -> Result<()> {
+        let paths = if self.paths.is_empty() {
+            baml_src_files(&self.from)
+                .context("Failed while searching for .baml files in baml_src/")?
+                .into_iter()
+                .filter(|p| p.extension().is_some_and(|ext| ext == "baml"))
+                .collect()
+        } else {
+            self.paths.clone()
+        };
+
+        let mut failed = 0;
+        let mut unformatted = vec![];
+        for path in paths {
+            let source = std::fs::read_to_string(&path)
+                .context(format!("Failed to read {}", path.display()))?;
+            let formatted = match format_schema(&source) {
+                Ok(formatted) => formatted,
+                Err(e) => {
+                    log::error!("{}:{}: {}", path.display(), e.line, e.message);
+                    failed += 1;
+                    continue;
+                }
+            };
+            if formatted == source {
+                continue;
+            }
+            if self.check {
+                log::info!("Would reformat {}", path.display());
+            } else {
+                std::fs::write(&path, formatted)
+                    .context(format!("Failed to write {}", path.display()))?;
+                log::info!("Formatted {}", path.display());
+            }
+            unformatted.push(path);
+        }
+
+        if failed > 0 {
+            anyhow::bail!("{failed} file(s) could not be formatted");
+        }
+        if self.check && !unformatted.is_empty() {
+            anyhow::bail!(
+                "{} file(s) are not formatted. Run 'baml-cli fmt' to format them.",
+                unformatted.len()
+            );
+        }
+        Ok(())
+    }
+}
@@ -61,6 +61,18 @@ impl GenerateArgs {
                     // this has no meaning
                     GeneratorDefaultClientMode::Sync
                 }
+                internal_baml_core::configuration::GeneratorOutputType::Rust => {
+                    // this has no meaning
+                    GeneratorDefaultClientMode::Async
+                }
+                internal_baml_core::configuration::GeneratorOutputType::Go => {
+                    // this has no meaning
+                    GeneratorDefaultClientMode::Sync
+                }
+                internal_baml_core::configuration::GeneratorOutputType::JsonSchema => {
+                    // this has no meaning
+                    GeneratorDefaultClientMode::Sync
+                }
             };
             // Normally `baml_client` is added via the generator, but since we're not running the generator, we need to add it manually.
             let output_dir_relative_to_baml_src = PathBuf::from("..");
@@ -77,6 +89,7 @@ impl GenerateArgs {
                         default_client_mode,
                         // TODO: this should be set if user is asking for openapi
                         vec![],
+                        false,
                     )
                     .context("Failed while resolving .baml paths in baml_src/")?,
                 )
@@ -87,6 +87,9 @@ impl InitArgs {
                 GeneratorOutputType::PythonPydantic => "Python clients".to_string(),
                 GeneratorOutputType::Typescript => "TypeScript clients".to_string(),
                 GeneratorOutputType::RubySorbet => "Ruby clients".to_string(),
+                GeneratorOutputType::Rust => "Rust clients".to_string(),
+                GeneratorOutputType::Go => "Go clients".to_string(),
+                GeneratorOutputType::JsonSchema => "JSON Schema".to_string(),
                 GeneratorOutputType::OpenApi => match &self.openapi_client_type {
                     Some(s) => format!("{} clients via OpenAPI", s),
                     None => "REST clients".to_string(),
@@ -99,6 +102,9 @@ impl InitArgs {
                 GeneratorOutputType::PythonPydantic => "python",
                 GeneratorOutputType::Typescript => "typescript",
                 GeneratorOutputType::RubySorbet => "ruby",
+                GeneratorOutputType::Rust => "rust",
+                GeneratorOutputType::Go => "go",
+                GeneratorOutputType::JsonSchema => "json-schema",
                 GeneratorOutputType::OpenApi => "openapi",
             }
         );
@@ -113,7 +119,11 @@ fn generate_main_baml_content(
     openapi_client_type: Option<&str>,
 ) -> String {
     let default_client_mode = match output_type {
-        GeneratorOutputType::OpenApi | GeneratorOutputType::RubySorbet => "".to_string(),
+        GeneratorOutputType::OpenApi
+        | GeneratorOutputType::RubySorbet
+        | GeneratorOutputType::Rust
+        | GeneratorOutputType::Go
+        | GeneratorOutputType::JsonSchema => "".to_string(),
         GeneratorOutputType::PythonPydantic | GeneratorOutputType::Typescript => format!(
             r#"
     // Valid values: "sync", "async"
@@ -183,7 +193,7 @@ fn generate_main_baml_content(
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "{output_type}"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -221,7 +231,7 @@ mod tests {
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "python/pydantic"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -250,7 +260,7 @@ generator target {{
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "typescript"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -279,7 +289,7 @@ generator target {{
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "ruby/sorbet"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -295,6 +305,31 @@ generator target {{
         );
     }
 
+    #[test]
+    fn test_generate_content_go() {
+        assert_eq!(
+            generate_main_baml_content(GeneratorOutputType::Go, None, None),
+            format!(r#"
+// This helps use auto generate libraries you can use in the language of
+// your choice. You can have multiple generators if you use multiple languages.
+// Just ensure that the output_dir is different for each generator.
+generator target {{
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
+    output_type "go"
+
+    // Where the generated code will be saved (relative to baml_src/)
+    output_dir "../"
+
+    // The version of the BAML package you have installed (e.g. same version as your baml-py or @boundaryml/baml).
+    // The BAML VSCode extension version should also match this version.
+    version "{}"
+}}
+"#,
+                env!("CARGO_PKG_VERSION")
+            ).trim_start()
+        );
+    }
+
     #[test]
     fn test_generate_content_openapi_go() {
         assert_eq!(
@@ -304,7 +339,7 @@ generator target {{
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "rest/openapi"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -333,7 +368,7 @@ generator target {{
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "rest/openapi"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -362,7 +397,7 @@ generator target {{
 // your choice. You can have multiple generators if you use multiple languages.
 // Just ensure that the output_dir is different for each generator.
 generator target {{
-    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rest/openapi"
+    // Valid values: "python/pydantic", "typescript", "ruby/sorbet", "rust", "go", "json-schema", "rest/openapi"
     output_type "rest/openapi"
 
     // Where the generated code will be saved (relative to baml_src/)
@@ -0,0 +1,342 @@
+use std::path::Path;
+
+use internal_baml_core::internal_baml_diagnostics::Span;
+use internal_baml_core::internal_baml_parser_database::{BLOCK_ATTRIBUTES, FIELD_ATTRIBUTES};
+use internal_baml_core::ir::{repr::IntermediateRepr, IRHelper};
+use lsp_types::{
+    CodeLens, Command, CompletionItem, CompletionItemKind, Location, MarkupContent, MarkupKind, Url,
+};
+
+use super::text::{enclosing_block, is_identifier_char, line_prefix, range_of};
+
+/// The command that the test code lenses run. Its arguments are the URI of
+/// the file that the test is in (to find its project), the function name and
+/// the test name.
+pub(super) const RUN_TEST_COMMAND: &str = "baml.runTest";
+
+const PRIMITIVE_TYPES: &[&str] = &[
+    "string", "int", "float", "bool", "null", "image", "audio", "pdf", "video", "map",
+];
+
+pub(super) fn span_location(span: &Span) -> Option<Location> {
+    Some(Location {
+        uri: Url::from_file_path(span.file.path_buf()).ok()?,
+        range: range_of(span.file.as_str(), span.start, span.end),
+    })
+}
+
+/// Finds where the class, enum, function, client, retry policy or template
+/// string called `name` is declared.
+pub(super) fn definition(ir: &IntermediateRepr, name: &str) -> Option<Location> {
+    let span = if let Ok(walker) = ir.find_enum(name) {
+        walker.span().cloned()
+    } else if let Ok(walker) = ir.find_class(name) {
+        walker.span().cloned()
+    } else if let Ok(walker) = ir.find_function(name) {
+        walker.span().cloned()
+    } else if let Ok(walker) = ir.find_client(name) {
+        walker.span().cloned()
+    } else if let Ok(walker) = ir.find_retry_policy(name) {
+        walker.span().cloned()
+    } else if let Ok(walker) = ir.find_template_string(name) {
+        walker.span().cloned()
+    } else {
+        None
+    };
+    span_location(&span?)
+}
+
+/// Renders the declaration of `name` (without bodies such as prompts), and
+/// its docstring, as markdown.
+pub(super) fn hover(ir: &IntermediateRepr, name: &str) -> Option<MarkupContent> {
+    let (signature, docs) = if let Ok(walker) = ir.find_class(name) {
+        let fields = walker
+            .walk_fields()
+            .map(|field| format!("  {} {}\n", field.name(), field.r#type()))
+            .collect::<String>();
+        let docs = walker.elem().docstring.as_ref().map(|d| d.0.clone());
+        (format!("class {} {{\n{}}}", name, fields), docs)
+    } else if let Ok(walker) = ir.find_enum(name) {
+        let values = walker
+            .walk_values()
+            .map(|value| format!("  {}\n", value.name()))
+            .collect::<String>();
+        let docs = walker.elem().docstring.as_ref().map(|d| d.0.clone());
+        (format!("enum {} {{\n{}}}", name, values), docs)
+    } else if let Ok(walker) = ir.find_function(name) {
+        let inputs = walker
+            .inputs()
+            .iter()
+            .map(|(arg, r#type)| format!("{}: {}", arg, r#type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let docs = walker
+            .client_name()
+            .map(|client| format!("Uses client `{}`", client));
+        (
+            format!("function {}({}) -> {}", name, inputs, walker.output()),
+            docs,
+        )
+    } else if let Ok(walker) = ir.find_client(name) {
+        let provider = &walker.elem().provider;
+        (
+            format!("client<llm> {}", name),
+            Some(format!("Provider: `{}`", provider)),
+        )
+    } else if let Ok(walker) = ir.find_template_string(name) {
+        let inputs = walker
+            .inputs()
+            .iter()
+            .map(|field| format!("{}: {}", field.name, field.r#type.elem))
+            .collect::<Vec<_>>()
+            .join(", ");
+        (format!("template_string {}({})", name, inputs), None)
+    } else if let Ok(walker) = ir.find_retry_policy(name) {
+        (
+            format!("retry_policy {}", name),
+            Some(format!("Retries up to {} times", walker.max_retries())),
+        )
+    } else {
+        return None;
+    };
+
+    let mut value = format!("```baml\n{}\n```", signature);
+    if let Some(docs) = docs {
+        value.push_str("\n\n");
+        value.push_str(&docs);
+    }
+    Some(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value,
+    })
+}
+
+/// What can be typed at the cursor, judging from the text before it.
+#[derive(Debug, PartialEq)]
+enum CompletionContext {
+    BlockAttribute,
+    FieldAttribute,
+    Client,
+    Type,
+}
+
+fn completion_context(text: &str, offset: usize) -> Option<CompletionContext> {
+    let prefix = line_prefix(text, offset);
+    let before_word = prefix.trim_end_matches(is_identifier_char);
+    if before_word.ends_with("@@") {
+        return Some(CompletionContext::BlockAttribute);
+    }
+    if before_word.ends_with('@') {
+        return Some(CompletionContext::FieldAttribute);
+    }
+
+    let block = enclosing_block(text, offset);
+    let trimmed = before_word.trim();
+    match block {
+        Some("function") if trimmed.trim_end_matches('"').trim_end() == "client" => {
+            Some(CompletionContext::Client)
+        }
+        Some("function" | "template_string")
+            if ["->", ":", "|", "<", ","]
+                .iter()
+                .any(|token| trimmed.ends_with(token)) =>
+        {
+            Some(CompletionContext::Type)
+        }
+        Some("class") => {
+            // `name Type`, or after a `|` or `<` in the middle of a type.
+            let is_field_type = !trimmed.is_empty()
+                && trimmed.chars().all(is_identifier_char)
+                && before_word.ends_with(char::is_whitespace);
+            (is_field_type || ["|", "<", ","].iter().any(|token| trimmed.ends_with(token)))
+                .then_some(CompletionContext::Type)
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn completions(
+    ir: Option<&IntermediateRepr>,
+    text: &str,
+    offset: usize,
+) -> Vec<CompletionItem> {
+    let item = |label: &str, kind: CompletionItemKind| CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        ..Default::default()
+    };
+    match completion_context(text, offset) {
+        Some(CompletionContext::BlockAttribute) => BLOCK_ATTRIBUTES
+            .iter()
+            .map(|name| item(name, CompletionItemKind::PROPERTY))
+            .collect(),
+        Some(CompletionContext::FieldAttribute) => FIELD_ATTRIBUTES
+            .iter()
+            .map(|name| item(name, CompletionItemKind::PROPERTY))
+            .collect(),
+        Some(CompletionContext::Client) => ir
+            .into_iter()
+            .flat_map(|ir| ir.walk_clients())
+            .map(|client| item(client.name(), CompletionItemKind::MODULE))
+            .collect(),
+        Some(CompletionContext::Type) => PRIMITIVE_TYPES
+            .iter()
+            .map(|name| item(name, CompletionItemKind::KEYWORD))
+            .chain(ir.into_iter().flat_map(|ir| {
+                ir.walk_classes()
+                    .map(|class| item(class.name(), CompletionItemKind::STRUCT))
+                    .chain(
+                        ir.walk_enums()
+                            .map(|e| item(e.name(), CompletionItemKind::ENUM)),
+                    )
+                    .collect::<Vec<_>>()
+            }))
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// A "Run" lens above every test in `uri`, one per function it tests.
+pub(super) fn test_code_lenses(ir: &IntermediateRepr, uri: &Url, path: &Path) -> Vec<CodeLens> {
+    ir.walk_tests()
+        .filter_map(|test| {
+            let span = test.span()?;
+            if span.file.path_buf() != path {
+                return None;
+            }
+            let function = test.function().name().to_string();
+            Some(CodeLens {
+                range: range_of(span.file.as_str(), span.start, span.start),
+                command: Some(Command {
+                    title: format!("▶ Run {}", function),
+                    command: RUN_TEST_COMMAND.to_string(),
+                    arguments: Some(vec![
+                        serde_json::json!(uri),
+                        serde_json::json!(function),
+                        serde_json::json!(test.test_case().name),
+                    ]),
+                }),
+                data: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{runtime_interface::InternalRuntimeInterface, BamlRuntime};
+
+    const PATH: &str = "/project/baml_src/main.baml";
+    const SOURCE: &str = r##"
+/// Someone mentioned in the text.
+class Person {
+  name string
+  age int
+}
+
+client<llm> GPT4 {
+  provider openai
+  options {
+    model "gpt-4o"
+    api_key "sk-test"
+  }
+}
+
+function Extract(text: string) -> Person {
+  client GPT4
+  prompt #"{{ text }} {{ ctx.output_format }}"#
+}
+"##;
+
+    fn runtime() -> BamlRuntime {
+        let files = HashMap::from([(PATH, SOURCE)]);
+        BamlRuntime::from_file_content("/project/baml_src", &files, HashMap::<&str, &str>::new())
+            .unwrap()
+    }
+
+    fn context_at(text: &str) -> Option<CompletionContext> {
+        let offset = text.find('|').unwrap();
+        let text = text.replacen('|', "", 1);
+        completion_context(&text, offset)
+    }
+
+    #[test]
+    fn test_completion_context() {
+        use CompletionContext::*;
+
+        assert_eq!(context_at("class Foo {\n  name |\n}"), Some(Type));
+        assert_eq!(context_at("class Foo {\n  name str|\n}"), Some(Type));
+        assert_eq!(
+            context_at("class Foo {\n  name string @|\n}"),
+            Some(FieldAttribute)
+        );
+        assert_eq!(context_at("class Foo {\n  @@dyn|\n}"), Some(BlockAttribute));
+        assert_eq!(context_at("class Foo {\n  na|\n}"), None);
+        assert_eq!(context_at("function Foo(a: |) -> string {\n}"), Some(Type));
+        assert_eq!(context_at("function Foo(a: int) -> |"), Some(Type));
+        assert_eq!(
+            context_at("function Foo(a: int) -> string {\n  client GP|\n}"),
+            Some(Client)
+        );
+        assert_eq!(context_at("client<llm> GPT4 {\n  provider open|\n}"), None);
+        assert_eq!(context_at("test Foo {\n  args {\n    a |\n  }\n}"), None);
+    }
+
+    #[test]
+    fn test_definition() {
+        let runtime = runtime();
+        let ir = runtime.inner.ir();
+
+        let location = definition(ir, "Person").unwrap();
+        assert_eq!(location.uri, Url::from_file_path(PATH).unwrap());
+        assert_eq!(location.range.start.line, 2);
+
+        let location = definition(ir, "Extract").unwrap();
+        assert_eq!(location.range.start.line, 15);
+
+        assert_eq!(definition(ir, "Missing"), None);
+    }
+
+    #[test]
+    fn test_hover() {
+        let runtime = runtime();
+        let ir = runtime.inner.ir();
+
+        let class = hover(ir, "Person").unwrap();
+        assert_eq!(
+            class.value,
+            "```baml\nclass Person {\n  name string\n  age int\n}\n```\n\nSomeone mentioned in the text."
+        );
+
+        let function = hover(ir, "Extract").unwrap();
+        assert_eq!(
+            function.value,
+            "```baml\nfunction Extract(text: string) -> Person\n```\n\nUses client `GPT4`"
+        );
+
+        assert_eq!(hover(ir, "Missing"), None);
+    }
+
+    #[test]
+    fn test_attribute_completions() {
+        let labels = |text: &str| {
+            let offset = text.find('|').unwrap();
+            completions(None, &text.replacen('|', "", 1), offset)
+                .into_iter()
+                .map(|item| item.label)
+                .collect::<Vec<_>>()
+        };
+
+        let field = labels("class Foo {\n  name string @|\n}");
+        for name in ["alias", "default", "min_length", "pattern", "one_of"] {
+            assert!(field.contains(&name.to_string()), "missing @{name}");
+        }
+        let block = labels("class Foo {\n  @@|\n}");
+        for name in ["dynamic", "tag"] {
+            assert!(block.contains(&name.to_string()), "missing @@{name}");
+        }
+    }
+}
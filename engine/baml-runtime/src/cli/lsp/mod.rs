@@ -0,0 +1,415 @@
+//! A language server for `.baml` files, for editors other than VSCode.
+//!
+//! The server speaks LSP over stdio and is built on the same compiler APIs as
+//! the VSCode extension. Every `baml_src` directory that has an open file is
+//! compiled as its own project, with the unsaved contents of open files.
+
+mod features;
+mod project;
+mod text;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use baml_types::BamlValue;
+use crossbeam_channel::Sender;
+use internal_baml_core::internal_baml_schema_ast::formatter::format_schema;
+use internal_baml_core::ir::repr::IntermediateRepr;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    DidSaveTextDocument, LogMessage, Notification as _, PublishDiagnostics, ShowMessage,
+};
+use lsp_types::request::{
+    CodeLensRequest, Completion, ExecuteCommand, Formatting, GotoDefinition, HoverRequest,
+    Request as _,
+};
+use lsp_types::{
+    CodeLens, CodeLensOptions, CodeLensParams, CompletionOptions, CompletionParams,
+    CompletionResponse, DocumentFormattingParams, ExecuteCommandOptions, ExecuteCommandParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, LogMessageParams, MessageType, OneOf, Position,
+    PublishDiagnosticsParams, Range, ServerCapabilities, ShowMessageParams,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+};
+
+use self::features::RUN_TEST_COMMAND;
+use self::project::{project_root, Project};
+use self::text::{offset_at, position_at, word_at};
+use crate::{
+    runtime_interface::InternalRuntimeInterface, BamlRuntime, FunctionResult, TestFailReason,
+    TestResponse, TestStatus,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct LspArgs {}
+
+impl LspArgs {
+    pub fn run(&self) -> Result<()> {
+        let (connection, io_threads) = Connection::stdio();
+        connection
+            .initialize(serde_json::to_value(server_capabilities())?)
+            .context("Failed to initialize the language server")?;
+        log::info!("BAML language server started");
+
+        LanguageServer::new(connection.sender.clone()).main_loop(&connection)?;
+        io_threads.join()?;
+        Ok(())
+    }
+}
+
+fn server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["@".to_string(), " ".to_string(), "\"".to_string()]),
+            ..Default::default()
+        }),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![RUN_TEST_COMMAND.to_string()],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+struct LanguageServer {
+    sender: Sender<Message>,
+    /// Contents of the open documents, which may not have been saved yet.
+    documents: HashMap<PathBuf, String>,
+    /// Projects, keyed by their `baml_src` directory.
+    projects: HashMap<PathBuf, Project>,
+}
+
+impl LanguageServer {
+    fn new(sender: Sender<Message>) -> Self {
+        Self {
+            sender,
+            documents: HashMap::new(),
+            projects: HashMap::new(),
+        }
+    }
+
+    fn main_loop(&mut self, connection: &Connection) -> Result<()> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request);
+                }
+                Message::Notification(notification) => {
+                    if let Err(e) = self.handle_notification(notification) {
+                        log::error!("Failed to handle notification: {:#}", e);
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) {
+        let id = request.id.clone();
+        let result = match request.method.as_str() {
+            GotoDefinition::METHOD => self.on::<GotoDefinition>(request, Self::definition),
+            HoverRequest::METHOD => self.on::<HoverRequest>(request, Self::hover),
+            Completion::METHOD => self.on::<Completion>(request, Self::completion),
+            CodeLensRequest::METHOD => self.on::<CodeLensRequest>(request, Self::code_lens),
+            Formatting::METHOD => self.on::<Formatting>(request, Self::formatting),
+            ExecuteCommand::METHOD => self.on::<ExecuteCommand>(request, Self::execute_command),
+            method => {
+                self.send(Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("Unsupported request: {}", method),
+                ));
+                return;
+            }
+        };
+        let response = match result {
+            Ok(result) => Response::new_ok(id, result),
+            Err(e) => Response::new_err(id, ErrorCode::InternalError as i32, format!("{:#}", e)),
+        };
+        self.send(response);
+    }
+
+    fn on<R: lsp_types::request::Request>(
+        &mut self,
+        request: Request,
+        handler: fn(&mut Self, R::Params) -> Result<R::Result>,
+    ) -> Result<serde_json::Value> {
+        let (_, params) = request.extract::<R::Params>(R::METHOD)?;
+        Ok(serde_json::to_value(handler(self, params)?)?)
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = extract::<DidOpenTextDocument>(notification)?;
+                let path = to_path(&params.text_document.uri)?;
+                self.documents
+                    .insert(path.clone(), params.text_document.text);
+                self.reload(&path);
+            }
+            DidChangeTextDocument::METHOD => {
+                let mut params = extract::<DidChangeTextDocument>(notification)?;
+                let path = to_path(&params.text_document.uri)?;
+                // We only ask for full syncs, so the last change is the whole document.
+                if let Some(change) = params.content_changes.pop() {
+                    self.documents.insert(path.clone(), change.text);
+                }
+                self.reload(&path);
+            }
+            DidSaveTextDocument::METHOD => {
+                let params = extract::<DidSaveTextDocument>(notification)?;
+                self.reload(&to_path(&params.text_document.uri)?);
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = extract::<DidCloseTextDocument>(notification)?;
+                let path = to_path(&params.text_document.uri)?;
+                self.documents.remove(&path);
+                self.reload(&path);
+            }
+            DidChangeWatchedFiles::METHOD => {
+                let params = extract::<DidChangeWatchedFiles>(notification)?;
+                for change in params.changes {
+                    self.reload(&to_path(&change.uri)?);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Recompiles the project that `path` belongs to, and publishes its
+    /// diagnostics.
+    fn reload(&mut self, path: &Path) {
+        let root = project_root(path);
+        let project = self
+            .projects
+            .entry(root.clone())
+            .or_insert_with(|| Project::new(root));
+        let diagnostics = project.reload(&self.documents);
+
+        for (path, diagnostics) in diagnostics {
+            let Ok(uri) = Url::from_file_path(&path) else {
+                continue;
+            };
+            self.notify::<PublishDiagnostics>(PublishDiagnosticsParams {
+                uri,
+                diagnostics,
+                version: None,
+            });
+        }
+    }
+
+    fn runtime(&self, path: &Path) -> Option<&Arc<BamlRuntime>> {
+        self.projects.get(&project_root(path))?.runtime()
+    }
+
+    fn ir(&self, path: &Path) -> Option<&IntermediateRepr> {
+        self.runtime(path).map(|runtime| runtime.inner.ir())
+    }
+
+    /// Returns the document, the offset of the cursor in it, and the IR of
+    /// its project.
+    fn locate(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Result<Option<(&str, usize, Option<&IntermediateRepr>)>> {
+        let path = to_path(&position.text_document.uri)?;
+        let Some(text) = self.documents.get(&path) else {
+            return Ok(None);
+        };
+        let offset = offset_at(text, position.position);
+        Ok(Some((text, offset, self.ir(&path))))
+    }
+
+    fn definition(
+        &mut self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let Some((text, offset, Some(ir))) = self.locate(&params.text_document_position_params)?
+        else {
+            return Ok(None);
+        };
+        Ok(word_at(text, offset)
+            .and_then(|word| features::definition(ir, word))
+            .map(GotoDefinitionResponse::Scalar))
+    }
+
+    fn hover(&mut self, params: HoverParams) -> Result<Option<Hover>> {
+        let Some((text, offset, Some(ir))) = self.locate(&params.text_document_position_params)?
+        else {
+            return Ok(None);
+        };
+        Ok(word_at(text, offset)
+            .and_then(|word| features::hover(ir, word))
+            .map(|contents| Hover {
+                contents: HoverContents::Markup(contents),
+                range: None,
+            }))
+    }
+
+    fn completion(&mut self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let Some((text, offset, ir)) = self.locate(&params.text_document_position)? else {
+            return Ok(None);
+        };
+        Ok(Some(CompletionResponse::Array(features::completions(
+            ir, text, offset,
+        ))))
+    }
+
+    fn code_lens(&mut self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let path = to_path(&params.text_document.uri)?;
+        let lenses = self
+            .ir(&path)
+            .map(|ir| features::test_code_lenses(ir, &params.text_document.uri, &path))
+            .unwrap_or_default();
+        Ok(Some(lenses))
+    }
+
+    fn formatting(&mut self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let path = to_path(&params.text_document.uri)?;
+        let Some(text) = self.documents.get(&path) else {
+            return Ok(None);
+        };
+        // Files with syntax errors are left alone; diagnostics already point at the problem.
+        let Ok(formatted) = format_schema(text) else {
+            return Ok(None);
+        };
+        if &formatted == text {
+            return Ok(Some(vec![]));
+        }
+        let range = Range::new(Position::new(0, 0), position_at(text, text.len()));
+        Ok(Some(vec![TextEdit::new(range, formatted)]))
+    }
+
+    fn execute_command(
+        &mut self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        if params.command != RUN_TEST_COMMAND {
+            anyhow::bail!("Unknown command: {}", params.command);
+        }
+        let (uri, function_name, test_name): (Url, String, String) =
+            serde_json::from_value(serde_json::Value::Array(params.arguments))
+                .context("Expected the arguments to be [uri, function name, test name]")?;
+        let Some(runtime) = self.runtime(&to_path(&uri)?).cloned() else {
+            anyhow::bail!("The project of {} has errors, so its tests can't run", uri);
+        };
+
+        // Tests call LLMs, so run them off the main loop to keep the editor responsive.
+        let sender = self.sender.clone();
+        std::thread::spawn(move || {
+            let name = format!("{}::{}", function_name, test_name);
+            let ctx = runtime.create_ctx_manager(BamlValue::String("lsp".to_string()), None);
+            let (result, _) = runtime.async_runtime.block_on(runtime.run_test(
+                &function_name,
+                &test_name,
+                &ctx,
+                None::<fn(FunctionResult)>,
+            ));
+
+            if let Ok(response) = &result {
+                send_notification::<LogMessage>(
+                    &sender,
+                    LogMessageParams {
+                        typ: MessageType::LOG,
+                        message: format!("{}\n{}", name, response),
+                    },
+                );
+            }
+            let (typ, message) = describe_test_result(&name, &result);
+            send_notification::<ShowMessage>(&sender, ShowMessageParams { typ, message });
+        });
+        Ok(None)
+    }
+
+    fn send(&self, message: impl Into<Message>) {
+        if let Err(e) = self.sender.send(message.into()) {
+            log::error!("Failed to send a message to the editor: {}", e);
+        }
+    }
+
+    fn notify<N: lsp_types::notification::Notification>(&self, params: N::Params) {
+        send_notification::<N>(&self.sender, params);
+    }
+}
+
+fn send_notification<N: lsp_types::notification::Notification>(
+    sender: &Sender<Message>,
+    params: N::Params,
+) {
+    let notification = Notification::new(N::METHOD.to_string(), params);
+    if let Err(e) = sender.send(notification.into()) {
+        log::error!("Failed to send a message to the editor: {}", e);
+    }
+}
+
+fn extract<N: lsp_types::notification::Notification>(
+    notification: Notification,
+) -> Result<N::Params> {
+    Ok(notification.extract(N::METHOD)?)
+}
+
+fn to_path(uri: &Url) -> Result<PathBuf> {
+    uri.to_file_path()
+        .map_err(|_| anyhow::anyhow!("Only file:// documents are supported, got {}", uri))
+}
+
+fn describe_test_result(name: &str, result: &Result<TestResponse>) -> (MessageType, String) {
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            return (
+                MessageType::ERROR,
+                format!("{} could not run: {:#}", name, e),
+            )
+        }
+    };
+    match response.status() {
+        TestStatus::Pass => (MessageType::INFO, format!("{} passed", name)),
+        TestStatus::NeedsHumanEval(checks) => (
+            MessageType::WARNING,
+            format!(
+                "{} passed, but these checks need a human to evaluate them: {}",
+                name,
+                checks.join(", ")
+            ),
+        ),
+        TestStatus::Fail(reason) => {
+            let reason = match reason {
+                TestFailReason::TestUnspecified(e) => format!("{:#}", e),
+                TestFailReason::TestLLMFailure(_) => "the LLM call failed".to_string(),
+                TestFailReason::TestParseFailure(e) => {
+                    format!("the response could not be parsed: {:#}", e)
+                }
+                TestFailReason::TestConstraintsFailure {
+                    failed_assert: Some(assert),
+                    ..
+                } => format!("assert {} failed", assert),
+                TestFailReason::TestConstraintsFailure { checks, .. } => format!(
+                    "checks failed: {}",
+                    checks
+                        .iter()
+                        .filter(|(_, passed)| !passed)
+                        .map(|(check, _)| check.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            (MessageType::ERROR, format!("{} failed: {}", name, reason))
+        }
+    }
+}
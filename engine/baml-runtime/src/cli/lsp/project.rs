@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use internal_baml_core::internal_baml_diagnostics::{Diagnostics, Span};
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use super::text::range_of;
+use crate::{baml_src_files, runtime_interface::InternalRuntimeInterface, BamlRuntime};
+
+/// Returns the `baml_src` directory that `path` belongs to. Files outside of
+/// a `baml_src` directory are treated as a project of their own directory.
+pub(super) fn project_root(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|p| p.file_name() == Some(std::ffi::OsStr::new("baml_src")))
+        .or_else(|| path.parent())
+        .unwrap_or(path)
+        .to_path_buf()
+}
+
+/// A `baml_src` directory, as seen by the editor: the files on disk, with the
+/// contents of the open documents in place of their saved versions.
+pub(super) struct Project {
+    root: PathBuf,
+    /// The last runtime that compiled. It is kept around while the project has
+    /// errors, so that navigation keeps working while the user is typing.
+    runtime: Option<Arc<BamlRuntime>>,
+    /// Files that diagnostics were last published for.
+    published: HashSet<PathBuf>,
+}
+
+impl Project {
+    pub(super) fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            runtime: None,
+            published: HashSet::new(),
+        }
+    }
+
+    pub(super) fn runtime(&self) -> Option<&Arc<BamlRuntime>> {
+        self.runtime.as_ref()
+    }
+
+    /// Recompiles the project and returns the diagnostics of every file,
+    /// including empty lists for files whose diagnostics were cleared.
+    pub(super) fn reload(
+        &mut self,
+        documents: &HashMap<PathBuf, String>,
+    ) -> HashMap<PathBuf, Vec<Diagnostic>> {
+        let mut files = match baml_src_files(&self.root) {
+            Ok(paths) => paths
+                .into_iter()
+                .filter_map(|path| {
+                    let contents = std::fs::read_to_string(&path).ok()?;
+                    Some((path, contents))
+                })
+                .collect::<HashMap<_, _>>(),
+            Err(e) => {
+                log::warn!("Failed to list files in {}: {:#}", self.root.display(), e);
+                HashMap::new()
+            }
+        };
+        files.extend(
+            documents
+                .iter()
+                .filter(|(path, _)| path.starts_with(&self.root))
+                .map(|(path, contents)| (path.clone(), contents.clone())),
+        );
+
+        let sources = files
+            .iter()
+            .map(|(path, contents)| (path.to_string_lossy().to_string(), contents.clone()))
+            .collect::<HashMap<_, _>>();
+        let diagnostics = match BamlRuntime::from_file_content(
+            &self.root.to_string_lossy(),
+            &sources,
+            std::env::vars().collect::<HashMap<_, _>>(),
+        ) {
+            Ok(runtime) => {
+                let diagnostics = runtime.inner.diagnostics().clone();
+                self.runtime = Some(Arc::new(runtime));
+                diagnostics
+            }
+            Err(e) => match e.downcast::<Diagnostics>() {
+                Ok(diagnostics) => diagnostics,
+                Err(e) => {
+                    log::error!("Failed to load {}: {:#}", self.root.display(), e);
+                    Diagnostics::new(self.root.clone())
+                }
+            },
+        };
+
+        let mut by_file: HashMap<PathBuf, Vec<Diagnostic>> =
+            files.keys().map(|path| (path.clone(), vec![])).collect();
+        let errors = diagnostics
+            .errors()
+            .iter()
+            .map(|e| (e.span(), e.message(), DiagnosticSeverity::ERROR));
+        let warnings = diagnostics
+            .warnings()
+            .iter()
+            .map(|w| (w.span(), w.message(), DiagnosticSeverity::WARNING));
+        for (span, message, severity) in errors.chain(warnings) {
+            by_file
+                .entry(span.file.path_buf().clone())
+                .or_default()
+                .push(to_diagnostic(span, message, severity));
+        }
+
+        for stale in self.published.drain() {
+            by_file.entry(stale).or_default();
+        }
+        self.published = by_file
+            .iter()
+            .filter(|(_, diagnostics)| !diagnostics.is_empty())
+            .map(|(path, _)| path.clone())
+            .collect();
+        by_file
+    }
+}
+
+fn to_diagnostic(span: &Span, message: &str, severity: DiagnosticSeverity) -> Diagnostic {
+    Diagnostic {
+        range: range_of(span.file.as_str(), span.start, span.end),
+        severity: Some(severity),
+        source: Some("baml".to_string()),
+        message: message.to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reload_publishes_and_clears_diagnostics() {
+        let root = PathBuf::from("/project/baml_src");
+        let path = root.join("main.baml");
+        let mut project = Project::new(root);
+
+        let broken = HashMap::from([(
+            path.clone(),
+            "class Person {\n  name Missing\n}\n".to_string(),
+        )]);
+        let diagnostics = project.reload(&broken);
+        let errors = &diagnostics[&path];
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert_eq!(errors[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(errors[0].range.start.line, 1);
+        assert!(project.runtime().is_none());
+
+        let fixed = HashMap::from([(
+            path.clone(),
+            "class Person {\n  name string\n}\n".to_string(),
+        )]);
+        let diagnostics = project.reload(&fixed);
+        assert_eq!(diagnostics[&path], vec![]);
+        assert!(project.runtime().is_some());
+    }
+}
@@ -0,0 +1,148 @@
+//! Conversions between LSP positions and byte offsets into a `.baml` file.
+//!
+//! LSP positions count lines from 0 and columns in UTF-16 code units, while
+//! every span produced by the BAML parser is a byte offset.
+
+use lsp_types::{Position, Range};
+
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+/// Returns the byte offset of `position` in `text`, clamped to the end of
+/// the line (or file) it points past.
+pub(super) fn offset_at(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |i| line_start + i);
+
+    let mut column = 0;
+    for (i, c) in text[line_start..line_end].char_indices() {
+        if column >= position.character as usize {
+            return line_start + i;
+        }
+        column += c.len_utf16();
+    }
+    line_end
+}
+
+pub(super) fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..floor_char_boundary(text, offset)];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let character = before[line_start..].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
+
+pub(super) fn range_of(text: &str, start: usize, end: usize) -> Range {
+    Range::new(position_at(text, start), position_at(text, end))
+}
+
+pub(super) fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the identifier that contains (or ends at) `offset`.
+pub(super) fn word_at(text: &str, offset: usize) -> Option<&str> {
+    let offset = floor_char_boundary(text, offset);
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_identifier_char(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+    let end = text[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_identifier_char(*c))
+        .map_or(text.len(), |(i, _)| offset + i);
+    (start < end).then(|| &text[start..end])
+}
+
+/// Returns the text between the start of the line and `offset`.
+pub(super) fn line_prefix(text: &str, offset: usize) -> &str {
+    let offset = floor_char_boundary(text, offset);
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    &text[line_start..offset]
+}
+
+/// Returns the keyword of the top-level block that `offset` is in, e.g.
+/// `class` or `function`, by looking for the closest block header above it
+/// (or on the same line).
+pub(super) fn enclosing_block(text: &str, offset: usize) -> Option<&str> {
+    let offset = floor_char_boundary(text, offset);
+    let mut depth = 0;
+    for (i, line) in text[..offset].split('\n').rev().enumerate() {
+        depth += line.matches('}').count();
+        let opened = line.matches('{').count();
+        if ((i == 0 && !line.is_empty()) || opened > depth)
+            && !line.starts_with(char::is_whitespace)
+        {
+            return line
+                .split(|c: char| !is_identifier_char(c))
+                .next()
+                .filter(|keyword| !keyword.is_empty());
+        }
+        depth = depth.saturating_sub(opened);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_round_trip() {
+        let text = "class Foo {\n  name string // ñame\n  age int\n}\n";
+        for offset in [0, 6, 12, 18, 32, 38, text.len()] {
+            assert_eq!(offset_at(text, position_at(text, offset)), offset);
+        }
+        assert_eq!(
+            position_at(text, text.find("int").unwrap()),
+            Position::new(2, 6)
+        );
+        // Columns are counted in UTF-16 code units, not bytes.
+        let age = text.find("age").unwrap();
+        assert_eq!(position_at(text, age - 3), Position::new(1, 21));
+        assert_eq!(offset_at(text, Position::new(1, 100)), age - 3);
+        assert_eq!(offset_at(text, Position::new(10, 0)), text.len());
+    }
+
+    #[test]
+    fn test_word_at() {
+        let text = "function Foo(input: Resume) -> string[] {";
+        assert_eq!(word_at(text, 10), Some("Foo"));
+        assert_eq!(word_at(text, 26), Some("Resume"));
+        assert_eq!(word_at(text, 12), Some("Foo"));
+        assert_eq!(word_at(text, 27), None);
+    }
+
+    #[test]
+    fn test_enclosing_block() {
+        let text = "class Foo {\n  bar Bar\n}\n\nfunction Go(a: int) -> Foo {\n  client GPT4\n  prompt #\"\n    {{ a }}\n  \"#\n}\n";
+        assert_eq!(
+            enclosing_block(text, text.find("Bar").unwrap()),
+            Some("class")
+        );
+        assert_eq!(
+            enclosing_block(text, text.find("GPT4").unwrap()),
+            Some("function")
+        );
+        assert_eq!(
+            enclosing_block(text, text.find("{{ a").unwrap()),
+            Some("function")
+        );
+        assert_eq!(enclosing_block(text, text.find("\n\n").unwrap() + 1), None);
+    }
+}
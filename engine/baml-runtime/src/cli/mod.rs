@@ -1,6 +1,10 @@
 pub mod dev;
+pub mod format;
 pub mod generate;
 pub mod init;
+pub mod lsp;
+pub mod parse;
+pub mod schema;
 pub mod serve;
 
 use internal_baml_core::configuration::GeneratorOutputType;
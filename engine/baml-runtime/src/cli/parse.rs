@@ -0,0 +1,83 @@
+use crate::{
+    internal::llm_client::ResponseBamlValue, BamlRuntime, FunctionResult, RuntimeContextManager,
+};
+use anyhow::{Context, Result};
+use baml_types::{BamlValue, BamlValueWithMeta};
+use serde_json::json;
+use std::{io::Read, path::PathBuf};
+
+#[derive(clap::Args, Debug)]
+pub struct ParseArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub from: PathBuf,
+    #[arg(
+        long,
+        help = "The function whose return type to parse the text from stdin into"
+    )]
+    pub(super) function: String,
+    #[arg(
+        long,
+        help = "Parse the text as an unfinished streamed response",
+        default_value_t = false
+    )]
+    pub(super) partial: bool,
+}
+
+impl ParseArgs {
+    pub fn run(&self) -> Result<()> {
+        let mut raw_text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut raw_text)
+            .context("Failed to read the LLM output from stdin")?;
+
+        let runtime = BamlRuntime::from_directory(&self.from, std::env::vars().collect())
+            .context("Failed to build BAML runtime")?;
+        let ctx = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
+        let result =
+            runtime.parse_response(&self.function, &raw_text, self.partial, &ctx, None, None)?;
+
+        println!("{}", serde_json::to_string_pretty(&parse_report(&result)?)?);
+        Ok(())
+    }
+}
+
+/// The parsed value of `result`, with the outcome of every check, the
+/// coercion flags the parser applied and their score, as JSON.
+pub(super) fn parse_report(result: &FunctionResult) -> Result<serde_json::Value> {
+    let value = result.result_with_constraints_content()?;
+    let report = result.parse_report()?;
+
+    let mut checks = vec![];
+    collect_checks(value, "$", &mut checks);
+
+    Ok(json!({
+        "value": BamlValue::from(value.clone()),
+        "checks": checks,
+        "score": report.score,
+        "flags": report.flags,
+    }))
+}
+
+fn collect_checks(value: &ResponseBamlValue, path: &str, out: &mut Vec<serde_json::Value>) {
+    for check in value.meta() {
+        out.push(json!({
+            "path": path,
+            "name": check.name,
+            "expression": check.expression,
+            "status": check.status,
+        }));
+    }
+    match value {
+        BamlValueWithMeta::List(items, _) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_checks(item, &format!("{path}[{i}]"), out);
+            }
+        }
+        BamlValueWithMeta::Map(fields, _) | BamlValueWithMeta::Class(_, fields, _) => {
+            for (k, v) in fields.iter() {
+                collect_checks(v, &format!("{path}.{k}"), out);
+            }
+        }
+        _ => {}
+    }
+}
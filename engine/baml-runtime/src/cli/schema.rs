@@ -0,0 +1,46 @@
+use crate::{runtime_interface::InternalRuntimeInterface, BamlRuntime};
+use anyhow::{Context, Result};
+use internal_baml_core::ir::{
+    json_schema::{self, WithJsonSchema},
+    IRHelper,
+};
+use std::path::PathBuf;
+
+#[derive(clap::Args, Debug)]
+pub struct SchemaArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub from: PathBuf,
+    #[arg(help = "Only export this class, enum or function (exports everything by default)")]
+    pub(super) name: Option<String>,
+    #[arg(
+        long,
+        help = "Export the arguments of the function instead of its result",
+        default_value_t = false
+    )]
+    pub(super) input: bool,
+}
+
+impl SchemaArgs {
+    pub fn run(&self) -> Result<()> {
+        let runtime = BamlRuntime::from_directory(&self.from, std::env::vars().collect())
+            .context("Failed to build BAML runtime")?;
+        let ir = runtime.inner.ir();
+
+        let schema = match &self.name {
+            None if self.input => anyhow::bail!("--input requires a function name"),
+            None => ir.json_schema(),
+            Some(name) if ir.find_function(name).is_ok() => {
+                if self.input {
+                    json_schema::function_input_document(ir, name)?
+                } else {
+                    json_schema::function_output_document(ir, name)?
+                }
+            }
+            Some(_) if self.input => anyhow::bail!("--input requires a function name"),
+            Some(name) => json_schema::type_document(ir, name)?,
+        };
+
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        Ok(())
+    }
+}
@@ -32,10 +32,12 @@ use std::{path::PathBuf, sync::Arc, task::Poll};
 use tokio::{net::TcpListener, sync::RwLock};
 use tokio_stream::StreamExt;
 
+use super::parse::parse_report;
 use crate::{
     client_registry::ClientRegistry,
     errors::ExposedError,
     internal::llm_client::{LLMResponse, ResponseBamlValue},
+    type_builder::TypeBuilder,
     BamlRuntime, FunctionResult, RuntimeContextManager,
 };
 use internal_baml_codegen::openapi::OpenApiSchema;
@@ -59,6 +61,33 @@ pub struct ServeArgs {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct BamlOptions {
     pub client_registry: Option<ClientRegistry>,
+    /// Class and enum declarations to add to the call's types, each added
+    /// in order as with `TypeBuilder.add_baml`.
+    pub type_builder: Option<Vec<String>>,
+}
+
+impl BamlOptions {
+    /// Builds the TypeBuilder for `type_builder`, if the call has one.
+    fn type_builder(&self, runtime: &BamlRuntime) -> Result<Option<TypeBuilder>, BamlError> {
+        let Some(snippets) = &self.type_builder else {
+            return Ok(None);
+        };
+        let tb = TypeBuilder::new();
+        for baml in snippets {
+            tb.add_baml(baml, runtime)
+                .map_err(|e| BamlError::InvalidArgument {
+                    message: format!("Invalid type_builder: {:#}", e),
+                })?;
+        }
+        Ok(Some(tb))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ParseQuery {
+    /// Parse the body as an unfinished streamed response.
+    #[serde(default)]
+    partial: bool,
 }
 
 impl ServeArgs {
@@ -300,6 +329,12 @@ impl Server {
             "/stream/:msg",
             post(move |b_fn, b_args| s.clone().baml_stream_axum2(b_fn, b_args)),
         );
+        let s = self.clone();
+        let app = app.route(
+            "/parse/:msg",
+            post(move |b_fn, query, raw_text| s.clone().baml_parse_axum(b_fn, query, raw_text)),
+        );
+
         let s = self.clone();
         let app = app.route("/docs", get(move || s.clone().docs_handler()));
 
@@ -357,11 +392,19 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         };
 
         let ctx_mgr = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
-        let client_registry = b_options.and_then(|options| options.client_registry);
 
         let locked = self.b.read().await;
+        let tb = match b_options
+            .as_ref()
+            .map(|options| options.type_builder(&locked))
+        {
+            Some(Err(e)) => return e.into_response(),
+            Some(Ok(tb)) => tb,
+            None => None,
+        };
+        let client_registry = b_options.and_then(|options| options.client_registry);
         let (result, _trace_id) = locked
-            .call_function(b_fn, &args, &ctx_mgr, None, client_registry.as_ref())
+            .call_function(b_fn, &args, &ctx_mgr, tb.as_ref(), client_registry.as_ref())
             .await;
 
         match result {
@@ -429,11 +472,32 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         self.baml_call(b_fn, b_args, b_options).await
     }
 
+    /// Parses the request body, a raw LLM output, into the return type of
+    /// `b_fn` without calling the LLM.
+    async fn baml_parse_axum(
+        self: Arc<Self>,
+        extract::Path(b_fn): extract::Path<String>,
+        extract::Query(query): extract::Query<ParseQuery>,
+        raw_text: String,
+    ) -> Response {
+        let ctx_mgr = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
+
+        let locked = self.b.read().await;
+        match locked
+            .parse_response(&b_fn, &raw_text, query.partial, &ctx_mgr, None, None)
+            .and_then(|result| parse_report(&result))
+        {
+            Ok(report) => (StatusCode::OK, Json(report)).into_response(),
+            Err(e) => BamlError::from_anyhow(e).into_response(),
+        }
+    }
+
     fn baml_stream(
         self: Arc<Self>,
         b_fn: String,
         b_args: serde_json::Value,
-        b_options: Option<BamlOptions>,
+        client_registry: Option<ClientRegistry>,
+        tb: Option<TypeBuilder>,
     ) -> Response {
         let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
 
@@ -442,8 +506,6 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
             Err(e) => return e.into_response(),
         };
 
-        let client_registry = b_options.and_then(|options| options.client_registry);
-
         tokio::spawn(async move {
             let ctx_mgr =
                 RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
@@ -452,7 +514,7 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
                 b_fn,
                 &args,
                 &ctx_mgr,
-                None,
+                tb.as_ref(),
                 client_registry.as_ref(),
             );
 
@@ -471,8 +533,8 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
                                 }
                             }),
                             &ctx_mgr,
-                            None,
-                            None,
+                            tb.as_ref(),
+                            client_registry.as_ref(),
                         )
                         .await;
 
@@ -559,7 +621,15 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
                 }
             }
         }
-        self.baml_stream(path, body, b_options)
+        let tb = match &b_options {
+            Some(options) => match options.type_builder(&*self.b.read().await) {
+                Ok(tb) => tb,
+                Err(e) => return e.into_response(),
+            },
+            None => None,
+        };
+        let client_registry = b_options.and_then(|options| options.client_registry);
+        self.baml_stream(path, body, client_registry, tb)
     }
 
     /// Serve an HTML page that loads swagger-ui from local static files.
@@ -619,6 +689,7 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
             true,
             GeneratorDefaultClientMode::Sync,
             Vec::new(),
+            false,
         ).map_err(|_| BamlError::InternalError{ message: "Failed to make placeholder generator".to_string()})?;
         let schema: OpenApiSchema = (locked.inner.ir.as_ref(), &fake_generator)
             .try_into()
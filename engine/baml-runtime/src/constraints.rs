@@ -339,7 +339,9 @@ mod tests {
                 prompt_tokens: None,
                 output_tokens: None,
                 total_tokens: None,
+                cache_hit: false,
             },
+            http: Default::default(),
         }
     }
 
@@ -6,6 +6,8 @@ pub mod orchestrator;
 pub mod primitive;
 
 mod properties_hander;
+pub mod raw_http;
+pub mod response_cache;
 pub mod retry_policy;
 mod strategy;
 pub mod traits;
@@ -19,6 +21,7 @@ use jsonish::BamlValueWithFlags;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
+use raw_http::RawHttp;
 use reqwest::StatusCode;
 
 #[cfg(target_arch = "wasm32")]
@@ -161,6 +164,24 @@ impl std::fmt::Display for LLMResponse {
 }
 
 impl LLMResponse {
+    /// Attaches the HTTP exchange which produced this response.
+    pub(crate) fn with_http(mut self, http: RawHttp) -> Self {
+        match &mut self {
+            Self::Success(response) => response.http = http,
+            Self::LLMFailure(failure) => failure.http = http,
+            Self::UserFailure(_) | Self::InternalFailure(_) => (),
+        }
+        self
+    }
+
+    pub fn http(&self) -> Option<&RawHttp> {
+        match self {
+            Self::Success(response) => Some(&response.http),
+            Self::LLMFailure(failure) => Some(&failure.http),
+            Self::UserFailure(_) | Self::InternalFailure(_) => None,
+        }
+    }
+
     pub fn content(&self) -> Result<&str> {
         match self {
             Self::Success(response) => Ok(&response.content),
@@ -175,7 +196,7 @@ impl LLMResponse {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Clone, Serialize)]
 pub struct LLMErrorResponse {
     pub client: String,
     pub model: Option<String>,
@@ -188,6 +209,26 @@ pub struct LLMErrorResponse {
     // Short error message
     pub message: String,
     pub code: ErrorCode,
+    /// Exposed through `FunctionResult::raw_http`.
+    #[serde(skip)]
+    pub http: RawHttp,
+}
+
+// Failures are shown to users with `{:?}`; the raw HTTP exchange is left out
+// to keep those messages readable.
+impl std::fmt::Debug for LLMErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LLMErrorResponse")
+            .field("client", &self.client)
+            .field("model", &self.model)
+            .field("prompt", &self.prompt)
+            .field("request_options", &self.request_options)
+            .field("start_time", &self.start_time)
+            .field("latency", &self.latency)
+            .field("message", &self.message)
+            .field("code", &self.code)
+            .finish()
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -264,6 +305,9 @@ pub struct LLMCompleteResponse {
     pub start_time: web_time::SystemTime,
     pub latency: web_time::Duration,
     pub metadata: LLMCompleteResponseMetadata,
+    /// Exposed through `FunctionResult::raw_http`.
+    #[serde(skip)]
+    pub http: RawHttp,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -273,6 +317,8 @@ pub struct LLMCompleteResponseMetadata {
     pub prompt_tokens: Option<u64>,
     pub output_tokens: Option<u64>,
     pub total_tokens: Option<u64>,
+    /// True if this response was served from the response cache instead of the provider.
+    pub cache_hit: bool,
 }
 
 // This is how the response gets logged if you print the result to the console.
@@ -282,10 +328,11 @@ impl std::fmt::Display for LLMCompleteResponse {
             f,
             "{}",
             format!(
-                "Client: {} ({}) - {}ms. StopReason: {}",
+                "Client: {} ({}) - {}ms{}. StopReason: {}",
                 self.client,
                 self.model,
                 self.latency.as_millis(),
+                if self.metadata.cache_hit { " (cached)" } else { "" },
                 self.metadata.finish_reason.as_deref().unwrap_or("unknown")
             )
             .yellow()
@@ -304,10 +351,11 @@ impl crate::tracing::Visualize for LLMCompleteResponse {
             format!(
                 "{}",
                 format!(
-                    "Client: {} ({}) - {}ms. StopReason: {}. Tokens(in/out): {}/{}",
+                    "Client: {} ({}) - {}ms{}. StopReason: {}. Tokens(in/out): {}/{}",
                     self.client,
                     self.model,
                     self.latency.as_millis(),
+                    if self.metadata.cache_hit { " (cached)" } else { "" },
                     self.metadata.finish_reason.as_deref().unwrap_or("unknown"),
                     self.metadata
                         .prompt_tokens
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
 use baml_types::BamlValue;
 use internal_baml_core::ir::repr::IntermediateRepr;
@@ -7,7 +9,7 @@ use web_time::Duration;
 use crate::{
     internal::{
         llm_client::{
-            parsed_value_to_response, traits::{WithPrompt, WithSingleCallable}, LLMResponse, ResponseBamlValue
+            parsed_value_to_response, response_cache::ResponseCache, traits::{WithPrompt, WithSingleCallable}, LLMResponse, ResponseBamlValue
         },
         prompt_renderer::PromptRenderer,
     },
@@ -35,7 +37,25 @@ pub async fn orchestrate(
     let mut results = Vec::new();
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
 
+    let cache = match ResponseCache::from_ctx(ctx) {
+        Ok(cache) => cache,
+        Err(e) => {
+            results.push((
+                OrchestrationScope::default(),
+                LLMResponse::UserFailure(e.to_string()),
+                None,
+                None,
+            ));
+            return (results, total_sleep_duration);
+        }
+    };
+
+    // Clients that the prompt is too long for. Retrying them would fail the same way.
+    let mut too_long = HashSet::new();
     for node in iter {
+        if too_long.contains(node.provider.name()) {
+            continue;
+        }
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -48,7 +68,25 @@ pub async fn orchestrate(
                 continue;
             }
         };
-        let response = node.single_call(&ctx, &prompt).await;
+        if let Some(failure) = node.check_input_tokens(&prompt) {
+            too_long.insert(node.provider.name().to_string());
+            results.push((node.scope, failure, None, None));
+            continue;
+        }
+        let cache_key = cache.key(&node, ctx, &prompt).await;
+        let cached = cache_key
+            .as_deref()
+            .and_then(|key| cache.get(&node, key, &prompt));
+        let response = match cached {
+            Some(cached) => cached,
+            None => {
+                let response = node.single_call(&ctx, &prompt).await;
+                if let Some(key) = &cache_key {
+                    cache.put(&node, key, &response);
+                }
+                response
+            }
+        };
         let parsed_response = match &response {
             LLMResponse::Success(s) => Some(parse_fn(&s.content)),
             _ => None,
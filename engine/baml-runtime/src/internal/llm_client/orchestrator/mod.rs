@@ -5,7 +5,8 @@ use web_time::Duration; // Add this line
 
 use crate::RenderCurlSettings;
 use crate::{
-    internal::prompt_renderer::PromptRenderer, runtime_interface::InternalClientLookup,
+    internal::{prompt_renderer::PromptRenderer, tokenizer::TokenCount},
+    runtime_interface::InternalClientLookup,
     RuntimeContext,
 };
 
@@ -75,6 +76,27 @@ impl OrchestratorNode {
         }
     }
 
+    /// Fails without calling the model if the prompt is longer than the
+    /// client's `max_input_tokens`.
+    pub fn check_input_tokens(&self, prompt: &RenderedPrompt) -> Option<LLMResponse> {
+        let max_input_tokens = self.provider.max_input_tokens()?;
+        let count = TokenCount::new(prompt, self.provider.tokenizer(), Some(max_input_tokens));
+        let counted_by = if count.tokenizer.is_exact() {
+            count.tokenizer.name()
+        } else {
+            "estimated"
+        };
+        count.exceeds_limit().then(|| {
+            LLMResponse::UserFailure(format!(
+                "The prompt has {} tokens ({}), but client {} allows at most {} (max_input_tokens)",
+                count.tokens,
+                counted_by,
+                self.provider.name(),
+                max_input_tokens,
+            ))
+        })
+    }
+
     pub fn error_sleep_duration(&self) -> Option<&Duration> {
         // in reverse find the first retry scope, and return the delay
         self.scope.scope.iter().rev().find_map(|scope| match scope {
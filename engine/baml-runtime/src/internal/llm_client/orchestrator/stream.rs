@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
 use async_std::stream::StreamExt;
 use baml_types::BamlValue;
@@ -8,7 +10,7 @@ use web_time::Duration;
 use crate::{
     internal::{
         llm_client::{
-            parsed_value_to_response, traits::{WithPrompt, WithStreamable}, LLMErrorResponse, LLMResponse, ResponseBamlValue
+            parsed_value_to_response, response_cache::ResponseCache, traits::{StreamResponse, WithPrompt, WithStreamable}, LLMErrorResponse, LLMResponse, ResponseBamlValue
         },
         prompt_renderer::PromptRenderer,
     },
@@ -41,8 +43,26 @@ where
     let mut results = Vec::new();
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
 
+    let cache = match ResponseCache::from_ctx(ctx) {
+        Ok(cache) => cache,
+        Err(e) => {
+            results.push((
+                OrchestrationScope::default(),
+                LLMResponse::UserFailure(e.to_string()),
+                None,
+                None,
+            ));
+            return (results, total_sleep_duration);
+        }
+    };
+
     //advanced curl viewing, use render_raw_curl on each node. TODO
+    // Clients that the prompt is too long for. Retrying them would fail the same way.
+    let mut too_long = HashSet::new();
     for node in iter {
+        if too_long.contains(node.provider.name()) {
+            continue;
+        }
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -55,9 +75,22 @@ where
                 continue;
             }
         };
+        if let Some(failure) = node.check_input_tokens(&prompt) {
+            too_long.insert(node.provider.name().to_string());
+            results.push((node.scope, failure, None, None));
+            continue;
+        }
 
         let (system_start, instant_start) = (web_time::SystemTime::now(), web_time::Instant::now());
-        let stream_res = node.stream(ctx, &prompt).await;
+        let cache_key = cache.key(&node, ctx, &prompt).await;
+        let cached_response = cache_key
+            .as_deref()
+            .and_then(|key| cache.get(&node, key, &prompt));
+        let stream_res: StreamResponse = match cached_response {
+            // A cache hit is replayed as a stream with a single, complete event.
+            Some(cached) => Ok(Box::pin(futures::stream::once(async move { cached }))),
+            None => node.stream(ctx, &prompt).await,
+        };
         let final_response = match stream_res {
             Ok(response) => response
                 .map(|stream_part| {
@@ -87,16 +120,20 @@ where
                     LLMResponse::LLMFailure(LLMErrorResponse {
                         client: node.provider.name().into(),
                         model: None,
-                        prompt,
+                        prompt: prompt.clone(),
                         start_time: system_start,
                         latency: instant_start.elapsed(),
                         request_options: node.provider.request_options().clone(),
                         message: "Stream ended without response".to_string(),
                         code: crate::internal::llm_client::ErrorCode::from_u16(2),
+                        http: Default::default(),
                     })
                 }),
             Err(response) => response,
         };
+        if let Some(key) = &cache_key {
+            cache.put(&node, key, &final_response);
+        }
 
         let parsed_response = match &final_response {
             LLMResponse::Success(s) => Some(parse_fn(&s.content)),
@@ -1,12 +1,13 @@
 use crate::internal::llm_client::{
     properties_hander::PropertiesHandler,
+    response_cache::CacheMode,
     traits::{ToProviderMessage, ToProviderMessageExt, WithClientProperties},
     AllowedMetadata, ResolveMediaUrls, SupportedRequestModes,
 };
 use std::collections::HashMap;
 
 use anyhow::{Context, Result};
-use baml_types::{BamlMedia, BamlMediaContent};
+use baml_types::{BamlMedia, BamlMediaContent, BamlMediaType};
 use eventsource_stream::Eventsource;
 use futures::StreamExt;
 use internal_baml_core::ir::ClientWalker;
@@ -19,8 +20,10 @@ use crate::{
     internal::llm_client::{
         primitive::{
             anthropic::types::{AnthropicMessageResponse, StopReason},
+            batch::{self, BatchRequest, WithBatch},
             request::{make_parsed_request, make_request, RequestBuilder},
         },
+        raw_http::{RawHttp, RawHttpBody, SseEventLog},
         traits::{
             SseResponseTrait, StreamResponse, WithChat, WithClient, WithNoCompletion,
             WithRetryPolicy, WithStreamChat,
@@ -30,9 +33,10 @@ use crate::{
     },
     request::create_client,
 };
+use serde::Deserialize;
 use serde_json::json;
 
-use crate::RuntimeContext;
+use crate::{BatchJobStatus, RuntimeContext};
 
 use super::types::MessageChunk;
 
@@ -47,6 +51,8 @@ struct PostRequestProperities {
     // These are passed directly to the Anthropic API.
     properties: HashMap<String, serde_json::Value>,
     supported_request_modes: SupportedRequestModes,
+    max_input_tokens: Option<usize>,
+    response_cache: Option<CacheMode>,
 }
 
 // represents client that interacts with the Anthropic API
@@ -83,7 +89,9 @@ fn resolve_properties(
         .entry("anthropic-version".to_string())
         .or_insert("2023-06-01".to_string());
 
-        let supported_request_modes = properties.pull_supported_request_modes()?;
+    let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     let mut properties = properties.finalize();
     // Anthropic has a very low max_tokens by default, so we increase it to 4096.
@@ -102,6 +110,8 @@ fn resolve_properties(
         properties,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
 
@@ -122,6 +132,12 @@ impl WithClientProperties for AnthropicClient {
     fn supports_streaming(&self) -> bool {
         self.properties.supported_request_modes.stream.unwrap_or(true)
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        self.properties.max_input_tokens
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        self.properties.response_cache
+    }
 }
 
 impl WithClient for AnthropicClient {
@@ -141,10 +157,17 @@ impl SseResponseTrait for AnthropicClient {
     fn response_stream(
         &self,
         resp: reqwest::Response,
+        http: RawHttp,
         prompt: &Vec<RenderedChatMessage>,
         system_start: web_time::SystemTime,
         instant_start: web_time::Instant,
     ) -> StreamResponse {
+        let events = SseEventLog::default();
+        let http = http.with_response(
+            resp.status().as_u16(),
+            resp.headers(),
+            RawHttpBody::Events(events.clone()),
+        );
         let prompt = prompt.clone();
         let client_name = self.context.name.clone();
         let params = self.properties.properties.clone();
@@ -153,6 +176,11 @@ impl SseResponseTrait for AnthropicClient {
             resp.bytes_stream()
                 .inspect(|event| log::trace!("anthropic event bytes: {:#?}", event))
                 .eventsource()
+                .inspect(move |event| {
+                    if let Ok(event) = event {
+                        events.push(&event.data);
+                    }
+                })
                 .map(|event| -> Result<MessageChunk> { Ok(serde_json::from_str(&event?.data)?) })
                 .inspect(|event| log::trace!("anthropic eventsource: {:#?}", event))
                 .scan(
@@ -170,7 +198,9 @@ impl SseResponseTrait for AnthropicClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache_hit: false,
                         },
+                        http: http.clone(),
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
                         let Ok(ref mut inner) = accumulated else {
@@ -195,6 +225,7 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        http: http.clone(),
                                     },
                                 )));
                             }
@@ -260,6 +291,7 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: err.message,
                                         code: ErrorCode::Other(2),
+                                        http: http.clone(),
                                     },
                                 )));
                             }
@@ -277,15 +309,15 @@ impl SseResponseTrait for AnthropicClient {
 impl WithStreamChat for AnthropicClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
-        let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+        let (response, http, system_now, instant_now) =
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
-        self.response_stream(response, prompt, system_now, instant_now)
+        self.response_stream(response, http, prompt, system_now, instant_now)
     }
 }
 
@@ -411,18 +443,33 @@ impl WithChat for AnthropicClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
-        let (response, system_now, instant_now) = match make_parsed_request::<
-            AnthropicMessageResponse,
-        >(
-            self, either::Either::Right(prompt), false
-        )
-        .await
-        {
-            Ok(v) => v,
-            Err(e) => return e,
-        };
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+        let (response, http, system_now, instant_now) =
+            match make_parsed_request::<AnthropicMessageResponse>(
+                self,
+                ctx,
+                either::Either::Right(prompt),
+                false,
+            )
+            .await
+            {
+                Ok(v) => v,
+                Err(e) => return e,
+            };
+
+        self.chat_response(response, prompt, system_now, instant_now)
+            .with_http(http)
+    }
+}
 
+impl AnthropicClient {
+    fn chat_response(
+        &self,
+        response: AnthropicMessageResponse,
+        prompt: &Vec<RenderedChatMessage>,
+        system_now: web_time::SystemTime,
+        instant_now: web_time::Instant,
+    ) -> LLMResponse {
         if response.content.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
                 client: self.context.name.to_string(),
@@ -436,6 +483,7 @@ impl WithChat for AnthropicClient {
                     response.content.len()
                 ),
                 code: ErrorCode::Other(200),
+                http: Default::default(),
             });
         }
 
@@ -459,11 +507,131 @@ impl WithChat for AnthropicClient {
                 prompt_tokens: Some(response.usage.input_tokens),
                 output_tokens: Some(response.usage.output_tokens),
                 total_tokens: Some(response.usage.input_tokens + response.usage.output_tokens),
+                cache_hit: false,
             },
+            http: Default::default(),
         })
     }
 }
 
+impl AnthropicClient {
+    fn batch_api_request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        let mut req = self.client.request(method, url);
+        for (key, value) in &self.properties.headers {
+            req = req.header(key, value);
+        }
+        if let Some(key) = &self.properties.api_key {
+            req = req.header("x-api-key", key);
+        }
+        req
+    }
+
+    fn batches_url(&self) -> String {
+        format!("{}/v1/messages/batches", self.properties.base_url)
+    }
+
+    fn batch_result_response(&self, result: &serde_json::Value) -> LLMResponse {
+        let failure = |message: String, code: ErrorCode| {
+            batch::batch_failure(
+                &self.context.name,
+                &self.properties.properties,
+                message,
+                code,
+            )
+        };
+        match result["type"].as_str() {
+            Some("succeeded") => match AnthropicMessageResponse::deserialize(&result["message"]) {
+                Ok(message) => self.chat_response(
+                    message,
+                    &vec![],
+                    web_time::SystemTime::now(),
+                    web_time::Instant::now(),
+                ),
+                Err(e) => failure(
+                    format!("Failed to parse batch response: {}", e),
+                    ErrorCode::UnsupportedResponse(2),
+                ),
+            },
+            Some("errored") => failure(
+                format!("Batch request failed: {}", result["error"]),
+                ErrorCode::Other(2),
+            ),
+            other => failure(
+                format!(
+                    "Batch request did not complete: {}",
+                    other.unwrap_or("unknown")
+                ),
+                ErrorCode::Other(2),
+            ),
+        }
+    }
+}
+
+impl WithBatch for AnthropicClient {
+    async fn submit_batch(
+        &self,
+        _ctx: &RuntimeContext,
+        requests: &[BatchRequest],
+    ) -> Result<String> {
+        let mut batch_requests = Vec::with_capacity(requests.len());
+        for request in requests {
+            batch_requests.push(json!({
+                "custom_id": request.custom_id,
+                "params": batch::request_body(self, &request.prompt).await?,
+            }));
+        }
+
+        let batch = batch::send_json(
+            self.batch_api_request(reqwest::Method::POST, &self.batches_url())
+                .json(&json!({ "requests": batch_requests })),
+        )
+        .await
+        .context("Failed to create message batch")?;
+        Ok(batch["id"]
+            .as_str()
+            .context("Expected message batch response to have an id")?
+            .to_string())
+    }
+
+    async fn batch_status(&self, _ctx: &RuntimeContext, batch_id: &str) -> Result<BatchJobStatus> {
+        let batch = batch::send_json(self.batch_api_request(
+            reqwest::Method::GET,
+            &format!("{}/{}", self.batches_url(), batch_id),
+        ))
+        .await?;
+        // Message batches only report "ended"; per-request outcomes are part of the results.
+        Ok(match batch["processing_status"].as_str() {
+            Some("in_progress" | "canceling") => BatchJobStatus::InProgress,
+            Some("ended") => BatchJobStatus::Completed,
+            other => anyhow::bail!("Unknown message batch status: {:?}", other),
+        })
+    }
+
+    async fn batch_results(
+        &self,
+        _ctx: &RuntimeContext,
+        batch_id: &str,
+    ) -> Result<HashMap<String, LLMResponse>> {
+        let content = batch::send(self.batch_api_request(
+            reqwest::Method::GET,
+            &format!("{}/{}/results", self.batches_url(), batch_id),
+        ))
+        .await
+        .context("Failed to download message batch results")?;
+
+        let mut results = HashMap::new();
+        for line in batch::parse_jsonl(&content)? {
+            if let Some(custom_id) = line["custom_id"].as_str() {
+                results.insert(
+                    custom_id.to_string(),
+                    self.batch_result_response(&line["result"]),
+                );
+            }
+        }
+        Ok(results)
+    }
+}
+
 impl ToProviderMessage for AnthropicClient {
     fn to_chat_message(
         &self,
@@ -480,9 +648,17 @@ impl ToProviderMessage for AnthropicClient {
         mut content: serde_json::Map<String, serde_json::Value>,
         media: &baml_types::BamlMedia,
     ) -> Result<serde_json::Map<String, serde_json::Value>> {
+        let media_type = match media.media_type {
+            BamlMediaType::Image => "image",
+            BamlMediaType::Audio => "audio",
+            BamlMediaType::Pdf => "document",
+            BamlMediaType::Video => {
+                anyhow::bail!("video inputs are not supported by the anthropic provider")
+            }
+        };
         match &media.content {
             BamlMediaContent::Base64(data) => {
-                content.insert("type".into(), media.media_type.to_string().into());
+                content.insert("type".into(), media_type.into());
                 let mut source = serde_json::Map::new();
                 source.insert("type".into(), "base64".into());
                 source.insert("media_type".into(), media.mime_type_as_ok()?.into());
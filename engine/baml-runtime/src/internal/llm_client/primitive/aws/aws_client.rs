@@ -19,6 +19,7 @@ use serde_json::Map;
 use web_time::Instant;
 use web_time::SystemTime;
 
+use crate::internal::llm_client::response_cache::CacheMode;
 use crate::internal::llm_client::traits::{ToProviderMessageExt, WithClientProperties};
 use crate::internal::llm_client::{AllowedMetadata, SupportedRequestModes};
 use crate::internal::llm_client::{
@@ -33,6 +34,9 @@ use crate::internal::llm_client::{
 
 use crate::{RenderCurlSettings, RuntimeContext};
 
+use super::raw_http::RawHttpRecorder;
+use super::request_hooks::RequestHookInterceptor;
+
 // stores properties required for making a post request to the API
 struct RequestProperties {
     model_id: String,
@@ -48,6 +52,8 @@ struct RequestProperties {
     request_options: HashMap<String, serde_json::Value>,
     ctx_env: HashMap<String, String>,
     supported_request_modes: SupportedRequestModes,
+    max_input_tokens: Option<usize>,
+    response_cache: Option<CacheMode>,
 }
 
 // represents client that interacts with the Anthropic API
@@ -98,6 +104,8 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
         .unwrap_or_else(|_| ctx.env.get("AWS_SECRET_ACCESS_KEY").map(|s| s.to_string()));
 
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     let properties = properties.finalize();
 
@@ -110,6 +118,8 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
         request_options: properties,
         ctx_env: ctx.env.clone(),
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
 
@@ -235,6 +245,7 @@ impl AwsClient {
                 "Expected message output to be text, got {}",
                 match content {
                     bedrock::types::ContentBlock::Image(_) => "image",
+                    bedrock::types::ContentBlock::Document(_) => "document",
                     bedrock::types::ContentBlock::GuardContent(_) => "guardContent",
                     bedrock::types::ContentBlock::ToolResult(_) => "toolResult",
                     bedrock::types::ContentBlock::ToolUse(_) => "toolUse",
@@ -338,6 +349,12 @@ impl WithClientProperties for AwsClient {
     fn supports_streaming(&self) -> bool {
         self.properties.supported_request_modes.stream.unwrap_or(true)
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        self.properties.max_input_tokens
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        self.properties.response_cache
+    }
 }
 
 impl WithClient for AwsClient {
@@ -375,6 +392,7 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    http: Default::default(),
                 }));
             }
         };
@@ -391,16 +409,24 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    http: Default::default(),
                 }))
             }
         };
 
+        let recorder = RawHttpRecorder::default();
         let request = aws_client
             .converse_stream()
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .customize()
+            .interceptor(RequestHookInterceptor::new(
+                client.clone(),
+                ctx.request_hooks.clone(),
+            ))
+            .interceptor(recorder.clone());
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -442,6 +468,7 @@ impl WithStreamChat for AwsClient {
                         }
                         _ => ErrorCode::Other(2),
                     },
+                    http: recorder.http(),
                 }));
             }
         };
@@ -462,7 +489,9 @@ impl WithStreamChat for AwsClient {
                         prompt_tokens: None,
                         output_tokens: None,
                         total_tokens: None,
+                        cache_hit: false,
                     },
+                    http: recorder.http(),
                 }),
                 response,
             ),
@@ -535,6 +564,7 @@ impl WithStreamChat for AwsClient {
                                 latency: instant_start.elapsed(),
                                 message: format!("Failed to parse event: {:#?}", e),
                                 code: ErrorCode::Other(2),
+                                http: new_state.http,
                             }),
                             (None, response),
                         )),
@@ -556,9 +586,9 @@ impl AwsClient {
         &self,
         media: &baml_types::BamlMedia,
     ) -> Result<bedrock::types::ContentBlock> {
-        if media.media_type != BamlMediaType::Image {
+        if !matches!(media.media_type, BamlMediaType::Image | BamlMediaType::Pdf) {
             anyhow::bail!(
-                "AWS supports images, but does not support this media type: {:#?}",
+                "AWS supports images and pdfs, but does not support this media type: {:#?}",
                 media
             )
         }
@@ -573,6 +603,19 @@ impl AwsClient {
                     "BAML internal error (AWSBedrock): media URL should have been resolved to base64"
                 )
             }
+            BamlMediaContent::Base64(b64_media) if media.media_type == BamlMediaType::Pdf => {
+                Ok(bedrock::types::ContentBlock::Document(
+                    bedrock::types::DocumentBlock::builder()
+                        .set_format(Some(bedrock::types::DocumentFormat::Pdf))
+                        // Bedrock requires every document in a request to have a name.
+                        .set_name(Some("document".to_string()))
+                        .set_source(Some(bedrock::types::DocumentSource::Bytes(Blob::new(
+                            aws_smithy_types::base64::decode(b64_media.base64.clone())?,
+                        ))))
+                        .build()
+                        .context("Failed to build document block")?,
+                ))
+            }
             BamlMediaContent::Base64(b64_media) => Ok(bedrock::types::ContentBlock::Image(
                 bedrock::types::ImageBlock::builder()
                     .set_format(Some(bedrock::types::ImageFormat::from(
@@ -655,7 +698,7 @@ impl WithChat for AwsClient {
 
     async fn chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         chat_messages: &Vec<RenderedChatMessage>,
     ) -> LLMResponse {
         let client = self.context.name.to_string();
@@ -675,11 +718,12 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    http: Default::default(),
                 })
             }
         };
 
-        let request = match self.build_request(_ctx, chat_messages) {
+        let request = match self.build_request(ctx, chat_messages) {
             Ok(r) => r,
             Err(e) => {
                 return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -691,15 +735,23 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    http: Default::default(),
                 })
             }
         };
+        let recorder = RawHttpRecorder::default();
         let request = aws_client
             .converse()
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .customize()
+            .interceptor(RequestHookInterceptor::new(
+                client.clone(),
+                ctx.request_hooks.clone(),
+            ))
+            .interceptor(recorder.clone());
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -717,6 +769,7 @@ impl WithChat for AwsClient {
                     message: format!("{:#?}", e),
                     // TODO: derive this from the aws-returned error
                     code: ErrorCode::Other(2),
+                    http: recorder.http(),
                 });
             }
         };
@@ -752,7 +805,9 @@ impl WithChat for AwsClient {
                         .as_ref()
                         .map(|i| i.total_tokens.try_into().ok())
                         .flatten(),
+                    cache_hit: false,
                 },
+                http: recorder.http(),
             }),
             Err(e) => LLMResponse::LLMFailure(LLMErrorResponse {
                 client,
@@ -763,6 +818,7 @@ impl WithChat for AwsClient {
                 latency: instant_start.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(200),
+                http: recorder.http(),
             }),
         }
     }
@@ -1,4 +1,6 @@
 mod aws_client;
+mod raw_http;
+mod request_hooks;
 pub(super) mod types;
 #[cfg(target_arch = "wasm32")]
 pub(super) mod wasm;
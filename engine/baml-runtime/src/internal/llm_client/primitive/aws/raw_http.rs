@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    AfterDeserializationInterceptorContextRef, BeforeTransmitInterceptorContextRef,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::ConfigBag;
+
+use crate::internal::llm_client::raw_http::{
+    redacted_header_value, RawHttp, RawHttpBody, RawHttpRequest, RawHttpResponse,
+};
+
+/// Records the signed request and the response of a Bedrock call.
+///
+/// The SDK owns the interceptor once attached, so the recording is shared
+/// with the clone kept by the caller.
+#[derive(Debug, Clone, Default)]
+pub(super) struct RawHttpRecorder(Arc<Mutex<RawHttp>>);
+
+impl RawHttpRecorder {
+    pub(super) fn http(&self) -> RawHttp {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl Intercept for RawHttpRecorder {
+    fn name(&self) -> &'static str {
+        "RawHttpRecorder"
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let request = context.request();
+        let body = match request.body().bytes() {
+            Some(bytes) => serde_json::from_slice(bytes)
+                .unwrap_or_else(|_| String::from_utf8_lossy(bytes).to_string().into()),
+            None => serde_json::Value::Null,
+        };
+        *self.0.lock().unwrap() = RawHttp::from_request(Arc::new(RawHttpRequest {
+            method: request.method().to_string(),
+            url: request.uri().to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), redacted_header_value(k, v)))
+                .collect(),
+            body,
+        }));
+        Ok(())
+    }
+
+    fn read_after_deserialization(
+        &self,
+        context: &AfterDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let response = context.response();
+        let body = match response.body().bytes() {
+            Some(bytes) => RawHttpBody::Text(String::from_utf8_lossy(bytes).to_string()),
+            // Event streams are consumed after deserialization.
+            None => RawHttpBody::None,
+        };
+        let mut http = self.0.lock().unwrap();
+        http.response = Some(Arc::new(RawHttpResponse {
+            status: response.status().as_u16(),
+            headers: response
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body,
+        }));
+        Ok(())
+    }
+}
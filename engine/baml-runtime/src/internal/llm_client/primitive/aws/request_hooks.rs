@@ -0,0 +1,140 @@
+use std::sync::{Arc, Mutex};
+
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    AfterDeserializationInterceptorContextRef, BeforeTransmitInterceptorContextMut,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::config_bag::ConfigBag;
+use indexmap::IndexMap;
+
+use crate::request_hook::{HookRequest, HookResponse, RequestHook};
+
+/// Runs [`RequestHook`]s for requests sent through the Bedrock SDK.
+///
+/// Requests are modified before signing, so the signature covers any headers
+/// or body the hooks change. The SDK runs interceptors synchronously, so the
+/// hooks are driven to completion in place; on a multi-threaded runtime the
+/// worker's other tasks are handed off while they run.
+pub(super) struct RequestHookInterceptor {
+    client: String,
+    hooks: Vec<Arc<dyn RequestHook>>,
+    /// The url of the request, for reporting the response.
+    url: Mutex<String>,
+}
+
+impl RequestHookInterceptor {
+    pub(super) fn new(client: String, hooks: Vec<Arc<dyn RequestHook>>) -> Self {
+        Self {
+            client,
+            hooks,
+            url: Default::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for RequestHookInterceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestHookInterceptor")
+            .field("client", &self.client)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl Intercept for RequestHookInterceptor {
+    fn name(&self) -> &'static str {
+        "RequestHookInterceptor"
+    }
+
+    fn modify_before_signing(
+        &self,
+        context: &mut BeforeTransmitInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if self.hooks.is_empty() {
+            return Ok(());
+        }
+        let request = context.request_mut();
+        let body = match request.body().bytes() {
+            Some(bytes) => serde_json::from_slice(bytes)?,
+            None => serde_json::Value::Null,
+        };
+        let mut hook_request = HookRequest {
+            client: self.client.clone(),
+            url: request.uri().to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body,
+        };
+        let run_hooks = async {
+            for hook in &self.hooks {
+                hook.on_request(&mut hook_request).await?;
+            }
+            anyhow::Ok(())
+        };
+        match tokio::runtime::Handle::try_current().map(|h| h.runtime_flavor()) {
+            Ok(tokio::runtime::RuntimeFlavor::MultiThread) => tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current().block_on(run_hooks)
+            }),
+            _ => futures::executor::block_on(run_hooks),
+        }?;
+
+        let removed = request
+            .headers()
+            .iter()
+            .map(|(k, _)| k.to_string())
+            .filter(|k| !hook_request.headers.contains_key(k))
+            .collect::<Vec<_>>();
+        for name in removed {
+            request.headers_mut().remove(name.as_str());
+        }
+        for (name, value) in hook_request.headers {
+            request.headers_mut().try_insert(name, value)?;
+        }
+        request.set_uri(hook_request.url.as_str())?;
+        *self.url.lock().unwrap() = hook_request.url;
+        if !hook_request.body.is_null() {
+            *request.body_mut() = SdkBody::from(serde_json::to_vec(&hook_request.body)?);
+        }
+        Ok(())
+    }
+
+    fn read_after_deserialization(
+        &self,
+        context: &AfterDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if self.hooks.is_empty() {
+            return Ok(());
+        }
+        let response = context.response();
+        let hook_response = HookResponse {
+            client: self.client.clone(),
+            url: self.url.lock().unwrap().clone(),
+            status: response.status().as_u16(),
+            headers: response
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<IndexMap<_, _>>(),
+            body: response
+                .body()
+                .bytes()
+                .map(|b| String::from_utf8_lossy(b).to_string()),
+        };
+        for hook in &self.hooks {
+            if let Err(e) = hook.on_response(&hook_response) {
+                log::warn!("Response hook failed for {}: {:#}", self.client, e);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use internal_baml_jinja::RenderedChatMessage;
+
+use crate::{
+    internal::llm_client::{ErrorCode, LLMErrorResponse, LLMResponse},
+    BatchJobStatus, RuntimeContext,
+};
+
+use super::request::RequestBuilder;
+
+/// A single call in a batch, identified by `custom_id` in the provider's
+/// batch input and output.
+pub struct BatchRequest {
+    pub custom_id: String,
+    pub prompt: Vec<RenderedChatMessage>,
+}
+
+/// Providers which can run many chat requests as one asynchronous batch job.
+pub trait WithBatch {
+    /// Submits the requests and returns the provider's batch id.
+    #[allow(async_fn_in_trait)]
+    async fn submit_batch(&self, ctx: &RuntimeContext, requests: &[BatchRequest])
+        -> Result<String>;
+
+    #[allow(async_fn_in_trait)]
+    async fn batch_status(&self, ctx: &RuntimeContext, batch_id: &str) -> Result<BatchJobStatus>;
+
+    /// Returns one response per `custom_id` that the provider reported on.
+    #[allow(async_fn_in_trait)]
+    async fn batch_results(
+        &self,
+        ctx: &RuntimeContext,
+        batch_id: &str,
+    ) -> Result<HashMap<String, LLMResponse>>;
+}
+
+/// Builds the same request body a direct (non-streaming) call would send.
+pub(super) async fn request_body(
+    client: &impl RequestBuilder,
+    prompt: &Vec<RenderedChatMessage>,
+) -> Result<serde_json::Value> {
+    let req = client
+        .build_request(either::Either::Right(prompt), false, false)
+        .await?
+        .build()?;
+    let body = req
+        .body()
+        .and_then(|b| b.as_bytes())
+        .context("Expected request to have a JSON body")?;
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// A failed entry of a batch job. Batch results do not carry the rendered
+/// prompt, so the prompt is left empty.
+pub(crate) fn batch_failure(
+    client: &str,
+    request_options: &HashMap<String, serde_json::Value>,
+    message: String,
+    code: ErrorCode,
+) -> LLMResponse {
+    LLMResponse::LLMFailure(LLMErrorResponse {
+        client: client.to_string(),
+        model: None,
+        prompt: internal_baml_jinja::RenderedPrompt::Chat(vec![]),
+        start_time: web_time::SystemTime::now(),
+        latency: web_time::Duration::ZERO,
+        request_options: request_options.clone(),
+        message,
+        code,
+        http: Default::default(),
+    })
+}
+
+/// Sends a batch API request and returns the body of a successful response.
+pub(super) async fn send(req: reqwest::RequestBuilder) -> Result<String> {
+    let response = req.send().await?;
+    let status = response.status();
+    let text = response.text().await?;
+    if !status.is_success() {
+        anyhow::bail!("Batch request failed ({}): {}", status, text);
+    }
+    Ok(text)
+}
+
+pub(super) async fn send_json(req: reqwest::RequestBuilder) -> Result<serde_json::Value> {
+    let text = send(req).await?;
+    serde_json::from_str(&text).with_context(|| format!("Expected a JSON response, got: {text}"))
+}
+
+pub(super) fn to_jsonl(lines: &[serde_json::Value]) -> Result<String> {
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&serde_json::to_string(line)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+pub(super) fn parse_jsonl(text: &str) -> Result<Vec<serde_json::Value>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Failed to parse batch result line: {line}"))
+        })
+        .collect()
+}
+
+/// Encodes form fields and a single file as `multipart/form-data`, with a
+/// boundary generated for this request.
+///
+/// Returns the content type header value and the body.
+pub(super) fn multipart_body(
+    fields: &[(&str, &str)],
+    file_field: &str,
+    file_name: &str,
+    file_content: &str,
+) -> (String, String) {
+    let boundary = format!("baml-batch-{}", uuid::Uuid::new_v4().simple());
+    let mut body = String::new();
+    for (name, value) in fields {
+        body.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    body.push_str(&format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"{file_field}\"; filename=\"{file_name}\"\r\nContent-Type: application/jsonl\r\n\r\n{file_content}\r\n--{boundary}--\r\n"
+    ));
+    (format!("multipart/form-data; boundary={boundary}"), body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonl_roundtrip() {
+        let lines = vec![
+            serde_json::json!({"custom_id": "req-0", "body": {"a": 1}}),
+            serde_json::json!({"custom_id": "req-1", "body": {"a": "line\nbreak"}}),
+        ];
+        let text = to_jsonl(&lines).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(parse_jsonl(&text).unwrap(), lines);
+    }
+
+    #[test]
+    fn test_multipart_body() {
+        let (content_type, body) =
+            multipart_body(&[("purpose", "batch")], "file", "batch.jsonl", "{}\n");
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        assert!(body.starts_with(&format!("--{boundary}\r\n")));
+        assert!(body.contains("name=\"purpose\"\r\n\r\nbatch\r\n"));
+        assert!(body.contains("filename=\"batch.jsonl\""));
+        assert!(body.ends_with(&format!("--{boundary}--\r\n")));
+
+        let (other, _) = multipart_body(&[], "file", "batch.jsonl", "{}\n");
+        assert_ne!(content_type, other);
+    }
+}
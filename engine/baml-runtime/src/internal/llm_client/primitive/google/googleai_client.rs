@@ -1,5 +1,6 @@
 use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::properties_hander::{PropertiesHandler};
+use crate::internal::llm_client::response_cache::CacheMode;
 use crate::internal::llm_client::traits::{
     ToProviderMessage, ToProviderMessageExt, WithClientProperties,
 };
@@ -11,6 +12,7 @@ use crate::{
             google::types::{FinishReason, GoogleResponse},
             request::{make_parsed_request, make_request, RequestBuilder},
         },
+        raw_http::{RawHttp, RawHttpBody, SseEventLog},
         traits::{
             SseResponseTrait, StreamResponse, WithChat, WithClient, WithNoCompletion,
             WithRetryPolicy, WithStreamChat,
@@ -39,6 +41,8 @@ struct PostRequestProperities {
     properties: HashMap<String, serde_json::Value>,
     allowed_metadata: AllowedMetadata,
     supported_request_modes: SupportedRequestModes,
+    max_input_tokens: Option<usize>,
+    response_cache: Option<CacheMode>,
 }
 
 pub struct GoogleAIClient {
@@ -71,6 +75,8 @@ fn resolve_properties(
     let headers = properties.pull_headers()?;
 
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     Ok(PostRequestProperities {
         default_role,
@@ -82,6 +88,8 @@ fn resolve_properties(
         properties: properties.finalize(),
         allowed_metadata,
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
 
@@ -101,6 +109,12 @@ impl WithClientProperties for GoogleAIClient {
     fn supports_streaming(&self) -> bool {
         self.properties.supported_request_modes.stream.unwrap_or(true)
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        self.properties.max_input_tokens
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        self.properties.response_cache
+    }
 }
 
 impl WithClient for GoogleAIClient {
@@ -119,10 +133,17 @@ impl SseResponseTrait for GoogleAIClient {
     fn response_stream(
         &self,
         resp: reqwest::Response,
+        http: RawHttp,
         prompt: &Vec<RenderedChatMessage>,
         system_start: web_time::SystemTime,
         instant_start: web_time::Instant,
     ) -> StreamResponse {
+        let events = SseEventLog::default();
+        let http = http.with_response(
+            resp.status().as_u16(),
+            resp.headers(),
+            RawHttpBody::Events(events.clone()),
+        );
         let prompt = prompt.clone();
         let client_name = self.context.name.clone();
         let model_id = self.properties.model_id.clone().unwrap_or_default();
@@ -130,6 +151,11 @@ impl SseResponseTrait for GoogleAIClient {
         Ok(Box::pin(
             resp.bytes_stream()
                 .eventsource()
+                .inspect(move |event| {
+                    if let Ok(event) = event {
+                        events.push(&event.data);
+                    }
+                })
                 .inspect(|event| log::trace!("Received event: {:?}", event))
                 .take_while(|event| {
                     std::future::ready(event.as_ref().is_ok_and(|e| e.data != "data: \n"))
@@ -152,7 +178,9 @@ impl SseResponseTrait for GoogleAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache_hit: false,
                         },
+                        http: http.clone(),
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
                         let Ok(ref mut inner) = accumulated else {
@@ -178,6 +206,7 @@ impl SseResponseTrait for GoogleAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        http: http.clone(),
                                     },
                                 )));
                             }
@@ -208,16 +237,16 @@ impl SseResponseTrait for GoogleAIClient {
 impl WithStreamChat for GoogleAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
         //incomplete, streaming response object is returned
-        let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+        let (response, http, system_now, instant_now) =
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
-        self.response_stream(response, prompt, system_now, instant_now)
+        self.response_stream(response, http, prompt, system_now, instant_now)
     }
 }
 
@@ -345,15 +374,19 @@ impl WithChat for GoogleAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
         //non-streaming, complete response is returned
-        let (response, system_now, instant_now) =
-            match make_parsed_request::<GoogleResponse>(self, either::Either::Right(prompt), false)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => return e,
-            };
+        let (response, http, system_now, instant_now) = match make_parsed_request::<GoogleResponse>(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            false,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
 
         if response.candidates.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -368,6 +401,7 @@ impl WithChat for GoogleAIClient {
                     response.candidates.len()
                 ),
                 code: ErrorCode::Other(200),
+                http: http.clone(),
             });
         }
 
@@ -383,7 +417,7 @@ impl WithChat for GoogleAIClient {
                 .properties
                 .get("model")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
-                .or_else(|| _ctx.env.get("default model").map(|s| s.to_string()))
+                .or_else(|| ctx.env.get("default model").map(|s| s.to_string()))
                 .unwrap_or_else(|| "".to_string()),
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.candidates[0].finish_reason {
@@ -397,7 +431,9 @@ impl WithChat for GoogleAIClient {
                 prompt_tokens: response.usage_metadata.prompt_token_count,
                 output_tokens: response.usage_metadata.candidates_token_count,
                 total_tokens: response.usage_metadata.total_token_count,
+                cache_hit: false,
             },
+            http,
         })
     }
 }
@@ -1,17 +1,25 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::Result;
 use baml_types::BamlValue;
 use internal_baml_core::ir::{repr::IntermediateRepr, ClientWalker};
+use internal_baml_jinja::RenderedPrompt;
 
 use crate::{
-    client_registry::ClientProperty, internal::prompt_renderer::PromptRenderer,
-    runtime_interface::InternalClientLookup, RenderCurlSettings, RuntimeContext,
+    client_registry::ClientProperty,
+    internal::{prompt_renderer::PromptRenderer, tokenizer::Tokenizer},
+    runtime_interface::InternalClientLookup, BatchJobStatus, RenderCurlSettings, RuntimeContext,
 };
 
 use self::{
-    anthropic::AnthropicClient, aws::AwsClient, google::GoogleAIClient, openai::OpenAIClient,
-    request::RequestBuilder, vertex::VertexClient,
+    anthropic::AnthropicClient,
+    aws::AwsClient,
+    batch::{BatchRequest, WithBatch},
+    google::GoogleAIClient,
+    openai::OpenAIClient,
+    request::RequestBuilder,
+    vertex::VertexClient,
 };
 
 use super::{
@@ -19,15 +27,18 @@ use super::{
         ExecutionScope, IterOrchestrator, OrchestrationScope, OrchestrationState, OrchestratorNode,
         OrchestratorNodeIterator,
     },
+    raw_http::RawHttpRequest,
+    response_cache::CacheMode,
     traits::{
-        WithClient, WithClientProperties, WithPrompt, WithRenderRawCurl, WithRetryPolicy,
-        WithSingleCallable, WithStreamable,
+        process_media_urls, WithClient, WithClientProperties, WithPrompt, WithRenderRawCurl,
+        WithRetryPolicy, WithSingleCallable, WithStreamable,
     },
     LLMResponse,
 };
 
 mod anthropic;
 mod aws;
+pub(crate) mod batch;
 mod google;
 mod openai;
 pub(super) mod request;
@@ -96,6 +107,12 @@ impl WithClientProperties for LLMPrimitiveProvider {
     fn supports_streaming(&self) -> bool {
         match_llm_provider!(self, supports_streaming)
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        match_llm_provider!(self, max_input_tokens)
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        match_llm_provider!(self, response_cache)
+    }
 }
 
 impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMPrimitiveProvider {
@@ -215,6 +232,52 @@ impl WithStreamable for LLMPrimitiveProvider {
     }
 }
 
+impl WithBatch for LLMPrimitiveProvider {
+    async fn submit_batch(
+        &self,
+        ctx: &RuntimeContext,
+        requests: &[BatchRequest],
+    ) -> Result<String> {
+        // Batch inputs are uploaded as-is, so media has to be resolved the same way
+        // a direct call would resolve it.
+        let resolve_media_urls = match_llm_provider!(self, model_features).resolve_media_urls;
+        let mut resolved = Vec::with_capacity(requests.len());
+        for request in requests {
+            resolved.push(BatchRequest {
+                custom_id: request.custom_id.clone(),
+                prompt: process_media_urls(resolve_media_urls, true, None, ctx, &request.prompt)
+                    .await?,
+            });
+        }
+
+        match self {
+            LLMPrimitiveProvider::OpenAI(client) => client.submit_batch(ctx, &resolved).await,
+            LLMPrimitiveProvider::Anthropic(client) => client.submit_batch(ctx, &resolved).await,
+            other => anyhow::bail!("Batch jobs are not supported for {} clients", other),
+        }
+    }
+
+    async fn batch_status(&self, ctx: &RuntimeContext, batch_id: &str) -> Result<BatchJobStatus> {
+        match self {
+            LLMPrimitiveProvider::OpenAI(client) => client.batch_status(ctx, batch_id).await,
+            LLMPrimitiveProvider::Anthropic(client) => client.batch_status(ctx, batch_id).await,
+            other => anyhow::bail!("Batch jobs are not supported for {} clients", other),
+        }
+    }
+
+    async fn batch_results(
+        &self,
+        ctx: &RuntimeContext,
+        batch_id: &str,
+    ) -> Result<HashMap<String, LLMResponse>> {
+        match self {
+            LLMPrimitiveProvider::OpenAI(client) => client.batch_results(ctx, batch_id).await,
+            LLMPrimitiveProvider::Anthropic(client) => client.batch_results(ctx, batch_id).await,
+            other => anyhow::bail!("Batch jobs are not supported for {} clients", other),
+        }
+    }
+}
+
 impl IterOrchestrator for Arc<LLMPrimitiveProvider> {
     fn iter_orchestrator<'a>(
         &self,
@@ -250,6 +313,54 @@ impl LLMPrimitiveProvider {
     pub fn request_options(&self) -> &std::collections::HashMap<String, serde_json::Value> {
         match_llm_provider!(self, request_options)
     }
+
+    /// The tokenizer that prompts for this client are counted with.
+    pub fn tokenizer(&self) -> Tokenizer {
+        match self {
+            LLMPrimitiveProvider::OpenAI(client) => client.tokenizer(),
+            _ => Tokenizer::Approximate,
+        }
+    }
+
+    /// Builds the HTTP request that calling this client with `prompt` would
+    /// send, without sending it.
+    pub async fn build_raw_request(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        stream: bool,
+    ) -> Result<RawHttpRequest> {
+        let stream = stream && self.supports_streaming();
+        let chat;
+        let prompt = match prompt {
+            RenderedPrompt::Chat(messages) => {
+                let resolve_media_urls =
+                    match_llm_provider!(self, model_features).resolve_media_urls;
+                chat = process_media_urls(resolve_media_urls, true, None, ctx, messages).await?;
+                either::Right(&chat)
+            }
+            RenderedPrompt::Completion(prompt) => either::Left(prompt),
+        };
+
+        match self {
+            LLMPrimitiveProvider::OpenAI(client) => {
+                request::build_raw_request(client, ctx, prompt, stream).await
+            }
+            LLMPrimitiveProvider::Anthropic(client) => {
+                request::build_raw_request(client, ctx, prompt, stream).await
+            }
+            LLMPrimitiveProvider::Google(client) => {
+                request::build_raw_request(client, ctx, prompt, stream).await
+            }
+            LLMPrimitiveProvider::Vertex(client) => {
+                request::build_raw_request(client, ctx, prompt, stream).await
+            }
+            // Bedrock requests are built and signed inside the AWS SDK.
+            LLMPrimitiveProvider::Aws(_) => {
+                anyhow::bail!("Building requests is not supported for aws-bedrock clients")
+            }
+        }
+    }
 }
 
 use super::resolve_properties_walker;
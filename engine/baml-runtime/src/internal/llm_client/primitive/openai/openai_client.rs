@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
 use crate::internal::llm_client::ResolveMediaUrls;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use baml_types::{BamlMedia, BamlMediaContent, BamlMediaType};
 use internal_baml_core::ir::ClientWalker;
 use internal_baml_jinja::{ChatMessagePart, RenderContext_Client, RenderedChatMessage};
+use serde::Deserialize;
 use serde_json::json;
 
 use crate::internal::llm_client::{
@@ -15,9 +16,12 @@ use super::properties::{self, PostRequestProperties};
 use super::types::{ChatCompletionResponse, ChatCompletionResponseDelta, FinishReason};
 
 use crate::client_registry::ClientProperty;
+use crate::internal::llm_client::primitive::batch::{self, BatchRequest, WithBatch};
 use crate::internal::llm_client::primitive::request::{
     make_parsed_request, make_request, RequestBuilder,
 };
+use crate::internal::llm_client::raw_http::{RawHttp, RawHttpBody, SseEventLog};
+use crate::internal::llm_client::response_cache::CacheMode;
 use crate::internal::llm_client::traits::{
     SseResponseTrait, StreamResponse, ToProviderMessage, ToProviderMessageExt,
     WithClientProperties, WithStreamChat,
@@ -27,8 +31,9 @@ use crate::internal::llm_client::{
     LLMResponse, ModelFeatures,
 };
 
+use crate::internal::tokenizer::Tokenizer;
 use crate::request::create_client;
-use crate::RuntimeContext;
+use crate::{BatchJobStatus, RuntimeContext};
 use eventsource_stream::Eventsource;
 use futures::StreamExt;
 
@@ -71,6 +76,12 @@ impl WithClientProperties for OpenAIClient {
             }
         }
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        self.properties.max_input_tokens
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        self.properties.response_cache
+    }
 }
 
 impl WithClient for OpenAIClient {
@@ -163,10 +174,11 @@ impl WithChat for OpenAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
-        let (response, system_start, instant_start) =
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+        let (response, http, system_start, instant_start) =
             match make_parsed_request::<ChatCompletionResponse>(
                 self,
+                ctx,
                 either::Either::Right(prompt),
                 false,
             )
@@ -176,6 +188,19 @@ impl WithChat for OpenAIClient {
                 Err(e) => return e,
             };
 
+        self.chat_response(response, prompt, system_start, instant_start)
+            .with_http(http)
+    }
+}
+
+impl OpenAIClient {
+    fn chat_response(
+        &self,
+        response: ChatCompletionResponse,
+        prompt: &Vec<RenderedChatMessage>,
+        system_start: web_time::SystemTime,
+        instant_start: web_time::Instant,
+    ) -> LLMResponse {
         if response.choices.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
                 client: self.context.name.to_string(),
@@ -189,6 +214,7 @@ impl WithChat for OpenAIClient {
                     response.choices.len()
                 ),
                 code: ErrorCode::Other(200),
+                http: Default::default(),
             });
         }
 
@@ -226,7 +252,9 @@ impl WithChat for OpenAIClient {
                 prompt_tokens: usage.map(|u| u.prompt_tokens),
                 output_tokens: usage.map(|u| u.completion_tokens),
                 total_tokens: usage.map(|u| u.total_tokens),
+                cache_hit: false,
             },
+            http: Default::default(),
         })
     }
 }
@@ -309,16 +337,28 @@ impl SseResponseTrait for OpenAIClient {
     fn response_stream(
         &self,
         resp: reqwest::Response,
+        http: RawHttp,
         prompt: &Vec<RenderedChatMessage>,
         system_start: web_time::SystemTime,
         instant_start: web_time::Instant,
     ) -> StreamResponse {
+        let events = SseEventLog::default();
+        let http = http.with_response(
+            resp.status().as_u16(),
+            resp.headers(),
+            RawHttpBody::Events(events.clone()),
+        );
         let prompt = prompt.clone();
         let client_name = self.context.name.clone();
         let params = self.properties.properties.clone();
         Ok(Box::pin(
             resp.bytes_stream()
                 .eventsource()
+                .inspect(move |event| {
+                    if let Ok(event) = event {
+                        events.push(&event.data);
+                    }
+                })
                 .take_while(|event| {
                     std::future::ready(event.as_ref().is_ok_and(|e| e.data != "[DONE]"))
                 })
@@ -343,7 +383,9 @@ impl SseResponseTrait for OpenAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache_hit: false,
                         },
+                        http: http.clone(),
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
                         let Ok(ref mut inner) = accumulated else {
@@ -369,6 +411,7 @@ impl SseResponseTrait for OpenAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        http: http.clone(),
                                     },
                                 )));
                             }
@@ -408,15 +451,184 @@ impl SseResponseTrait for OpenAIClient {
 impl WithStreamChat for OpenAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
-        let (resp, system_start, instant_start) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+        let (resp, http, system_start, instant_start) =
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
-        self.response_stream(resp, prompt, system_start, instant_start)
+        self.response_stream(resp, http, prompt, system_start, instant_start)
+    }
+}
+
+impl OpenAIClient {
+    fn batch_api_request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let mut req = self
+            .client
+            .request(method, format!("{}{}", self.properties.base_url, path));
+        for (key, value) in &self.properties.headers {
+            req = req.header(key, value);
+        }
+        if let Some(key) = &self.properties.api_key {
+            req = req.bearer_auth(key);
+        }
+        req
+    }
+
+    fn batch_line_response(&self, line: &serde_json::Value) -> LLMResponse {
+        let failure = |message: String, code: ErrorCode| {
+            batch::batch_failure(
+                &self.context.name,
+                &self.properties.properties,
+                message,
+                code,
+            )
+        };
+        if !line["error"].is_null() {
+            return failure(
+                format!("Batch request failed: {}", line["error"]),
+                ErrorCode::Other(2),
+            );
+        }
+        let response = &line["response"];
+        let status = response["status_code"].as_u64().unwrap_or_default() as u16;
+        if !(200..300).contains(&status) {
+            return failure(
+                format!("Batch request failed: {}", response["body"]),
+                ErrorCode::from_u16(status),
+            );
+        }
+        match ChatCompletionResponse::deserialize(&response["body"]) {
+            Ok(body) => self.chat_response(
+                body,
+                &vec![],
+                web_time::SystemTime::now(),
+                web_time::Instant::now(),
+            ),
+            Err(e) => failure(
+                format!("Failed to parse batch response: {}", e),
+                ErrorCode::UnsupportedResponse(2),
+            ),
+        }
+    }
+}
+
+impl WithBatch for OpenAIClient {
+    async fn submit_batch(
+        &self,
+        _ctx: &RuntimeContext,
+        requests: &[BatchRequest],
+    ) -> Result<String> {
+        if self.provider != "openai" {
+            anyhow::bail!(
+                "Batch jobs are not supported for the {} provider",
+                self.provider
+            );
+        }
+
+        let mut lines = Vec::with_capacity(requests.len());
+        for request in requests {
+            lines.push(json!({
+                "custom_id": request.custom_id,
+                "method": "POST",
+                "url": "/v1/chat/completions",
+                "body": batch::request_body(self, &request.prompt).await?,
+            }));
+        }
+
+        let (content_type, body) = batch::multipart_body(
+            &[("purpose", "batch")],
+            "file",
+            "batch.jsonl",
+            &batch::to_jsonl(&lines)?,
+        );
+        let file = batch::send_json(
+            self.batch_api_request(reqwest::Method::POST, "/files")
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(body),
+        )
+        .await
+        .context("Failed to upload batch input file")?;
+        let file_id = file["id"]
+            .as_str()
+            .context("Expected file upload response to have an id")?;
+
+        let batch = batch::send_json(
+            self.batch_api_request(reqwest::Method::POST, "/batches")
+                .json(&json!({
+                    "input_file_id": file_id,
+                    "endpoint": "/v1/chat/completions",
+                    "completion_window": "24h",
+                })),
+        )
+        .await
+        .context("Failed to create batch")?;
+        Ok(batch["id"]
+            .as_str()
+            .context("Expected batch response to have an id")?
+            .to_string())
+    }
+
+    async fn batch_status(&self, _ctx: &RuntimeContext, batch_id: &str) -> Result<BatchJobStatus> {
+        let batch = batch::send_json(
+            self.batch_api_request(reqwest::Method::GET, &format!("/batches/{}", batch_id)),
+        )
+        .await?;
+        Ok(match batch["status"].as_str() {
+            Some("validating" | "in_progress" | "finalizing" | "cancelling") => {
+                BatchJobStatus::InProgress
+            }
+            Some("completed") => BatchJobStatus::Completed,
+            Some("failed") => BatchJobStatus::Failed {
+                message: batch["errors"]["data"]
+                    .as_array()
+                    .map(|errors| {
+                        errors
+                            .iter()
+                            .filter_map(|e| e["message"].as_str())
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    })
+                    .unwrap_or_default(),
+            },
+            Some("expired") => BatchJobStatus::Expired,
+            Some("cancelled") => BatchJobStatus::Cancelled,
+            other => anyhow::bail!("Unknown batch status: {:?}", other),
+        })
+    }
+
+    async fn batch_results(
+        &self,
+        _ctx: &RuntimeContext,
+        batch_id: &str,
+    ) -> Result<HashMap<String, LLMResponse>> {
+        let batch = batch::send_json(
+            self.batch_api_request(reqwest::Method::GET, &format!("/batches/{}", batch_id)),
+        )
+        .await?;
+
+        let mut results = HashMap::new();
+        // Successful requests are written to the output file, failed ones to the error file.
+        for key in ["output_file_id", "error_file_id"] {
+            let Some(file_id) = batch[key].as_str() else {
+                continue;
+            };
+            let content =
+                batch::send(self.batch_api_request(
+                    reqwest::Method::GET,
+                    &format!("/files/{}/content", file_id),
+                ))
+                .await
+                .with_context(|| format!("Failed to download batch file {}", file_id))?;
+            for line in batch::parse_jsonl(&content)? {
+                if let Some(custom_id) = line["custom_id"].as_str() {
+                    results.insert(custom_id.to_string(), self.batch_line_response(&line));
+                }
+            }
+        }
+        Ok(results)
     }
 }
 
@@ -470,6 +682,20 @@ macro_rules! make_openai_client {
 }
 
 impl OpenAIClient {
+    /// OpenAI and Azure serve OpenAI's models, whose encodings are known. The
+    /// other providers that use this client can serve any model.
+    pub fn tokenizer(&self) -> Tokenizer {
+        match self.provider.as_str() {
+            "openai" | "azure" => Tokenizer::for_openai_model(
+                self.properties
+                    .properties
+                    .get("model")
+                    .and_then(|model| model.as_str()),
+            ),
+            _ => Tokenizer::Approximate,
+        }
+    }
+
     pub fn new(client: &ClientWalker, ctx: &RuntimeContext) -> Result<OpenAIClient> {
         let properties = super::super::resolve_properties_walker(client, ctx)?;
         let properties = properties::openai::resolve_properties(properties, ctx)?;
@@ -543,6 +769,12 @@ impl ToProviderMessage for OpenAIClient {
         let media_type = match media.media_type {
             BamlMediaType::Image => "image",
             BamlMediaType::Audio => "audio",
+            BamlMediaType::Pdf | BamlMediaType::Video => {
+                anyhow::bail!(
+                    "{} inputs are not supported by the openai provider",
+                    media.media_type
+                )
+            }
         };
         let media_type = format!("{}_url", media_type);
         match &media.content {
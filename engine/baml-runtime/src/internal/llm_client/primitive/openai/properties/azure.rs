@@ -49,6 +49,8 @@ pub fn resolve_properties(
     };
 
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
 
     let properties = {
@@ -71,5 +73,7 @@ pub fn resolve_properties(
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         query_params,
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
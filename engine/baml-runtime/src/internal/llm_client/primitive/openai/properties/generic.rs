@@ -28,6 +28,8 @@ pub fn resolve_properties(
         _ => None,
     };
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     let properties = properties.finalize();
 
@@ -41,5 +43,7 @@ pub fn resolve_properties(
         query_params: Default::default(),
         allowed_metadata,
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
@@ -3,7 +3,9 @@ pub(crate) mod generic;
 pub(crate) mod ollama;
 pub(crate) mod openai;
 
-use crate::internal::llm_client::{AllowedMetadata, SupportedRequestModes};
+use crate::internal::llm_client::{
+    response_cache::CacheMode, AllowedMetadata, SupportedRequestModes,
+};
 use std::collections::HashMap;
 
 pub struct PostRequestProperties {
@@ -17,4 +19,6 @@ pub struct PostRequestProperties {
     pub properties: HashMap<String, serde_json::Value>,
     pub allowed_metadata: AllowedMetadata,
     pub supported_request_modes: SupportedRequestModes,
+    pub max_input_tokens: Option<usize>,
+    pub response_cache: Option<CacheMode>,
 }
@@ -26,6 +26,8 @@ pub fn resolve_properties(
     let headers = properties.pull_headers()?;
 
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     Ok(PostRequestProperties {
         default_role,
@@ -41,5 +43,7 @@ pub fn resolve_properties(
             .unwrap_or(None),
         query_params: Default::default(),
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
@@ -1,11 +1,22 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Context, Result};
 use internal_baml_jinja::RenderedChatMessage;
-use reqwest::Response;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Response,
+};
 use serde::de::DeserializeOwned;
 
-use crate::internal::llm_client::{traits::WithClient, ErrorCode, LLMErrorResponse, LLMResponse};
+use crate::{
+    internal::llm_client::{
+        raw_http::{RawHttp, RawHttpBody, RawHttpRequest},
+        traits::WithClient,
+        ErrorCode, LLMErrorResponse, LLMResponse,
+    },
+    request_hook::{header_map_to_index_map, HookRequest, HookResponse, RequestHook},
+    RuntimeContext,
+};
 
 pub trait RequestBuilder {
     #[allow(async_fn_in_trait)]
@@ -30,11 +41,89 @@ fn to_prompt(
     }
 }
 
+/// Runs the `on_request` hooks and writes their changes back into `req`.
+async fn apply_request_hooks(
+    client: &str,
+    hooks: &[Arc<dyn RequestHook>],
+    mut req: reqwest::Request,
+) -> Result<reqwest::Request> {
+    if hooks.is_empty() {
+        return Ok(req);
+    }
+
+    let body = match req.body().and_then(|b| b.as_bytes()) {
+        Some(bytes) => {
+            serde_json::from_slice(bytes).context("Request hooks require a JSON request body")?
+        }
+        None => serde_json::Value::Null,
+    };
+    let mut request = HookRequest {
+        client: client.to_string(),
+        url: req.url().to_string(),
+        headers: header_map_to_index_map(req.headers()),
+        body,
+    };
+    for hook in hooks {
+        hook.on_request(&mut request)
+            .await
+            .context("Request hook failed")?;
+    }
+
+    let original = std::mem::take(req.headers_mut());
+    let mut headers = HeaderMap::new();
+    for (name, value) in &request.headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .with_context(|| format!("Invalid header name from request hook: {name}"))?;
+        // Hooks see one value per header. Keep every original value of the
+        // headers they left unchanged.
+        let unchanged = original
+            .get_all(&name)
+            .iter()
+            .last()
+            .is_some_and(|original| original.as_bytes() == value.as_bytes());
+        if unchanged {
+            for original in original.get_all(&name) {
+                headers.append(&name, original.clone());
+            }
+        } else {
+            headers.append(
+                &name,
+                HeaderValue::from_str(value).with_context(|| {
+                    format!("Invalid value for header {name} from request hook")
+                })?,
+            );
+        }
+    }
+    *req.headers_mut() = headers;
+    *req.url_mut() = reqwest::Url::parse(&request.url)
+        .with_context(|| format!("Invalid url from request hook: {}", request.url))?;
+    if !(req.body().is_none() && request.body.is_null()) {
+        *req.body_mut() = Some(serde_json::to_vec(&request.body)?.into());
+    }
+    Ok(req)
+}
+
+/// Runs the `on_response` hooks. Hook errors do not fail the request.
+fn run_response_hooks(hooks: &[Arc<dyn RequestHook>], response: HookResponse) {
+    for hook in hooks {
+        if let Err(e) = hook.on_response(&response) {
+            log::warn!("Response hook failed for {}: {:#}", response.client, e);
+        }
+    }
+}
+
+/// Sends the request for `prompt`, returning the response together with the
+/// request as it was sent.
+///
+/// Response hooks see the body of failed responses. Successful streaming
+/// responses are reported without a body; successful non-streaming responses
+/// are reported by [`make_parsed_request`] once the body has been read.
 pub async fn make_request(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
     stream: bool,
-) -> Result<(Response, web_time::SystemTime, web_time::Instant), LLMResponse> {
+) -> Result<(Response, RawHttp, web_time::SystemTime, web_time::Instant), LLMResponse> {
     let (system_now, instant_now) = (web_time::SystemTime::now(), web_time::Instant::now());
 
     let req = match client
@@ -53,6 +142,7 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                http: Default::default(),
             }));
         }
     };
@@ -69,10 +159,30 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                http: Default::default(),
             }));
         }
     };
 
+    let req = match apply_request_hooks(&client.context().name, &ctx.request_hooks, req).await {
+        Ok(req) => req,
+        Err(e) => {
+            return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                client: client.context().name.to_string(),
+                model: None,
+                prompt: to_prompt(prompt),
+                start_time: system_now,
+                request_options: client.request_options().clone(),
+                latency: instant_now.elapsed(),
+                message: format!("{:#?}", e),
+                code: ErrorCode::Other(2),
+                http: Default::default(),
+            }));
+        }
+    };
+
+    let http = RawHttp::from_request(Arc::new(RawHttpRequest::from_reqwest(&req)));
+
     let response = match client.http_client().execute(req).await {
         Ok(response) => response,
         Err(e) => {
@@ -85,12 +195,27 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:?}", e),
                 code: ErrorCode::Other(2),
+                http,
             }));
         }
     };
 
     let status = response.status();
     if !status.is_success() {
+        let url = response.url().to_string();
+        let raw_headers = response.headers().clone();
+        let headers = header_map_to_index_map(&raw_headers);
+        let body = response.text().await.ok();
+        run_response_hooks(
+            &ctx.request_hooks,
+            HookResponse {
+                client: client.context().name.to_string(),
+                url,
+                status: status.as_u16(),
+                headers,
+                body: body.clone(),
+            },
+        );
         return Err(LLMResponse::LLMFailure(LLMErrorResponse {
             client: client.context().name.to_string(),
             model: None,
@@ -98,24 +223,94 @@ pub async fn make_request(
             start_time: system_now,
             request_options: client.request_options().clone(),
             latency: instant_now.elapsed(),
-            message: format!(
-                "Request failed: {}",
-                response.text().await.unwrap_or("<no response>".into())
+            http: http.with_response(
+                status.as_u16(),
+                &raw_headers,
+                body.clone().map_or(RawHttpBody::None, RawHttpBody::Text),
             ),
+            message: format!("Request failed: {}", body.unwrap_or("<no response>".into())),
             code: ErrorCode::from_status(status),
         }));
     }
 
-    Ok((response, system_now, instant_now))
+    if stream && !ctx.request_hooks.is_empty() {
+        run_response_hooks(
+            &ctx.request_hooks,
+            HookResponse {
+                client: client.context().name.to_string(),
+                url: response.url().to_string(),
+                status: status.as_u16(),
+                headers: header_map_to_index_map(response.headers()),
+                body: None,
+            },
+        );
+    }
+
+    Ok((response, http, system_now, instant_now))
+}
+
+/// Builds the request for `prompt` as [`make_request`] would send it, after
+/// the request hooks ran, without sending it. Credentials are kept.
+pub async fn build_raw_request(
+    client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
+    prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+    stream: bool,
+) -> Result<RawHttpRequest> {
+    let req = client
+        .build_request(prompt, false, stream)
+        .await
+        .context("Failed to build request")?
+        .build()?;
+    let req = apply_request_hooks(&client.context().name, &ctx.request_hooks, req).await?;
+    Ok(RawHttpRequest::from_reqwest_unredacted(&req))
 }
 
 pub async fn make_parsed_request<T: DeserializeOwned>(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
     stream: bool,
-) -> Result<(T, web_time::SystemTime, web_time::Instant), LLMResponse> {
-    let (response, system_now, instant_now) = make_request(client, prompt, stream).await?;
-    let j = match response.json::<serde_json::Value>().await {
+) -> Result<(T, RawHttp, web_time::SystemTime, web_time::Instant), LLMResponse> {
+    let (response, http, system_now, instant_now) =
+        make_request(client, ctx, prompt, stream).await?;
+    let (url, status, raw_headers) = (
+        response.url().to_string(),
+        response.status().as_u16(),
+        response.headers().clone(),
+    );
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(e) => {
+            return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                client: client.context().name.to_string(),
+                model: None,
+                prompt: to_prompt(prompt),
+                start_time: system_now,
+                request_options: client.request_options().clone(),
+                latency: instant_now.elapsed(),
+                message: e.to_string(),
+                code: ErrorCode::Other(2),
+                http: http.with_response(status, &raw_headers, RawHttpBody::None),
+            }))
+        }
+    };
+
+    if !ctx.request_hooks.is_empty() {
+        run_response_hooks(
+            &ctx.request_hooks,
+            HookResponse {
+                client: client.context().name.to_string(),
+                url,
+                status,
+                headers: header_map_to_index_map(&raw_headers),
+                body: Some(body.clone()),
+            },
+        );
+    }
+    let http = http.with_response(status, &raw_headers, RawHttpBody::Text(body.clone()));
+
+    let j = match serde_json::from_str::<serde_json::Value>(&body) {
         Ok(response) => response,
         Err(e) => {
             return Err(LLMResponse::LLMFailure(LLMErrorResponse {
@@ -127,6 +322,7 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
                 latency: instant_now.elapsed(),
                 message: e.to_string(),
                 code: ErrorCode::Other(2),
+                http: http.clone(),
             }))
         }
     };
@@ -136,7 +332,7 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
         std::any::type_name::<T>(),
         j
     )) {
-        Ok(response) => Ok((response, system_now, instant_now)),
+        Ok(response) => Ok((response, http, system_now, instant_now)),
         Err(e) => Err(LLMResponse::LLMFailure(LLMErrorResponse {
             client: client.context().name.to_string(),
             model: None,
@@ -146,6 +342,79 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
             latency: instant_now.elapsed(),
             message: format!("{:?}", e),
             code: ErrorCode::Other(2),
+            http,
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TenantHook;
+
+    impl RequestHook for TenantHook {
+        fn on_request<'a>(
+            &'a self,
+            request: &'a mut HookRequest,
+        ) -> futures::future::BoxFuture<'a, Result<()>> {
+            Box::pin(async move {
+                request
+                    .headers
+                    .insert("x-tenant-id".to_string(), "acme".to_string());
+                request.headers.shift_remove("x-remove-me");
+                request.body["messages"][0]["content"] = "[REDACTED]".into();
+                request.url = request.url.replace("api.example.com", "proxy.example.com");
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_request_hooks() {
+        let req = reqwest::Client::new()
+            .post("https://api.example.com/v1/chat/completions")
+            .header("x-remove-me", "1")
+            .json(&serde_json::json!({
+                "messages": [{"role": "user", "content": "my ssn is 123-45-6789"}]
+            }))
+            .build()
+            .unwrap();
+
+        let hooks: Vec<Arc<dyn RequestHook>> = vec![Arc::new(TenantHook)];
+        let req = apply_request_hooks("MyClient", &hooks, req).await.unwrap();
+
+        assert_eq!(
+            req.url().as_str(),
+            "https://proxy.example.com/v1/chat/completions"
+        );
+        assert_eq!(req.headers()["x-tenant-id"], "acme");
+        assert!(req.headers().get("x-remove-me").is_none());
+        assert_eq!(req.headers()["content-type"], "application/json");
+        let body: serde_json::Value =
+            serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+        assert_eq!(body["messages"][0]["content"], "[REDACTED]");
+    }
+
+    #[tokio::test]
+    async fn test_apply_request_hooks_keeps_repeated_headers() {
+        let req = reqwest::Client::new()
+            .post("https://api.example.com/v1/chat/completions")
+            .header("x-trace", "a")
+            .header("x-trace", "b")
+            .json(&serde_json::json!({"messages": [{"role": "user", "content": "hi"}]}))
+            .build()
+            .unwrap();
+
+        let hooks: Vec<Arc<dyn RequestHook>> = vec![Arc::new(TenantHook)];
+        let req = apply_request_hooks("MyClient", &hooks, req).await.unwrap();
+
+        let values: Vec<_> = req
+            .headers()
+            .get_all("x-trace")
+            .iter()
+            .map(|v| v.to_str().unwrap())
+            .collect();
+        assert_eq!(values, vec!["a", "b"]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::properties_hander::{ PropertiesHandler};
+use crate::internal::llm_client::response_cache::CacheMode;
 use crate::internal::llm_client::traits::{
     ToProviderMessage, ToProviderMessageExt, WithClientProperties,
 };
@@ -13,6 +14,7 @@ use crate::{
             request::{make_parsed_request, make_request, RequestBuilder},
             vertex::types::{FinishReason, VertexResponse},
         },
+        raw_http::{RawHttp, RawHttpBody, SseEventLog},
         traits::{
             SseResponseTrait, StreamResponse, WithChat, WithClient, WithNoCompletion,
             WithRetryPolicy, WithStreamChat,
@@ -67,6 +69,8 @@ struct PostRequestProperties {
     location: Option<String>,
     allowed_metadata: AllowedMetadata,
     supported_request_modes: SupportedRequestModes,
+    max_input_tokens: Option<usize>,
+    response_cache: Option<CacheMode>,
 }
 
 pub struct VertexClient {
@@ -167,6 +171,8 @@ fn resolve_properties(
     };
 
     let supported_request_modes = properties.pull_supported_request_modes()?;
+    let max_input_tokens = properties.pull_max_input_tokens()?;
+    let response_cache = properties.pull_response_cache()?;
 
     Ok(PostRequestProperties {
         default_role,
@@ -180,6 +186,8 @@ fn resolve_properties(
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         allowed_metadata,
         supported_request_modes,
+        max_input_tokens,
+        response_cache,
     })
 }
 
@@ -199,6 +207,12 @@ impl WithClientProperties for VertexClient {
     fn supports_streaming(&self) -> bool {
         self.properties.supported_request_modes.stream.unwrap_or(true)
     }
+    fn max_input_tokens(&self) -> Option<usize> {
+        self.properties.max_input_tokens
+    }
+    fn response_cache(&self) -> Option<CacheMode> {
+        self.properties.response_cache
+    }
 }
 
 impl WithClient for VertexClient {
@@ -217,10 +231,17 @@ impl SseResponseTrait for VertexClient {
     fn response_stream(
         &self,
         resp: reqwest::Response,
+        http: RawHttp,
         prompt: &Vec<RenderedChatMessage>,
         system_start: web_time::SystemTime,
         instant_start: web_time::Instant,
     ) -> StreamResponse {
+        let events = SseEventLog::default();
+        let http = http.with_response(
+            resp.status().as_u16(),
+            resp.headers(),
+            RawHttpBody::Events(events.clone()),
+        );
         let prompt = prompt.clone();
         let client_name = self.context.name.clone();
         let model_id = self.properties.model_id.clone().unwrap_or_default();
@@ -228,6 +249,11 @@ impl SseResponseTrait for VertexClient {
         Ok(Box::pin(
             resp.bytes_stream()
                 .eventsource()
+                .inspect(move |event| {
+                    if let Ok(event) = event {
+                        events.push(&event.data);
+                    }
+                })
                 .inspect(|event| log::trace!("Received event: {:?}", event))
                 .take_while(|event| {
                     std::future::ready(event.as_ref().is_ok_and(|e| e.data != "data: \n"))
@@ -250,7 +276,9 @@ impl SseResponseTrait for VertexClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache_hit: false,
                         },
+                        http: http.clone(),
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
                         let Ok(ref mut inner) = accumulated else {
@@ -276,6 +304,7 @@ impl SseResponseTrait for VertexClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        http: http.clone(),
                                     },
                                 )));
                             }
@@ -310,12 +339,12 @@ impl WithStreamChat for VertexClient {
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
         //incomplete, streaming response object is returned
-        let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+        let (response, http, system_now, instant_now) =
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
-        self.response_stream(response, prompt, system_now, instant_now)
+        self.response_stream(response, http, prompt, system_now, instant_now)
     }
 }
 
@@ -541,15 +570,19 @@ impl WithChat for VertexClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
         //non-streaming, complete response is returned
-        let (response, system_now, instant_now) =
-            match make_parsed_request::<VertexResponse>(self, either::Either::Right(prompt), false)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => return e,
-            };
+        let (response, http, system_now, instant_now) = match make_parsed_request::<VertexResponse>(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            false,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
 
         if response.candidates.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -564,6 +597,7 @@ impl WithChat for VertexClient {
                     response.candidates.len()
                 ),
                 code: ErrorCode::Other(200),
+                http: http.clone(),
             });
         }
         let usage_metadata = response.usage_metadata.clone().unwrap();
@@ -593,7 +627,9 @@ impl WithChat for VertexClient {
                 prompt_tokens: usage_metadata.prompt_token_count,
                 output_tokens: usage_metadata.candidates_token_count,
                 total_tokens: usage_metadata.total_token_count,
+                cache_hit: false,
             },
+            http,
         })
     }
 }
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 
-use super::{AllowedMetadata, SupportedRequestModes};
+use super::{response_cache::CacheMode, AllowedMetadata, SupportedRequestModes};
 
 pub(super) struct PropertiesHandler {
     properties: HashMap<String, serde_json::Value>,
@@ -25,6 +25,8 @@ impl PropertiesHandler {
         match key {
             "allowed_role_metadata"
             | "supports_streaming"
+            | "max_input_tokens"
+            | "response_cache"
             | "base_url"
             | "api_key"
             | "headers"
@@ -133,6 +135,38 @@ impl PropertiesHandler {
         }
     }
 
+    pub fn pull_max_input_tokens(&mut self) -> Result<Option<usize>> {
+        match self.get("max_input_tokens") {
+            Some(v) => match v.as_u64() {
+                Some(n) if n > 0 => Ok(Some(n as usize)),
+                _ => anyhow::bail!("max_input_tokens must be a positive integer: Got {:?}", v),
+            },
+            None => Ok(None),
+        }
+    }
+
+    pub fn pull_response_cache(&mut self) -> Result<Option<CacheMode>> {
+        match self.get("response_cache") {
+            Some(serde_json::Value::Bool(enabled)) => Ok(Some(if enabled {
+                CacheMode::Memory
+            } else {
+                CacheMode::Off
+            })),
+            Some(serde_json::Value::String(s)) => match CacheMode::parse(&s) {
+                Some(mode) => Ok(Some(mode)),
+                None => anyhow::bail!(
+                    "response_cache must be one of: true, false, memory, disk. Got {:?}",
+                    s
+                ),
+            },
+            Some(v) => anyhow::bail!(
+                "response_cache must be one of: true, false, memory, disk. Got {:?}",
+                v
+            ),
+            None => Ok(None),
+        }
+    }
+
     pub fn pull_supported_request_modes(&mut self) -> Result<SupportedRequestModes> {
         let supports_streaming = match self.get("supports_streaming") {
             Some(v) => match v {
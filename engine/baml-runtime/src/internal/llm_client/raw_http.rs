@@ -0,0 +1,202 @@
+use std::sync::{Arc, Mutex};
+
+use indexmap::IndexMap;
+use serde::{Serialize, Serializer};
+
+/// Headers whose values are replaced before a request is recorded, in
+/// addition to any header `reqwest` marks as sensitive.
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "api-key",
+    "x-goog-api-key",
+    "x-amz-security-token",
+];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// The HTTP request and response behind one attempt of an LLM call.
+///
+/// Both sides are reference counted: streaming clients clone their partial
+/// response for every event, and the request body can be large.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RawHttp {
+    pub request: Option<Arc<RawHttpRequest>>,
+    pub response: Option<Arc<RawHttpResponse>>,
+}
+
+/// The raw HTTP of one attempt of a function call.
+#[derive(Debug, Serialize)]
+pub struct RawHttpAttempt<'a> {
+    /// The orchestration scope of the attempt, e.g. `MyFallback + GPT4`.
+    pub client: String,
+    #[serde(flatten)]
+    pub http: &'a RawHttp,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RawHttpRequest {
+    pub method: String,
+    pub url: String,
+    /// Credentials are replaced with `[REDACTED]`, except in requests built
+    /// for the caller to send.
+    pub headers: IndexMap<String, String>,
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RawHttpResponse {
+    pub status: u16,
+    pub headers: IndexMap<String, String>,
+    pub body: RawHttpBody,
+}
+
+/// Serialized as a string, a list of event payloads, or `null`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RawHttpBody {
+    Text(String),
+    /// The data of each server-sent event, in the order received.
+    Events(SseEventLog),
+    /// The body was not captured (e.g. a binary event stream).
+    None,
+}
+
+/// An append-only log of server-sent events, shared between a response stream
+/// and the responses it yields.
+#[derive(Debug, Clone, Default)]
+pub struct SseEventLog(Arc<Mutex<Vec<String>>>);
+
+impl SseEventLog {
+    pub(crate) fn push(&self, data: &str) {
+        self.0.lock().unwrap().push(data.to_string());
+    }
+
+    pub fn events(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl Serialize for SseEventLog {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.lock().unwrap().serialize(serializer)
+    }
+}
+
+impl RawHttp {
+    pub(crate) fn from_request(request: Arc<RawHttpRequest>) -> Self {
+        Self {
+            request: Some(request),
+            response: None,
+        }
+    }
+
+    pub(crate) fn with_response(
+        &self,
+        status: u16,
+        headers: &reqwest::header::HeaderMap,
+        body: RawHttpBody,
+    ) -> Self {
+        Self {
+            request: self.request.clone(),
+            response: Some(Arc::new(RawHttpResponse {
+                status,
+                headers: header_map(headers, false),
+                body,
+            })),
+        }
+    }
+}
+
+impl RawHttpRequest {
+    pub(crate) fn from_reqwest(request: &reqwest::Request) -> Self {
+        Self::new(request, true)
+    }
+
+    /// Like [`RawHttpRequest::from_reqwest`], but keeps credentials, for
+    /// requests that are handed to the caller to send.
+    pub(crate) fn from_reqwest_unredacted(request: &reqwest::Request) -> Self {
+        Self::new(request, false)
+    }
+
+    fn new(request: &reqwest::Request, redact: bool) -> Self {
+        let body = match request.body().and_then(|b| b.as_bytes()) {
+            Some(bytes) => serde_json::from_slice(bytes)
+                .unwrap_or_else(|_| String::from_utf8_lossy(bytes).to_string().into()),
+            None => serde_json::Value::Null,
+        };
+        Self {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: header_map(request.headers(), redact),
+            body,
+        }
+    }
+}
+
+fn header_map(headers: &reqwest::header::HeaderMap, redact: bool) -> IndexMap<String, String> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            let value = if redact && is_secret_header(k.as_str(), v.is_sensitive()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(v.as_bytes()).to_string()
+            };
+            (k.to_string(), value)
+        })
+        .collect()
+}
+
+pub(crate) fn is_secret_header(name: &str, sensitive: bool) -> bool {
+    sensitive || SECRET_HEADERS.contains(&name.to_ascii_lowercase().as_str())
+}
+
+pub(crate) fn redacted_header_value(name: &str, value: &str) -> String {
+    if is_secret_header(name, false) {
+        REDACTED.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_headers_are_redacted() {
+        let request = reqwest::Client::new()
+            .post("https://api.example.com/v1/messages")
+            .bearer_auth("sk-secret")
+            .header("x-api-key", "sk-secret")
+            .header("anthropic-version", "2023-06-01")
+            .json(&serde_json::json!({"model": "m"}))
+            .build()
+            .unwrap();
+
+        let raw = RawHttpRequest::from_reqwest(&request);
+        assert_eq!(raw.method, "POST");
+        assert_eq!(raw.headers["authorization"], REDACTED);
+        assert_eq!(raw.headers["x-api-key"], REDACTED);
+        assert_eq!(raw.headers["anthropic-version"], "2023-06-01");
+        assert_eq!(raw.body, serde_json::json!({"model": "m"}));
+
+        let raw = RawHttpRequest::from_reqwest_unredacted(&request);
+        assert_eq!(raw.headers["authorization"], "Bearer sk-secret");
+        assert_eq!(raw.headers["x-api-key"], "sk-secret");
+    }
+
+    #[test]
+    fn test_sse_event_log_is_shared() {
+        let log = SseEventLog::default();
+        let body = RawHttpBody::Events(log.clone());
+        log.push("{\"a\":1}");
+        log.push("{\"a\":2}");
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            serde_json::json!(["{\"a\":1}", "{\"a\":2}"])
+        );
+    }
+}
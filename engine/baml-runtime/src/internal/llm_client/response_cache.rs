@@ -0,0 +1,508 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use internal_baml_jinja::RenderedPrompt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use web_time::Duration;
+
+use crate::RuntimeContext;
+
+use super::{
+    orchestrator::OrchestratorNode, traits::WithClientProperties, LLMCompleteResponse,
+    LLMCompleteResponseMetadata, LLMResponse,
+};
+
+/// Set to `memory` or `disk` to enable the response cache.
+const CACHE_ENV_VAR: &str = "BAML_RESPONSE_CACHE";
+/// Directory used by the `disk` backend.
+const CACHE_DIR_ENV_VAR: &str = "BAML_RESPONSE_CACHE_DIR";
+/// Time-to-live of a cache entry, in seconds. Entries never expire if unset.
+const CACHE_TTL_ENV_VAR: &str = "BAML_RESPONSE_CACHE_TTL";
+
+const DEFAULT_CACHE_DIR: &str = ".baml_cache";
+
+/// Most entries the `memory` backend holds. The least recently used entry is
+/// evicted first.
+const MEMORY_CACHE_CAPACITY: usize = 1024;
+
+static MEMORY_CACHE: OnceLock<Mutex<IndexMap<String, CachedResponse>>> = OnceLock::new();
+
+/// Where a client's responses are cached, set with the `response_cache` client
+/// option or, for all clients, the `BAML_RESPONSE_CACHE` environment variable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CacheMode {
+    Off,
+    Memory,
+    #[cfg(not(target_arch = "wasm32"))]
+    Disk,
+}
+
+impl CacheMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "" | "0" | "false" | "off" => Some(CacheMode::Off),
+            "1" | "true" | "memory" => Some(CacheMode::Memory),
+            #[cfg(not(target_arch = "wasm32"))]
+            "disk" => Some(CacheMode::Disk),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CacheBackend {
+    Memory,
+    #[cfg(not(target_arch = "wasm32"))]
+    Disk(std::path::PathBuf),
+}
+
+/// An opt-in cache for successful LLM responses, keyed on the client, model,
+/// request options and the fully rendered prompt.
+///
+/// A cache hit skips the HTTP call entirely, but the cached content still goes
+/// through parsing and constraint evaluation like a fresh response.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    /// Used for clients that don't set `response_cache`.
+    default_mode: CacheMode,
+    #[cfg(not(target_arch = "wasm32"))]
+    dir: std::path::PathBuf,
+    ttl: Option<Duration>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CachedResponse {
+    model: String,
+    content: String,
+    finish_reason: Option<String>,
+    prompt_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    total_tokens: Option<u64>,
+    // Seconds since the unix epoch.
+    created_at: u64,
+}
+
+#[derive(Serialize)]
+struct CacheKey<'a> {
+    client: &'a str,
+    provider: String,
+    model: Option<&'a serde_json::Value>,
+    request_options: BTreeMap<&'a String, &'a serde_json::Value>,
+    prompt: &'a RenderedPrompt,
+    /// The url and body of the request as the request hooks left it. Only
+    /// set when hooks are registered, so other keys don't change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request: Option<(&'a str, &'a serde_json::Value)>,
+}
+
+fn now_secs() -> u64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Entries are kept from least to most recently used.
+fn lru_get(cache: &mut IndexMap<String, CachedResponse>, key: &str) -> Option<CachedResponse> {
+    let index = cache.get_index_of(key)?;
+    let last = cache.len() - 1;
+    cache.move_index(index, last);
+    cache.get_index(last).map(|(_, entry)| entry.clone())
+}
+
+fn lru_insert(
+    cache: &mut IndexMap<String, CachedResponse>,
+    key: String,
+    entry: CachedResponse,
+    capacity: usize,
+) {
+    cache.shift_remove(&key);
+    cache.insert(key, entry);
+    while cache.len() > capacity {
+        cache.shift_remove_index(0);
+    }
+}
+
+impl ResponseCache {
+    /// Reads the cache configuration from the environment. Clients can still
+    /// opt in or out with their `response_cache` option.
+    pub fn from_ctx(ctx: &RuntimeContext) -> Result<Self> {
+        let default_mode = match ctx.env.get(CACHE_ENV_VAR) {
+            Some(value) => CacheMode::parse(value).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid value for {CACHE_ENV_VAR}: '{value}'. Expected one of: memory, disk"
+                )
+            })?,
+            None => CacheMode::Off,
+        };
+
+        let ttl = match ctx.env.get(CACHE_TTL_ENV_VAR) {
+            Some(ttl) => Some(Duration::from_secs(ttl.trim().parse::<u64>().map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid value for {CACHE_TTL_ENV_VAR}: '{ttl}' (expected seconds): {e}"
+                )
+            })?)),
+            None => None,
+        };
+
+        Ok(Self {
+            default_mode,
+            #[cfg(not(target_arch = "wasm32"))]
+            dir: ctx
+                .env
+                .get(CACHE_DIR_ENV_VAR)
+                .map(|s| s.as_str())
+                .unwrap_or(DEFAULT_CACHE_DIR)
+                .into(),
+            ttl,
+        })
+    }
+
+    fn backend(&self, node: &OrchestratorNode) -> Option<CacheBackend> {
+        match node.provider.response_cache().unwrap_or(self.default_mode) {
+            CacheMode::Off => None,
+            CacheMode::Memory => Some(CacheBackend::Memory),
+            #[cfg(not(target_arch = "wasm32"))]
+            CacheMode::Disk => Some(CacheBackend::Disk(self.dir.clone())),
+        }
+    }
+
+    /// The key responses of `node` to `prompt` are cached under, or `None` if
+    /// they are not cached.
+    ///
+    /// With request hooks registered, the key covers the request as the hooks
+    /// left it, so the hooks run once more to compute it. Clients whose
+    /// requests can't be built ahead of sending are then not cached.
+    pub async fn key(
+        &self,
+        node: &OrchestratorNode,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+    ) -> Option<String> {
+        self.backend(node)?;
+        let request = match ctx.request_hooks.is_empty() {
+            true => None,
+            false => match node.provider.build_raw_request(ctx, prompt, false).await {
+                Ok(request) => Some(request),
+                Err(e) => {
+                    log::debug!("Not caching responses of {}: {e:#}", node.provider.name());
+                    return None;
+                }
+            },
+        };
+        let key = Self::key_for(
+            node.provider.name(),
+            node.provider.to_string(),
+            node.provider.request_options(),
+            prompt,
+            request.as_ref().map(|r| (r.url.as_str(), &r.body)),
+        );
+        match key {
+            Ok(key) => Some(key),
+            Err(e) => {
+                log::debug!("Failed to compute response cache key: {e:#}");
+                None
+            }
+        }
+    }
+
+    fn key_for(
+        client: &str,
+        provider: String,
+        request_options: &HashMap<String, serde_json::Value>,
+        prompt: &RenderedPrompt,
+        request: Option<(&str, &serde_json::Value)>,
+    ) -> Result<String> {
+        let key = CacheKey {
+            client,
+            provider,
+            model: request_options.get("model"),
+            request_options: request_options.iter().collect(),
+            prompt,
+            request,
+        };
+        let digest = Sha256::digest(serde_json::to_vec(&key)?);
+        Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    fn is_expired(&self, entry: &CachedResponse) -> bool {
+        match self.ttl {
+            Some(ttl) => now_secs().saturating_sub(entry.created_at) > ttl.as_secs(),
+            None => false,
+        }
+    }
+
+    /// Looks up a previous response of this node to `prompt`, cached under
+    /// `key` (see [`ResponseCache::key`]).
+    pub fn get(
+        &self,
+        node: &OrchestratorNode,
+        key: &str,
+        prompt: &RenderedPrompt,
+    ) -> Option<LLMResponse> {
+        let (system_start, instant_start) = (web_time::SystemTime::now(), web_time::Instant::now());
+        let backend = self.backend(node)?;
+        let entry = self.get_entry(&backend, key)?;
+
+        log::debug!("Response cache hit for client {}", node.provider.name());
+        Some(LLMResponse::Success(LLMCompleteResponse {
+            client: node.provider.name().to_string(),
+            model: entry.model,
+            prompt: prompt.clone(),
+            request_options: node.provider.request_options().clone(),
+            content: entry.content,
+            start_time: system_start,
+            latency: instant_start.elapsed(),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: entry.finish_reason,
+                prompt_tokens: entry.prompt_tokens,
+                output_tokens: entry.output_tokens,
+                total_tokens: entry.total_tokens,
+                cache_hit: true,
+            },
+            http: Default::default(),
+        }))
+    }
+
+    /// Stores a response of this node under `key`. Only complete, successful
+    /// responses are cached.
+    pub fn put(&self, node: &OrchestratorNode, key: &str, response: &LLMResponse) {
+        let LLMResponse::Success(response) = response else {
+            return;
+        };
+        if !response.metadata.baml_is_complete || response.metadata.cache_hit {
+            return;
+        }
+        let Some(backend) = self.backend(node) else {
+            return;
+        };
+        let entry = CachedResponse {
+            model: response.model.clone(),
+            content: response.content.clone(),
+            finish_reason: response.metadata.finish_reason.clone(),
+            prompt_tokens: response.metadata.prompt_tokens,
+            output_tokens: response.metadata.output_tokens,
+            total_tokens: response.metadata.total_tokens,
+            created_at: now_secs(),
+        };
+        self.put_entry(&backend, key.to_string(), entry);
+    }
+
+    fn get_entry(&self, backend: &CacheBackend, key: &str) -> Option<CachedResponse> {
+        let entry = match backend {
+            CacheBackend::Memory => lru_get(
+                &mut MEMORY_CACHE.get_or_init(Default::default).lock().unwrap(),
+                key,
+            ),
+            #[cfg(not(target_arch = "wasm32"))]
+            CacheBackend::Disk(dir) => std::fs::read(dir.join(format!("{key}.json")))
+                .ok()
+                .and_then(|bytes| serde_json::from_slice::<CachedResponse>(&bytes).ok()),
+        }?;
+
+        if self.is_expired(&entry) {
+            Self::remove(backend, key);
+            return None;
+        }
+        Some(entry)
+    }
+
+    fn put_entry(&self, backend: &CacheBackend, key: String, entry: CachedResponse) {
+        match backend {
+            CacheBackend::Memory => lru_insert(
+                &mut MEMORY_CACHE.get_or_init(Default::default).lock().unwrap(),
+                key,
+                entry,
+                MEMORY_CACHE_CAPACITY,
+            ),
+            #[cfg(not(target_arch = "wasm32"))]
+            CacheBackend::Disk(dir) => {
+                let write = || -> Result<()> {
+                    std::fs::create_dir_all(dir)?;
+                    std::fs::write(dir.join(format!("{key}.json")), serde_json::to_vec(&entry)?)?;
+                    Ok(())
+                };
+                if let Err(e) = write() {
+                    log::warn!(
+                        "Failed to write response cache entry to {}: {e:#}",
+                        dir.display()
+                    );
+                }
+            }
+        }
+    }
+
+    fn remove(backend: &CacheBackend, key: &str) {
+        match backend {
+            CacheBackend::Memory => {
+                MEMORY_CACHE
+                    .get_or_init(Default::default)
+                    .lock()
+                    .unwrap()
+                    .shift_remove(key);
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            CacheBackend::Disk(dir) => {
+                let _ = std::fs::remove_file(dir.join(format!("{key}.json")));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_baml_jinja::{ChatMessagePart, RenderedChatMessage};
+
+    fn entry(content: &str) -> CachedResponse {
+        CachedResponse {
+            model: "gpt-4o".to_string(),
+            content: content.to_string(),
+            finish_reason: None,
+            prompt_tokens: None,
+            output_tokens: None,
+            total_tokens: None,
+            created_at: now_secs(),
+        }
+    }
+
+    fn cache(ttl: Option<Duration>) -> ResponseCache {
+        ResponseCache {
+            default_mode: CacheMode::Memory,
+            #[cfg(not(target_arch = "wasm32"))]
+            dir: DEFAULT_CACHE_DIR.into(),
+            ttl,
+        }
+    }
+
+    fn chat(text: &str) -> RenderedPrompt {
+        RenderedPrompt::Chat(vec![RenderedChatMessage {
+            role: "user".to_string(),
+            allow_duplicate_role: false,
+            parts: vec![ChatMessagePart::Text(text.to_string())],
+        }])
+    }
+
+    fn options(model: &str, temperature: f64) -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("model".to_string(), serde_json::json!(model)),
+            ("temperature".to_string(), serde_json::json!(temperature)),
+        ])
+    }
+
+    #[test]
+    fn test_expiry() {
+        let cache = cache(Some(Duration::from_secs(60)));
+        let mut entry = entry("{}");
+        assert!(!cache.is_expired(&entry));
+
+        entry.created_at = now_secs() - 120;
+        assert!(cache.is_expired(&entry));
+
+        let no_ttl = self::cache(None);
+        assert!(!no_ttl.is_expired(&entry));
+    }
+
+    #[test]
+    fn test_key() {
+        let key = |client: &str, options: &HashMap<_, _>, prompt: &RenderedPrompt| {
+            ResponseCache::key_for(client, "openai".to_string(), options, prompt, None).unwrap()
+        };
+        let base = key("GPT4", &options("gpt-4o", 0.0), &chat("hello"));
+
+        // Request options are sorted before hashing, so the key is stable.
+        assert_eq!(base, key("GPT4", &options("gpt-4o", 0.0), &chat("hello")));
+
+        assert_ne!(base, key("GPT4", &options("gpt-4o", 0.0), &chat("hello!")));
+        assert_ne!(
+            base,
+            key(
+                "GPT4",
+                &options("gpt-4o", 0.0),
+                &RenderedPrompt::Completion("hello".to_string())
+            )
+        );
+        assert_ne!(
+            base,
+            key("GPT4", &options("gpt-4o-mini", 0.0), &chat("hello"))
+        );
+        assert_ne!(base, key("GPT4", &options("gpt-4o", 0.5), &chat("hello")));
+        assert_ne!(base, key("Other", &options("gpt-4o", 0.0), &chat("hello")));
+
+        // With request hooks, the request they produced is part of the key.
+        let hooked = |body: serde_json::Value| {
+            ResponseCache::key_for(
+                "GPT4",
+                "openai".to_string(),
+                &options("gpt-4o", 0.0),
+                &chat("hello"),
+                Some(("https://api.openai.com/v1/chat/completions", &body)),
+            )
+            .unwrap()
+        };
+        let with_hooks = hooked(serde_json::json!({"user": "a"}));
+        assert_ne!(base, with_hooks);
+        assert_eq!(with_hooks, hooked(serde_json::json!({"user": "a"})));
+        assert_ne!(with_hooks, hooked(serde_json::json!({"user": "b"})));
+    }
+
+    #[test]
+    fn test_memory_round_trip() {
+        let cache = cache(None);
+        let key = ResponseCache::key_for(
+            "test_memory_round_trip",
+            "openai".to_string(),
+            &options("gpt-4o", 0.0),
+            &chat("hello"),
+            None,
+        )
+        .unwrap();
+
+        assert!(cache.get_entry(&CacheBackend::Memory, &key).is_none());
+        cache.put_entry(&CacheBackend::Memory, key.clone(), entry("{\"a\": 1}"));
+        let cached = cache.get_entry(&CacheBackend::Memory, &key).unwrap();
+        assert_eq!(cached.content, "{\"a\": 1}");
+        assert_eq!(cached.model, "gpt-4o");
+
+        // Expired entries are dropped on read.
+        let mut expired = entry("{}");
+        expired.created_at = now_secs() - 120;
+        cache.put_entry(&CacheBackend::Memory, key.clone(), expired);
+        let with_ttl = self::cache(Some(Duration::from_secs(60)));
+        assert!(with_ttl.get_entry(&CacheBackend::Memory, &key).is_none());
+        assert!(cache.get_entry(&CacheBackend::Memory, &key).is_none());
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut cache = IndexMap::new();
+        lru_insert(&mut cache, "a".to_string(), entry("a"), 2);
+        lru_insert(&mut cache, "b".to_string(), entry("b"), 2);
+
+        // Reading `a` makes `b` the least recently used entry.
+        assert!(lru_get(&mut cache, "a").is_some());
+        lru_insert(&mut cache, "c".to_string(), entry("c"), 2);
+        assert!(lru_get(&mut cache, "b").is_none());
+        assert_eq!(lru_get(&mut cache, "a").unwrap().content, "a");
+        assert_eq!(lru_get(&mut cache, "c").unwrap().content, "c");
+
+        // Overwriting an entry doesn't grow the cache.
+        lru_insert(&mut cache, "c".to_string(), entry("c2"), 2);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(lru_get(&mut cache, "c").unwrap().content, "c2");
+    }
+
+    #[test]
+    fn test_cache_mode() {
+        assert_eq!(CacheMode::parse("memory"), Some(CacheMode::Memory));
+        assert_eq!(CacheMode::parse(" true "), Some(CacheMode::Memory));
+        assert_eq!(CacheMode::parse("off"), Some(CacheMode::Off));
+        #[cfg(not(target_arch = "wasm32"))]
+        assert_eq!(CacheMode::parse("disk"), Some(CacheMode::Disk));
+        assert_eq!(CacheMode::parse("redis"), None);
+    }
+}
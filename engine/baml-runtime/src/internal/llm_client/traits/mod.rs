@@ -36,6 +36,10 @@ pub trait WithClientProperties {
     fn client_properties(&self) -> &HashMap<String, serde_json::Value>;
     fn allowed_metadata(&self) -> &super::AllowedMetadata;
     fn supports_streaming(&self) -> bool;
+    /// The most tokens the rendered prompt may have, if the client sets `max_input_tokens`.
+    fn max_input_tokens(&self) -> Option<usize>;
+    /// Whether and where responses are cached, if the client sets `response_cache`.
+    fn response_cache(&self) -> Option<super::response_cache::CacheMode>;
 }
 
 pub trait WithSingleCallable {
@@ -313,6 +317,7 @@ pub trait SseResponseTrait {
     fn response_stream(
         &self,
         resp: reqwest::Response,
+        http: super::raw_http::RawHttp,
         prompt: &Vec<internal_baml_jinja::RenderedChatMessage>,
         system_start: web_time::SystemTime,
         instant_start: web_time::Instant,
@@ -389,7 +394,7 @@ where
 
 /// We assume b64 with mime-type is the universally accepted format in an API request.
 /// Other formats will be converted into that, depending on what formats are allowed according to supported_media_formats.
-async fn process_media_urls(
+pub(super) async fn process_media_urls(
     resolve_media_urls: ResolveMediaUrls,
     resolve_files: bool,
     render_settings: Option<RenderCurlSettings>,
@@ -476,7 +481,7 @@ async fn process_media(
                                 .strip_prefix("file://")
                                 .unwrap_or(media_path.as_str())
                         ),
-                        Some(format!("{}/{}", part.media_type, ext)),
+                        Some(part.media_type.mime_type_for_extension(&ext)),
                     ));
                 }
             }
@@ -493,7 +498,7 @@ async fn process_media(
 
             if mime_type == None {
                 if let Some(ext) = media_file.extension() {
-                    mime_type = Some(format!("{}/{}", part.media_type, ext));
+                    mime_type = Some(part.media_type.mime_type_for_extension(&ext));
                 }
             }
 
@@ -1,6 +1,7 @@
 pub mod ir_features;
 pub mod llm_client;
 pub mod prompt_renderer;
+pub mod tokenizer;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm_jwt;
@@ -1,28 +1,39 @@
 mod render_output_format;
-use jsonish::BamlValueWithFlags;
+use jsonish::{BamlValueWithFlags, ParseThresholds};
 use render_output_format::render_output_format;
 
 use anyhow::Result;
-use baml_types::{BamlValue, FieldType};
+use baml_types::{BamlValue, FieldType, ParserMode};
 use internal_baml_core::{
-    error_unsupported,
+    error_not_found, error_unsupported,
     ir::{
         repr::{ClientSpec, IntermediateRepr},
         FunctionWalker, IRHelper,
     },
 };
 use internal_baml_jinja::{
-    types::OutputFormatContent, RenderContext, RenderContext_Client, RenderedPrompt,
-    TemplateStringMacro,
+    types::{OutputFormatContent, ResponseFormat},
+    RenderContext, RenderContext_Client, RenderedPrompt, TemplateStringMacro,
 };
 
-use crate::RuntimeContext;
+use crate::{
+    internal::{
+        llm_client::{orchestrator::LLMPrimitiveProvider, traits::WithClientProperties},
+        tokenizer::TokenCount,
+    },
+    RuntimeContext,
+};
 
 pub struct PromptRenderer {
     function_name: String,
+    /// The variant selected in the context, if any. Otherwise the function's
+    /// default config is used.
+    variant: Option<String>,
     client_spec: ClientSpec,
     output_defs: OutputFormatContent,
     output_type: FieldType,
+    parser_mode: ParserMode,
+    parse_thresholds: ParseThresholds,
 }
 
 impl PromptRenderer {
@@ -32,18 +43,34 @@ impl PromptRenderer {
         ctx: &RuntimeContext,
     ) -> Result<PromptRenderer> {
         let func_v2 = function.elem();
-        let Some(config) = func_v2.configs.first() else {
-            error_unsupported!("function", function.name(), "no valid prompt found")
+        let variant = ctx.variants.get(function.name());
+        let Some(config) = func_v2.find_config(variant.unwrap_or(&func_v2.default_config)) else {
+            match variant {
+                Some(variant) => {
+                    let variants = func_v2
+                        .configs
+                        .iter()
+                        .map(|c| c.name.clone())
+                        .collect::<Vec<_>>();
+                    error_not_found!("variant", variant, &variants)
+                }
+                None => error_unsupported!("function", function.name(), "no valid prompt found"),
+            }
         };
 
+        let output_defs = render_output_format(ir, ctx, &func_v2.output)?;
+
         Ok(PromptRenderer {
             function_name: function.name().into(),
+            variant: variant.cloned(),
             client_spec: match &ctx.client_overrides {
                 Some((Some(client), _)) => ClientSpec::Named(client.clone()),
                 _ => config.client.clone(),
             },
-            output_defs: render_output_format(ir, ctx, &func_v2.output)?,
+            output_defs,
             output_type: func_v2.output.clone(),
+            parser_mode: func_v2.parser_mode,
+            parse_thresholds: ctx.parse_thresholds.clone(),
         })
     }
 
@@ -51,13 +78,53 @@ impl PromptRenderer {
         &self.client_spec
     }
 
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    pub fn parser_mode(&self) -> ParserMode {
+        self.parser_mode
+    }
+
+    /// The format the rendered prompt asked the LLM to answer in.
+    pub fn response_format(&self) -> ResponseFormat {
+        self.output_defs
+            .rendered_format()
+            .unwrap_or(ResponseFormat::Json)
+    }
+
+    /// Records the format a prompt rendered elsewhere asked for.
+    pub fn record_response_format(&self, format: ResponseFormat) {
+        self.output_defs.record_format(format);
+    }
+
+    /// Parses `raw_string` into the function's return type. Final responses
+    /// are rejected if they are beyond the context's parse thresholds.
     pub fn parse(&self, raw_string: &str, allow_partials: bool) -> Result<BamlValueWithFlags> {
-        jsonish::from_str(
-            &self.output_defs,
+        // The parser only reads YAML or XML if the prompt asked for it.
+        let mut output_defs = self.output_defs.clone();
+        output_defs.format = self.response_format();
+        let parsed = jsonish::from_str_with_mode(
+            &output_defs,
             &self.output_type,
             raw_string,
             allow_partials,
-        )
+            self.parser_mode,
+        )?;
+        if !allow_partials && !self.parse_thresholds.is_empty() {
+            self.parse_thresholds.check(&parsed.report())?;
+        }
+        Ok(parsed)
+    }
+
+    /// Counts the tokens of a prompt rendered for `client`, with that client's
+    /// tokenizer.
+    pub fn count_tokens(
+        &self,
+        prompt: &RenderedPrompt,
+        client: &LLMPrimitiveProvider,
+    ) -> TokenCount {
+        TokenCount::new(prompt, client.tokenizer(), client.max_input_tokens())
     }
 
     pub fn render_prompt(
@@ -71,7 +138,9 @@ impl PromptRenderer {
 
         let func_v2 = func.elem();
 
-        let Some(config) = func_v2.configs.first() else {
+        let Some(config) =
+            func_v2.find_config(self.variant.as_deref().unwrap_or(&func_v2.default_config))
+        else {
             error_unsupported!("function", self.function_name, "no valid prompt found")
         };
 
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use anyhow::Result;
 use baml_types::BamlValue;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use internal_baml_core::ir::{
     repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType, IRHelper,
 };
@@ -71,25 +71,46 @@ fn find_new_class_field<'a>(
     field_name: &str,
     class_walker: &Result<ClassWalker<'_>>,
     overrides: &'a RuntimeClassOverride,
-    _ctx: &RuntimeContext,
+    ctx: &RuntimeContext,
 ) -> Result<(Name, FieldType, Option<String>)> {
     let Some(field_overrides) = overrides.new_fields.get(field_name) else {
         anyhow::bail!("Class {} does not have a field: {}", class_name, field_name);
     };
 
-    // Ensure the original field does not exist
-    if let Ok(class_walker) = class_walker {
-        if class_walker.find_field(field_name).is_some() {
-            anyhow::bail!(
-                "Class {} already has a pre-defined field: {}",
-                class_name,
-                field_name
-            );
+    let mut alias = OverridableValue::<String>::from(field_overrides.1.alias.as_ref());
+    let mut desc = OverridableValue::<String>::from(field_overrides.1.meta.get("description"));
+
+    // Only the pre-defined fields of `@@dynamic` classes can be given a new
+    // type.
+    let field_walker = match class_walker {
+        Ok(class_walker) => match class_walker.find_field(field_name) {
+            Some(_) if class_walker.item.attributes.get("dynamic_type").is_none() => {
+                anyhow::bail!(
+                    "Class {} already has a pre-defined field: {}. Only fields of @@dynamic classes can be given a new type",
+                    class_name,
+                    field_name
+                );
+            }
+            field_walker => field_walker,
+        },
+        Err(_) => None,
+    };
+
+    // A pre-defined field that is given a new type keeps its own alias and
+    // description unless those are overridden too.
+    if let Some(field_walker) = field_walker {
+        if matches!(alias, OverridableValue::Unset) {
+            if let Some(_alias) = field_walker.alias(&ctx.env)? {
+                alias = OverridableValue::Set(_alias);
+            }
         }
-    }
 
-    let alias = OverridableValue::<String>::from(field_overrides.1.alias.as_ref());
-    let desc = OverridableValue::<String>::from(field_overrides.1.meta.get("description"));
+        if matches!(desc, OverridableValue::Unset) {
+            if let Some(_desc) = field_walker.description(&ctx.env)? {
+                desc = OverridableValue::Set(_desc);
+            }
+        }
+    }
 
     let name = Name::new_with_alias(field_name.to_string(), alias.value());
     let desc = desc.value();
@@ -135,7 +156,10 @@ fn find_existing_class_field<'a>(
     }
 
     let name = Name::new_with_alias(field_name.to_string(), alias.value());
-    let desc = desc.value();
+    let desc = match (desc.value(), field_walker.value_constraints_description()) {
+        (Some(desc), Some(constraints)) => Some(format!("{desc} ({constraints})")),
+        (desc, constraints) => desc.or(constraints),
+    };
     let r#type = field_walker.r#type();
     Ok((name, r#type.clone(), desc))
 }
@@ -294,30 +318,47 @@ fn relevant_data_models<'a>(
                         .map(|e| e.walk_fields().map(|v| v.name().to_string()))
                         .ok();
                     let override_fields = overrides
-                        .map(|o| o.update_fields.keys().map(|k| k.clone()))
+                        .map(|o| o.update_fields.keys().chain(o.new_fields.keys()).cloned())
                         .into_iter()
                         .flatten();
 
-                    let fields = real_fields
+                    let mut field_names = real_fields
                         .into_iter()
                         .flatten()
                         .chain(override_fields)
-                        .collect::<IndexSet<_>>()
-                        .into_iter()
-                        .map(|field| {
-                            let meta =
-                                find_existing_class_field(&cls, &field, &walker, &overrides, ctx)?;
-                            Ok(meta)
-                        });
+                        .collect::<IndexSet<_>>();
+
+                    if let Some(order) = overrides.map(|o| &o.order) {
+                        for (index, field) in order.iter().enumerate() {
+                            let Some(current) = field_names.get_index_of(field) else {
+                                anyhow::bail!("Class {} does not have a field: {}", cls, field);
+                            };
+                            field_names.move_index(current, index);
+                        }
+                    }
 
-                    let new_fields = overrides
-                        .map(|o| {
-                            o.new_fields
-                                .keys()
-                                .map(|k| find_new_class_field(&cls, k, &walker, o, ctx))
-                        })
-                        .into_iter()
-                        .flatten();
+                    let fields = field_names.into_iter().map(|field| {
+                        match overrides.filter(|o| o.new_fields.contains_key(&field)) {
+                            Some(o) => find_new_class_field(&cls, &field, &walker, o, ctx),
+                            None => {
+                                find_existing_class_field(&cls, &field, &walker, &overrides, ctx)
+                            }
+                        }
+                    });
+
+                    let mut defaults = IndexMap::new();
+                    if let Ok(walker) = &walker {
+                        for field in walker.walk_fields() {
+                            if let Some(value) = field.default_value(&ctx.env)? {
+                                defaults.insert(field.name().to_string(), value);
+                            }
+                        }
+                    }
+                    let tag = walker
+                        .as_ref()
+                        .ok()
+                        .and_then(|walker| walker.tag())
+                        .map(|(field, _)| field.to_string());
 
                     let mut alias =
                         OverridableValue::<String>::from(overrides.and_then(|o| o.alias.as_ref()));
@@ -330,7 +371,7 @@ fn relevant_data_models<'a>(
                         }
                     }
 
-                    let fields = fields.chain(new_fields).collect::<Result<Vec<_>>>()?;
+                    let fields = fields.collect::<Result<Vec<_>>>()?;
 
                     for (_, t, _) in fields.iter().as_ref() {
                         if !checked_types.contains(&t.to_string()) {
@@ -358,6 +399,8 @@ fn relevant_data_models<'a>(
                         name: Name::new_with_alias(cls.to_string(), alias.value()),
                         fields,
                         constraints,
+                        defaults,
+                        tag,
                     });
                 } else {
                     recursive_classes.insert(cls.to_owned());
@@ -379,7 +422,7 @@ mod tests {
     use std::collections::HashMap;
 
     use super::*;
-    use crate::BamlRuntime;
+    use crate::{type_builder::TypeBuilder, BamlRuntime};
 
     #[test]
     fn skipped_variants_are_not_rendered() {
@@ -406,4 +449,131 @@ mod tests {
         assert_eq!(foo_enum.values[0].0.real_name(), "Bar".to_string());
         assert_eq!(foo_enum.values.len(), 1);
     }
+
+    #[test]
+    fn type_builder_updates_dynamic_types() {
+        let files = vec![(
+            "test-file.baml",
+            r#"
+          enum Color {
+            Red
+            Green
+            @@dynamic
+          }
+
+          class Shape {
+            name string @description("what to call it")
+            size int
+            @@dynamic
+          }"#,
+        )]
+        .into_iter()
+        .collect();
+        let env_vars: HashMap<&str, &str> = HashMap::new();
+        let baml_runtime = BamlRuntime::from_file_content(".", &files, env_vars).unwrap();
+        let ctx_manager = baml_runtime.create_ctx_manager(BamlValue::Null, None);
+
+        let tb = TypeBuilder::new();
+        {
+            let color = tb.r#enum("Color");
+            let color = color.lock().unwrap();
+            color.value("Blue");
+            color.remove_value("Red");
+        }
+        {
+            let point = tb.class("Point");
+            let point = point.lock().unwrap();
+            point.property("x").lock().unwrap().r#type(FieldType::int());
+        }
+        {
+            let shape = tb.class("Shape");
+            let shape = shape.lock().unwrap();
+            shape
+                .property("size")
+                .lock()
+                .unwrap()
+                .r#type(FieldType::float());
+            shape
+                .property("center")
+                .lock()
+                .unwrap()
+                .r#type(FieldType::class("Point"));
+            shape
+                .property("color")
+                .lock()
+                .unwrap()
+                .r#type(FieldType::r#enum("Color"));
+            shape.reorder_properties(vec!["color".to_string(), "size".to_string()]);
+        }
+        let ctx: RuntimeContext = ctx_manager.create_ctx(Some(&tb), None).unwrap();
+
+        let field_type = FieldType::class("Shape");
+        let render_output =
+            render_output_format(baml_runtime.inner.ir.as_ref(), &ctx, &field_type).unwrap();
+
+        let shape = render_output.find_class("Shape").unwrap();
+        let fields = shape
+            .fields
+            .iter()
+            .map(|(name, r#type, desc)| (name.real_name(), r#type.clone(), desc.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("color", FieldType::r#enum("Color"), None),
+                ("size", FieldType::float(), None),
+                (
+                    "name",
+                    FieldType::string(),
+                    Some("what to call it".to_string())
+                ),
+                ("center", FieldType::class("Point"), None),
+            ]
+        );
+
+        let color = render_output.find_enum("Color").unwrap();
+        let values = color
+            .values
+            .iter()
+            .map(|(name, _)| name.real_name())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec!["Green", "Blue"]);
+
+        assert!(render_output.find_class("Point").is_ok());
+    }
+
+    #[test]
+    fn type_builder_cannot_retype_static_class_fields() {
+        let files = vec![(
+            "test-file.baml",
+            r#"
+          class Shape {
+            size int
+          }"#,
+        )]
+        .into_iter()
+        .collect();
+        let env_vars: HashMap<&str, &str> = HashMap::new();
+        let baml_runtime = BamlRuntime::from_file_content(".", &files, env_vars).unwrap();
+        let ctx_manager = baml_runtime.create_ctx_manager(BamlValue::Null, None);
+
+        let tb = TypeBuilder::new();
+        tb.class("Shape")
+            .lock()
+            .unwrap()
+            .property("size")
+            .lock()
+            .unwrap()
+            .r#type(FieldType::float());
+        let ctx: RuntimeContext = ctx_manager.create_ctx(Some(&tb), None).unwrap();
+
+        let field_type = FieldType::class("Shape");
+        let err = render_output_format(baml_runtime.inner.ir.as_ref(), &ctx, &field_type)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("already has a pre-defined field: size"),
+            "{err}"
+        );
+    }
 }
@@ -0,0 +1,187 @@
+//! Offline token counting for rendered prompts.
+//!
+//! OpenAI's encodings are bundled with the runtime, so counts for OpenAI
+//! models are exact. Other providers don't publish their tokenizers, so their
+//! counts are an estimate. The encodings are too large for the wasm build,
+//! where every count is an estimate.
+
+use internal_baml_jinja::{ChatMessagePart, RenderedPrompt};
+use serde::Serialize;
+#[cfg(not(target_arch = "wasm32"))]
+use tiktoken_rs::{cl100k_base_singleton, o200k_base_singleton};
+
+/// Tokens that chat models add for every message, and to prime the reply.
+/// See <https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken>.
+const TOKENS_PER_MESSAGE: usize = 3;
+const TOKENS_PER_REPLY: usize = 3;
+
+/// Roughly how many characters a token covers in English text.
+const CHARS_PER_TOKEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tokenizer {
+    /// The encoding of gpt-4o, o1 and newer OpenAI models.
+    O200kBase,
+    /// The encoding of gpt-4 and gpt-3.5-turbo.
+    Cl100kBase,
+    /// An estimate of one token for every four characters.
+    Approximate,
+}
+
+impl Tokenizer {
+    /// The encoding of an OpenAI model. Models that are unknown (or unset)
+    /// are assumed to be newer ones.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn for_openai_model(model: Option<&str>) -> Self {
+        match model.and_then(tiktoken_rs::tokenizer::get_tokenizer) {
+            None | Some(tiktoken_rs::tokenizer::Tokenizer::O200kBase) => Tokenizer::O200kBase,
+            Some(_) => Tokenizer::Cl100kBase,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn for_openai_model(_model: Option<&str>) -> Self {
+        Tokenizer::Approximate
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tokenizer::O200kBase => "o200k_base",
+            Tokenizer::Cl100kBase => "cl100k_base",
+            Tokenizer::Approximate => "approximate",
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        !matches!(self, Tokenizer::Approximate)
+    }
+
+    pub fn count_text(&self, text: &str) -> usize {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            Tokenizer::O200kBase => o200k_base_singleton().lock().encode_ordinary(text).len(),
+            #[cfg(not(target_arch = "wasm32"))]
+            Tokenizer::Cl100kBase => cl100k_base_singleton().lock().encode_ordinary(text).len(),
+            #[cfg(target_arch = "wasm32")]
+            Tokenizer::O200kBase | Tokenizer::Cl100kBase => Tokenizer::Approximate.count_text(text),
+            Tokenizer::Approximate => text.chars().count().div_ceil(CHARS_PER_TOKEN),
+        }
+    }
+
+    /// Counts the text of a prompt. Media is not counted, since providers
+    /// resize it before counting it.
+    pub fn count_prompt(&self, prompt: &RenderedPrompt) -> usize {
+        match prompt {
+            RenderedPrompt::Completion(text) => self.count_text(text),
+            RenderedPrompt::Chat(messages) => {
+                messages
+                    .iter()
+                    .map(|message| {
+                        TOKENS_PER_MESSAGE
+                            + self.count_text(&message.role)
+                            + message
+                                .parts
+                                .iter()
+                                .map(|part| self.count_part(part))
+                                .sum::<usize>()
+                    })
+                    .sum::<usize>()
+                    + TOKENS_PER_REPLY
+            }
+        }
+    }
+
+    fn count_part(&self, part: &ChatMessagePart) -> usize {
+        match part {
+            ChatMessagePart::Text(text) => self.count_text(text),
+            ChatMessagePart::Media(_) => 0,
+            ChatMessagePart::WithMeta(part, _) => self.count_part(part),
+        }
+    }
+}
+
+impl std::fmt::Display for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The size of a rendered prompt, as counted for the client it is sent to.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TokenCount {
+    pub tokens: usize,
+    pub tokenizer: Tokenizer,
+    /// The client's `max_input_tokens`, if it has one.
+    pub max_input_tokens: Option<usize>,
+}
+
+impl TokenCount {
+    pub fn new(
+        prompt: &RenderedPrompt,
+        tokenizer: Tokenizer,
+        max_input_tokens: Option<usize>,
+    ) -> Self {
+        TokenCount {
+            tokens: tokenizer.count_prompt(prompt),
+            tokenizer,
+            max_input_tokens,
+        }
+    }
+
+    pub fn exceeds_limit(&self) -> bool {
+        self.max_input_tokens
+            .is_some_and(|max_input_tokens| self.tokens > max_input_tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_baml_jinja::RenderedChatMessage;
+
+    #[test]
+    fn test_for_openai_model() {
+        assert_eq!(
+            Tokenizer::for_openai_model(Some("gpt-4o-mini")),
+            Tokenizer::O200kBase
+        );
+        assert_eq!(
+            Tokenizer::for_openai_model(Some("gpt-4-turbo")),
+            Tokenizer::Cl100kBase
+        );
+        assert_eq!(
+            Tokenizer::for_openai_model(Some("gpt-3.5-turbo")),
+            Tokenizer::Cl100kBase
+        );
+        assert_eq!(Tokenizer::for_openai_model(None), Tokenizer::O200kBase);
+    }
+
+    #[test]
+    fn test_count_text() {
+        assert_eq!(Tokenizer::O200kBase.count_text("hello world"), 2);
+        assert_eq!(Tokenizer::Cl100kBase.count_text("hello world"), 2);
+        assert_eq!(Tokenizer::Approximate.count_text("hello world"), 3);
+        assert_eq!(Tokenizer::Approximate.count_text(""), 0);
+    }
+
+    #[test]
+    fn test_count_chat_prompt() {
+        let prompt = RenderedPrompt::Chat(vec![
+            RenderedChatMessage {
+                role: "system".into(),
+                allow_duplicate_role: false,
+                parts: vec![ChatMessagePart::Text("hello world".into())],
+            },
+            RenderedChatMessage {
+                role: "user".into(),
+                allow_duplicate_role: false,
+                parts: vec![
+                    ChatMessagePart::Text("hello world".into()).with_meta(Default::default())
+                ],
+            },
+        ]);
+        // 2 messages * (3 + 1 role token + 2 text tokens) + 3 for the reply.
+        assert_eq!(Tokenizer::O200kBase.count_prompt(&prompt), 15);
+    }
+}
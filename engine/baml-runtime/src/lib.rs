@@ -24,8 +24,8 @@ use std::sync::Arc;
 use anyhow::Context;
 use anyhow::Result;
 
-use baml_types::BamlMap;
-use baml_types::BamlValue;
+pub use baml_types::BamlMap;
+pub use baml_types::BamlValue;
 use baml_types::Constraint;
 use cfg_if::cfg_if;
 use client_registry::ClientRegistry;
@@ -35,6 +35,7 @@ use internal_baml_core::configuration::CodegenGenerator;
 use internal_baml_core::configuration::Generator;
 use internal_baml_core::configuration::GeneratorOutputType;
 use on_log_event::LogEventCallbackSync;
+use request_hook::{RequestHook, RequestHooks};
 use runtime::InternalBamlRuntime;
 use std::sync::OnceLock;
 
@@ -65,6 +66,7 @@ pub use internal_baml_core::internal_baml_diagnostics::Diagnostics as Diagnostic
 pub use internal_baml_core::ir::{scope_diagnostics, FieldType, IRHelper, TypeValue};
 
 use crate::constraints::{evaluate_test_constraints, TestConstraintsResult};
+use crate::internal::llm_client::raw_http::RawHttpRequest;
 use crate::internal::llm_client::LLMResponse;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -74,6 +76,7 @@ pub struct BamlRuntime {
     pub(crate) inner: InternalBamlRuntime,
     tracer: Arc<BamlTracer>,
     env_vars: HashMap<String, String>,
+    request_hooks: RequestHooks,
     #[cfg(not(target_arch = "wasm32"))]
     pub async_runtime: Arc<tokio::runtime::Runtime>,
 }
@@ -140,6 +143,7 @@ impl BamlRuntime {
             inner: InternalBamlRuntime::from_directory(&path)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
             env_vars: copy,
+            request_hooks: Default::default(),
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
         })
@@ -158,6 +162,7 @@ impl BamlRuntime {
             inner: InternalBamlRuntime::from_file_content(root_path, files)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
             env_vars: copy,
+            request_hooks: Default::default(),
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
         })
@@ -173,7 +178,8 @@ impl BamlRuntime {
         language: BamlValue,
         baml_src_reader: BamlSrcReader,
     ) -> RuntimeContextManager {
-        let ctx = RuntimeContextManager::new_from_env_vars(self.env_vars.clone(), baml_src_reader);
+        let ctx = RuntimeContextManager::new_from_env_vars(self.env_vars.clone(), baml_src_reader)
+            .with_request_hooks(self.request_hooks.clone());
         let tags: HashMap<String, BamlValue> = [("baml.language", language)]
             .into_iter()
             .map(|(k, v)| (k.to_string(), v))
@@ -181,6 +187,17 @@ impl BamlRuntime {
         ctx.upsert_tags(tags);
         ctx
     }
+
+    /// Registers a hook to run around every HTTP request sent to an LLM
+    /// provider. Hooks apply to all context managers of this runtime,
+    /// including ones created before the hook was added.
+    pub fn add_request_hook(&self, hook: Arc<dyn RequestHook>) {
+        self.request_hooks.lock().unwrap().push(hook);
+    }
+
+    pub fn clear_request_hooks(&self) {
+        self.request_hooks.lock().unwrap().clear();
+    }
 }
 
 impl BamlRuntime {
@@ -304,7 +321,14 @@ impl BamlRuntime {
         log::trace!("Calling function: {}", function_name);
         let span = self.tracer.start_span(&function_name, ctx, &params);
         let response = match ctx.create_ctx(tb, cb) {
-            Ok(rctx) => {
+            Ok(mut rctx) => {
+                self.split_variants(&function_name, &mut rctx);
+                if let Some(variant) = rctx.variants.get(&function_name) {
+                    ctx.record_variant(variant);
+                }
+                if let Ok(function) = self.inner.ir().find_function(&function_name) {
+                    ctx.record_parser_mode(function.elem().parser_mode);
+                }
                 self.inner
                     .call_function_impl(function_name, params, rctx)
                     .await
@@ -336,16 +360,124 @@ impl BamlRuntime {
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
     ) -> Result<FunctionResultStream> {
+        let mut rctx = ctx.create_ctx(tb, cb)?;
+        self.split_variants(&function_name, &mut rctx);
         self.inner.stream_function_impl(
             function_name,
             params,
             self.tracer.clone(),
-            ctx.create_ctx(tb, cb)?,
+            rctx,
             #[cfg(not(target_arch = "wasm32"))]
             self.async_runtime.clone(),
         )
     }
 
+    /// Builds the HTTP request that calling `function_name` would send to its
+    /// client, without sending it, for callers that send requests through
+    /// their own HTTP stack. Request hooks are applied and credentials are
+    /// kept. Fallback and round-robin clients build the request of the client
+    /// they would try first, and the selected variant is used without a
+    /// traffic split.
+    pub async fn build_request(
+        &self,
+        function_name: &str,
+        params: &BamlMap<String, BamlValue>,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+        stream: bool,
+    ) -> Result<RawHttpRequest> {
+        let rctx = ctx.create_ctx(tb, cb)?;
+        self.inner
+            .build_request(function_name, &rctx, params, stream, None)
+            .await
+    }
+
+    /// Parses the text of a response to a request from [`Self::build_request`]
+    /// into the return type of `function_name`, evaluating its checks and
+    /// asserts. With `partial`, `raw_text` may be the text of an unfinished
+    /// stream.
+    pub fn parse_response(
+        &self,
+        function_name: &str,
+        raw_text: &str,
+        partial: bool,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> Result<FunctionResult> {
+        let rctx = ctx.create_ctx(tb, cb)?;
+        self.inner
+            .parse_response(function_name, &rctx, raw_text, partial)
+    }
+
+    /// Calls that don't select a variant of `function_name` are split between
+    /// its variants by their `percent`. Tests always call the selected or
+    /// default variant, so they compare prompts on the same inputs.
+    fn split_variants(&self, function_name: &str, ctx: &mut RuntimeContext) {
+        if ctx.variants.contains_key(function_name) {
+            return;
+        }
+        let Ok(function) = self.inner.ir().find_function(function_name) else {
+            return;
+        };
+        let function = function.elem();
+        if function.configs.len() < 2 {
+            return;
+        }
+        if let Some(config) = function.config_for_roll(fastrand::u32(0..100)) {
+            ctx.variants
+                .insert(function_name.to_string(), config.name.clone());
+        }
+    }
+
+    /// Submits one call of `function_name` per entry of `params` as a single
+    /// provider batch job. The function's client must be an `openai` or
+    /// `anthropic` client; retries and fallbacks are not applied.
+    pub async fn submit_batch(
+        &self,
+        function_name: String,
+        params: &[BamlMap<String, BamlValue>],
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> Result<BatchJob> {
+        log::trace!(
+            "Submitting batch of {} calls to: {}",
+            params.len(),
+            function_name
+        );
+        self.inner
+            .submit_batch_impl(function_name, params, ctx.create_ctx(tb, cb)?)
+            .await
+    }
+
+    pub async fn batch_status(
+        &self,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> Result<BatchJobStatus> {
+        self.inner
+            .batch_status_impl(job, ctx.create_ctx(tb, cb)?)
+            .await
+    }
+
+    /// Downloads and parses the results of a completed batch job, in the order
+    /// the calls were submitted.
+    pub async fn batch_results(
+        &self,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> Result<Vec<FunctionResult>> {
+        self.inner
+            .batch_results_impl(job, ctx.create_ctx(tb, cb)?)
+            .await
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn generate_client(
         &self,
@@ -432,6 +564,7 @@ impl BamlRuntime {
                         no_version_check,
                         generator.default_client_mode(),
                         generator.on_generate.clone(),
+                        generator.zod,
                     )?,
                 ))
             })
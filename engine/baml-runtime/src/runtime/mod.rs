@@ -15,7 +15,7 @@ cfg_if::cfg_if!(
 use internal_baml_core::{
     internal_baml_diagnostics::{Diagnostics, SourceFile},
     ir::repr::IntermediateRepr,
-    validate,
+    validate, validate_type_builder_snippet,
 };
 use std::sync::Arc;
 
@@ -24,6 +24,8 @@ use crate::internal::llm_client::{llm_provider::LLMProvider, retry_policy::Calla
 pub struct InternalBamlRuntime {
     pub(crate) ir: Arc<IntermediateRepr>,
     diagnostics: Diagnostics,
+    /// The files the IR was built from, kept to validate types added at runtime.
+    pub(crate) source_files: Vec<SourceFile>,
     clients: DashMap<String, Arc<LLMProvider>>,
     retry_policies: DashMap<String, CallablePolicy>,
 }
@@ -42,13 +44,14 @@ impl InternalBamlRuntime {
                 )))
             })
             .collect::<Result<Vec<_>>>()?;
-        let mut schema = validate(&PathBuf::from(directory), contents);
+        let mut schema = validate(&PathBuf::from(directory), contents.clone());
         schema.diagnostics.to_result()?;
 
         let ir = IntermediateRepr::from_parser_database(&schema.db, schema.configuration)?;
         Ok(InternalBamlRuntime {
             ir: Arc::new(ir),
             diagnostics: schema.diagnostics,
+            source_files: contents,
             clients: Default::default(),
             retry_policies: Default::default(),
         })
@@ -62,8 +65,8 @@ impl InternalBamlRuntime {
                 Err(e) => Err(e),
             })
             .filter_map(|res| res.ok())
-            .collect();
-        let mut schema = validate(directory, contents);
+            .collect::<Vec<_>>();
+        let mut schema = validate(directory, contents.clone());
         schema.diagnostics.to_result()?;
 
         let ir = IntermediateRepr::from_parser_database(&schema.db, schema.configuration)?;
@@ -71,8 +74,32 @@ impl InternalBamlRuntime {
         Ok(Self {
             ir: Arc::new(ir),
             diagnostics: schema.diagnostics,
+            source_files: contents,
             clients: Default::default(),
             retry_policies: Default::default(),
         })
     }
+
+    /// Validates BAML source added through a TypeBuilder against the files this
+    /// runtime was built from and `existing`, which declares the types the
+    /// TypeBuilder already has. Returns the resulting IR along with the types the
+    /// snippet declares or extends, and the fields or values it declares for each.
+    pub(crate) fn validate_type_builder_snippet(
+        &self,
+        baml: &str,
+        existing: &str,
+    ) -> Result<(IntermediateRepr, Vec<(String, Vec<String>)>)> {
+        let snippet = SourceFile::from((PathBuf::from("type_builder.baml"), baml));
+        let mut files = self.source_files.clone();
+        files.push(SourceFile::from((
+            PathBuf::from("type_builder_existing.baml"),
+            existing,
+        )));
+        let (mut schema, declared) =
+            validate_type_builder_snippet(&self.diagnostics.root_path, files, &snippet);
+        schema.diagnostics.to_result()?;
+
+        let ir = IntermediateRepr::from_parser_database(&schema.db, schema.configuration)?;
+        Ok((ir, declared))
+    }
 }
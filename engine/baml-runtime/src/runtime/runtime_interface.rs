@@ -2,7 +2,10 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use super::InternalBamlRuntime;
 use crate::internal::llm_client::traits::WithClientProperties;
-use crate::internal::llm_client::{AllowedMetadata, LLMResponse};
+use crate::internal::llm_client::{
+    parsed_value_to_response, raw_http::RawHttpRequest, AllowedMetadata, ErrorCode,
+    LLMCompleteResponse, LLMCompleteResponseMetadata, LLMResponse,
+};
 use crate::{
     client_registry::ClientProperty,
     internal::{
@@ -10,18 +13,23 @@ use crate::{
         llm_client::{
             llm_provider::LLMProvider,
             orchestrator::{
-                orchestrate_call, IterOrchestrator, OrchestrationScope, OrchestratorNode,
+                orchestrate_call, ExecutionScope, IterOrchestrator, OrchestrationScope,
+                OrchestratorNode,
+            },
+            primitive::{
+                batch::{batch_failure, BatchRequest, WithBatch},
+                LLMPrimitiveProvider,
             },
-            primitive::LLMPrimitiveProvider,
             retry_policy::CallablePolicy,
             traits::{WithPrompt, WithRenderRawCurl},
         },
         prompt_renderer::PromptRenderer,
+        tokenizer::TokenCount,
     },
     runtime_interface::{InternalClientLookup, RuntimeConstructor},
     tracing::BamlTracer,
-    FunctionResult, FunctionResultStream, InternalRuntimeInterface, RenderCurlSettings,
-    RuntimeContext, RuntimeInterface,
+    BatchJob, BatchJobStatus, FunctionResult, FunctionResultStream, InternalRuntimeInterface,
+    RenderCurlSettings, RuntimeContext, RuntimeInterface,
 };
 use anyhow::{Context, Result};
 use baml_types::{BamlMap, BamlValue, Constraint};
@@ -221,6 +229,124 @@ impl InternalRuntimeInterface for InternalBamlRuntime {
             .await;
     }
 
+    fn count_tokens(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        node_index: Option<usize>,
+    ) -> Result<TokenCount> {
+        let func = self.get_function(function_name, ctx)?;
+
+        let renderer = PromptRenderer::from_function(&func, &self.ir(), ctx)?;
+
+        let client_spec = renderer.client_spec();
+        let client = self.get_llm_provider(client_spec, ctx)?;
+        let mut selected =
+            client.iter_orchestrator(&mut Default::default(), Default::default(), ctx, self)?;
+
+        let node_index = node_index.unwrap_or(0);
+
+        if node_index >= selected.len() {
+            return Err(anyhow::anyhow!(
+                "Execution Node out of bounds (count tokens): {} >= {} for client {}",
+                node_index,
+                selected.len(),
+                client_spec,
+            ));
+        }
+
+        let node = selected.swap_remove(node_index);
+        Ok(renderer.count_tokens(prompt, &node.provider))
+    }
+
+    async fn build_request(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        params: &BamlMap<String, BamlValue>,
+        stream: bool,
+        node_index: Option<usize>,
+    ) -> Result<RawHttpRequest> {
+        let func = self.get_function(function_name, ctx)?;
+        let baml_args = self.ir().check_function_params(
+            &func,
+            params,
+            ArgCoercer {
+                span_path: None,
+                allow_implicit_cast_to_string: false,
+            },
+        )?;
+
+        let renderer = PromptRenderer::from_function(&func, &self.ir(), ctx)?;
+
+        let client_spec = renderer.client_spec();
+        let client = self.get_llm_provider(client_spec, ctx)?;
+        let mut selected =
+            client.iter_orchestrator(&mut Default::default(), Default::default(), ctx, self)?;
+        let node_index = node_index.unwrap_or(0);
+
+        if node_index >= selected.len() {
+            return Err(anyhow::anyhow!(
+                "Execution Node out of bounds (build request): {} >= {} for client {}",
+                node_index,
+                selected.len(),
+                client_spec,
+            ));
+        }
+
+        let node = selected.swap_remove(node_index);
+        let prompt = node
+            .render_prompt(self.ir(), &renderer, ctx, &baml_args)
+            .await?;
+        if let Some(failure) = node.check_input_tokens(&prompt) {
+            return Err(anyhow::anyhow!(failure));
+        }
+        node.provider.build_raw_request(ctx, &prompt, stream).await
+    }
+
+    fn parse_response(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        raw_text: &str,
+        partial: bool,
+    ) -> Result<FunctionResult> {
+        let func = self.get_function(function_name, ctx)?;
+        let renderer = PromptRenderer::from_function(&func, &self.ir(), ctx)?;
+
+        // The response was received outside of BAML, so only its text is known.
+        let client = renderer.client_spec().to_string();
+        let response = LLMResponse::Success(LLMCompleteResponse {
+            client: client.clone(),
+            model: String::new(),
+            prompt: RenderedPrompt::Completion(String::new()),
+            request_options: Default::default(),
+            content: raw_text.to_string(),
+            start_time: web_time::SystemTime::now(),
+            latency: Default::default(),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: !partial,
+                finish_reason: None,
+                prompt_tokens: None,
+                output_tokens: None,
+                total_tokens: None,
+                cache_hit: false,
+            },
+            http: Default::default(),
+        });
+        let (parsed, response_with_constraints) = match renderer.parse(raw_text, partial) {
+            Ok(v) => (Some(Ok(v.clone())), Some(Ok(parsed_value_to_response(&v)))),
+            Err(e) => (None, Some(Err(e))),
+        };
+        Ok(FunctionResult::new(
+            ExecutionScope::Direct(client).into(),
+            response,
+            parsed,
+            response_with_constraints,
+        ))
+    }
+
     fn get_function<'ir>(
         &'ir self,
         function_name: &str,
@@ -291,6 +417,41 @@ impl InternalRuntimeInterface for InternalBamlRuntime {
     }
 }
 
+impl InternalBamlRuntime {
+    /// Batch jobs run on a single client: retries and fallbacks do not apply,
+    /// so the first node of the function's orchestration graph is used.
+    fn batch_node(
+        &self,
+        renderer: &PromptRenderer,
+        ctx: &RuntimeContext,
+    ) -> Result<OrchestratorNode> {
+        self.orchestration_graph(renderer.client_spec(), ctx)?
+            .into_iter()
+            .next()
+            .context(format!("No client found for {}", renderer.client_spec()))
+    }
+
+    fn batch_job_node(
+        &self,
+        job: &BatchJob,
+        ctx: &RuntimeContext,
+    ) -> Result<(PromptRenderer, OrchestratorNode)> {
+        let func = self.get_function(&job.function_name, ctx)?;
+        let renderer = PromptRenderer::from_function(&func, self.ir(), ctx)?;
+        let node = self.batch_node(&renderer, ctx)?;
+        if node.provider.name() != job.client {
+            anyhow::bail!(
+                "Batch job {} was submitted with client {}, but {} now uses client {}",
+                job.id,
+                job.client,
+                job.function_name,
+                node.provider.name()
+            );
+        }
+        Ok((renderer, node))
+    }
+}
+
 impl RuntimeConstructor for InternalBamlRuntime {
     fn from_file_content<T: AsRef<str>>(
         root_path: &str,
@@ -306,7 +467,7 @@ impl RuntimeConstructor for InternalBamlRuntime {
             })
             .collect::<Result<Vec<_>>>()?;
         let directory = PathBuf::from(root_path);
-        let mut schema = validate(&PathBuf::from(directory), contents);
+        let mut schema = validate(&PathBuf::from(directory), contents.clone());
         schema.diagnostics.to_result()?;
 
         let ir = IntermediateRepr::from_parser_database(&schema.db, schema.configuration)?;
@@ -316,6 +477,7 @@ impl RuntimeConstructor for InternalBamlRuntime {
         Ok(Self {
             ir: Arc::new(ir),
             diagnostics: schema.diagnostics,
+            source_files: contents,
             clients: Default::default(),
             retry_policies: Default::default(),
         })
@@ -426,4 +588,101 @@ impl RuntimeInterface for InternalBamlRuntime {
             tokio_runtime,
         })
     }
+
+    async fn submit_batch_impl(
+        &self,
+        function_name: String,
+        params: &[BamlMap<String, BamlValue>],
+        ctx: RuntimeContext,
+    ) -> Result<BatchJob> {
+        if params.is_empty() {
+            anyhow::bail!("A batch job needs at least one call of {}", function_name);
+        }
+        let func = self.get_function(&function_name, &ctx)?;
+        let renderer = PromptRenderer::from_function(&func, self.ir(), &ctx)?;
+        let node = self.batch_node(&renderer, &ctx)?;
+
+        let mut requests = Vec::with_capacity(params.len());
+        for (i, params) in params.iter().enumerate() {
+            let baml_args = self.ir().check_function_params(
+                &func,
+                params,
+                ArgCoercer {
+                    span_path: None,
+                    allow_implicit_cast_to_string: false,
+                },
+            )?;
+            let prompt = match node
+                .render_prompt(self.ir(), &renderer, &ctx, &baml_args)
+                .await?
+            {
+                RenderedPrompt::Chat(chat) => chat,
+                RenderedPrompt::Completion(_) => {
+                    anyhow::bail!("Batch jobs only support chat prompts")
+                }
+            };
+            requests.push(BatchRequest {
+                custom_id: format!("req-{}", i),
+                prompt,
+            });
+        }
+
+        let id = node.provider.submit_batch(&ctx, &requests).await?;
+        Ok(BatchJob {
+            id,
+            function_name,
+            client: node.provider.name().to_string(),
+            custom_ids: requests.into_iter().map(|r| r.custom_id).collect(),
+            response_format: Some(renderer.response_format().as_ref().to_string()),
+        })
+    }
+
+    async fn batch_status_impl(
+        &self,
+        job: &BatchJob,
+        ctx: RuntimeContext,
+    ) -> Result<BatchJobStatus> {
+        let (_, node) = self.batch_job_node(job, &ctx)?;
+        node.provider.batch_status(&ctx, &job.id).await
+    }
+
+    async fn batch_results_impl(
+        &self,
+        job: &BatchJob,
+        ctx: RuntimeContext,
+    ) -> Result<Vec<FunctionResult>> {
+        let (renderer, node) = self.batch_job_node(job, &ctx)?;
+        if let Some(format) = job.response_format.as_deref() {
+            renderer.record_response_format(format.parse()?);
+        }
+        let mut responses = node.provider.batch_results(&ctx, &job.id).await?;
+
+        Ok(job
+            .custom_ids
+            .iter()
+            .map(|custom_id| {
+                let response = responses.remove(custom_id).unwrap_or_else(|| {
+                    batch_failure(
+                        node.provider.name(),
+                        node.provider.request_options(),
+                        format!("Batch job {} has no result for {}", job.id, custom_id),
+                        ErrorCode::Other(2),
+                    )
+                });
+                let (parsed, response_with_constraints) = match &response {
+                    LLMResponse::Success(s) => match renderer.parse(&s.content, false) {
+                        Ok(v) => (Some(Ok(v.clone())), Some(Ok(parsed_value_to_response(&v)))),
+                        Err(e) => (None, Some(Err(e))),
+                    },
+                    _ => (None, None),
+                };
+                FunctionResult::new(
+                    node.scope.clone(),
+                    response,
+                    parsed,
+                    response_with_constraints,
+                )
+            })
+            .collect())
+    }
 }
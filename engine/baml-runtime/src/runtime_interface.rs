@@ -8,14 +8,16 @@ use std::{collections::HashMap, sync::Arc};
 
 use crate::internal::llm_client::llm_provider::LLMProvider;
 use crate::internal::llm_client::orchestrator::{OrchestrationScope, OrchestratorNode};
+use crate::internal::llm_client::raw_http::RawHttpRequest;
 use crate::internal::llm_client::AllowedMetadata;
+use crate::internal::tokenizer::TokenCount;
 use crate::tracing::{BamlTracer, TracingSpan};
 use crate::types::on_log_event::LogEventCallbackSync;
 use crate::{
     internal::{ir_features::IrFeatures, llm_client::retry_policy::CallablePolicy},
     runtime::InternalBamlRuntime,
     types::FunctionResultStream,
-    FunctionResult, RuntimeContext,
+    BatchJob, BatchJobStatus, FunctionResult, RuntimeContext,
 };
 use crate::{RenderCurlSettings, RuntimeContextManager};
 
@@ -47,6 +49,28 @@ pub trait RuntimeInterface {
         ctx: RuntimeContext,
         #[cfg(not(target_arch = "wasm32"))] tokio_runtime: Arc<tokio::runtime::Runtime>,
     ) -> Result<FunctionResultStream>;
+
+    #[allow(async_fn_in_trait)]
+    async fn submit_batch_impl(
+        &self,
+        function_name: String,
+        params: &[BamlMap<String, BamlValue>],
+        ctx: RuntimeContext,
+    ) -> Result<BatchJob>;
+
+    #[allow(async_fn_in_trait)]
+    async fn batch_status_impl(
+        &self,
+        job: &BatchJob,
+        ctx: RuntimeContext,
+    ) -> Result<BatchJobStatus>;
+
+    #[allow(async_fn_in_trait)]
+    async fn batch_results_impl(
+        &self,
+        job: &BatchJob,
+        ctx: RuntimeContext,
+    ) -> Result<Vec<FunctionResult>>;
 }
 
 //
@@ -151,6 +175,39 @@ pub trait InternalRuntimeInterface {
         node_index: Option<usize>,
     ) -> Result<String>;
 
+    /// Counts the tokens of a prompt from `render_prompt`, with the tokenizer
+    /// of the client at `node_index`.
+    fn count_tokens(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        node_index: Option<usize>,
+    ) -> Result<TokenCount>;
+
+    /// Renders the prompt of `function_name` and builds the HTTP request the
+    /// client at `node_index` would send for it, without sending it.
+    #[allow(async_fn_in_trait)]
+    async fn build_request(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        params: &BamlMap<String, BamlValue>,
+        stream: bool,
+        node_index: Option<usize>,
+    ) -> Result<RawHttpRequest>;
+
+    /// Parses `raw_text` as the response of `function_name`, evaluating its
+    /// checks and asserts. With `partial`, `raw_text` may be an incomplete
+    /// streamed response.
+    fn parse_response(
+        &self,
+        function_name: &str,
+        ctx: &RuntimeContext,
+        raw_text: &str,
+        partial: bool,
+    ) -> Result<FunctionResult>;
+
     fn ir(&self) -> &IntermediateRepr;
 
     fn get_test_params(
@@ -608,7 +608,7 @@ impl
             .iter()
             .map(|ctx| EventChain {
                 function_name: ctx.name.clone(),
-                variant_name: None,
+                variant_name: ctx.variant_name.clone(),
             })
             .collect::<Vec<_>>();
         LogSchemaContext {
@@ -956,6 +956,30 @@ impl From<&internal_baml_jinja::ChatMessagePart> for ContentPart {
                     (BamlMediaType::Audio, baml_types::BamlMediaContent::Url(data)) => {
                         ContentPart::UrlAudio(data.url.clone())
                     }
+                    (BamlMediaType::Pdf, baml_types::BamlMediaContent::File(data)) => {
+                        ContentPart::FilePdf(
+                            data.span_path.to_string_lossy().into_owned(),
+                            data.relpath.to_string_lossy().into_owned(),
+                        )
+                    }
+                    (BamlMediaType::Pdf, baml_types::BamlMediaContent::Base64(data)) => {
+                        ContentPart::B64Pdf(data.base64.clone())
+                    }
+                    (BamlMediaType::Pdf, baml_types::BamlMediaContent::Url(data)) => {
+                        ContentPart::UrlPdf(data.url.clone())
+                    }
+                    (BamlMediaType::Video, baml_types::BamlMediaContent::File(data)) => {
+                        ContentPart::FileVideo(
+                            data.span_path.to_string_lossy().into_owned(),
+                            data.relpath.to_string_lossy().into_owned(),
+                        )
+                    }
+                    (BamlMediaType::Video, baml_types::BamlMediaContent::Base64(data)) => {
+                        ContentPart::B64Video(data.base64.clone())
+                    }
+                    (BamlMediaType::Video, baml_types::BamlMediaContent::Url(data)) => {
+                        ContentPart::UrlVideo(data.url.clone())
+                    }
                 }
             }
             internal_baml_jinja::ChatMessagePart::WithMeta(inner, meta) => ContentPart::WithMeta(
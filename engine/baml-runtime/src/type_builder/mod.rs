@@ -1,9 +1,14 @@
 use std::sync::{Arc, Mutex};
 
+use anyhow::Result;
 use baml_types::{BamlValue, FieldType};
 use indexmap::IndexMap;
+use internal_baml_core::ir::{repr::IntermediateRepr, IRHelper};
 
-use crate::runtime_context::{PropertyAttributes, RuntimeClassOverride, RuntimeEnumOverride};
+use crate::{
+    runtime_context::{PropertyAttributes, RuntimeClassOverride, RuntimeEnumOverride},
+    BamlRuntime,
+};
 
 type MetaData = Arc<Mutex<IndexMap<String, BamlValue>>>;
 
@@ -56,6 +61,7 @@ impl<T: Meta> From<&Arc<Mutex<T>>> for PropertyAttributes {
 
 pub struct ClassBuilder {
     properties: Arc<Mutex<IndexMap<String, Arc<Mutex<ClassPropertyBuilder>>>>>,
+    order: Arc<Mutex<Vec<String>>>,
     meta: MetaData,
 }
 impl_meta!(ClassBuilder);
@@ -77,6 +83,7 @@ impl ClassBuilder {
     pub fn new() -> Self {
         Self {
             properties: Default::default(),
+            order: Default::default(),
             meta: Arc::new(Mutex::new(Default::default())),
         }
     }
@@ -90,6 +97,13 @@ impl ClassBuilder {
             }))
         }))
     }
+
+    /// Renders the given properties first, in this order. Properties that
+    /// are not listed keep their original relative order after them.
+    pub fn reorder_properties(&self, names: Vec<String>) -> &Self {
+        *self.order.lock().unwrap() = names;
+        self
+    }
 }
 
 pub struct EnumBuilder {
@@ -111,13 +125,29 @@ impl EnumBuilder {
         }
     }
 
+    /// Adds a value to the enum, or returns the existing one. Asking for a
+    /// value that was removed adds it back.
     pub fn value(&self, name: &str) -> Arc<Mutex<EnumValueBuilder>> {
         let mut values = self.values.lock().unwrap();
-        Arc::clone(values.entry(name.to_string()).or_insert_with(|| {
+        let value = values.entry(name.to_string()).or_insert_with(|| {
             Arc::new(Mutex::new(EnumValueBuilder {
                 meta: Default::default(),
             }))
-        }))
+        });
+        value.lock().unwrap().meta.lock().unwrap().shift_remove("skip");
+        Arc::clone(value)
+    }
+
+    /// Skips a value of the enum for this call, so it is left out of the
+    /// prompt and the parser. The value is kept in the builder, marked with
+    /// `skip`, whether it was declared in BAML or added through the builder,
+    /// until `value` adds it back.
+    pub fn remove_value(&self, name: &str) -> &Self {
+        self.value(name)
+            .lock()
+            .unwrap()
+            .with_meta("skip", BamlValue::Bool(true));
+        self
     }
 }
 
@@ -188,6 +218,108 @@ impl TypeBuilder {
         )
     }
 
+    /// Adds the classes and enums declared in `baml` to this builder. The
+    /// source is validated together with the runtime's own BAML files and the
+    /// types this builder already created, so it can refer to their types, and
+    /// a declaration named after a `@@dynamic` class or enum adds its fields or
+    /// values to that type.
+    pub fn add_baml(&self, baml: &str, rt: &BamlRuntime) -> Result<()> {
+        let existing = self.created_types_baml(&rt.inner.ir);
+        let (ir, declared) = rt.inner.validate_type_builder_snippet(baml, &existing)?;
+        let env = rt.env_vars();
+
+        for (name, members) in declared {
+            if let Ok(class) = ir.find_class(&name) {
+                let cls = self.class(&name);
+                let cls = cls.lock().unwrap();
+                if let Some(alias) = class.alias(env)? {
+                    cls.with_meta("alias", BamlValue::String(alias));
+                }
+                for member in members {
+                    let Some(field) = class.find_field(&member) else {
+                        continue;
+                    };
+                    let property = cls.property(&member);
+                    let property = property.lock().unwrap();
+                    property.r#type(field.r#type().clone());
+                    if let Some(alias) = field.alias(env)? {
+                        property.with_meta("alias", BamlValue::String(alias));
+                    }
+                    if let Some(description) = field.description(env)? {
+                        property.with_meta("description", BamlValue::String(description));
+                    }
+                }
+            } else if let Ok(r#enum) = ir.find_enum(&name) {
+                let enm = self.r#enum(&name);
+                let enm = enm.lock().unwrap();
+                if let Some(alias) = r#enum.alias(env)? {
+                    enm.with_meta("alias", BamlValue::String(alias));
+                }
+                for member in members {
+                    let Some(value) = r#enum.find_value(&member) else {
+                        continue;
+                    };
+                    let builder = enm.value(&member);
+                    let builder = builder.lock().unwrap();
+                    if let Some(alias) = value.alias(env)? {
+                        builder.with_meta("alias", BamlValue::String(alias));
+                    }
+                    if let Some(description) = value.description(env)? {
+                        builder.with_meta("description", BamlValue::String(description));
+                    }
+                    if value.skip(env)? {
+                        builder.with_meta("skip", BamlValue::Bool(true));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// BAML declaring the classes and enums this builder created, which are
+    /// not in `ir`. They are `@@dynamic`, since BAML added later may extend
+    /// them. Names BAML can't spell are left out: BAML can't refer to them
+    /// either.
+    fn created_types_baml(&self, ir: &IntermediateRepr) -> String {
+        let (classes, enums) = self.to_overrides();
+        let mut baml = String::new();
+        for (name, cls) in classes
+            .iter()
+            .filter(|(name, _)| is_identifier(name) && ir.find_class(name).is_err())
+        {
+            baml.push_str(&format!("class {} {{\n", name));
+            for (field, (r#type, _)) in cls
+                .new_fields
+                .iter()
+                .filter(|(field, (r#type, _))| is_identifier(field) && is_spellable(r#type))
+            {
+                baml.push_str(&format!("  {} {}\n", field, r#type));
+            }
+            baml.push_str("  @@dynamic\n}\n\n");
+        }
+        for (name, enm) in enums
+            .iter()
+            .filter(|(name, _)| is_identifier(name) && ir.find_enum(name).is_err())
+        {
+            let values = enm
+                .values
+                .keys()
+                .filter(|value| is_identifier(value))
+                .collect::<Vec<_>>();
+            // An enum needs at least one value to be declared.
+            if values.is_empty() {
+                continue;
+            }
+            baml.push_str(&format!("enum {} {{\n", name));
+            for value in values {
+                baml.push_str(&format!("  {}\n", value));
+            }
+            baml.push_str("  @@dynamic\n}\n\n");
+        }
+        baml
+    }
+
     pub fn to_overrides(
         &self,
     ) -> (
@@ -202,15 +334,16 @@ impl TypeBuilder {
             .iter()
             .map(|(name, cls)| {
                 log::debug!("Converting class: {}", name);
+                let attributes = PropertyAttributes::from(cls);
+                let cls = cls.lock().unwrap();
                 let mut overrides = RuntimeClassOverride {
-                    alias: None,
+                    alias: attributes.alias,
                     new_fields: Default::default(),
                     update_fields: Default::default(),
+                    order: cls.order.lock().unwrap().clone(),
                 };
 
-                cls.lock()
-                    .unwrap()
-                    .properties
+                cls.properties
                     .lock()
                     .unwrap()
                     .iter()
@@ -274,6 +407,26 @@ impl TypeBuilder {
     }
 }
 
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Whether every class and enum `r#type` refers to has a name BAML can spell.
+fn is_spellable(r#type: &FieldType) -> bool {
+    match r#type {
+        FieldType::Class(name) | FieldType::Enum(name) => is_identifier(name),
+        FieldType::Primitive(_) | FieldType::Literal(_) => true,
+        FieldType::List(inner) | FieldType::Optional(inner) => is_spellable(inner),
+        FieldType::Map(key, value) => is_spellable(key) && is_spellable(value),
+        FieldType::Union(items) | FieldType::Tuple(items) => items.iter().all(is_spellable),
+        FieldType::Constrained { base, .. } => is_spellable(base),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +445,205 @@ mod tests {
             .r#type(FieldType::int())
             .with_meta("alias", BamlValue::String("years".to_string()));
     }
+
+    fn add_baml_runtime() -> BamlRuntime {
+        let files = vec![(
+            "main.baml",
+            r#"
+            class Person {
+              name string
+              @@dynamic
+            }
+
+            enum Mood {
+              Happy
+              @@dynamic
+            }
+
+            class Fixed {
+              value int
+            }"#,
+        )]
+        .into_iter()
+        .collect();
+        BamlRuntime::from_file_content(
+            "baml_src",
+            &files,
+            std::collections::HashMap::<&str, &str>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_baml() {
+        let runtime = add_baml_runtime();
+
+        let builder = TypeBuilder::new();
+        builder
+            .add_baml(
+                r#"
+                class Address {
+                  street string @alias("street_name")
+                  owner Person?
+                }
+
+                class Person {
+                  address Address @description("where they live")
+                  mood Mood
+                }
+
+                enum Mood {
+                  Sad @skip
+                }
+                "#,
+                &runtime,
+            )
+            .unwrap();
+
+        let (classes, enums) = builder.to_overrides();
+
+        let address = &classes["Address"].new_fields;
+        assert_eq!(address["street"].0, FieldType::string());
+        assert_eq!(
+            address["street"].1.alias,
+            Some(BamlValue::String("street_name".to_string()))
+        );
+        assert_eq!(address["owner"].0, FieldType::class("Person").as_optional());
+
+        let person = &classes["Person"].new_fields;
+        assert_eq!(person.keys().collect::<Vec<_>>(), vec!["address", "mood"]);
+        assert_eq!(
+            person["address"].1.meta.get("description"),
+            Some(&BamlValue::String("where they live".to_string()))
+        );
+
+        assert_eq!(enums["Mood"].values["Sad"].skip, Some(true));
+
+        let err = builder
+            .add_baml("class Fixed {\n  other int\n}", &runtime)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`Fixed` is not marked `@@dynamic`"));
+
+        let err = builder
+            .add_baml("class Extra {\n  missing Missing\n}", &runtime)
+            .unwrap_err();
+        assert!(err.to_string().contains("type_builder.baml:2"));
+    }
+
+    #[test]
+    fn test_add_baml_refers_to_types_of_the_builder() {
+        let runtime = add_baml_runtime();
+
+        let builder = TypeBuilder::new();
+        builder
+            .add_baml("class Address {\n  street string\n}", &runtime)
+            .unwrap();
+        builder
+            .add_baml("class Person {\n  address Address\n}", &runtime)
+            .unwrap();
+
+        builder.r#enum("Tier").lock().unwrap().value("Gold");
+        builder
+            .add_baml("class Address {\n  tier Tier\n}", &runtime)
+            .unwrap();
+
+        let (classes, _) = builder.to_overrides();
+        assert_eq!(
+            classes["Person"].new_fields["address"].0,
+            FieldType::class("Address")
+        );
+        assert_eq!(
+            classes["Address"].new_fields.keys().collect::<Vec<_>>(),
+            vec!["street", "tier"]
+        );
+        assert_eq!(
+            classes["Address"].new_fields["tier"].0,
+            FieldType::r#enum("Tier")
+        );
+    }
+
+    #[test]
+    fn test_add_baml_skips_names_baml_cant_spell() {
+        let runtime = add_baml_runtime();
+
+        let builder = TypeBuilder::new();
+        builder
+            .class("Odd Name")
+            .lock()
+            .unwrap()
+            .property("x")
+            .lock()
+            .unwrap()
+            .r#type(FieldType::int());
+        let address = builder.class("Address");
+        let address = address.lock().unwrap();
+        address
+            .property("first line")
+            .lock()
+            .unwrap()
+            .r#type(FieldType::string());
+        address
+            .property("odd")
+            .lock()
+            .unwrap()
+            .r#type(FieldType::class("Odd Name"));
+        address
+            .property("zip")
+            .lock()
+            .unwrap()
+            .r#type(FieldType::string());
+        drop(address);
+        builder.r#enum("Tier").lock().unwrap().value("Gold Plus");
+
+        builder
+            .add_baml("class Person {\n  address Address\n}", &runtime)
+            .unwrap();
+        let err = builder
+            .add_baml("class Person {\n  tier Tier\n}", &runtime)
+            .unwrap_err();
+        assert!(err.to_string().contains("type_builder.baml:2"));
+    }
+
+    #[test]
+    fn test_add_baml_rejects_attributes_overrides_cant_carry() {
+        let runtime = add_baml_runtime();
+
+        let builder = TypeBuilder::new();
+        for (baml, attribute) in [
+            (
+                "class Circle {\n  kind \"circle\"\n  @@tag(\"kind\")\n}",
+                "`@@tag`",
+            ),
+            ("class Task {\n  done bool @default(false)\n}", "`@default`"),
+            (
+                "class Range {\n  low int\n  high int\n  @@assert({{ this.low < this.high }})\n}",
+                "`@@assert`",
+            ),
+        ] {
+            let err = builder.add_baml(baml, &runtime).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("{attribute} can't be added through a TypeBuilder")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_enum_value_added_back_after_remove() {
+        let builder = TypeBuilder::new();
+        let mood = builder.r#enum("Mood");
+        let mood = mood.lock().unwrap();
+        mood.value("Sad");
+        mood.remove_value("Sad");
+        mood.remove_value("Happy");
+        mood.value("Sad");
+        drop(mood);
+
+        let (_, enums) = builder.to_overrides();
+        assert_eq!(enums["Mood"].values["Sad"].skip, None);
+        assert_eq!(enums["Mood"].values["Happy"].skip, Some(true));
+    }
 }
@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A handle to a batch job submitted to a provider's batch API.
+///
+/// The handle is plain data, so it can be serialized with [`BatchJob::to_json`],
+/// persisted, and loaded again in another process with [`BatchJob::from_json`]
+/// to poll the job or collect its results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchJob {
+    /// The id the provider assigned to the batch.
+    pub id: String,
+    pub function_name: String,
+    /// The name of the client the batch was submitted with.
+    pub client: String,
+    /// One id per submitted call, in the order the calls were submitted.
+    pub custom_ids: Vec<String>,
+    /// The format the prompts asked the LLM to answer in, e.g. `yaml`.
+    /// Missing means JSON.
+    #[serde(default)]
+    pub response_format: Option<String>,
+}
+
+impl BatchJob {
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).context("Failed to serialize batch job")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("Failed to parse batch job")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BatchJobStatus {
+    /// The provider is still validating or processing the batch.
+    InProgress,
+    /// Results are ready to be collected.
+    Completed,
+    Failed {
+        message: String,
+    },
+    Cancelled,
+    Expired,
+}
+
+impl BatchJobStatus {
+    /// Whether the job will not change status anymore.
+    pub fn is_done(&self) -> bool {
+        !matches!(self, BatchJobStatus::InProgress)
+    }
+}
+
+impl std::fmt::Display for BatchJobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchJobStatus::InProgress => write!(f, "in_progress"),
+            BatchJobStatus::Completed => write!(f, "completed"),
+            BatchJobStatus::Failed { message } => write!(f, "failed: {}", message),
+            BatchJobStatus::Cancelled => write!(f, "cancelled"),
+            BatchJobStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_job_roundtrip() {
+        let job = BatchJob {
+            id: "batch_abc123".to_string(),
+            function_name: "ExtractResume".to_string(),
+            client: "GPT4o".to_string(),
+            custom_ids: vec!["req-0".to_string(), "req-1".to_string()],
+            response_format: Some("yaml".to_string()),
+        };
+        let json = job.to_json().unwrap();
+        assert_eq!(BatchJob::from_json(&json).unwrap(), job);
+    }
+}
@@ -4,14 +4,24 @@ use std::{
 };
 
 use anyhow::{Context, Result};
-use baml_types::BamlValue;
+use baml_types::{BamlValue, ParserMode};
 use std::fmt;
 
-use crate::{client_registry::ClientRegistry, type_builder::TypeBuilder, RuntimeContext, SpanCtx};
+use crate::{
+    client_registry::ClientRegistry, request_hook::RequestHooks, type_builder::TypeBuilder,
+    RuntimeContext, SpanCtx,
+};
 
 use super::runtime_context::BamlSrcReader;
 
-type BamlContext = (uuid::Uuid, String, HashMap<String, BamlValue>);
+/// A span on the context stack: its id, name, the variant of the function it
+/// calls (if any) and its tags.
+type BamlContext = (
+    uuid::Uuid,
+    String,
+    Option<String>,
+    HashMap<String, BamlValue>,
+);
 
 #[derive(Clone)]
 pub struct RuntimeContextManager {
@@ -20,6 +30,10 @@ pub struct RuntimeContextManager {
     context: Arc<Mutex<Vec<BamlContext>>>,
     env_vars: HashMap<String, String>,
     global_tags: Arc<Mutex<HashMap<String, BamlValue>>>,
+    /// The variant selected for each function, by function name.
+    variants: Arc<Mutex<HashMap<String, String>>>,
+    parse_thresholds: Arc<Mutex<jsonish::ParseThresholds>>,
+    request_hooks: RequestHooks,
 }
 
 impl fmt::Debug for RuntimeContextManager {
@@ -39,6 +53,9 @@ impl RuntimeContextManager {
             context: Arc::new(Mutex::new(self.context.lock().unwrap().clone())),
             env_vars: self.env_vars.clone(),
             global_tags: Arc::new(Mutex::new(self.global_tags.lock().unwrap().clone())),
+            variants: Arc::new(Mutex::new(self.variants.lock().unwrap().clone())),
+            parse_thresholds: Arc::new(Mutex::new(self.parse_thresholds.lock().unwrap().clone())),
+            request_hooks: self.request_hooks.clone(),
         }
     }
 
@@ -51,9 +68,19 @@ impl RuntimeContextManager {
             context: Default::default(),
             env_vars,
             global_tags: Default::default(),
+            variants: Default::default(),
+            parse_thresholds: Default::default(),
+            request_hooks: Default::default(),
         }
     }
 
+    /// Shares the runtime's request hooks with every context created by this
+    /// manager.
+    pub(crate) fn with_request_hooks(mut self, request_hooks: RequestHooks) -> Self {
+        self.request_hooks = request_hooks;
+        self
+    }
+
     pub fn upsert_tags(&self, tags: HashMap<String, BamlValue>) {
         let mut ctx = self.context.lock().unwrap();
         if let Some((.., last_tags)) = ctx.last_mut() {
@@ -68,17 +95,61 @@ impl RuntimeContextManager {
             .lock()
             .unwrap()
             .last()
-            .map(|(_, _, tags)| tags.clone())
+            .map(|(.., tags)| tags.clone())
             .unwrap_or_default()
     }
 
+    /// Selects the variant of `function_name` that calls made through this
+    /// context use, instead of splitting them between its variants.
+    pub fn set_variant(&self, function_name: &str, variant: &str) {
+        self.variants
+            .lock()
+            .unwrap()
+            .insert(function_name.to_string(), variant.to_string());
+    }
+
+    pub fn clear_variant(&self, function_name: &str) {
+        self.variants.lock().unwrap().remove(function_name);
+    }
+
+    /// Rejects parses of final responses that score above `max_score` or
+    /// apply any of `forbidden_flags`, instead of returning them.
+    pub fn set_parse_thresholds(
+        &self,
+        max_score: Option<i32>,
+        forbidden_flags: Vec<String>,
+    ) -> Result<()> {
+        *self.parse_thresholds.lock().unwrap() =
+            jsonish::ParseThresholds::new(max_score, forbidden_flags)?;
+        Ok(())
+    }
+
+    /// Records the parser mode of the function called by the current span as
+    /// its `baml.parser_mode` tag.
+    pub(crate) fn record_parser_mode(&self, mode: ParserMode) {
+        if let Some((.., tags)) = self.context.lock().unwrap().last_mut() {
+            tags.insert(
+                "baml.parser_mode".to_string(),
+                BamlValue::String(mode.to_string()),
+            );
+        }
+    }
+
+    /// Records the variant called by the current span, so it shows up in its
+    /// trace.
+    pub(crate) fn record_variant(&self, variant: &str) {
+        if let Some((_, _, last_variant, _)) = self.context.lock().unwrap().last_mut() {
+            *last_variant = Some(variant.to_string());
+        }
+    }
+
     pub fn enter(&self, name: &str) -> uuid::Uuid {
         let last_tags = self.clone_last_tags();
         let span = uuid::Uuid::new_v4();
         self.context
             .lock()
             .unwrap()
-            .push((span.clone(), name.to_string(), last_tags));
+            .push((span.clone(), name.to_string(), None, last_tags));
         log::trace!("Entering with: {:#?}", self.context.lock().unwrap());
         span
     }
@@ -89,12 +160,13 @@ impl RuntimeContextManager {
 
         let prev = ctx
             .iter()
-            .map(|(span, name, _)| SpanCtx {
+            .map(|(span, name, variant, _)| SpanCtx {
                 span_id: span.clone(),
                 name: name.clone(),
+                variant_name: variant.clone(),
             })
             .collect();
-        let Some((id, _, mut tags)) = ctx.pop() else {
+        let Some((id, _, _, mut tags)) = ctx.pop() else {
             return None;
         };
 
@@ -134,8 +206,11 @@ impl RuntimeContextManager {
             env: self.env_vars.clone(),
             tags,
             client_overrides: Default::default(),
+            variants: self.variants.lock().unwrap().clone(),
+            parse_thresholds: self.parse_thresholds.lock().unwrap().clone(),
             class_override: cls,
             enum_overrides: enm,
+            request_hooks: self.request_hooks.lock().unwrap().clone(),
         };
 
         let client_overrides = match cb {
@@ -166,8 +241,11 @@ impl RuntimeContextManager {
             env: env_vars.collect(),
             tags: ctx.last().map(|(.., x)| x).cloned().unwrap_or_default(),
             client_overrides: Default::default(),
+            variants: self.variants.lock().unwrap().clone(),
+            parse_thresholds: self.parse_thresholds.lock().unwrap().clone(),
             class_override: Default::default(),
             enum_overrides: Default::default(),
+            request_hooks: self.request_hooks.lock().unwrap().clone(),
         }
     }
 
@@ -1,11 +1,14 @@
+mod batch;
 mod context_manager;
 mod expression_helper;
 pub mod on_log_event;
+pub mod request_hook;
 mod response;
 pub(crate) mod runtime_context;
 mod stream;
 mod trace_stats;
 
+pub use batch::{BatchJob, BatchJobStatus};
 pub use context_manager::RuntimeContextManager;
 pub use response::{FunctionResult, TestFailReason, TestResponse, TestStatus};
 pub use runtime_context::{RuntimeContext, SpanCtx};
@@ -0,0 +1,65 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// An outgoing LLM request, as seen by a [`RequestHook`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HookRequest {
+    /// The name of the client making the request.
+    pub client: String,
+    pub url: String,
+    pub headers: IndexMap<String, String>,
+    /// The JSON body. Changes made by a hook are sent to the provider.
+    pub body: serde_json::Value,
+}
+
+/// A raw HTTP response from an LLM provider, as seen by a [`RequestHook`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HookResponse {
+    /// The name of the client which made the request.
+    pub client: String,
+    pub url: String,
+    pub status: u16,
+    pub headers: IndexMap<String, String>,
+    /// The response body. `None` for successful streaming responses, whose
+    /// body is consumed incrementally.
+    pub body: Option<String>,
+}
+
+/// Middleware around every HTTP request a primitive LLM client sends.
+///
+/// Hooks run in registration order. An error from `on_request` fails the
+/// request; errors from `on_response` are logged and otherwise ignored.
+///
+/// `on_request` is async so hooks implemented in another language can wait
+/// on their host's event loop without blocking the runtime.
+pub trait RequestHook: Send + Sync {
+    fn on_request<'a>(&'a self, _request: &'a mut HookRequest) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn on_response(&self, _response: &HookResponse) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The hooks registered on a runtime, shared with every context manager it
+/// creates so hooks registered later still apply.
+pub type RequestHooks = Arc<Mutex<Vec<Arc<dyn RequestHook>>>>;
+
+pub(crate) fn header_map_to_index_map(
+    headers: &reqwest::header::HeaderMap,
+) -> IndexMap<String, String> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            (
+                k.to_string(),
+                String::from_utf8_lossy(v.as_bytes()).to_string(),
+            )
+        })
+        .collect()
+}
@@ -2,13 +2,17 @@ pub use crate::internal::llm_client::LLMResponse;
 use crate::{
     constraints::TestConstraintsResult,
     errors::ExposedError,
-    internal::llm_client::{orchestrator::OrchestrationScope, ResponseBamlValue},
+    internal::llm_client::{
+        orchestrator::OrchestrationScope,
+        raw_http::{RawHttp, RawHttpAttempt},
+        ResponseBamlValue,
+    },
 };
 use anyhow::Result;
 use colored::*;
 
 use baml_types::BamlValue;
-use jsonish::BamlValueWithFlags;
+use jsonish::{BamlValueWithFlags, ParseReport};
 
 #[derive(Debug)]
 pub struct FunctionResult {
@@ -99,6 +103,30 @@ impl FunctionResult {
         &self.event_chain.last().unwrap().0
     }
 
+    /// The HTTP request and response of every attempt, oldest first.
+    ///
+    /// Attempts which never reached the provider (e.g. cache hits or
+    /// rendering failures) have neither a request nor a response.
+    pub fn raw_http(&self) -> Vec<RawHttpAttempt<'_>> {
+        static EMPTY: RawHttp = RawHttp {
+            request: None,
+            response: None,
+        };
+        self.event_chain
+            .iter()
+            .map(|(scope, response, _, _)| RawHttpAttempt {
+                client: scope.name(),
+                http: response.http().unwrap_or(&EMPTY),
+            })
+            .collect()
+    }
+
+    /// The flags the parser applied to coerce the last response into the
+    /// return type, and their total score.
+    pub fn parse_report(&self) -> Result<ParseReport> {
+        Ok(self.parsed_content()?.report())
+    }
+
     pub fn parsed(&self) -> &Option<Result<BamlValueWithFlags>> {
         &self.event_chain.last().unwrap().2
     }
@@ -7,11 +7,13 @@ use serde_json;
 use std::{collections::HashMap, sync::Arc};
 
 use crate::internal::llm_client::llm_provider::LLMProvider;
+use crate::request_hook::RequestHook;
 
 #[derive(Debug, Clone)]
 pub struct SpanCtx {
     pub span_id: uuid::Uuid,
     pub name: String,
+    pub variant_name: Option<String>,
 }
 
 #[derive(Debug)]
@@ -32,6 +34,8 @@ pub struct RuntimeClassOverride {
     pub(crate) alias: Option<BamlValue>,
     pub(crate) new_fields: IndexMap<String, (FieldType, PropertyAttributes)>,
     pub(crate) update_fields: IndexMap<String, PropertyAttributes>,
+    /// Fields to render first, in this order.
+    pub(crate) order: Vec<String>,
 }
 
 // #[cfg(target_arch = "wasm32")]
@@ -56,8 +60,14 @@ pub struct RuntimeContext {
     pub env: HashMap<String, String>,
     pub tags: HashMap<String, BamlValue>,
     pub client_overrides: Option<(Option<String>, HashMap<String, Arc<LLMProvider>>)>,
+    /// The variant to call for each function, by function name. Functions
+    /// that aren't listed call their default prompt and client.
+    pub variants: HashMap<String, String>,
+    /// Parses of final responses beyond these limits are rejected.
+    pub parse_thresholds: jsonish::ParseThresholds,
     pub class_override: IndexMap<String, RuntimeClassOverride>,
     pub enum_overrides: IndexMap<String, RuntimeEnumOverride>,
+    pub request_hooks: Vec<Arc<dyn RequestHook>>,
 }
 
 impl RuntimeContext {
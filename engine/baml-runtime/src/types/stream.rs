@@ -90,6 +90,10 @@ impl FunctionResultStream {
         let span = self
             .tracer
             .start_span(&self.function_name, ctx, &local_params);
+        if let Some(variant) = self.renderer.variant() {
+            ctx.record_variant(variant);
+        }
+        ctx.record_parser_mode(self.renderer.parser_mode());
 
         let rctx = ctx.create_ctx(tb, cb);
         let res = match rctx {
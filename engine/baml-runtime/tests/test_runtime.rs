@@ -507,4 +507,315 @@ test TestTree {
 
         Ok(())
     }
+
+    #[test]
+    fn test_build_request_and_parse_response() -> anyhow::Result<()> {
+        let runtime = make_test_runtime(
+            r##"
+class Person {
+  name string
+  age int @assert(adult, {{ this >= 18 }})
+}
+
+function ExtractPerson(text: string) -> Person {
+  client "openai/gpt-4o"
+  prompt #"
+    {{ _.role("user") }}
+    Extract the person from: {{ text }}
+
+    {{ ctx.output_format }}
+  "#
+}
+        "##,
+        )?;
+
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+        let params = [("text".to_string(), BamlValue::String("Ada, 36".to_string()))]
+            .into_iter()
+            .collect();
+
+        let request = runtime.async_runtime.block_on(runtime.build_request(
+            "ExtractPerson",
+            &params,
+            &ctx,
+            None,
+            None,
+            false,
+        ))?;
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "https://api.openai.com/v1/chat/completions");
+        assert_eq!(request.headers["authorization"], "Bearer OPENAI_API_KEY");
+        assert_eq!(request.body["model"], "gpt-4o");
+        assert!(request.body["messages"][0]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Extract the person from: Ada, 36"));
+
+        let result = runtime.parse_response(
+            "ExtractPerson",
+            r#"{"name": "Ada", "age": 36}"#,
+            false,
+            &ctx,
+            None,
+            None,
+        )?;
+        let parsed: BamlValue = result.result_with_constraints_content()?.clone().into();
+        assert_eq!(
+            serde_json::to_value(parsed)?,
+            serde_json::json!({"name": "Ada", "age": 36})
+        );
+
+        let result = runtime.parse_response(
+            "ExtractPerson",
+            r#"{"name": "Ada", "age": 12}"#,
+            false,
+            &ctx,
+            None,
+            None,
+        )?;
+        assert!(result.result_with_constraints_content().is_err());
+
+        let result =
+            runtime.parse_response("ExtractPerson", r#"{"name": "Ad"#, true, &ctx, None, None)?;
+        assert!(result.parsed_content().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_thresholds() -> anyhow::Result<()> {
+        let runtime = make_test_runtime(
+            r##"
+class Person {
+  name string
+  age int?
+}
+
+function ExtractPerson(text: string) -> Person {
+  client "openai/gpt-4o"
+  prompt #"
+    Extract the person from: {{ text }}
+
+    {{ ctx.output_format }}
+  "#
+}
+        "##,
+        )?;
+
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+        let output = "```json\n{\"name\": \"Ada\", \"age\": \"unknown\"}\n```";
+
+        let result = runtime.parse_response("ExtractPerson", output, false, &ctx, None, None)?;
+        let report = result.parse_report()?;
+        assert!(report.score > 0);
+        assert!(report
+            .flags
+            .iter()
+            .any(|f| f.path == "$.age" && f.flag == "DefaultButHadUnparseableValue"));
+
+        ctx.set_parse_thresholds(Some(report.score), vec![])?;
+        let result = runtime.parse_response("ExtractPerson", output, false, &ctx, None, None)?;
+        assert!(result.result_with_constraints_content().is_ok());
+
+        ctx.set_parse_thresholds(Some(report.score - 1), vec![])?;
+        let result = runtime.parse_response("ExtractPerson", output, false, &ctx, None, None)?;
+        assert!(result.result_with_constraints_content().is_err());
+
+        // Partial results are never rejected.
+        let result = runtime.parse_response("ExtractPerson", output, true, &ctx, None, None)?;
+        assert!(result.parsed_content().is_ok());
+
+        ctx.set_parse_thresholds(None, vec!["ObjectFromMarkdown".to_string()])?;
+        let result = runtime.parse_response("ExtractPerson", output, false, &ctx, None, None)?;
+        assert!(result.result_with_constraints_content().is_err());
+
+        assert!(ctx
+            .set_parse_thresholds(None, vec!["NotAFlag".to_string()])
+            .is_err());
+
+        Ok(())
+    }
+
+    /// Serves the OpenAI file and batch endpoints a batch job goes through.
+    /// The batch completes on the second status poll, with a result for
+    /// `req-0` in the output file and a failure for `req-1` in the error file.
+    async fn serve_stub_batch_api() -> anyhow::Result<String> {
+        use axum::{
+            extract::{Path, State},
+            routing::{get, post},
+            Json, Router,
+        };
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Default)]
+        struct Stub {
+            input: Mutex<String>,
+            polls: Mutex<u32>,
+        }
+
+        let stub = Arc::new(Stub::default());
+        let app = Router::new()
+            .route(
+                "/v1/files",
+                post(|State(stub): State<Arc<Stub>>, body: String| async move {
+                    *stub.input.lock().unwrap() = body;
+                    Json(serde_json::json!({"id": "file-in"}))
+                }),
+            )
+            .route(
+                "/v1/batches",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["input_file_id"], "file-in");
+                    assert_eq!(body["endpoint"], "/v1/chat/completions");
+                    Json(serde_json::json!({"id": "batch-1", "status": "validating"}))
+                }),
+            )
+            .route(
+                "/v1/batches/:id",
+                get(
+                    |State(stub): State<Arc<Stub>>, Path(id): Path<String>| async move {
+                        assert_eq!(id, "batch-1");
+                        let mut polls = stub.polls.lock().unwrap();
+                        *polls += 1;
+                        Json(match *polls {
+                            1 => serde_json::json!({"id": id, "status": "in_progress"}),
+                            _ => serde_json::json!({
+                                "id": id,
+                                "status": "completed",
+                                "output_file_id": "file-out",
+                                "error_file_id": "file-err",
+                            }),
+                        })
+                    },
+                ),
+            )
+            .route(
+                "/v1/files/:id/content",
+                get(
+                    |State(stub): State<Arc<Stub>>, Path(id): Path<String>| async move {
+                        let input = stub.input.lock().unwrap().clone();
+                        assert!(input.contains(r#""custom_id":"req-0""#));
+                        assert!(input.contains("Extract the person from: Ada, 36"));
+                        let line = match id.as_str() {
+                            "file-out" => serde_json::json!({
+                                "custom_id": "req-0",
+                                "response": {
+                                    "status_code": 200,
+                                    "body": {
+                                        "id": "chatcmpl-0",
+                                        "object": "chat.completion",
+                                        "created": 0,
+                                        "model": "gpt-4o",
+                                        "choices": [{
+                                            "index": 0,
+                                            "message": {
+                                                "role": "assistant",
+                                                "content": r#"{"name": "Ada", "age": 36}"#,
+                                            },
+                                            "finish_reason": "stop",
+                                            "logprobs": null,
+                                        }],
+                                    },
+                                },
+                                "error": null,
+                            }),
+                            _ => serde_json::json!({
+                                "custom_id": "req-1",
+                                "response": {
+                                    "status_code": 400,
+                                    "body": {"error": {"message": "bad request"}},
+                                },
+                                "error": null,
+                            }),
+                        };
+                        format!("{}\n", line)
+                    },
+                ),
+            )
+            .with_state(stub);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(format!("http://{}/v1", addr))
+    }
+
+    #[test]
+    fn test_batch_job_against_stub_server() -> anyhow::Result<()> {
+        // Build once to get an async runtime, then again with the stub's address.
+        let runtime = make_test_runtime("")?;
+        let base_url = runtime.async_runtime.block_on(serve_stub_batch_api())?;
+
+        let runtime = make_test_runtime(&format!(
+            r##"
+client<llm> Stub {{
+  provider openai
+  options {{
+    model "gpt-4o"
+    base_url "{base_url}"
+    api_key "test-key"
+  }}
+}}
+
+class Person {{
+  name string
+  age int
+}}
+
+function ExtractPerson(text: string) -> Person {{
+  client Stub
+  prompt #"
+    {{{{ _.role("user") }}}}
+    Extract the person from: {{{{ text }}}}
+
+    {{{{ ctx.output_format }}}}
+  "#
+}}
+        "##
+        ))?;
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+        let params = ["Ada, 36", "Grace, 45"]
+            .iter()
+            .map(|text| {
+                [("text".to_string(), BamlValue::String(text.to_string()))]
+                    .into_iter()
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+
+        let job = runtime.async_runtime.block_on(runtime.submit_batch(
+            "ExtractPerson".to_string(),
+            &params,
+            &ctx,
+            None,
+            None,
+        ))?;
+        assert_eq!(job.id, "batch-1");
+        assert_eq!(job.custom_ids, vec!["req-0", "req-1"]);
+
+        let status = runtime
+            .async_runtime
+            .block_on(runtime.batch_status(&job, &ctx, None, None))?;
+        assert_eq!(status, baml_runtime::BatchJobStatus::InProgress);
+        let status = runtime
+            .async_runtime
+            .block_on(runtime.batch_status(&job, &ctx, None, None))?;
+        assert_eq!(status, baml_runtime::BatchJobStatus::Completed);
+
+        let results = runtime
+            .async_runtime
+            .block_on(runtime.batch_results(&job, &ctx, None, None))?;
+        assert_eq!(results.len(), 2);
+        let parsed: BamlValue = results[0].result_with_constraints_content()?.clone().into();
+        assert_eq!(
+            serde_json::to_value(parsed)?,
+            serde_json::json!({"name": "Ada", "age": 36})
+        );
+        assert!(matches!(
+            results[1].llm_response(),
+            LLMResponse::LLMFailure(e) if e.message.contains("bad request")
+        ));
+
+        Ok(())
+    }
 }
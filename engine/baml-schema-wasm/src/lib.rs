@@ -7,3 +7,10 @@ pub fn version() -> String {
     // register_panic_hook();
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+/// Formats the contents of a single `.baml` file, as `baml-cli fmt` would.
+#[wasm_bindgen]
+pub fn format_document(source: &str) -> Result<String, JsError> {
+    internal_baml_core::internal_baml_schema_ast::formatter::format_schema(source)
+        .map_err(|e| JsError::new(&e.to_string()))
+}
@@ -11,6 +11,7 @@ use baml_runtime::InternalRuntimeInterface;
 use baml_runtime::RenderCurlSettings;
 use baml_runtime::{
     internal::llm_client::LLMResponse, BamlRuntime, DiagnosticsError, IRHelper, RenderedPrompt,
+    RuntimeContextManager,
 };
 use baml_types::{BamlMediaType, BamlValue, GeneratorOutputType, TypeValue};
 use indexmap::IndexMap;
@@ -302,6 +303,9 @@ pub struct WasmFunction {
     pub test_snippet: String,
     #[wasm_bindgen(readonly)]
     pub signature: String,
+    /// The function's configs: `default_config` first, then its named variants.
+    #[wasm_bindgen(readonly)]
+    pub variants: Vec<String>,
 }
 
 #[wasm_bindgen(getter_with_clone, inspectable)]
@@ -439,6 +443,7 @@ pub struct WasmLLMResponse {
     pub output_tokens: Option<u64>,
     pub total_tokens: Option<u64>,
     pub stop_reason: Option<String>,
+    pub cache_hit: bool,
 }
 
 #[wasm_bindgen(getter_with_clone, inspectable)]
@@ -769,6 +774,7 @@ impl IntoWasm
                 output_tokens: s.metadata.output_tokens,
                 total_tokens: s.metadata.total_tokens,
                 stop_reason: s.metadata.finish_reason.clone(),
+                cache_hit: s.metadata.cache_hit,
             }),
             _ => None,
         }
@@ -846,12 +852,21 @@ fn get_dummy_value(
                 TypeValue::Float => "0.5".to_string(),
                 TypeValue::Bool => "true".to_string(),
                 TypeValue::Null => "null".to_string(),
+                TypeValue::Date => "\"2024-03-03\"".to_string(),
+                TypeValue::DateTime => "\"2024-03-03T10:30:00Z\"".to_string(),
+                TypeValue::Duration => "\"PT1H30M\"".to_string(),
                 TypeValue::Media(BamlMediaType::Image) => {
                     "{ url \"https://imgs.xkcd.com/comics/standards.png\"}".to_string()
                 }
                 TypeValue::Media(BamlMediaType::Audio) => {
                     "{ url \"https://actions.google.com/sounds/v1/emergency/beeper_emergency_call.ogg\"}".to_string()
                 }
+                TypeValue::Media(BamlMediaType::Pdf) => {
+                    "{ url \"https://www.w3.org/WAI/ER/tests/xhtml/testfiles/resources/pdf/dummy.pdf\"}".to_string()
+                }
+                TypeValue::Media(BamlMediaType::Video) => {
+                    "{ url \"https://storage.googleapis.com/gtv-videos-bucket/sample/ForBiggerBlazes.mp4\"}".to_string()
+                }
             };
 
             Some(dummy)
@@ -1005,6 +1020,7 @@ impl WasmRuntime {
                         format!("({}) -> {}", inputs, f.output().to_string())
                     },
                     test_snippet: snippet,
+                    variants: f.elem().configs.iter().map(|c| c.name.clone()).collect(),
                     test_cases: f
                         .walk_tests()
                         .map(|tc| {
@@ -1488,19 +1504,48 @@ pub struct WasmCallContext {
     /// Index of the orchestration graph node to use for the call
     /// Defaults to 0 when unset
     node_index: Option<usize>,
+    /// Variant of the function to call
+    /// Defaults to the function's own prompt and client when unset
+    variant: Option<String>,
 }
 
 #[wasm_bindgen]
 impl WasmCallContext {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self { node_index: None }
+        Self {
+            node_index: None,
+            variant: None,
+        }
     }
 
     #[wasm_bindgen(setter)]
     pub fn set_node_index(&mut self, node_index: Option<usize>) {
         self.node_index = node_index;
     }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_variant(&mut self, variant: Option<String>) {
+        self.variant = variant;
+    }
+}
+
+impl WasmCallContext {
+    fn ctx_manager(
+        &self,
+        rt: &BamlRuntime,
+        function_name: &str,
+        get_baml_src_cb: js_sys::Function,
+    ) -> RuntimeContextManager {
+        let ctx_manager = rt.create_ctx_manager(
+            BamlValue::String("wasm".to_string()),
+            js_fn_to_baml_src_reader(get_baml_src_cb),
+        );
+        if let Some(variant) = &self.variant {
+            ctx_manager.set_variant(function_name, variant);
+        }
+        ctx_manager
+    }
 }
 
 #[wasm_bindgen]
@@ -1514,12 +1559,8 @@ impl WasmFunction {
         get_baml_src_cb: js_sys::Function,
     ) -> JsResult<WasmPrompt> {
         let missing_env_vars = rt.runtime.internal().ir().required_env_vars();
-        let ctx = rt
-            .runtime
-            .create_ctx_manager(
-                BamlValue::String("wasm".to_string()),
-                js_fn_to_baml_src_reader(get_baml_src_cb),
-            )
+        let ctx = wasm_call_context
+            .ctx_manager(&rt.runtime, &self.name, get_baml_src_cb)
             .create_ctx_with_default(missing_env_vars.iter());
 
         let params = rt
@@ -1527,13 +1568,24 @@ impl WasmFunction {
             .get_test_params(&self.name, &test_name, &ctx)
             .map_err(|e| JsError::new(format!("{e:?}").as_str()))?;
 
-        rt.runtime
+        let (prompt, scope, allowed) = rt
+            .runtime
             .internal()
             .render_prompt(&self.name, &ctx, &params, wasm_call_context.node_index)
             .await
-            .as_ref()
-            .map(|(p, scope, allowed)| (p, scope, allowed).into())
-            .map_err(|e| JsError::new(format!("{e:?}").as_str()))
+            .map_err(|e| JsError::new(format!("{e:?}").as_str()))?;
+        let wasm_prompt: WasmPrompt = (&prompt, &scope, &allowed).into();
+
+        // The prompt is still worth showing if its tokens can't be counted.
+        match rt.runtime.internal().count_tokens(
+            &self.name,
+            &ctx,
+            &prompt,
+            wasm_call_context.node_index,
+        ) {
+            Ok(token_count) => Ok(wasm_prompt.with_token_count(token_count)),
+            Err(_) => Ok(wasm_prompt),
+        }
     }
 
     #[wasm_bindgen]
@@ -1562,12 +1614,8 @@ impl WasmFunction {
     ) -> Result<String, wasm_bindgen::JsError> {
         let missing_env_vars = rt.runtime.internal().ir().required_env_vars();
 
-        let ctx = rt
-            .runtime
-            .create_ctx_manager(
-                BamlValue::String("wasm".to_string()),
-                js_fn_to_baml_src_reader(get_baml_src_cb),
-            )
+        let ctx = wasm_call_context
+            .ctx_manager(&rt.runtime, &self.name, get_baml_src_cb)
             .create_ctx_with_default(missing_env_vars.iter());
 
         let params = rt
@@ -1610,6 +1658,7 @@ impl WasmFunction {
         &self,
         rt: &mut WasmRuntime,
         test_name: String,
+        wasm_call_context: &WasmCallContext,
         on_partial_response: js_sys::Function,
         get_baml_src_cb: js_sys::Function,
     ) -> Result<WasmTestResponse, JsValue> {
@@ -1626,10 +1675,7 @@ impl WasmFunction {
             on_partial_response.call1(&this, &res).unwrap();
         });
 
-        let ctx = rt.create_ctx_manager(
-            BamlValue::String("wasm".to_string()),
-            js_fn_to_baml_src_reader(get_baml_src_cb),
-        );
+        let ctx = wasm_call_context.ctx_manager(rt, &function_name, get_baml_src_cb);
         let (test_response, span) = rt
             .run_test(&function_name, &test_name, &ctx, Some(cb))
             .await;
@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 
 use baml_runtime::{
-    internal::llm_client::{
-        orchestrator::{ExecutionScope, OrchestrationScope},
-        AllowedMetadata,
+    internal::{
+        llm_client::{
+            orchestrator::{ExecutionScope, OrchestrationScope},
+            AllowedMetadata,
+        },
+        tokenizer::TokenCount,
     },
     ChatMessagePart, RenderedPrompt,
 };
@@ -24,6 +27,7 @@ pub struct WasmPrompt {
     prompt: RenderedPrompt,
     pub client_name: String,
     allowed: AllowedMetadata,
+    token_count: Option<TokenCount>,
 }
 
 impl From<OrchestrationScope> for WasmScope {
@@ -40,10 +44,18 @@ impl From<(&RenderedPrompt, &OrchestrationScope, &AllowedMetadata)> for WasmProm
             prompt: prompt.clone(),
             client_name: client_name.name(),
             allowed: allowed.clone(),
+            token_count: None,
         }
     }
 }
 
+impl WasmPrompt {
+    pub fn with_token_count(mut self, token_count: TokenCount) -> Self {
+        self.token_count = Some(token_count);
+        self
+    }
+}
+
 #[wasm_bindgen(getter_with_clone)]
 pub struct WasmChatMessage {
     #[wasm_bindgen(readonly)]
@@ -124,6 +136,22 @@ impl WasmChatMessagePart {
         )
     }
 
+    #[wasm_bindgen]
+    pub fn is_pdf(&self) -> bool {
+        matches!(
+            self.part.as_media().map(|s| s.media_type),
+            Some(BamlMediaType::Pdf)
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn is_video(&self) -> bool {
+        matches!(
+            self.part.as_media().map(|s| s.media_type),
+            Some(BamlMediaType::Video)
+        )
+    }
+
     #[wasm_bindgen]
     pub fn as_text(&self) -> Option<String> {
         self.part.as_text().map(|s| s.clone())
@@ -217,6 +245,23 @@ impl WasmPrompt {
         matches!(self.prompt, RenderedPrompt::Completion(_))
     }
 
+    /// The number of tokens in the prompt, for the client it is sent to.
+    #[wasm_bindgen]
+    pub fn token_count(&self) -> Option<usize> {
+        self.token_count.map(|c| c.tokens)
+    }
+
+    /// Whether the token count is exact, or an estimate.
+    #[wasm_bindgen]
+    pub fn token_count_is_exact(&self) -> bool {
+        self.token_count.map_or(false, |c| c.tokenizer.is_exact())
+    }
+
+    #[wasm_bindgen]
+    pub fn max_input_tokens(&self) -> Option<usize> {
+        self.token_count.and_then(|c| c.max_input_tokens)
+    }
+
     #[wasm_bindgen]
     pub fn as_chat(&self) -> Option<Vec<WasmChatMessage>> {
         if let RenderedPrompt::Chat(s) = &self.prompt {
@@ -21,9 +21,21 @@ pub(crate) enum Commands {
     #[command(about = "Runs all generators in the baml_src directory")]
     Generate(baml_runtime::cli::generate::GenerateArgs),
 
+    #[command(about = "Formats .baml files")]
+    Fmt(baml_runtime::cli::format::FormatArgs),
+
+    #[command(about = "Starts a language server for .baml files over stdio")]
+    Lsp(baml_runtime::cli::lsp::LspArgs),
+
     #[command(about = "Starts a server that translates LLM responses to BAML responses")]
     Serve(baml_runtime::cli::serve::ServeArgs),
 
+    #[command(about = "Exports BAML types and functions as JSON Schema")]
+    Schema(baml_runtime::cli::schema::SchemaArgs),
+
+    #[command(about = "Parses an LLM output from stdin into the return type of a function")]
+    Parse(baml_runtime::cli::parse::ParseArgs),
+
     #[command(about = "Starts a development server")]
     Dev(baml_runtime::cli::dev::DevArgs),
 
@@ -50,10 +62,25 @@ impl RuntimeCli {
                 args.run(defaults)
             }
             Commands::Init(args) => args.run(defaults),
+            Commands::Fmt(args) => {
+                if args.paths.is_empty() {
+                    args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                }
+                args.run()
+            }
+            Commands::Lsp(args) => args.run(),
             Commands::Serve(args) => {
                 args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
                 args.run()
             }
+            Commands::Schema(args) => {
+                args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.run()
+            }
+            Commands::Parse(args) => {
+                args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.run()
+            }
             Commands::Dev(args) => {
                 args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
                 args.run(defaults)
@@ -0,0 +1,465 @@
+use std::cell::RefCell;
+
+use anyhow::Result;
+use baml_types::{BamlMediaType, LiteralValue, TypeValue};
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+use internal_baml_core::ir::{
+    repr::{Docstring, IntermediateRepr},
+    ClassWalker, EnumWalker, FieldType,
+};
+
+use crate::{field_type_attributes, GeneratorArgs};
+
+use super::go_language_features::to_go_exported;
+
+#[derive(askama::Template)]
+#[template(path = "types.go.j2", escape = "none")]
+pub(crate) struct GoTypes {
+    enums: Vec<GoEnum>,
+    classes: Vec<GoClass>,
+    unions: Vec<GoUnion>,
+}
+
+#[derive(askama::Template)]
+#[template(path = "partial_types.go.j2", escape = "none")]
+pub(crate) struct GoStreamTypes {
+    classes: Vec<GoClass>,
+    unions: Vec<GoUnion>,
+}
+
+impl GoStreamTypes {
+    /// Go rejects unused imports, and only unions and dynamic classes
+    /// marshal JSON themselves.
+    fn uses_json(&self) -> bool {
+        !self.unions.is_empty() || self.classes.iter().any(|c| c.dynamic)
+    }
+}
+
+struct GoEnum {
+    name: String,
+    /// The aligned constant declaration and docstring of each value.
+    values: Vec<(String, Option<String>)>,
+    dynamic: bool,
+    docstring: Option<String>,
+}
+
+struct GoClass {
+    name: String,
+    /// The aligned field declaration and docstring of each field.
+    fields: Vec<(String, Option<String>)>,
+    json_names: Vec<String>,
+    dynamic: bool,
+    docstring: Option<String>,
+}
+
+struct GoUnion {
+    name: String,
+    /// The aligned field declaration of each member.
+    fields: Vec<String>,
+    /// The field name and Go type of each member, in the order they are tried.
+    variants: Vec<(String, String)>,
+}
+
+/// Renders BAML types as Go types.
+///
+/// Go has no sum types, so every union is given a struct named after its
+/// members (e.g. `Union2IntOrString`), with one pointer field per member.
+/// The structs are collected as types are rendered, so that `types.go` and
+/// `partial_types.go` can define them.
+pub(super) struct GoTypeRefs {
+    unions: RefCell<IndexMap<String, Vec<(String, String)>>>,
+    partial_unions: RefCell<IndexMap<String, Vec<(String, String)>>>,
+}
+
+impl GoTypeRefs {
+    pub(super) fn new() -> Self {
+        Self {
+            unions: Default::default(),
+            partial_unions: Default::default(),
+        }
+    }
+
+    /// The type of a value of type `t`.
+    pub(super) fn type_ref(&self, t: &FieldType) -> String {
+        self.render(t, false)
+    }
+
+    /// The type of a streamed, possibly incomplete value of type `t`.
+    pub(super) fn partial_type_ref(&self, t: &FieldType) -> String {
+        self.render(t, true)
+    }
+
+    fn render(&self, t: &FieldType, partial: bool) -> String {
+        match t {
+            FieldType::Primitive(v) => primitive(v).to_string(),
+            FieldType::Enum(name) => name.clone(),
+            FieldType::Literal(v) => literal(v).to_string(),
+            FieldType::Class(name) => {
+                if partial {
+                    format!("Partial{name}")
+                } else {
+                    name.clone()
+                }
+            }
+            FieldType::List(inner) => format!("[]{}", self.render(inner, partial)),
+            FieldType::Map(key, value) => format!(
+                "map[{}]{}",
+                self.render(key, false),
+                self.render(value, partial)
+            ),
+            FieldType::Optional(inner) => optional(self.render(inner, partial)),
+            // Tuple members may have different types.
+            FieldType::Tuple(_) => "[]any".to_string(),
+            FieldType::Union(inner) => self.render_union(inner, partial),
+            FieldType::Constrained { base, .. } => match field_type_attributes(t) {
+                Some(_) => format!("Checked[{}]", self.render(base, partial)),
+                None => self.render(base, partial),
+            },
+        }
+    }
+
+    fn render_union(&self, inner: &[FieldType], partial: bool) -> String {
+        let nullable = inner.iter().any(|t| t.is_null());
+        let members = inner
+            .iter()
+            .filter(|t| !t.is_null())
+            .map(|t| (t, self.render(t, partial)))
+            .unique_by(|(_, go_type)| go_type.clone())
+            .collect::<Vec<_>>();
+
+        let rendered = match members.as_slice() {
+            [] => "any".to_string(),
+            [(_, go_type)] => go_type.clone(),
+            _ => {
+                let mut variants: Vec<(String, String)> = vec![];
+                for (t, go_type) in members.iter() {
+                    let mut variant = member_name(t);
+                    if variants.iter().any(|(v, _)| *v == variant) {
+                        variant = format!("{variant}{}", variants.len());
+                    }
+                    variants.push((variant, go_type.clone()));
+                }
+                let mut name = format!(
+                    "Union{}{}",
+                    variants.len(),
+                    variants.iter().map(|(v, _)| v).join("Or")
+                );
+                let unions = if partial {
+                    name = format!("Partial{name}");
+                    &self.partial_unions
+                } else {
+                    &self.unions
+                };
+                unions.borrow_mut().entry(name.clone()).or_insert(variants);
+                name
+            }
+        };
+
+        if nullable {
+            optional(rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn take_unions(&self, partial: bool) -> Vec<GoUnion> {
+        let unions = if partial {
+            &self.partial_unions
+        } else {
+            &self.unions
+        };
+        unions
+            .take()
+            .into_iter()
+            .map(|(name, variants)| GoUnion {
+                name,
+                fields: align(
+                    variants
+                        .iter()
+                        .map(|(variant, go_type)| vec![variant.clone(), format!("*{go_type}")])
+                        .collect(),
+                ),
+                variants,
+            })
+            .collect()
+    }
+}
+
+/// Slices, maps and `any` are already nullable, so only other types are
+/// made pointers.
+fn optional(go_type: String) -> String {
+    if go_type == "any" || ["*", "[]", "map["].iter().any(|p| go_type.starts_with(p)) {
+        go_type
+    } else {
+        format!("*{go_type}")
+    }
+}
+
+fn primitive(v: &TypeValue) -> &'static str {
+    match v {
+        // Dates and durations are kept in their ISO 8601 form.
+        TypeValue::String | TypeValue::Date | TypeValue::DateTime | TypeValue::Duration => "string",
+        TypeValue::Int => "int64",
+        TypeValue::Float => "float64",
+        TypeValue::Bool => "bool",
+        TypeValue::Null => "any",
+        TypeValue::Media(media_type) => media_name(media_type),
+    }
+}
+
+fn media_name(media_type: &BamlMediaType) -> &'static str {
+    match media_type {
+        BamlMediaType::Image => "Image",
+        BamlMediaType::Audio => "Audio",
+        BamlMediaType::Pdf => "Pdf",
+        BamlMediaType::Video => "Video",
+    }
+}
+
+/// Literal types are represented by their base type.
+fn literal(v: &LiteralValue) -> &'static str {
+    match v {
+        LiteralValue::String(_) => "string",
+        LiteralValue::Int(_) => "int64",
+        LiteralValue::Bool(_) => "bool",
+    }
+}
+
+/// The name of a union field holding a value of type `t`.
+fn member_name(t: &FieldType) -> String {
+    match t {
+        FieldType::Primitive(v) => match v {
+            TypeValue::String => "String".to_string(),
+            TypeValue::Int => "Int".to_string(),
+            TypeValue::Float => "Float".to_string(),
+            TypeValue::Bool => "Bool".to_string(),
+            TypeValue::Null => "Null".to_string(),
+            TypeValue::Date => "Date".to_string(),
+            TypeValue::DateTime => "DateTime".to_string(),
+            TypeValue::Duration => "Duration".to_string(),
+            TypeValue::Media(media_type) => media_name(media_type).to_string(),
+        },
+        FieldType::Enum(name) | FieldType::Class(name) => to_go_exported(name),
+        FieldType::Literal(v) => match v {
+            LiteralValue::String(_) => "String".to_string(),
+            LiteralValue::Int(_) => "Int".to_string(),
+            LiteralValue::Bool(_) => "Bool".to_string(),
+        },
+        FieldType::List(inner) => format!("List{}", member_name(inner)),
+        FieldType::Map(key, value) => format!("Map{}{}", member_name(key), member_name(value)),
+        FieldType::Optional(inner) => format!("Optional{}", member_name(inner)),
+        FieldType::Tuple(inner) => format!("Tuple{}", inner.iter().map(member_name).join("And")),
+        FieldType::Union(inner) => format!("Union{}", inner.iter().map(member_name).join("Or")),
+        FieldType::Constrained { base, .. } => member_name(base),
+    }
+}
+
+/// Pads the columns of `rows` to the same width, the way `gofmt` aligns
+/// struct fields and constant declarations.
+fn align(rows: Vec<Vec<String>>) -> Vec<String> {
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let widths = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    rows.into_iter()
+        .map(|row| {
+            let last = row.len().saturating_sub(1);
+            row.into_iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    if i == last {
+                        cell
+                    } else {
+                        format!("{cell:<width$}", width = widths[i])
+                    }
+                })
+                .join(" ")
+        })
+        .collect()
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for GoTypes {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs)) -> Result<GoTypes> {
+        let refs = GoTypeRefs::new();
+        let enums = ir.walk_enums().map(|e| GoEnum::from(&e)).collect();
+        let classes = ir
+            .walk_classes()
+            .map(|c| GoClass::new(&c, &refs, false))
+            .collect();
+        // Unions only used by function signatures are defined here too.
+        for f in ir.walk_functions() {
+            for (_, r#type) in f.inputs().iter() {
+                refs.type_ref(r#type);
+            }
+            refs.type_ref(f.elem().output());
+        }
+        Ok(GoTypes {
+            enums,
+            classes,
+            unions: refs.take_unions(false),
+        })
+    }
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for GoStreamTypes {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs)) -> Result<GoStreamTypes> {
+        let refs = GoTypeRefs::new();
+        let classes = ir
+            .walk_classes()
+            .map(|c| GoClass::new(&c, &refs, true))
+            .collect();
+        for f in ir.walk_functions() {
+            refs.partial_type_ref(f.elem().output());
+        }
+        Ok(GoStreamTypes {
+            classes,
+            unions: refs.take_unions(true),
+        })
+    }
+}
+
+impl<'ir> From<&EnumWalker<'ir>> for GoEnum {
+    fn from(e: &EnumWalker<'ir>) -> GoEnum {
+        let name = e.name().to_string();
+        let values = &e.item.elem.values;
+        let decls = align(
+            values
+                .iter()
+                .map(|v| {
+                    let value = v.0.elem.0.as_str();
+                    vec![
+                        format!("{name}{}", to_go_exported(value)),
+                        name.clone(),
+                        format!("= {value:?}"),
+                    ]
+                })
+                .collect(),
+        );
+        GoEnum {
+            dynamic: e.item.attributes.get("dynamic_type").is_some(),
+            values: decls
+                .into_iter()
+                .zip(values.iter())
+                .map(|(decl, v)| (decl, v.1.as_ref().map(|d| render_docstring(d, "\t"))))
+                .collect(),
+            docstring: e
+                .item
+                .elem
+                .docstring
+                .as_ref()
+                .map(|d| render_docstring(d, "")),
+            name,
+        }
+    }
+}
+
+impl GoClass {
+    /// Every field of a partial class is optional, since it may not have
+    /// been streamed yet.
+    fn new(c: &ClassWalker<'_>, refs: &GoTypeRefs, partial: bool) -> GoClass {
+        let fields = &c.item.elem.static_fields;
+        let decls = align(
+            fields
+                .iter()
+                .map(|f| {
+                    let r#type = &f.elem.r#type.elem;
+                    vec![
+                        to_go_exported(&f.elem.name),
+                        if partial {
+                            optional(refs.partial_type_ref(r#type))
+                        } else {
+                            refs.type_ref(r#type)
+                        },
+                        format!("`json:\"{}\"`", f.elem.name),
+                    ]
+                })
+                .collect(),
+        );
+        GoClass {
+            name: if partial {
+                format!("Partial{}", c.name())
+            } else {
+                c.name().to_string()
+            },
+            dynamic: c.item.attributes.get("dynamic_type").is_some(),
+            fields: decls
+                .into_iter()
+                .zip(fields.iter())
+                .map(|(decl, f)| {
+                    (
+                        decl,
+                        f.elem.docstring.as_ref().map(|d| render_docstring(d, "\t")),
+                    )
+                })
+                .collect(),
+            json_names: fields.iter().map(|f| f.elem.name.clone()).collect(),
+            docstring: c
+                .item
+                .elem
+                .docstring
+                .as_ref()
+                .map(|d| render_docstring(d, "")),
+        }
+    }
+}
+
+/// Render the BAML documentation (a bare string with padding stripped)
+/// into Go comments, each line prefixed with `indent`.
+fn render_docstring(d: &Docstring, indent: &str) -> String {
+    d.0.lines()
+        .map(|line| format!("{indent}// {line}").trim_end().to_string())
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_refs() {
+        let refs = GoTypeRefs::new();
+
+        let node = FieldType::Class("Node".to_string());
+        assert_eq!(
+            refs.type_ref(&FieldType::Optional(Box::new(node.clone()))),
+            "*Node"
+        );
+        assert_eq!(
+            refs.partial_type_ref(&FieldType::List(Box::new(node))),
+            "[]PartialNode"
+        );
+
+        let union = FieldType::Union(vec![
+            FieldType::Primitive(TypeValue::Int),
+            FieldType::Primitive(TypeValue::String),
+            FieldType::Primitive(TypeValue::Null),
+        ]);
+        assert_eq!(refs.type_ref(&union), "*Union2IntOrString");
+        let unions = refs.take_unions(false);
+        assert_eq!(unions.len(), 1);
+        assert_eq!(unions[0].fields, vec!["Int    *int64", "String *string"]);
+    }
+
+    #[test]
+    fn test_align() {
+        assert_eq!(
+            align(vec![
+                vec!["Name".to_string(), "string".to_string()],
+                vec!["Age".to_string(), "*int64".to_string()],
+            ]),
+            vec!["Name string", "Age  *int64"]
+        );
+    }
+}
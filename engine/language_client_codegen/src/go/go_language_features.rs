@@ -0,0 +1,105 @@
+use crate::dir_writer::LanguageFeatures;
+
+#[derive(Default)]
+pub(super) struct GoLanguageFeatures {}
+
+impl LanguageFeatures for GoLanguageFeatures {
+    const CONTENT_PREFIX: &'static str = r#"
+///////////////////////////////////////////////////////////////////////////////
+//
+//  Welcome to Baml! This package calls your BAML functions through
+//  `baml-cli serve`. To start the server, run:
+//
+//  $ baml-cli serve --preview
+//
+///////////////////////////////////////////////////////////////////////////////
+
+// Code generated by BAML. DO NOT EDIT.
+//
+// Edit the BAML files and re-generate this code instead.
+        "#;
+}
+
+/// Go keywords and predeclared identifiers which can't be used as parameter
+/// names, plus the names used by the generated methods themselves.
+const RESERVED_PARAMS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+    "any",
+    "error",
+    "string",
+    "int",
+    "bool",
+    "len",
+    "c",
+    "ctx",
+    "opts",
+];
+
+/// Converts a BAML name to an exported Go identifier, e.g. `first_name` to
+/// `FirstName`.
+pub(super) fn to_go_exported(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts a BAML name to a Go parameter name, e.g. `first_name` to
+/// `firstName`.
+pub(super) fn to_go_param(name: &str) -> String {
+    let exported = to_go_exported(name);
+    let mut chars = exported.chars();
+    let param = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect::<String>(),
+        None => "arg".to_string(),
+    };
+    if RESERVED_PARAMS.contains(&param.as_str()) {
+        format!("{param}_")
+    } else {
+        param
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_go_identifiers() {
+        assert_eq!(to_go_exported("first_name"), "FirstName");
+        assert_eq!(to_go_exported("ExtractResume"), "ExtractResume");
+        assert_eq!(to_go_param("first_name"), "firstName");
+        assert_eq!(to_go_param("type"), "type_");
+        assert_eq!(to_go_param("ctx"), "ctx_");
+    }
+}
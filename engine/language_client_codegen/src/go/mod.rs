@@ -0,0 +1,177 @@
+mod generate_types;
+mod go_language_features;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use internal_baml_core::ir::repr::IntermediateRepr;
+
+use self::generate_types::GoTypeRefs;
+use self::go_language_features::{to_go_exported, to_go_param, GoLanguageFeatures};
+use crate::dir_writer::FileCollector;
+
+#[derive(askama::Template)]
+#[template(path = "client.go.j2", escape = "none")]
+struct GoClient {
+    funcs: Vec<GoFunction>,
+}
+
+struct GoFunction {
+    name: String,
+    go_name: String,
+    partial_return_type: String,
+    return_type: String,
+    /// The parameter name, BAML name and type of each argument.
+    args: Vec<(String, String, String)>,
+}
+
+pub(crate) fn generate(
+    ir: &IntermediateRepr,
+    generator: &crate::GeneratorArgs,
+) -> Result<IndexMap<PathBuf, String>> {
+    let mut collector = FileCollector::<GoLanguageFeatures>::new();
+
+    collector.add_template::<generate_types::GoTypes>("types.go", (ir, generator))?;
+    collector.add_template::<generate_types::GoStreamTypes>("partial_types.go", (ir, generator))?;
+    collector.add_template::<GoClient>("client.go", (ir, generator))?;
+
+    collector.commit(&generator.output_dir())
+}
+
+impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for GoClient {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'_ IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+        let refs = GoTypeRefs::new();
+        let funcs = ir
+            .walk_functions()
+            .map(|f| GoFunction {
+                name: f.name().to_string(),
+                go_name: to_go_exported(f.name()),
+                partial_return_type: refs.partial_type_ref(f.elem().output()),
+                return_type: refs.type_ref(f.elem().output()),
+                args: f
+                    .inputs()
+                    .iter()
+                    .map(|(name, r#type)| {
+                        (to_go_param(name), name.to_string(), refs.type_ref(r#type))
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(GoClient { funcs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internal_baml_core::configuration::GeneratorDefaultClientMode;
+    use internal_baml_core::ir::repr::make_test_ir;
+    use sugar_path::SugarPath;
+
+    use super::*;
+
+    /// Generates a client covering every kind of type the generator maps and
+    /// runs `go build` and `go vet` on it. Skipped if Go isn't installed.
+    #[test]
+    fn test_generated_client_builds() {
+        let source = r##"
+client<llm> GPT4 {
+  provider openai
+  options {
+    model gpt-4o
+  }
+}
+
+enum Category {
+  Refund
+  CancelOrder @alias("cancel_order")
+  @@dynamic
+}
+
+class Address {
+  street string
+  city string @description("The city")
+}
+
+class Resume {
+  name string
+  email string?
+  score float
+  skills string[]
+  links map<string, string>
+  address Address?
+  category Category
+  kind "person" | "company"
+  contact int | string | bool
+  photo image?
+  @@dynamic
+}
+
+class Candidate {
+  resume Resume
+  age int @check(adult, {{ this >= 18 }})
+}
+
+class Tree {
+  value int
+  children Tree[]
+}
+
+function ExtractResume(text: string, photo: image?, tags: string[], weights: map<string, float>) -> Candidate {
+  client GPT4
+  prompt #"{{ text }}"#
+}
+
+function Classify(resumes: Resume[], tree: Tree, type: string) -> Category[] {
+  client GPT4
+  prompt #"{{ resumes }}"#
+}
+"##;
+        let ir = make_test_ir(source).expect("Valid source");
+
+        let module_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../target/generated-go-client")
+            .normalize();
+        let baml_src = module_dir.join("baml_src");
+        let files = [(baml_src.join("main.baml"), source.to_string())];
+        let args = crate::GeneratorArgs::new(
+            "../baml_client",
+            &baml_src,
+            files.iter().map(|(path, contents)| (path, contents)),
+            "0.0.0".to_string(),
+            true,
+            GeneratorDefaultClientMode::Async,
+            vec![],
+            false,
+        )
+        .unwrap();
+        generate(&ir, &args).unwrap();
+        std::fs::write(
+            module_dir.join("go.mod"),
+            "module example.com/generated\n\ngo 1.21\n",
+        )
+        .unwrap();
+
+        for command in ["build", "vet"] {
+            let output = match std::process::Command::new("go")
+                .args([command, "./..."])
+                .current_dir(&module_dir)
+                .output()
+            {
+                Ok(output) => output,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    eprintln!("Skipping: go is not installed");
+                    return;
+                }
+                Err(e) => panic!("Failed to run go {command}: {e}"),
+            };
+            assert!(
+                output.status.success(),
+                "go {command} failed on the generated client:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Exports BAML types as JSON Schema (2020-12) documents, so that BAML
+//! outputs can be validated where the generated clients can't run.
+//!
+//! The schemas themselves come from [`WithJsonSchema`]; this module only lays
+//! them out as files.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use internal_baml_core::ir::{
+    json_schema::{self, WithJsonSchema},
+    repr::IntermediateRepr,
+};
+use serde_json::Value;
+
+use crate::dir_writer::{FileCollector, LanguageFeatures};
+
+#[derive(Default)]
+pub(super) struct JsonSchemaLanguageFeatures {}
+
+impl LanguageFeatures for JsonSchemaLanguageFeatures {
+    // JSON has no comments: every document has a `$comment` instead.
+    const CONTENT_PREFIX: &'static str = "";
+}
+
+const GENERATED_COMMENT: &str = "This file was generated by BAML: do not edit it. Instead, edit the BAML files and re-generate this code.";
+
+pub(crate) fn generate(
+    ir: &IntermediateRepr,
+    generator: &crate::GeneratorArgs,
+) -> Result<IndexMap<PathBuf, String>> {
+    let mut collector = FileCollector::<JsonSchemaLanguageFeatures>::new();
+
+    collector.add_file("schema.json", render(ir.json_schema())?);
+    for c in ir.walk_classes() {
+        collector.add_file(
+            format!("types/{}.schema.json", c.name()),
+            render(json_schema::type_document(ir, c.name())?)?,
+        );
+    }
+    for e in ir.walk_enums() {
+        collector.add_file(
+            format!("types/{}.schema.json", e.name()),
+            render(json_schema::type_document(ir, e.name())?)?,
+        );
+    }
+    for f in ir.walk_functions() {
+        collector.add_file(
+            format!("functions/{}.input.schema.json", f.name()),
+            render(json_schema::function_input_document(ir, f.name())?)?,
+        );
+        collector.add_file(
+            format!("functions/{}.output.schema.json", f.name()),
+            render(json_schema::function_output_document(ir, f.name())?)?,
+        );
+    }
+
+    collector.commit(&generator.output_dir())
+}
+
+/// Pretty-prints `document` with a `$comment` marking it as generated, right
+/// after `$schema`.
+fn render(document: Value) -> Result<String> {
+    let document = match document {
+        Value::Object(fields) => {
+            let mut fields = fields.into_iter();
+            let mut with_comment = serde_json::Map::new();
+            with_comment.extend(fields.next());
+            with_comment.insert("$comment".into(), GENERATED_COMMENT.into());
+            with_comment.extend(fields);
+            Value::Object(with_comment)
+        }
+        other => other,
+    };
+    Ok(serde_json::to_string_pretty(&document)?)
+}
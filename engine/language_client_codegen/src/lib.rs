@@ -1,17 +1,20 @@
 use anyhow::{Context, Result};
-use baml_types::{Constraint, ConstraintLevel, FieldType};
+use baml_types::{BamlValue, Constraint, ConstraintLevel, FieldType, TypeValue};
 use indexmap::IndexMap;
 use internal_baml_core::{
     configuration::{GeneratorDefaultClientMode, GeneratorOutputType},
-    ir::repr::IntermediateRepr,
+    ir::repr::{Field, IntermediateRepr, Node},
 };
 use std::{collections::{BTreeMap, HashSet}, path::PathBuf};
 use version_check::{check_version, GeneratorType, VersionCheckMode};
 
 mod dir_writer;
+mod go;
+pub mod json_schema;
 pub mod openapi;
 mod python;
 mod ruby;
+mod rust;
 mod typescript;
 pub mod version_check;
 
@@ -30,6 +33,9 @@ pub struct GeneratorArgs {
     // Default call mode for functions
     default_client_mode: GeneratorDefaultClientMode,
     on_generate: Vec<String>,
+
+    /// Also generate Zod schemas (TypeScript only).
+    zod: bool,
 }
 
 fn relative_path_to_baml_src(path: &PathBuf, baml_src: &PathBuf) -> Result<PathBuf> {
@@ -51,6 +57,7 @@ impl GeneratorArgs {
         no_version_check: bool,
         default_client_mode: GeneratorDefaultClientMode,
         on_generate: Vec<String>,
+        zod: bool,
     ) -> Result<Self> {
         let baml_src = baml_src_dir.into();
         let input_file_map: BTreeMap<PathBuf, String> = input_files
@@ -67,6 +74,7 @@ impl GeneratorArgs {
             no_version_check,
             default_client_mode,
             on_generate,
+            zod,
         })
     }
 
@@ -181,6 +189,9 @@ impl GenerateClient for GeneratorOutputType {
             GeneratorOutputType::OpenApi => openapi::generate(ir, gen),
             GeneratorOutputType::PythonPydantic => python::generate(ir, gen),
             GeneratorOutputType::RubySorbet => ruby::generate(ir, gen),
+            GeneratorOutputType::Rust => rust::generate(ir, gen),
+            GeneratorOutputType::Go => go::generate(ir, gen),
+            GeneratorOutputType::JsonSchema => json_schema::generate(ir, gen),
             GeneratorOutputType::Typescript => typescript::generate(ir, gen),
         }?;
 
@@ -330,6 +341,46 @@ fn field_type_attributes<'a>(field_type: &FieldType) -> Option<TypeCheckAttribut
     }
 }
 
+/// The `@default` of a class field, if it has one.
+fn field_default_value(field: &Node<Field>) -> Option<BamlValue> {
+    field
+        .attributes
+        .get("default")
+        .and_then(|value| value.normalize(&Default::default()).ok())
+}
+
+/// The value constraints of a field, like `@min(1)`, by attribute name.
+fn field_value_constraints(field: &Node<Field>) -> IndexMap<&'static str, BamlValue> {
+    [
+        "min",
+        "max",
+        "min_length",
+        "max_length",
+        "pattern",
+        "one_of",
+    ]
+    .into_iter()
+    .filter_map(|name| {
+        let value = field.attributes.get(name)?;
+        Some((name, value.normalize(&Default::default()).ok()?))
+    })
+    .collect()
+}
+
+/// Whether a value of this type holds `date` or `datetime` values outside of
+/// classes. The runtime returns them as ISO 8601 strings, so clients that
+/// use native date types convert them after parsing.
+fn has_dates(field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::Primitive(TypeValue::Date | TypeValue::DateTime) => true,
+        FieldType::List(inner) | FieldType::Optional(inner) => has_dates(inner),
+        FieldType::Map(_, value) => has_dates(value),
+        FieldType::Constrained { base, .. } => has_dates(base),
+        FieldType::Union(options) | FieldType::Tuple(options) => options.iter().any(has_dates),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use internal_baml_core::ir::repr::make_test_ir;
@@ -1,16 +1,20 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use baml_types::{BamlMediaType, FieldType, LiteralValue, TypeValue};
+use baml_types::{BamlMediaType, ConstraintLevel, FieldType, LiteralValue, TypeValue};
 use indexmap::IndexMap;
 use internal_baml_core::ir::{
+    json_schema::{assert_keywords, length_kind},
     repr::{Function, IntermediateRepr, Node, Walker},
     ClassWalker, EnumWalker,
 };
 use serde::Serialize;
 use serde_json::json;
 
-use crate::{dir_writer::{FileCollector, LanguageFeatures, RemoveDirBehavior}, field_type_attributes, TypeCheckAttributes};
+use crate::{
+    dir_writer::{FileCollector, LanguageFeatures, RemoveDirBehavior},
+    field_type_attributes, TypeCheckAttributes,
+};
 
 #[derive(Default)]
 pub(super) struct OpenApiLanguageFeatures {}
@@ -183,6 +187,72 @@ impl Serialize for OpenApiSchema<'_> {
                             ],
                         }),
                     ),
+                    (
+                        "BamlPdf",
+                        json!({
+                            "oneOf": [
+                                {
+                                    "type": "object",
+                                    "title": "BamlPdfBase64",
+                                    "properties": {
+                                        "base64": {
+                                            "type": "string",
+                                        },
+                                        "media_type": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "required": ["base64"],
+                                },
+                                {
+                                    "type": "object",
+                                    "title": "BamlPdfUrl",
+                                    "properties": {
+                                        "url": {
+                                            "type": "string",
+                                        },
+                                        "media_type": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "required": ["url"],
+                                }
+                            ],
+                        }),
+                    ),
+                    (
+                        "BamlVideo",
+                        json!({
+                            "oneOf": [
+                                {
+                                    "type": "object",
+                                    "title": "BamlVideoBase64",
+                                    "properties": {
+                                        "base64": {
+                                            "type": "string",
+                                        },
+                                        "media_type": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "required": ["base64"],
+                                },
+                                {
+                                    "type": "object",
+                                    "title": "BamlVideoUrl",
+                                    "properties": {
+                                        "url": {
+                                            "type": "string",
+                                        },
+                                        "media_type": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "required": ["url"],
+                                }
+                            ],
+                        }),
+                    ),
                     (
                         "BamlOptions",
                         json!({
@@ -205,6 +275,12 @@ impl Serialize for OpenApiSchema<'_> {
                                         }
                                     },
                                     "required": ["clients"]
+                                },
+                                "type_builder": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "string"
+                                    }
                                 }
                             }
                         })
@@ -394,6 +470,7 @@ impl<'ir> TryFrom<Walker<'ir, &'ir Node<Function>>> for OpenApiMethodDef<'ir> {
                     r#enum: None,
                     r#const: None,
                     nullable: true,
+                    validations: Default::default(),
                 },
                 type_spec: TypeSpec::Ref {
                     r#ref: "#/components/schemas/BamlOptions".into(),
@@ -417,6 +494,7 @@ impl<'ir> TryFrom<Walker<'ir, &'ir Node<Function>>> for OpenApiMethodDef<'ir> {
                     r#enum: None,
                     r#const: None,
                     nullable: false,
+                    validations: Default::default(),
                 },
                 type_spec: TypeSpec::Inline(TypeDef::Class {
                     properties,
@@ -462,6 +540,7 @@ impl<'ir> TryFrom<EnumWalker<'ir>> for TypeSpecWithMeta {
                 ),
                 r#const: None,
                 nullable: false,
+                validations: Default::default(),
             },
             type_spec: TypeSpec::Inline(TypeDef::String),
         })
@@ -478,6 +557,7 @@ impl<'ir> TryFrom<ClassWalker<'ir>> for TypeSpecWithMeta {
                 r#enum: None,
                 r#const: None,
                 nullable: false,
+                validations: Default::default(),
             },
             type_spec: TypeSpec::Inline(TypeDef::Class {
                 properties: c
@@ -529,6 +609,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                     r#enum: None,
                     r#const: None,
                     nullable: false,
+                    validations: Default::default(),
                 },
                 type_spec: TypeSpec::Ref {
                     r#ref: format!("#/components/schemas/{}", name),
@@ -540,6 +621,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                     r#enum: None,
                     r#const: None,
                     nullable: false,
+                    validations: Default::default(),
                 },
                 type_spec: match v {
                     LiteralValue::Int(_) => TypeSpec::Inline(TypeDef::Int),
@@ -553,6 +635,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                     r#enum: None,
                     r#const: None,
                     nullable: false,
+                    validations: Default::default(),
                 },
                 type_spec: TypeSpec::Inline(TypeDef::Array {
                     items: inner.to_type_spec(ir)?.into(),
@@ -568,6 +651,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                         r#enum: None,
                         r#const: None,
                         nullable: false,
+                        validations: Default::default(),
                     },
                     type_spec: TypeSpec::Inline(TypeDef::Map {
                         additional_properties: Box::new(value.to_type_spec(ir)?),
@@ -580,6 +664,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                     r#enum: None,
                     r#const: None,
                     nullable: false,
+                    validations: Default::default(),
                 },
                 type_spec: match inner {
                     TypeValue::Bool => TypeSpec::Inline(TypeDef::Bool),
@@ -591,12 +676,27 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                         "BAML<->OpenAPI only allows nulls in unions, not as a literal"
                     ),
                     TypeValue::String => TypeSpec::Inline(TypeDef::String),
+                    TypeValue::Date => {
+                        TypeSpec::Inline(TypeDef::FormattedString { format: "date" })
+                    }
+                    TypeValue::DateTime => TypeSpec::Inline(TypeDef::FormattedString {
+                        format: "date-time",
+                    }),
+                    TypeValue::Duration => {
+                        TypeSpec::Inline(TypeDef::FormattedString { format: "duration" })
+                    }
                     TypeValue::Media(BamlMediaType::Audio) => TypeSpec::Ref {
                         r#ref: format!("#/components/schemas/BamlAudio"),
                     },
                     TypeValue::Media(BamlMediaType::Image) => TypeSpec::Ref {
                         r#ref: format!("#/components/schemas/BamlImage"),
                     },
+                    TypeValue::Media(BamlMediaType::Pdf) => TypeSpec::Ref {
+                        r#ref: format!("#/components/schemas/BamlPdf"),
+                    },
+                    TypeValue::Media(BamlMediaType::Video) => TypeSpec::Ref {
+                        r#ref: format!("#/components/schemas/BamlVideo"),
+                    },
                 },
             },
             FieldType::Union(union) => {
@@ -618,8 +718,20 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                         r#enum: None,
                         r#const: None,
                         nullable: false,
+                        validations: Default::default(),
+                    },
+                    type_spec: TypeSpec::Union {
+                        one_of,
+                        discriminator: ir.union_tag(union).map(|(field, tags)| Discriminator {
+                            property_name: field.to_string(),
+                            mapping: tags
+                                .into_iter()
+                                .map(|(tag, class)| {
+                                    (tag.to_string(), format!("#/components/schemas/{class}"))
+                                })
+                                .collect(),
+                        }),
                     },
-                    type_spec: TypeSpec::Union { one_of },
                 }
             }
             FieldType::Tuple(_) => {
@@ -631,7 +743,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                 // something i saw suggested doing this
                 type_spec
             }
-            FieldType::Constrained{base,..} => {
+            FieldType::Constrained { base, constraints } => {
                 match field_type_attributes(self) {
                     Some(checks) => {
                         let base_type_ref = base.to_type_spec(ir)?;
@@ -648,7 +760,20 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                         }
                     }
                     None => {
-                        base.to_type_spec(ir)?
+                        let mut type_spec = base.to_type_spec(ir)?;
+                        // Asserts OpenAPI can't express are left to the server.
+                        for assert in constraints
+                            .iter()
+                            .filter(|c| c.level == ConstraintLevel::Assert)
+                        {
+                            for (keyword, value) in
+                                assert_keywords(assert.expression.0.trim(), length_kind(base))
+                                    .unwrap_or_default()
+                            {
+                                type_spec.meta.validations.insert(keyword, value);
+                            }
+                        }
+                        type_spec
                     }
                 }
             },
@@ -684,6 +809,10 @@ struct TypeMetadata {
     /// Nulls in OpenAPI are weird: https://swagger.io/docs/specification/data-models/data-types/
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     nullable: bool,
+
+    /// Validation keywords, like `minimum`, translated from `@assert`s.
+    #[serde(flatten)]
+    validations: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Default for TypeMetadata {
@@ -693,6 +822,7 @@ impl Default for TypeMetadata {
             r#enum: None,
             r#const: None,
             nullable: false,
+            validations: Default::default(),
         }
     }
 }
@@ -711,15 +841,31 @@ enum TypeSpec {
     Union {
         #[serde(rename = "oneOf", alias = "oneOf")]
         one_of: Vec<TypeSpecWithMeta>,
+
+        /// The tag shared by every class in the union, from `@@tag`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        discriminator: Option<Discriminator>,
     },
 }
 
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Discriminator {
+    property_name: String,
+    /// Tag value to the `$ref` of its class.
+    mapping: IndexMap<String, String>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(tag = "type")]
 enum TypeDef {
     #[serde(rename = "string")]
     String,
 
+    /// A string in a standard format, like `date-time`.
+    #[serde(rename = "string")]
+    FormattedString { format: &'static str },
+
     #[serde(rename = "object")]
     #[serde(rename_all = "camelCase")]
     Class {
@@ -1,13 +1,17 @@
 use anyhow::Result;
-use baml_types::LiteralValue;
+use baml_types::{BamlValue, LiteralValue};
 use itertools::Itertools;
 use std::borrow::Cow;
 
-use crate::{field_type_attributes, type_check_attributes, TypeCheckAttributes};
+use crate::{
+    field_default_value, field_type_attributes, field_value_constraints, type_check_attributes,
+    TypeCheckAttributes,
+};
 
 use super::python_language_features::ToPython;
 use internal_baml_core::ir::{
-    repr::{Docstring, IntermediateRepr}, ClassWalker, EnumWalker, FieldType, IRHelper,
+    repr::{Docstring, Field, IntermediateRepr, Node},
+    ClassWalker, EnumWalker, FieldType, IRHelper,
 };
 
 #[derive(askama::Template)]
@@ -113,9 +117,13 @@ impl<'ir> From<ClassWalker<'ir>> for PythonClass<'ir> {
                 .map(|f| {
                     (
                         Cow::Borrowed(f.elem.name.as_str()),
-                        add_default_value(
-                            &f.elem.r#type.elem,
+                        with_field_constraints(
+                            f,
                             &f.elem.r#type.elem.to_type_ref(&c.db),
+                            // Checked fields wrap their value, which a default can't.
+                            field_default_value(f)
+                                .filter(|_| field_type_attributes(&f.elem.r#type.elem).is_none())
+                                .map(|value| to_python_value(&value, &f.elem.r#type.elem)),
                         ),
                         f.elem.docstring.as_ref().map(|d| render_docstring(d)),
                     )
@@ -155,6 +163,7 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
                         add_default_value(
                             &f.elem.r#type.elem,
                             &f.elem.r#type.elem.to_partial_type_ref(&c.db, false),
+                            None,
                         ),
                         f.elem.docstring.as_ref().map(|d| render_docstring(d)),
                     )
@@ -165,7 +174,10 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
     }
 }
 
-pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
+pub fn add_default_value(node: &FieldType, type_str: &String, default: Option<String>) -> String {
+    if let Some(default) = default {
+        return format!("{} = {}", type_str, default);
+    }
     if type_str.starts_with("Optional[") {
         return format!("{} = None", type_str);
     } else {
@@ -173,6 +185,49 @@ pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
     }
 }
 
+/// Like `add_default_value`, but declares the value constraints of the field
+/// with a pydantic `Field`. `@one_of` has no pydantic equivalent, and is only
+/// enforced when parsing. Neither are the constraints of checked fields, whose
+/// `Checked` wrapper pydantic would compare against them.
+fn with_field_constraints(
+    field: &Node<Field>,
+    type_str: &String,
+    default: Option<String>,
+) -> String {
+    if field_type_attributes(&field.elem.r#type.elem).is_some() {
+        return add_default_value(&field.elem.r#type.elem, type_str, default);
+    }
+    let constraints = field_value_constraints(field)
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let arg = match name {
+                "min" => "ge",
+                "max" => "le",
+                "min_length" | "max_length" | "pattern" => name,
+                _ => return None,
+            };
+            Some(format!("{arg}={}", serde_json::to_string(&value).ok()?))
+        })
+        .collect::<Vec<_>>();
+    if constraints.is_empty() {
+        return add_default_value(&field.elem.r#type.elem, type_str, default);
+    }
+    let default = default.or_else(|| {
+        type_str
+            .starts_with("Optional[")
+            .then(|| "None".to_string())
+    });
+    format!(
+        "{} = Field({})",
+        type_str,
+        default
+            .map(|default| format!("default={default}"))
+            .into_iter()
+            .chain(constraints)
+            .join(", ")
+    )
+}
+
 pub fn type_name_for_checks(checks: &TypeCheckAttributes) -> String {
     let check_names = checks
         .0
@@ -201,6 +256,53 @@ pub fn to_python_literal(literal: &LiteralValue) -> String {
     format!("Literal[{value}]")
 }
 
+/// The Python expression of a field's `@default`.
+fn to_python_value(value: &BamlValue, r#type: &FieldType) -> String {
+    match (value, r#type) {
+        (BamlValue::Null, _) => "None".to_string(),
+        (_, FieldType::Optional(inner)) | (_, FieldType::Constrained { base: inner, .. }) => {
+            to_python_value(value, inner)
+        }
+        (BamlValue::Bool(true), _) => "True".to_string(),
+        (BamlValue::Bool(false), _) => "False".to_string(),
+        (BamlValue::Int(i), _) => i.to_string(),
+        (BamlValue::Float(f), _) => format!("{f:?}"),
+        (BamlValue::String(s), FieldType::Enum(name)) => format!("{name}.{s}"),
+        (BamlValue::String(s), _) => serde_json::to_string(s).unwrap_or_default(),
+        (BamlValue::List(items), _) => {
+            let inner = match r#type {
+                FieldType::List(inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(|item| to_python_value(item, inner))
+                    .join(", ")
+            )
+        }
+        (BamlValue::Map(items), _) => {
+            let inner = match r#type {
+                FieldType::Map(_, inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "{{{}}}",
+                items
+                    .iter()
+                    .map(|(k, v)| format!(
+                        "{}: {}",
+                        serde_json::to_string(k).unwrap_or_default(),
+                        to_python_value(v, inner)
+                    ))
+                    .join(", ")
+            )
+        }
+        _ => "None".to_string(),
+    }
+}
+
 trait ToTypeReferenceInTypeDefinition {
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String;
     fn to_partial_type_ref(&self, ir: &IntermediateRepr, wrapped: bool) -> String;
@@ -227,14 +329,32 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                 format!("Dict[{}, {}]", key.to_type_ref(ir), value.to_type_ref(ir))
             }
             FieldType::Primitive(r#type) => r#type.to_python(),
-            FieldType::Union(inner) => format!(
-                "Union[{}]",
-                inner
-                    .iter()
-                    .map(|t| t.to_type_ref(ir))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            ),
+            FieldType::Union(inner) => match ir.union_tag(inner) {
+                // Pydantic picks the class by its `@@tag` instead of trying each.
+                Some((field, _)) => {
+                    let tagged = format!(
+                        "Annotated[Union[{}], Field(discriminator=\"{field}\")]",
+                        inner
+                            .iter()
+                            .filter(|t| !t.is_null())
+                            .map(|t| t.to_type_ref(ir))
+                            .join(", ")
+                    );
+                    if inner.iter().any(|t| t.is_null()) {
+                        format!("Optional[{tagged}]")
+                    } else {
+                        tagged
+                    }
+                }
+                None => format!(
+                    "Union[{}]",
+                    inner
+                        .iter()
+                        .map(|t| t.to_type_ref(ir))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
             FieldType::Tuple(inner) => format!(
                 "Tuple[{}]",
                 inner
@@ -46,6 +46,7 @@ struct PythonFunction {
     name: String,
     partial_return_type: String,
     return_type: String,
+    return_dates: bool,
     args: Vec<(String, String)>,
 }
 
@@ -150,7 +151,7 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonClient
         let functions = ir
             .walk_functions()
             .map(|f| {
-                let configs = f.walk_impls();
+                let configs = f.walk_impls().filter(|c| c.is_default());
 
                 let funcs = configs
                     .into_iter()
@@ -160,6 +161,7 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonClient
                             name: f.name().to_string(),
                             partial_return_type: f.elem().output().to_partial_type_ref(ir, true),
                             return_type: f.elem().output().to_type_ref(ir, true),
+                            return_dates: crate::has_dates(f.elem().output()),
                             args: f
                                 .inputs()
                                 .iter()
@@ -36,8 +36,13 @@ impl ToPython for TypeValue {
             TypeValue::Int => "int",
             TypeValue::String => "str",
             TypeValue::Null => "None",
+            TypeValue::Date => "datetime.date",
+            TypeValue::DateTime => "datetime.datetime",
+            TypeValue::Duration => "datetime.timedelta",
             TypeValue::Media(BamlMediaType::Image) => "baml_py.Image",
             TypeValue::Media(BamlMediaType::Audio) => "baml_py.Audio",
+            TypeValue::Media(BamlMediaType::Pdf) => "baml_py.Pdf",
+            TypeValue::Media(BamlMediaType::Video) => "baml_py.Video",
         }
         .to_string()
     }
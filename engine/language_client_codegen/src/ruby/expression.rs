@@ -49,8 +49,13 @@ impl ToRuby for TypeValue {
             TypeValue::Int => "number",
             TypeValue::String => "string",
             TypeValue::Null => "null",
+            TypeValue::Date => "date",
+            TypeValue::DateTime => "datetime",
+            TypeValue::Duration => "duration",
             TypeValue::Media(BamlMediaType::Image) => "Image",
             TypeValue::Media(BamlMediaType::Audio) => "Audio",
+            TypeValue::Media(BamlMediaType::Pdf) => "Pdf",
+            TypeValue::Media(BamlMediaType::Video) => "Video",
         }
         .to_string()
     }
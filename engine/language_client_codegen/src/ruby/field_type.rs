@@ -31,9 +31,15 @@ impl ToRuby for FieldType {
                 TypeValue::Int => "Integer",
                 TypeValue::String => "String",
                 TypeValue::Null => "NilClass",
+                TypeValue::Date => "Date",
+                TypeValue::DateTime => "Time",
+                // Durations stay ISO 8601 strings, since Ruby has no duration class.
+                TypeValue::Duration => "String",
                 // TODO: Create Baml::Types::Image
                 TypeValue::Media(BamlMediaType::Image) => "Baml::Image",
                 TypeValue::Media(BamlMediaType::Audio) => "Baml::Audio",
+                TypeValue::Media(BamlMediaType::Pdf) => "Baml::Pdf",
+                TypeValue::Media(BamlMediaType::Video) => "Baml::Video",
             }),
             FieldType::Union(inner) => format!(
                 // https://sorbet.org/docs/union-types
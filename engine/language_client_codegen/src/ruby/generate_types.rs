@@ -2,10 +2,13 @@ use std::borrow::Cow;
 use std::collections::HashSet;
 
 use anyhow::Result;
-use baml_types::LiteralValue;
+use baml_types::{BamlValue, LiteralValue};
 use itertools::Itertools;
 
-use crate::{field_type_attributes, type_check_attributes, TypeCheckAttributes};
+use crate::{
+    field_default_value, field_type_attributes, has_dates, type_check_attributes,
+    TypeCheckAttributes,
+};
 
 use super::ruby_language_features::ToRuby;
 use internal_baml_core::ir::{
@@ -29,7 +32,9 @@ struct RubyEnum<'ir> {
 
 struct RubyStruct<'ir> {
     name: Cow<'ir, str>,
-    fields: Vec<(Cow<'ir, str>, String, Option<String>)>,
+    // the name, type, docstring and default of the field, and whether it
+    // holds dates to revive.
+    fields: Vec<(Cow<'ir, str>, String, Option<String>, Option<String>, bool)>,
     dynamic: bool,
     docstring: Option<String>,
 }
@@ -43,8 +48,9 @@ pub(crate) struct RubyStreamTypes<'ir> {
 /// The Python class corresponding to Partial<TypeDefinedjInBaml>
 struct PartialRubyStruct<'ir> {
     name: &'ir str,
-    // the name, type and docstring of the field
-    fields: Vec<(&'ir str, String, Option<String>)>,
+    // the name, type and docstring of the field, and whether it holds dates
+    // to revive.
+    fields: Vec<(&'ir str, String, Option<String>, bool)>,
     docstring: Option<String>,
 }
 
@@ -103,6 +109,9 @@ impl<'ir> From<ClassWalker<'ir>> for RubyStruct<'ir> {
                         Cow::Borrowed(f.elem.name.as_str()),
                         f.elem.r#type.elem.to_type_ref(),
                         f.elem.docstring.as_ref().map(|d| render_docstring(d, true)),
+                        field_default_value(f)
+                            .map(|value| to_ruby_value(&value, &f.elem.r#type.elem)),
+                        has_dates(&f.elem.r#type.elem),
                     )
                 })
                 .collect(),
@@ -140,6 +149,7 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
                         f.elem.name.as_str(),
                         f.elem.r#type.elem.to_partial_type_ref(),
                         f.elem.docstring.as_ref().map(|d| render_docstring(d, true)),
+                        has_dates(&f.elem.r#type.elem),
                     )
                 })
                 .collect(),
@@ -153,6 +163,53 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
     }
 }
 
+/// The Ruby expression of a field's `@default`.
+fn to_ruby_value(value: &BamlValue, r#type: &FieldType) -> String {
+    match (value, r#type) {
+        (BamlValue::Null, _) => "nil".to_string(),
+        (_, FieldType::Optional(inner)) | (_, FieldType::Constrained { base: inner, .. }) => {
+            to_ruby_value(value, inner)
+        }
+        (BamlValue::Bool(b), _) => b.to_string(),
+        (BamlValue::Int(i), _) => i.to_string(),
+        (BamlValue::Float(f), _) => format!("{f:?}"),
+        (BamlValue::String(s), FieldType::Enum(name)) => format!("Baml::Types::{name}::{s}"),
+        (BamlValue::String(s), _) => ruby_string(s),
+        (BamlValue::List(items), _) => {
+            let inner = match r#type {
+                FieldType::List(inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(|item| to_ruby_value(item, inner))
+                    .join(", ")
+            )
+        }
+        (BamlValue::Map(items), _) => {
+            let inner = match r#type {
+                FieldType::Map(_, inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "{{{}}}",
+                items
+                    .iter()
+                    .map(|(k, v)| format!("{} => {}", ruby_string(k), to_ruby_value(v, inner)))
+                    .join(", ")
+            )
+        }
+        _ => "nil".to_string(),
+    }
+}
+
+/// A single-quoted Ruby string, so `#{...}` isn't interpolated.
+fn ruby_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 pub(super) trait ToTypeReferenceInTypeDefinition {
     fn to_type_ref(&self) -> String;
     fn to_partial_type_ref(&self) -> String;
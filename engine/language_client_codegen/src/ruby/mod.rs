@@ -26,6 +26,7 @@ struct RubyFunction {
     name: String,
     partial_return_type: String,
     return_type: String,
+    return_dates: bool,
     args: Vec<(String, String)>,
 }
 
@@ -58,7 +59,7 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir crate::GeneratorArgs)> for RubyCl
         let functions = ir
             .walk_functions()
             .map(|f| {
-                let configs = f.walk_impls();
+                let configs = f.walk_impls().filter(|c| c.is_default());
 
                 let funcs = configs
                     .map(|c| {
@@ -67,6 +68,7 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir crate::GeneratorArgs)> for RubyCl
                             name: f.name().to_string(),
                             partial_return_type: f.elem().output().to_partial_type_ref(),
                             return_type: f.elem().output().to_ruby(),
+                            return_dates: crate::has_dates(f.elem().output()),
                             args: f
                                 .inputs()
                                 .iter()
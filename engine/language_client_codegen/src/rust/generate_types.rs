@@ -0,0 +1,482 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use anyhow::Result;
+use baml_types::{BamlMediaType, LiteralValue, TypeValue};
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+use internal_baml_core::ir::{
+    repr::{Docstring, IntermediateRepr},
+    ClassWalker, EnumWalker, FieldType,
+};
+
+use crate::{field_type_attributes, GeneratorArgs};
+
+use super::rust_language_features::to_rust_ident;
+
+#[derive(askama::Template)]
+#[template(path = "types.rs.j2", escape = "none")]
+pub(crate) struct RustTypes {
+    enums: Vec<RustEnum>,
+    classes: Vec<RustClass>,
+    unions: Vec<RustUnion>,
+}
+
+#[derive(askama::Template)]
+#[template(path = "partial_types.rs.j2", escape = "none")]
+pub(crate) struct RustStreamTypes {
+    classes: Vec<RustClass>,
+    unions: Vec<RustUnion>,
+}
+
+struct RustEnum {
+    name: String,
+    values: Vec<(String, Option<String>)>,
+    dynamic: bool,
+    docstring: Option<String>,
+}
+
+struct RustClass {
+    name: String,
+    fields: Vec<RustField>,
+    dynamic: bool,
+    docstring: Option<String>,
+}
+
+struct RustField {
+    name: String,
+    rust_type: String,
+    docstring: Option<String>,
+}
+
+struct RustUnion {
+    name: String,
+    variants: Vec<(String, String)>,
+}
+
+/// The generated module a type is referenced from, which decides the path
+/// used to reach other generated types.
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum Module {
+    Types,
+    PartialTypes,
+    Client,
+}
+
+impl Module {
+    fn types_prefix(self) -> &'static str {
+        match self {
+            Module::Types => "",
+            Module::PartialTypes | Module::Client => "types::",
+        }
+    }
+
+    fn partial_types_prefix(self) -> &'static str {
+        match self {
+            Module::PartialTypes => "",
+            Module::Types | Module::Client => "partial_types::",
+        }
+    }
+}
+
+/// Renders BAML types as Rust types.
+///
+/// Rust has no anonymous unions, so every union is given an enum named after
+/// its members (e.g. `Union2IntOrString`). The enums are collected as types
+/// are rendered, so that `types.rs` and `partial_types.rs` can define them.
+pub(super) struct RustTypeRefs<'ir> {
+    recursive_classes: HashSet<&'ir str>,
+    unions: RefCell<IndexMap<String, Vec<(String, String)>>>,
+    partial_unions: RefCell<IndexMap<String, Vec<(String, String)>>>,
+}
+
+impl<'ir> RustTypeRefs<'ir> {
+    pub(super) fn new(ir: &'ir IntermediateRepr) -> Self {
+        Self {
+            recursive_classes: ir
+                .finite_recursive_cycles()
+                .iter()
+                .flatten()
+                .map(|s| s.as_str())
+                .collect(),
+            unions: Default::default(),
+            partial_unions: Default::default(),
+        }
+    }
+
+    /// The type of a value of type `t`, as referenced from `module`.
+    pub(super) fn type_ref(&self, t: &FieldType, module: Module) -> String {
+        self.render(t, module, false, true)
+    }
+
+    /// The type of a streamed, possibly incomplete value of type `t`, as
+    /// referenced from `module`.
+    pub(super) fn partial_type_ref(&self, t: &FieldType, module: Module) -> String {
+        self.render(t, module, true, true)
+    }
+
+    /// `boxed` is set where a recursive class needs indirection, i.e.
+    /// anywhere but directly inside a `Vec` or `HashMap`.
+    fn render(&self, t: &FieldType, module: Module, partial: bool, boxed: bool) -> String {
+        match t {
+            FieldType::Primitive(v) => primitive(v, module),
+            FieldType::Enum(name) => format!("{}{name}", module.types_prefix()),
+            FieldType::Literal(v) => literal(v).to_string(),
+            FieldType::Class(name) => {
+                let prefix = if partial {
+                    module.partial_types_prefix()
+                } else {
+                    module.types_prefix()
+                };
+                if boxed && self.recursive_classes.contains(name.as_str()) {
+                    format!("Box<{prefix}{name}>")
+                } else {
+                    format!("{prefix}{name}")
+                }
+            }
+            FieldType::List(inner) => {
+                format!("Vec<{}>", self.render(inner, module, partial, false))
+            }
+            FieldType::Map(key, value) => format!(
+                "HashMap<{}, {}>",
+                self.render(key, module, false, false),
+                self.render(value, module, partial, false)
+            ),
+            FieldType::Optional(inner) => optional(self.render(inner, module, partial, boxed)),
+            FieldType::Tuple(inner) => format!(
+                "({},)",
+                inner
+                    .iter()
+                    .map(|t| self.render(t, module, partial, boxed))
+                    .join(", ")
+            ),
+            FieldType::Union(inner) => self.render_union(inner, module, partial, boxed),
+            FieldType::Constrained { base, .. } => match field_type_attributes(t) {
+                Some(_) => format!(
+                    "{}Checked<{}>",
+                    module.types_prefix(),
+                    self.render(base, module, partial, boxed)
+                ),
+                None => self.render(base, module, partial, boxed),
+            },
+        }
+    }
+
+    fn render_union(
+        &self,
+        inner: &[FieldType],
+        module: Module,
+        partial: bool,
+        boxed: bool,
+    ) -> String {
+        let nullable = inner.iter().any(|t| t.is_null());
+        // Variants are rendered as seen from the module defining the union.
+        let defined_in = if partial {
+            Module::PartialTypes
+        } else {
+            Module::Types
+        };
+        let members = inner
+            .iter()
+            .filter(|t| !t.is_null())
+            .map(|t| (t, self.render(t, defined_in, partial, true)))
+            .unique_by(|(_, rust_type)| rust_type.clone())
+            .collect::<Vec<_>>();
+
+        let rendered = match members.as_slice() {
+            [] => "()".to_string(),
+            [(t, _)] => self.render(t, module, partial, boxed),
+            _ => {
+                let mut variants: Vec<(String, String)> = vec![];
+                for (t, rust_type) in members.iter() {
+                    let mut variant = member_name(t);
+                    if variants.iter().any(|(v, _)| *v == variant) {
+                        variant = format!("{variant}{}", variants.len());
+                    }
+                    variants.push((variant, rust_type.clone()));
+                }
+                let name = format!(
+                    "Union{}{}",
+                    variants.len(),
+                    variants.iter().map(|(v, _)| v).join("Or")
+                );
+                let (unions, prefix) = if partial {
+                    (&self.partial_unions, module.partial_types_prefix())
+                } else {
+                    (&self.unions, module.types_prefix())
+                };
+                unions.borrow_mut().entry(name.clone()).or_insert(variants);
+                format!("{prefix}{name}")
+            }
+        };
+
+        if nullable {
+            optional(rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn take_unions(&self, partial: bool) -> Vec<RustUnion> {
+        let unions = if partial {
+            &self.partial_unions
+        } else {
+            &self.unions
+        };
+        unions
+            .take()
+            .into_iter()
+            .map(|(name, variants)| RustUnion { name, variants })
+            .collect()
+    }
+}
+
+fn optional(rust_type: String) -> String {
+    if rust_type.starts_with("Option<") {
+        rust_type
+    } else {
+        format!("Option<{rust_type}>")
+    }
+}
+
+fn primitive(v: &TypeValue, module: Module) -> String {
+    match v {
+        // Dates and durations are kept in their ISO 8601 form.
+        TypeValue::String | TypeValue::Date | TypeValue::DateTime | TypeValue::Duration => {
+            "String".to_string()
+        }
+        TypeValue::Int => "i64".to_string(),
+        TypeValue::Float => "f64".to_string(),
+        TypeValue::Bool => "bool".to_string(),
+        TypeValue::Null => "()".to_string(),
+        TypeValue::Media(media_type) => {
+            format!("{}{}", module.types_prefix(), media_name(media_type))
+        }
+    }
+}
+
+fn media_name(media_type: &BamlMediaType) -> &'static str {
+    match media_type {
+        BamlMediaType::Image => "Image",
+        BamlMediaType::Audio => "Audio",
+        BamlMediaType::Pdf => "Pdf",
+        BamlMediaType::Video => "Video",
+    }
+}
+
+/// Literal types are represented by their base type.
+fn literal(v: &LiteralValue) -> &'static str {
+    match v {
+        LiteralValue::String(_) => "String",
+        LiteralValue::Int(_) => "i64",
+        LiteralValue::Bool(_) => "bool",
+    }
+}
+
+/// The name of a union variant holding a value of type `t`.
+fn member_name(t: &FieldType) -> String {
+    match t {
+        FieldType::Primitive(v) => match v {
+            TypeValue::String => "String".to_string(),
+            TypeValue::Int => "Int".to_string(),
+            TypeValue::Float => "Float".to_string(),
+            TypeValue::Bool => "Bool".to_string(),
+            TypeValue::Null => "Null".to_string(),
+            TypeValue::Date => "Date".to_string(),
+            TypeValue::DateTime => "DateTime".to_string(),
+            TypeValue::Duration => "Duration".to_string(),
+            TypeValue::Media(media_type) => media_name(media_type).to_string(),
+        },
+        FieldType::Enum(name) | FieldType::Class(name) => name.clone(),
+        FieldType::Literal(v) => match v {
+            LiteralValue::String(_) => "String".to_string(),
+            LiteralValue::Int(_) => "Int".to_string(),
+            LiteralValue::Bool(_) => "Bool".to_string(),
+        },
+        FieldType::List(inner) => format!("List{}", member_name(inner)),
+        FieldType::Map(key, value) => format!("Map{}{}", member_name(key), member_name(value)),
+        FieldType::Optional(inner) => format!("Optional{}", member_name(inner)),
+        FieldType::Tuple(inner) => format!("Tuple{}", inner.iter().map(member_name).join("And")),
+        FieldType::Union(inner) => format!("Union{}", inner.iter().map(member_name).join("Or")),
+        FieldType::Constrained { base, .. } => member_name(base),
+    }
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for RustTypes {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs)) -> Result<RustTypes> {
+        let refs = RustTypeRefs::new(ir);
+        let enums = ir.walk_enums().map(|e| RustEnum::from(&e)).collect();
+        let classes = ir
+            .walk_classes()
+            .map(|c| RustClass::new(&c, &refs, false))
+            .collect();
+        // Unions only used by function signatures are defined here too.
+        for f in ir.walk_functions() {
+            for (_, r#type) in f.inputs().iter() {
+                refs.type_ref(r#type, Module::Types);
+            }
+            refs.type_ref(f.elem().output(), Module::Types);
+        }
+        Ok(RustTypes {
+            enums,
+            classes,
+            unions: refs.take_unions(false),
+        })
+    }
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for RustStreamTypes {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs)) -> Result<RustStreamTypes> {
+        let refs = RustTypeRefs::new(ir);
+        let classes = ir
+            .walk_classes()
+            .map(|c| RustClass::new(&c, &refs, true))
+            .collect();
+        for f in ir.walk_functions() {
+            refs.partial_type_ref(f.elem().output(), Module::PartialTypes);
+        }
+        Ok(RustStreamTypes {
+            classes,
+            unions: refs.take_unions(true),
+        })
+    }
+}
+
+impl<'ir> From<&EnumWalker<'ir>> for RustEnum {
+    fn from(e: &EnumWalker<'ir>) -> RustEnum {
+        RustEnum {
+            name: e.name().to_string(),
+            dynamic: e.item.attributes.get("dynamic_type").is_some(),
+            values: e
+                .item
+                .elem
+                .values
+                .iter()
+                .map(|v| {
+                    (
+                        to_rust_ident(v.0.elem.0.as_str()),
+                        v.1.as_ref().map(|d| render_docstring(d, "    ")),
+                    )
+                })
+                .collect(),
+            docstring: e
+                .item
+                .elem
+                .docstring
+                .as_ref()
+                .map(|d| render_docstring(d, "")),
+        }
+    }
+}
+
+impl RustClass {
+    /// Every field of a partial class is optional, since it may not have
+    /// been streamed yet.
+    fn new(c: &ClassWalker<'_>, refs: &RustTypeRefs<'_>, partial: bool) -> RustClass {
+        RustClass {
+            name: c.name().to_string(),
+            dynamic: c.item.attributes.get("dynamic_type").is_some(),
+            fields: c
+                .item
+                .elem
+                .static_fields
+                .iter()
+                .map(|f| {
+                    let r#type = &f.elem.r#type.elem;
+                    RustField {
+                        name: to_rust_ident(&f.elem.name),
+                        rust_type: if partial {
+                            optional(refs.partial_type_ref(r#type, Module::PartialTypes))
+                        } else {
+                            refs.type_ref(r#type, Module::Types)
+                        },
+                        docstring: f
+                            .elem
+                            .docstring
+                            .as_ref()
+                            .map(|d| render_docstring(d, "    ")),
+                    }
+                })
+                .collect(),
+            docstring: c
+                .item
+                .elem
+                .docstring
+                .as_ref()
+                .map(|d| render_docstring(d, "")),
+        }
+    }
+}
+
+/// Render the BAML documentation (a bare string with padding stripped)
+/// into Rust doc comments, each line prefixed with `indent`.
+fn render_docstring(d: &Docstring, indent: &str) -> String {
+    d.0.lines()
+        .map(|line| format!("{indent}/// {line}").trim_end().to_string())
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use internal_baml_core::ir::repr::make_test_ir;
+
+    use super::*;
+
+    #[test]
+    fn test_type_refs() {
+        let ir = make_test_ir(
+            r##"
+class Node {
+  value int
+  next Node?
+}
+"##,
+        )
+        .expect("Valid source");
+        let refs = RustTypeRefs::new(&ir);
+
+        let node = FieldType::Class("Node".to_string());
+        assert_eq!(
+            refs.type_ref(&FieldType::Optional(Box::new(node.clone())), Module::Types),
+            "Option<Box<Node>>"
+        );
+        assert_eq!(
+            refs.type_ref(&FieldType::List(Box::new(node.clone())), Module::Client),
+            "Vec<types::Node>"
+        );
+        assert_eq!(
+            refs.partial_type_ref(&FieldType::List(Box::new(node)), Module::Client),
+            "Vec<partial_types::Node>"
+        );
+
+        let union = FieldType::Union(vec![
+            FieldType::Primitive(TypeValue::Int),
+            FieldType::Primitive(TypeValue::String),
+            FieldType::Primitive(TypeValue::Null),
+        ]);
+        assert_eq!(
+            refs.type_ref(&union, Module::Client),
+            "Option<types::Union2IntOrString>"
+        );
+        let unions = refs.take_unions(false);
+        assert_eq!(unions.len(), 1);
+        assert_eq!(
+            unions[0].variants,
+            vec![
+                ("Int".to_string(), "i64".to_string()),
+                ("String".to_string(), "String".to_string())
+            ]
+        );
+
+        // Literals collapse into their base type.
+        let literals = FieldType::Union(vec![
+            FieldType::Literal(LiteralValue::String("a".to_string())),
+            FieldType::Literal(LiteralValue::String("b".to_string())),
+        ]);
+        assert_eq!(refs.type_ref(&literals, Module::Types), "String");
+    }
+}
@@ -0,0 +1,359 @@
+mod generate_types;
+mod rust_language_features;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use internal_baml_core::ir::{repr::IntermediateRepr, FieldType};
+
+use self::generate_types::{Module, RustTypeRefs};
+use self::rust_language_features::{
+    to_rust_ident, to_rust_string_literal, to_snake_case, RustLanguageFeatures,
+};
+use crate::dir_writer::FileCollector;
+
+#[derive(askama::Template)]
+#[template(path = "mod.rs.j2", escape = "none")]
+struct RustMod {}
+
+#[derive(askama::Template)]
+#[template(path = "client.rs.j2", escape = "none")]
+struct RustClient {
+    funcs: Vec<RustFunction>,
+}
+
+struct RustFunction {
+    /// The BAML name, which the runtime looks the function up by.
+    name: String,
+    partial_return_type: String,
+    return_type: String,
+    /// The Rust name, BAML name and (borrowed) type of each argument.
+    args: Vec<(String, String, String)>,
+}
+
+mod filters {
+    /// Rust method names are snake_case, whatever the BAML function is called.
+    pub fn snake_case<T: std::fmt::Display>(name: T) -> askama::Result<String> {
+        Ok(super::to_rust_ident(&super::to_snake_case(
+            &name.to_string(),
+        )))
+    }
+}
+
+#[derive(askama::Template)]
+#[template(path = "type_builder.rs.j2", escape = "none")]
+struct RustTypeBuilder {
+    classes: Vec<String>,
+    enums: Vec<String>,
+}
+
+#[derive(askama::Template)]
+#[template(path = "inlined_baml.rs.j2", escape = "none")]
+struct RustInlinedBaml {
+    file_map: Vec<(String, String)>,
+}
+
+pub(crate) fn generate(
+    ir: &IntermediateRepr,
+    generator: &crate::GeneratorArgs,
+) -> Result<IndexMap<PathBuf, String>> {
+    let mut collector = FileCollector::<RustLanguageFeatures>::new();
+
+    collector.add_template::<generate_types::RustTypes>("types.rs", (ir, generator))?;
+    collector
+        .add_template::<generate_types::RustStreamTypes>("partial_types.rs", (ir, generator))?;
+    collector.add_template::<RustTypeBuilder>("type_builder.rs", (ir, generator))?;
+    collector.add_template::<RustClient>("client.rs", (ir, generator))?;
+    collector.add_template::<RustInlinedBaml>("inlined_baml.rs", (ir, generator))?;
+    collector.add_template::<RustMod>("mod.rs", (ir, generator))?;
+
+    collector.commit(&generator.output_dir())
+}
+
+impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for RustMod {
+    type Error = anyhow::Error;
+
+    fn try_from(_: (&'_ IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+        Ok(RustMod {})
+    }
+}
+
+impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for RustInlinedBaml {
+    type Error = anyhow::Error;
+
+    fn try_from((_, args): (&'_ IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+        Ok(RustInlinedBaml {
+            file_map: args
+                .inlined_file_map
+                .iter()
+                .map(|(path, contents)| {
+                    (
+                        to_rust_string_literal(&path.display().to_string()),
+                        to_rust_string_literal(contents),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for RustTypeBuilder {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'_ IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+        Ok(RustTypeBuilder {
+            classes: ir
+                .walk_classes()
+                .filter(|c| c.item.attributes.get("dynamic_type").is_some())
+                .map(|c| c.name().to_string())
+                .collect(),
+            enums: ir
+                .walk_enums()
+                .filter(|e| e.item.attributes.get("dynamic_type").is_some())
+                .map(|e| e.name().to_string())
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for RustClient {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'_ IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+        let refs = RustTypeRefs::new(ir);
+        let funcs = ir
+            .walk_functions()
+            .map(|f| RustFunction {
+                name: f.name().to_string(),
+                partial_return_type: refs.partial_type_ref(f.elem().output(), Module::Client),
+                return_type: refs.type_ref(f.elem().output(), Module::Client),
+                args: f
+                    .inputs()
+                    .iter()
+                    .map(|(name, r#type)| {
+                        (
+                            to_rust_ident(name),
+                            name.to_string(),
+                            arg_type(r#type, &refs),
+                        )
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(RustClient { funcs })
+    }
+}
+
+/// Arguments are taken by reference, and serialized before the call.
+fn arg_type(t: &FieldType, refs: &RustTypeRefs<'_>) -> String {
+    borrowed(refs.type_ref(t, Module::Client))
+}
+
+fn borrowed(rust_type: String) -> String {
+    if let Some(inner) = rust_type
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        format!("Option<{}>", borrowed(inner.to_string()))
+    } else if let Some(inner) = rust_type
+        .strip_prefix("Box<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        format!("&{inner}")
+    } else if rust_type == "String" {
+        "&str".to_string()
+    } else if let Some(inner) = rust_type
+        .strip_prefix("Vec<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        format!("&[{inner}]")
+    } else {
+        format!("&{rust_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use askama::Template;
+    use internal_baml_core::configuration::GeneratorDefaultClientMode;
+    use internal_baml_core::ir::repr::make_test_ir;
+    use sugar_path::SugarPath;
+
+    use super::*;
+
+    #[test]
+    fn test_client_function_names() {
+        let ir = make_test_ir(
+            r##"
+client<llm> GPT4 {
+  provider openai
+  options {
+    model gpt-4o
+  }
+}
+
+function ExtractHTMLTitle(page: string) -> string {
+  client GPT4
+  prompt #"{{ page }}"#
+}
+"##,
+        )
+        .expect("Valid source");
+        let args = crate::GeneratorArgs::new(
+            "../baml_client",
+            "baml_src",
+            std::iter::empty(),
+            "0.0.0".to_string(),
+            true,
+            GeneratorDefaultClientMode::Async,
+            vec![],
+            false,
+        )
+        .unwrap();
+        let client = RustClient::try_from((&ir, &args))
+            .unwrap()
+            .render()
+            .unwrap();
+
+        assert!(client.contains("pub async fn extract_html_title("));
+        assert!(client.contains("pub fn extract_html_title("));
+        assert!(client.contains(r#"self.call("ExtractHTMLTitle", args)"#));
+        assert!(client.contains(r#"start_stream("ExtractHTMLTitle", args)"#));
+    }
+
+    /// Generates a client covering every kind of type the generator maps and
+    /// type-checks it as part of a crate depending on this workspace's
+    /// `baml-runtime`.
+    #[test]
+    fn test_generated_client_compiles() {
+        let source = r##"
+client<llm> GPT4 {
+  provider openai
+  options {
+    model gpt-4o
+  }
+}
+
+enum Category {
+  Refund
+  CancelOrder @alias("cancel_order")
+  @@dynamic
+}
+
+class Address {
+  street string
+  city string @description("The city")
+}
+
+class Resume {
+  name string
+  email string?
+  score float
+  skills string[]
+  links map<string, string>
+  address Address?
+  category Category
+  kind "person" | "company"
+  contact int | string | bool
+  photo image?
+  @@dynamic
+}
+
+class Candidate {
+  resume Resume
+  age int @check(adult, {{ this >= 18 }})
+}
+
+class Tree {
+  value int
+  children Tree[]
+}
+
+function ExtractResume(text: string, photo: image?, tags: string[], weights: map<string, float>) -> Candidate {
+  client GPT4
+  prompt #"{{ text }}"#
+}
+
+function Classify(resumes: Resume[], tree: Tree) -> Category[] {
+  client GPT4
+  prompt #"{{ resumes }}"#
+}
+
+function Summarize(a: string, b: string, c: string, d: string, e: string, f: string, g: string) -> string {
+  client GPT4
+  prompt #"{{ a }}"#
+}
+"##;
+        let ir = make_test_ir(source).expect("Valid source");
+
+        let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../target/generated-rust-client")
+            .normalize();
+        let baml_src = crate_dir.join("baml_src");
+        let files = [(baml_src.join("main.baml"), source.to_string())];
+        let args = crate::GeneratorArgs::new(
+            "../src/baml_client",
+            &baml_src,
+            files.iter().map(|(path, contents)| (path, contents)),
+            "0.0.0".to_string(),
+            true,
+            GeneratorDefaultClientMode::Async,
+            vec![],
+            false,
+        )
+        .unwrap();
+        generate(&ir, &args).unwrap();
+
+        let engine_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        std::fs::write(
+            crate_dir.join("Cargo.toml"),
+            format!(
+                r#"[package]
+name = "generated-rust-client"
+version = "0.0.0"
+edition = "2021"
+
+[workspace]
+
+[dependencies]
+baml-runtime = {{ path = {:?} }}
+anyhow = "1"
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+"#,
+                engine_dir.join("baml-runtime").display().to_string()
+            ),
+        )
+        .unwrap();
+        std::fs::write(crate_dir.join("src/lib.rs"), "pub mod baml_client;\n").unwrap();
+        // Resolve the same dependency versions as the workspace.
+        std::fs::copy(engine_dir.join("Cargo.lock"), crate_dir.join("Cargo.lock")).unwrap();
+
+        let output = std::process::Command::new(env!("CARGO"))
+            .args(["check", "--quiet"])
+            .current_dir(&crate_dir)
+            .output()
+            .expect("Failed to run cargo");
+        assert!(
+            output.status.success(),
+            "The generated client does not compile:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_borrowed_arg_types() {
+        assert_eq!(borrowed("String".to_string()), "&str");
+        assert_eq!(
+            borrowed("Vec<types::Resume>".to_string()),
+            "&[types::Resume]"
+        );
+        assert_eq!(borrowed("Option<String>".to_string()), "Option<&str>");
+        assert_eq!(borrowed("Box<types::Tree>".to_string()), "&types::Tree");
+        assert_eq!(
+            borrowed("HashMap<String, i64>".to_string()),
+            "&HashMap<String, i64>"
+        );
+    }
+}
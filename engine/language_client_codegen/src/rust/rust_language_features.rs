@@ -0,0 +1,100 @@
+use crate::dir_writer::LanguageFeatures;
+
+#[derive(Default)]
+pub(super) struct RustLanguageFeatures {}
+
+impl LanguageFeatures for RustLanguageFeatures {
+    const CONTENT_PREFIX: &'static str = r#"
+///////////////////////////////////////////////////////////////////////////////
+//
+//  Welcome to Baml! To use this generated code, add these dependencies to
+//  your Cargo.toml:
+//
+//  baml-runtime, anyhow, serde (with the "derive" feature), serde_json
+//
+//  and declare the module in your crate with `mod baml_client;`.
+//
+///////////////////////////////////////////////////////////////////////////////
+
+// This file was generated by BAML: do not edit it. Instead, edit the BAML
+// files and re-generate this code.
+//
+// @generated
+        "#;
+}
+
+/// Identifiers which cannot be used as field names, even as raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Keywords which must be written as raw identifiers (`r#type`).
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Escapes a BAML name for use as a Rust identifier.
+///
+/// Serde strips the `r#` prefix, so escaped fields keep their BAML name on
+/// the wire.
+pub(super) fn to_rust_ident(name: &str) -> String {
+    if KEYWORDS.contains(&name) && !NON_RAW_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Converts a BAML name (usually PascalCase) to snake_case, keeping acronyms
+/// together: `ExtractHTMLTitle` becomes `extract_html_title`.
+pub(super) fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev != '_' && (prev.is_lowercase() || prev.is_ascii_digit() || next_is_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Renders `s` as a Rust string literal.
+pub(super) fn to_rust_string_literal(s: &str) -> String {
+    // `str`'s Debug impl produces a valid, escaped Rust string literal.
+    format!("{:?}", s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keywords_are_escaped() {
+        assert_eq!(to_rust_ident("type"), "r#type");
+        assert_eq!(to_rust_ident("name"), "name");
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(to_snake_case("ExtractResume"), "extract_resume");
+        assert_eq!(to_snake_case("ExtractHTMLTitle"), "extract_html_title");
+        assert_eq!(to_snake_case("TestFnV2"), "test_fn_v2");
+        assert_eq!(to_snake_case("already_snake"), "already_snake");
+        assert_eq!(to_snake_case("Foo_Bar"), "foo_bar");
+    }
+
+    #[test]
+    fn test_string_literal_escapes() {
+        assert_eq!(
+            to_rust_string_literal("say \"hi\"\n\u{1b}"),
+            r#""say \"hi\"\n\u{1b}""#
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use baml_types::{Constraint, ConstraintLevel, FieldType, LiteralValue, TypeValue};
+use indexmap::IndexMap;
+use internal_baml_core::ir::repr::IntermediateRepr;
+use serde_json::{json, Value};
+
+use crate::GeneratorArgs;
+
+/// Converts the ISO 8601 strings BAML returns for `date` and `datetime`
+/// values into JS `Date`s, following where they are in each class.
+#[derive(askama::Template)]
+#[template(path = "dates.ts.j2", escape = "none")]
+pub(crate) struct TypescriptDates {
+    class_dates: String,
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptDates {
+    type Error = anyhow::Error;
+
+    fn try_from((ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs)) -> Result<Self> {
+        let class_dates = ClassDates::new(ir)
+            .fields
+            .into_iter()
+            .filter(|(_, fields)| !fields.is_empty())
+            .collect::<IndexMap<_, _>>();
+        Ok(TypescriptDates {
+            class_dates: serde_json::to_string_pretty(&class_dates)?,
+        })
+    }
+}
+
+/// The fields of each class that hold dates, and where in them.
+pub(super) struct ClassDates {
+    fields: IndexMap<String, IndexMap<String, Value>>,
+    with_dates: HashSet<String>,
+    shapes: HashMap<String, Value>,
+}
+
+impl ClassDates {
+    pub(super) fn new(ir: &IntermediateRepr) -> Self {
+        // How to tell each class apart from the other options of a union: by
+        // its `@@tag`, or else by its field names.
+        let shapes = ir
+            .walk_classes()
+            .map(|c| {
+                let mut shape = json!({
+                    "class": c.name(),
+                    "fields": c.item.elem.static_fields.iter().map(|f| &f.elem.name).collect::<Vec<_>>(),
+                });
+                if let Some((field, value)) = c.tag() {
+                    shape["tag"] = json!({ "field": field, "value": value });
+                }
+                (c.name().to_string(), shape)
+            })
+            .collect::<HashMap<_, _>>();
+        let mut with_dates = HashSet::new();
+        // Classes that only hold dates through other classes are found once
+        // those classes are, so repeat until nothing changes.
+        loop {
+            let fields: IndexMap<String, IndexMap<String, Value>> = ir
+                .walk_classes()
+                .map(|c| {
+                    let fields = c
+                        .item
+                        .elem
+                        .static_fields
+                        .iter()
+                        .filter_map(|f| {
+                            date_spec(&f.elem.r#type.elem, &with_dates, &shapes)
+                                .map(|spec| (f.elem.name.clone(), spec))
+                        })
+                        .collect::<IndexMap<_, _>>();
+                    (c.name().to_string(), fields)
+                })
+                .collect();
+            let found = fields
+                .iter()
+                .filter(|(_, fields)| !fields.is_empty())
+                .map(|(name, _)| name.clone())
+                .collect::<HashSet<_>>();
+            if found == with_dates {
+                return ClassDates {
+                    fields,
+                    with_dates,
+                    shapes,
+                };
+            }
+            with_dates = found;
+        }
+    }
+
+    /// Where the dates are in a value of type `t`, as JSON for `reviveDates`,
+    /// or `None` if it has none.
+    pub(super) fn spec(&self, t: &FieldType) -> Option<String> {
+        date_spec(t, &self.with_dates, &self.shapes).map(|spec| spec.to_string())
+    }
+}
+
+fn date_spec(
+    t: &FieldType,
+    with_dates: &HashSet<String>,
+    shapes: &HashMap<String, Value>,
+) -> Option<Value> {
+    match t {
+        FieldType::Primitive(TypeValue::Date | TypeValue::DateTime) => Some(json!("date")),
+        FieldType::Class(name) if with_dates.contains(name) => Some(json!({ "class": name })),
+        FieldType::List(item) => date_spec(item, with_dates, shapes).map(|s| json!({ "list": s })),
+        FieldType::Map(_, value) => {
+            date_spec(value, with_dates, shapes).map(|s| json!({ "map": s }))
+        }
+        FieldType::Optional(inner) => date_spec(inner, with_dates, shapes),
+        FieldType::Constrained { base, constraints } => {
+            let spec = date_spec(base, with_dates, shapes)?;
+            // Values with checks are wrapped in `{ value, checks }`.
+            Some(
+                if constraints
+                    .iter()
+                    .any(|Constraint { level, .. }| matches!(level, ConstraintLevel::Check))
+                {
+                    json!({ "checked": spec })
+                } else {
+                    spec
+                },
+            )
+        }
+        FieldType::Union(options) => {
+            // A string in a union that also allows other strings is kept.
+            let has_strings = options.iter().any(|o| {
+                matches!(
+                    o,
+                    FieldType::Primitive(TypeValue::String | TypeValue::Duration)
+                        | FieldType::Enum(_)
+                        | FieldType::Literal(LiteralValue::String(_))
+                )
+            });
+            let options = options
+                .iter()
+                .filter(|o| {
+                    !(has_strings
+                        && matches!(
+                            o,
+                            FieldType::Primitive(TypeValue::Date | TypeValue::DateTime)
+                        ))
+                })
+                .collect::<Vec<_>>();
+            let specs = options
+                .iter()
+                .filter_map(|o| date_spec(o, with_dates, shapes))
+                .collect::<Vec<_>>();
+            // Classes without dates are options too: `reviveDates` must not
+            // revive a value of one of them with the dates of another.
+            let mut classes = options
+                .iter()
+                .filter_map(|o| match o {
+                    FieldType::Class(name) => shapes.get(name).cloned(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            match (specs.len(), classes.len()) {
+                (0, _) => None,
+                (1, 0 | 1) => specs.into_iter().next(),
+                _ => {
+                    // Classes go first, as a map would match any object.
+                    classes.extend(specs.into_iter().filter(|s| s.get("class").is_none()));
+                    Some(json!({ "union": classes }))
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_baml_core::ir::repr::make_test_ir;
+
+    #[test]
+    fn test_union_spec() {
+        let ir = make_test_ir(
+            r#"
+            class Meeting {
+              kind "meeting"
+              at datetime
+              @@tag("kind")
+            }
+
+            class Note {
+              kind "note"
+              at string
+              @@tag("kind")
+            }
+
+            class Reminder {
+              text string
+              due date
+            }
+        "#,
+        )
+        .unwrap();
+        let dates = ClassDates::new(&ir);
+
+        // Only the class a value is tagged as gets revived.
+        assert_eq!(
+            dates
+                .spec(&FieldType::union(vec![
+                    FieldType::class("Meeting"),
+                    FieldType::class("Note"),
+                ]))
+                .unwrap(),
+            json!({ "union": [
+                { "class": "Meeting", "fields": ["kind", "at"], "tag": { "field": "kind", "value": "meeting" } },
+                { "class": "Note", "fields": ["kind", "at"], "tag": { "field": "kind", "value": "note" } },
+            ] })
+            .to_string()
+        );
+        // Without tags, classes are told apart by their fields.
+        assert_eq!(
+            dates
+                .spec(&FieldType::union(vec![
+                    FieldType::class("Reminder"),
+                    FieldType::List(Box::new(FieldType::Primitive(TypeValue::Date))),
+                    FieldType::null(),
+                ]))
+                .unwrap(),
+            json!({ "union": [
+                { "class": "Reminder", "fields": ["text", "due"] },
+                { "list": "date" },
+            ] })
+            .to_string()
+        );
+        assert_eq!(
+            dates.spec(&FieldType::union(vec![
+                FieldType::class("Reminder"),
+                FieldType::null(),
+            ])),
+            Some(json!({ "class": "Reminder" }).to_string())
+        );
+        assert_eq!(dates.spec(&FieldType::class("Note")), None);
+    }
+}
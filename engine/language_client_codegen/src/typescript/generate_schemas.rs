@@ -0,0 +1,630 @@
+use anyhow::Result;
+use baml_types::{BamlMediaType, Constraint, ConstraintLevel, FieldType, LiteralValue, TypeValue};
+use itertools::Itertools;
+
+use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker};
+
+use crate::{field_default_value, field_type_attributes, GeneratorArgs};
+
+use super::generate_types::to_ts_value;
+
+/// Zod schemas for every type in `types.ts`, and for their partial
+/// (streamed) counterparts. Only generated when the generator sets `zod true`.
+#[derive(askama::Template)]
+#[template(path = "schemas.ts.j2", escape = "none")]
+pub(crate) struct TypescriptSchemas<'ir> {
+    enums: Vec<ZodEnum<'ir>>,
+    classes: Vec<ZodClass<'ir>>,
+}
+
+struct ZodEnum<'ir> {
+    name: &'ir str,
+    dynamic: bool,
+}
+
+struct ZodClass<'ir> {
+    name: &'ir str,
+    dynamic: bool,
+    /// The name, schema and partial schema of each field.
+    fields: Vec<(&'ir str, String, String)>,
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptSchemas<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        (ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs),
+    ) -> Result<TypescriptSchemas<'ir>> {
+        Ok(TypescriptSchemas {
+            enums: ir.walk_enums().map(|e| (&e).into()).collect(),
+            classes: ir.walk_classes().map(|c| (&c).into()).collect(),
+        })
+    }
+}
+
+impl<'ir> TypescriptSchemas<'ir> {
+    fn types(&self) -> String {
+        self.enums
+            .iter()
+            .map(|e| e.name)
+            .chain(self.classes.iter().map(|c| c.name))
+            .join(", ")
+    }
+}
+
+impl<'ir> From<&EnumWalker<'ir>> for ZodEnum<'ir> {
+    fn from(e: &EnumWalker<'ir>) -> ZodEnum<'ir> {
+        ZodEnum {
+            name: e.name(),
+            dynamic: e.item.attributes.get("dynamic_type").is_some(),
+        }
+    }
+}
+
+impl<'ir> From<&ClassWalker<'ir>> for ZodClass<'ir> {
+    fn from(c: &ClassWalker<'ir>) -> ZodClass<'ir> {
+        ZodClass {
+            name: c.name(),
+            dynamic: c.item.attributes.get("dynamic_type").is_some(),
+            fields: c
+                .item
+                .elem
+                .static_fields
+                .iter()
+                .map(|f| {
+                    let r#type = &f.elem.r#type.elem;
+                    let schema = zod_schema(c.db, r#type, false);
+                    let schema = if r#type.is_optional() {
+                        format!("{schema}.optional()")
+                    } else {
+                        schema
+                    };
+                    // Checked fields wrap their value, which a default can't.
+                    let schema = match field_default_value(f) {
+                        Some(value) if field_type_attributes(r#type).is_none() => {
+                            format!("{schema}.default({})", to_ts_value(&value, r#type))
+                        }
+                        _ => schema,
+                    };
+                    (f.elem.name.as_str(), schema, zod_schema(c.db, r#type, true))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The Zod schema for a type. Partial schemas mirror `RecursivePartialNull`:
+/// every value may be missing or null, and asserts are not enforced.
+fn zod_schema(ir: &IntermediateRepr, r#type: &FieldType, partial: bool) -> String {
+    let schema = match r#type {
+        FieldType::Primitive(primitive) => primitive_schema(primitive).to_string(),
+        FieldType::Enum(name) => format!("{name}Schema"),
+        FieldType::Class(name) if partial => format!("Partial{name}Schema"),
+        FieldType::Class(name) => format!("{name}Schema"),
+        FieldType::Literal(value) => format!("z.literal({})", literal(value)),
+        FieldType::List(inner) => format!("z.array({})", zod_schema(ir, inner, partial)),
+        FieldType::Map(key, value) => format!(
+            "z.record({}, {})",
+            zod_schema(ir, key, false),
+            zod_schema(ir, value, partial)
+        ),
+        FieldType::Union(variants) => match (variants.as_slice(), ir.union_tag(variants)) {
+            ([variant], _) => zod_schema(ir, variant, partial),
+            // Tagged unions parse a value with the class its `@@tag` picks.
+            (_, Some((field, tags))) if !partial => {
+                let schema = format!(
+                    "taggedUnion({}, {{ {} }})",
+                    js_string(field),
+                    tags.iter()
+                        .map(|(tag, class)| format!("{}: {class}Schema", js_string(tag)))
+                        .join(", ")
+                );
+                if variants.iter().any(|v| v.is_null()) {
+                    format!("{schema}.nullable()")
+                } else {
+                    schema
+                }
+            }
+            _ => format!(
+                "z.union([{}])",
+                variants
+                    .iter()
+                    .map(|v| zod_schema(ir, v, partial))
+                    .join(", ")
+            ),
+        },
+        FieldType::Tuple(items) => format!(
+            "z.tuple([{}])",
+            items.iter().map(|t| zod_schema(ir, t, partial)).join(", ")
+        ),
+        // Partial schemas are already nullable.
+        FieldType::Optional(inner) if partial => return zod_schema(ir, inner, partial),
+        FieldType::Optional(inner) => format!("{}.nullable()", zod_schema(ir, inner, partial)),
+        FieldType::Constrained { .. } => return constrained_schema(ir, r#type, partial),
+    };
+    if partial {
+        format!("{schema}.nullish()")
+    } else {
+        schema
+    }
+}
+
+fn primitive_schema(primitive: &TypeValue) -> &'static str {
+    match primitive {
+        TypeValue::String => "z.string()",
+        TypeValue::Int => "z.number().int()",
+        TypeValue::Float => "z.number()",
+        TypeValue::Bool => "z.boolean()",
+        TypeValue::Null => "z.null()",
+        TypeValue::Date | TypeValue::DateTime => "z.coerce.date()",
+        TypeValue::Duration => "z.string()",
+        TypeValue::Media(BamlMediaType::Image) => "z.custom<Image>()",
+        TypeValue::Media(BamlMediaType::Audio) => "z.custom<Audio>()",
+        TypeValue::Media(BamlMediaType::Pdf) => "z.custom<Pdf>()",
+        TypeValue::Media(BamlMediaType::Video) => "z.custom<Video>()",
+    }
+}
+
+fn literal(value: &LiteralValue) -> String {
+    match value {
+        LiteralValue::String(s) => js_string(s),
+        LiteralValue::Int(i) => i.to_string(),
+        LiteralValue::Bool(b) => b.to_string(),
+    }
+}
+
+fn js_string(s: &str) -> String {
+    serde_json::to_string(s).expect("strings always serialize")
+}
+
+/// @asserts become `refine` calls, and @checks wrap the value in a `Checked`
+/// object, as in `types.ts`.
+fn constrained_schema(ir: &IntermediateRepr, r#type: &FieldType, partial: bool) -> String {
+    let mut base = r#type;
+    let mut constraints: Vec<&Constraint> = vec![];
+    while let FieldType::Constrained {
+        base: inner,
+        constraints: c,
+    } = base
+    {
+        constraints.extend(c);
+        base = inner;
+    }
+
+    let mut schema = zod_schema(ir, base, partial);
+    if !partial {
+        for assert in constraints
+            .iter()
+            .filter(|c| c.level == ConstraintLevel::Assert)
+        {
+            schema = refine(schema, assert);
+        }
+    }
+
+    let checks = constraints
+        .iter()
+        .filter(|c| c.level == ConstraintLevel::Check)
+        .filter_map(|c| c.label.as_deref())
+        .sorted()
+        .dedup()
+        .map(|name| format!("{}: CheckSchema", js_string(name)))
+        .collect::<Vec<_>>();
+    if checks.is_empty() {
+        return schema;
+    }
+    let checked = format!(
+        "z.object({{ value: {schema}, checks: z.object({{ {} }}) }})",
+        checks.join(", ")
+    );
+    if partial {
+        format!("{checked}.nullish()")
+    } else {
+        checked
+    }
+}
+
+/// Asserts that can't be translated to TypeScript are only enforced by the
+/// BAML runtime, and are left as a comment in the schema.
+fn refine(schema: String, assert: &Constraint) -> String {
+    let expression = assert.expression.0.trim();
+    match jinja_to_js(expression) {
+        Some(js) => format!(
+            "{schema}.refine((v) => {js}, {{ message: {} }})",
+            js_string(assert.label.as_deref().unwrap_or(expression))
+        ),
+        None => format!(
+            "{schema} /* @assert not checked by zod: {} */",
+            expression.replace("*/", "* /")
+        ),
+    }
+}
+
+/// Translates a Jinja expression over `this` into a TypeScript expression
+/// over `v`, if it only uses operators and filters that have an equivalent.
+fn jinja_to_js(expression: &str) -> Option<String> {
+    let mut translator = JsTranslator {
+        tokens: tokenize(expression)?,
+        pos: 0,
+    };
+    let js = translator.or()?;
+    (translator.pos == translator.tokens.len()).then_some(js)
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Op(String),
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let chars = expression.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c == '"' || c == '\'' {
+            let mut value = String::new();
+            i += 1;
+            loop {
+                let next = *chars.get(i)?;
+                i += 1;
+                if next == c {
+                    break;
+                }
+                if next == '\\' {
+                    let escaped = *chars.get(i)?;
+                    i += 1;
+                    match escaped {
+                        'n' => value.push('\n'),
+                        't' => value.push('\t'),
+                        '"' | '\'' | '\\' => value.push(escaped),
+                        // Unknown escapes are kept, e.g. `\d` in regexes.
+                        other => {
+                            value.push('\\');
+                            value.push(other);
+                        }
+                    }
+                } else {
+                    value.push(next);
+                }
+            }
+            tokens.push(Token::Str(value));
+        } else {
+            let two = chars[i..(i + 2).min(chars.len())]
+                .iter()
+                .collect::<String>();
+            if ["==", "!=", "<=", ">=", "//", "**"].contains(&two.as_str()) {
+                tokens.push(Token::Op(two));
+                i += 2;
+            } else if "<>+-*/%|.()[],~".contains(c) {
+                tokens.push(Token::Op(c.to_string()));
+                i += 1;
+            } else {
+                return None;
+            }
+        }
+    }
+    Some(tokens)
+}
+
+struct JsTranslator {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl JsTranslator {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat_op(&mut self, op: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Op(o)) if o == op);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Ident(i)) if i == ident);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn or(&mut self) -> Option<String> {
+        let mut left = self.and()?;
+        while self.eat_ident("or") {
+            left = format!("{left} || {}", self.and()?);
+        }
+        Some(left)
+    }
+
+    fn and(&mut self) -> Option<String> {
+        let mut left = self.not()?;
+        while self.eat_ident("and") {
+            left = format!("{left} && {}", self.not()?);
+        }
+        Some(left)
+    }
+
+    fn not(&mut self) -> Option<String> {
+        if self.eat_ident("not") {
+            Some(format!("!({})", self.not()?))
+        } else {
+            self.comparison()
+        }
+    }
+
+    fn comparison(&mut self) -> Option<String> {
+        let left = self.additive()?;
+        let op = match self.peek() {
+            Some(Token::Op(op)) => match op.as_str() {
+                "==" => "===",
+                "!=" => "!==",
+                "<" => "<",
+                "<=" => "<=",
+                ">" => ">",
+                ">=" => ">=",
+                _ => return Some(left),
+            },
+            Some(Token::Ident(i)) if i == "in" => {
+                self.pos += 1;
+                return Some(format!("{}.includes({left})", self.additive()?));
+            }
+            Some(Token::Ident(i))
+                if i == "not"
+                    && matches!(self.tokens.get(self.pos + 1), Some(Token::Ident(i)) if i == "in") =>
+            {
+                self.pos += 2;
+                return Some(format!("!{}.includes({left})", self.additive()?));
+            }
+            _ => return Some(left),
+        };
+        self.pos += 1;
+        Some(format!("{left} {op} {}", self.additive()?))
+    }
+
+    fn additive(&mut self) -> Option<String> {
+        let mut left = self.multiplicative()?;
+        loop {
+            if self.eat_op("+") {
+                left = format!("{left} + {}", self.multiplicative()?);
+            } else if self.eat_op("-") {
+                left = format!("{left} - {}", self.multiplicative()?);
+            } else if self.eat_op("~") {
+                left = format!("String({left}) + String({})", self.multiplicative()?);
+            } else {
+                return Some(left);
+            }
+        }
+    }
+
+    fn multiplicative(&mut self) -> Option<String> {
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op(op)) if ["*", "/", "%"].contains(&op.as_str()) => op.clone(),
+                _ => return Some(left),
+            };
+            self.pos += 1;
+            left = format!("{left} {op} {}", self.unary()?);
+        }
+    }
+
+    fn unary(&mut self) -> Option<String> {
+        if self.eat_op("-") {
+            Some(format!("-{}", self.unary()?))
+        } else {
+            self.postfix()
+        }
+    }
+
+    fn postfix(&mut self) -> Option<String> {
+        let mut value = self.primary()?;
+        loop {
+            if self.eat_op(".") {
+                match self.tokens.get(self.pos) {
+                    Some(Token::Ident(attr)) => {
+                        value = format!("{value}.{attr}");
+                        self.pos += 1;
+                    }
+                    _ => return None,
+                }
+                // Method calls have no general equivalent.
+                if matches!(self.peek(), Some(Token::Op(o)) if o == "(") {
+                    return None;
+                }
+            } else if self.eat_op("[") {
+                let index = self.or()?;
+                if !self.eat_op("]") {
+                    return None;
+                }
+                value = format!("{value}[{index}]");
+            } else if self.eat_op("|") {
+                let name = match self.tokens.get(self.pos) {
+                    Some(Token::Ident(name)) => name.clone(),
+                    _ => return None,
+                };
+                self.pos += 1;
+                let args = if self.eat_op("(") {
+                    self.list(")")?
+                } else {
+                    vec![]
+                };
+                value = filter(value, &name, &args)?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn primary(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        match token {
+            Token::Number(n) => Some(n.clone()),
+            Token::Str(s) => Some(js_string(s)),
+            Token::Ident(i) => match i.as_str() {
+                "this" => Some("v".to_string()),
+                "true" | "True" => Some("true".to_string()),
+                "false" | "False" => Some("false".to_string()),
+                "none" | "None" => Some("null".to_string()),
+                _ => None,
+            },
+            Token::Op(o) if o == "(" => {
+                let inner = self.or()?;
+                self.eat_op(")").then(|| format!("({inner})"))
+            }
+            Token::Op(o) if o == "[" => Some(format!("[{}]", self.list("]")?.join(", "))),
+            Token::Op(_) => None,
+        }
+    }
+
+    /// Comma-separated expressions, up to and including `close`.
+    fn list(&mut self, close: &str) -> Option<Vec<String>> {
+        let mut items = vec![];
+        if self.eat_op(close) {
+            return Some(items);
+        }
+        loop {
+            items.push(self.or()?);
+            if self.eat_op(close) {
+                return Some(items);
+            }
+            if !self.eat_op(",") {
+                return None;
+            }
+        }
+    }
+}
+
+fn filter(value: String, name: &str, args: &[String]) -> Option<String> {
+    Some(match (name, args) {
+        ("length" | "count", []) => format!("lengthOf({value})"),
+        ("lower", []) => format!("{value}.toLowerCase()"),
+        ("upper", []) => format!("{value}.toUpperCase()"),
+        ("trim", []) => format!("{value}.trim()"),
+        ("abs", []) => format!("Math.abs({value})"),
+        ("sum", []) => format!("{value}.reduce((a, b) => a + b, 0)"),
+        ("regex_match", [pattern]) => format!("new RegExp({pattern}).test({value})"),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baml_types::JinjaExpression;
+    use internal_baml_core::ir::repr::make_test_ir;
+
+    #[test]
+    fn test_jinja_to_js() {
+        assert_eq!(jinja_to_js("this|length > 0").unwrap(), "lengthOf(v) > 0");
+        assert_eq!(
+            jinja_to_js("this >= 0 and this <= 100").unwrap(),
+            "v >= 0 && v <= 100"
+        );
+        assert_eq!(
+            jinja_to_js("this.name == \"a\" or not this.ok").unwrap(),
+            "v.name === \"a\" || !(v.ok)"
+        );
+        assert_eq!(
+            jinja_to_js(r#"this|regex_match("\d+@x\.com")"#).unwrap(),
+            r#"new RegExp("\\d+@x\\.com").test(v)"#
+        );
+        assert_eq!(
+            jinja_to_js("this|lower in ['a', 'b']").unwrap(),
+            "[\"a\", \"b\"].includes(v.toLowerCase())"
+        );
+        // Unknown names, filters and method calls can't be translated.
+        assert_eq!(jinja_to_js("other > 0"), None);
+        assert_eq!(jinja_to_js("this|wordcount > 2"), None);
+        assert_eq!(jinja_to_js("this.startswith('a')"), None);
+        assert_eq!(jinja_to_js("0 < this < 10"), None);
+    }
+
+    #[test]
+    fn test_zod_schema() {
+        let ir = &IntermediateRepr::create_empty();
+        let optional_list = FieldType::Optional(Box::new(FieldType::List(Box::new(
+            FieldType::Class("Resume".to_string()),
+        ))));
+        assert_eq!(
+            zod_schema(ir, &optional_list, false),
+            "z.array(ResumeSchema).nullable()"
+        );
+        assert_eq!(
+            zod_schema(ir, &optional_list, true),
+            "z.array(PartialResumeSchema.nullish()).nullish()"
+        );
+
+        let constrained = FieldType::Constrained {
+            base: Box::new(FieldType::Primitive(TypeValue::Int)),
+            constraints: vec![
+                Constraint {
+                    level: ConstraintLevel::Assert,
+                    expression: JinjaExpression("this > 0".to_string()),
+                    label: Some("positive".to_string()),
+                },
+                Constraint {
+                    level: ConstraintLevel::Check,
+                    expression: JinjaExpression("this < 10".to_string()),
+                    label: Some("small".to_string()),
+                },
+            ],
+        };
+        assert_eq!(
+            zod_schema(ir, &constrained, false),
+            r#"z.object({ value: z.number().int().refine((v) => v > 0, { message: "positive" }), checks: z.object({ "small": CheckSchema }) })"#
+        );
+    }
+
+    #[test]
+    fn test_tagged_union_schema() {
+        let ir = make_test_ir(
+            r#"
+            class Circle {
+              kind "circle"
+              @@tag("kind")
+            }
+
+            class Square {
+              kind "square"
+              @@tag("kind")
+            }
+        "#,
+        )
+        .unwrap();
+        let shape = FieldType::union(vec![
+            FieldType::class("Circle"),
+            FieldType::class("Square"),
+            FieldType::null(),
+        ]);
+        assert_eq!(
+            zod_schema(&ir, &shape, false),
+            r#"taggedUnion("kind", { "circle": CircleSchema, "square": SquareSchema }).nullable()"#
+        );
+        assert_eq!(
+            zod_schema(&ir, &shape, true),
+            "z.union([PartialCircleSchema.nullish(), PartialSquareSchema.nullish(), z.null().nullish()]).nullish()"
+        );
+    }
+}
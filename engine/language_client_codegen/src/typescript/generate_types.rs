@@ -1,11 +1,15 @@
 use std::borrow::Cow;
 
 use anyhow::Result;
+use baml_types::{BamlValue, FieldType};
 use itertools::Itertools;
 
 use internal_baml_core::ir::{repr::{Docstring, IntermediateRepr}, ClassWalker, EnumWalker};
 
-use crate::{type_check_attributes, GeneratorArgs, TypeCheckAttributes};
+use crate::{
+    field_default_value, field_value_constraints, type_check_attributes, GeneratorArgs,
+    TypeCheckAttributes,
+};
 
 use super::ToTypeReferenceInClientDefinition;
 
@@ -101,11 +105,46 @@ impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
+                    // Interfaces can't hold values, so defaults and value
+                    // constraints are documented, with the tags JSON Schema
+                    // generators read.
+                    let default = field_default_value(f).map(|value| {
+                        format!("@default {}", to_ts_value(&value, &f.elem.r#type.elem))
+                    });
+                    let constraints =
+                        field_value_constraints(f).into_iter().map(|(name, value)| {
+                            let tag = match name {
+                                "min" => "minimum",
+                                "max" => "maximum",
+                                "min_length" => "minLength",
+                                "max_length" => "maxLength",
+                                "one_of" => "enum",
+                                _ => name,
+                            };
+                            format!(
+                                "@{tag} {}",
+                                serde_json::to_string(&value).unwrap_or_default()
+                            )
+                        });
+                    let tags = default.into_iter().chain(constraints).collect::<Vec<_>>();
+                    let with_tags = (!tags.is_empty()).then(|| {
+                        Docstring(
+                            f.elem
+                                .docstring
+                                .iter()
+                                .map(|d| d.0.clone())
+                                .chain(tags)
+                                .join("\n"),
+                        )
+                    });
                     (
                         Cow::Borrowed(f.elem.name.as_str()),
                         f.elem.r#type.elem.is_optional(),
                         f.elem.r#type.elem.to_type_ref(&c.db),
-                        f.elem.docstring.as_ref().map(|d| render_docstring(d, true)),
+                        with_tags
+                            .as_ref()
+                            .or(f.elem.docstring.as_ref())
+                            .map(|d| render_docstring(d, true)),
                     )
                 })
                 .collect(),
@@ -114,6 +153,49 @@ impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
     }
 }
 
+/// The TypeScript expression of a field's `@default`.
+pub(super) fn to_ts_value(value: &BamlValue, r#type: &FieldType) -> String {
+    match (value, r#type) {
+        (BamlValue::Null, _) => "null".to_string(),
+        (_, FieldType::Optional(inner)) | (_, FieldType::Constrained { base: inner, .. }) => {
+            to_ts_value(value, inner)
+        }
+        (BamlValue::Bool(b), _) => b.to_string(),
+        (BamlValue::Int(i), _) => i.to_string(),
+        (BamlValue::Float(f), _) => f.to_string(),
+        (BamlValue::String(s), FieldType::Enum(name)) => format!("{name}.{s}"),
+        (BamlValue::String(s), _) => serde_json::to_string(s).unwrap_or_default(),
+        (BamlValue::List(items), _) => {
+            let inner = match r#type {
+                FieldType::List(inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "[{}]",
+                items.iter().map(|item| to_ts_value(item, inner)).join(", ")
+            )
+        }
+        (BamlValue::Map(items), _) => {
+            let inner = match r#type {
+                FieldType::Map(_, inner) => inner.as_ref(),
+                _ => r#type,
+            };
+            format!(
+                "{{ {} }}",
+                items
+                    .iter()
+                    .map(|(k, v)| format!(
+                        "{}: {}",
+                        serde_json::to_string(k).unwrap_or_default(),
+                        to_ts_value(v, inner)
+                    ))
+                    .join(", ")
+            )
+        }
+        _ => "null".to_string(),
+    }
+}
+
 pub fn type_name_for_checks(checks: &TypeCheckAttributes) -> String {
     checks.0.iter().map(|check| format!("\"{check}\"")).sorted().join(" | ")
 }
@@ -1,3 +1,5 @@
+mod generate_dates;
+mod generate_schemas;
 mod generate_types;
 mod typescript_language_features;
 
@@ -57,6 +59,8 @@ struct TypescriptFunction {
     name: String,
     // partial_return_type: String,
     return_type: String,
+    /// Where the dates are in the return value, if it has any.
+    return_dates: Option<String>,
     args: Vec<(String, bool, String)>,
 }
 
@@ -64,6 +68,7 @@ struct TypescriptFunction {
 #[template(path = "index.ts.j2", escape = "none")]
 struct TypescriptInit {
     default_client_mode: GeneratorDefaultClientMode,
+    zod: bool,
 }
 
 #[derive(askama::Template)]
@@ -88,7 +93,12 @@ pub(crate) fn generate(
 ) -> Result<IndexMap<PathBuf, String>> {
     let mut collector = FileCollector::<TypescriptLanguageFeatures>::new();
     collector.add_template::<generate_types::TypescriptTypes>("types.ts", (ir, generator))?;
+    if generator.zod {
+        collector
+            .add_template::<generate_schemas::TypescriptSchemas>("schemas.ts", (ir, generator))?;
+    }
     collector.add_template::<generate_types::TypeBuilder>("type_builder.ts", (ir, generator))?;
+    collector.add_template::<generate_dates::TypescriptDates>("dates.ts", (ir, generator))?;
     collector.add_template::<AsyncTypescriptClient>("async_client.ts", (ir, generator))?;
     collector.add_template::<SyncTypescriptClient>("sync_client.ts", (ir, generator))?;
     collector.add_template::<TypescriptGlobals>("globals.ts", (ir, generator))?;
@@ -121,10 +131,11 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
     type Error = anyhow::Error;
 
     fn try_from((ir, _): (&IntermediateRepr, &crate::GeneratorArgs)) -> Result<Self> {
+        let class_dates = generate_dates::ClassDates::new(ir);
         let functions = ir
             .walk_functions()
             .map(|f| {
-                let configs = f.walk_impls();
+                let configs = f.walk_impls().filter(|c| c.is_default());
 
                 let funcs = configs
                     .map(|c| {
@@ -132,6 +143,7 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
                         Ok(TypescriptFunction {
                             name: f.name().to_string(),
                             return_type: f.elem().output().to_type_ref(ir),
+                            return_dates: class_dates.spec(f.elem().output()),
                             // partial_return_type: f.elem().output().to_partial_type_ref(ir),
                             args: f
                                 .inputs()
@@ -198,6 +210,7 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptIni
     fn try_from((_, gen): (&IntermediateRepr, &crate::GeneratorArgs)) -> Result<Self> {
         Ok(TypescriptInit {
             default_client_mode: gen.default_client_mode.clone(),
+            zod: gen.zod,
         })
     }
 }
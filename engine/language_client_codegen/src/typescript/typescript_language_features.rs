@@ -38,8 +38,12 @@ impl ToTypescript for TypeValue {
             TypeValue::Int => "number",
             TypeValue::String => "string",
             TypeValue::Null => "null",
+            TypeValue::Date | TypeValue::DateTime => "Date",
+            TypeValue::Duration => "string",
             TypeValue::Media(BamlMediaType::Image) => "Image",
             TypeValue::Media(BamlMediaType::Audio) => "Audio",
+            TypeValue::Media(BamlMediaType::Pdf) => "Pdf",
+            TypeValue::Media(BamlMediaType::Video) => "Video",
         };
         var_name.to_string()
     }
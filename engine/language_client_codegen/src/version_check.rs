@@ -84,7 +84,7 @@ pub fn check_version(
                 "https://docs.boundaryml.com/docs/calling-baml/generate-baml-client#troubleshooting-version-conflicts"
             )
         } else {
-            if matches!(generator_language, GeneratorOutputType::OpenApi) {
+            if matches!(generator_language, GeneratorOutputType::OpenApi | GeneratorOutputType::Go | GeneratorOutputType::JsonSchema) {
                 (
                     match generator_type {
                         GeneratorType::VSCode => {
@@ -102,6 +102,8 @@ pub fn check_version(
                     GeneratorOutputType::PythonPydantic => format!("pip install --upgrade baml-py=={}", gen_version),
                     GeneratorOutputType::Typescript => format!("npm install --save-dev @boundaryml/baml@{}", gen_version),
                     GeneratorOutputType::RubySorbet => format!("gem install baml -v {}", gen_version),
+                    GeneratorOutputType::Rust => format!("update the baml-runtime dependency in your Cargo.toml to version {}", gen_version),
+                    GeneratorOutputType::Go | GeneratorOutputType::JsonSchema => format!("use 'npx @boundaryml/baml@{gen_version}'"),
                 };
                 (
                     match generator_type {
@@ -13,8 +13,12 @@ from .baml_py import (
     FunctionResultStream,
     BamlImagePy as Image,
     BamlAudioPy as Audio,
+    BamlPdfPy as Pdf,
+    BamlVideoPy as Video,
     invoke_runtime_cli,
     ClientRegistry,
+    BatchJob,
+    BatchJobStatus,
 )
 from .stream import BamlStream, BamlSyncStream
 from .ctx_manager import CtxManager as BamlCtxManager
@@ -22,6 +26,8 @@ from .ctx_manager import CtxManager as BamlCtxManager
 __all__ = [
     "BamlRuntime",
     "ClientRegistry",
+    "BatchJob",
+    "BatchJobStatus",
     "BamlStream",
     "BamlSyncStream",
     "BamlCtxManager",
@@ -29,5 +35,7 @@ __all__ = [
     "FunctionResultStream",
     "Image",
     "Audio",
+    "Pdf",
+    "Video",
     "invoke_runtime_cli",
 ]
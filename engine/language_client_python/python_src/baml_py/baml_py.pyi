@@ -1,4 +1,4 @@
-from typing import Any, Callable, Dict, Optional, Tuple
+from typing import Any, Callable, Dict, List, Literal, Optional, Tuple, TypedDict, Union
 
 class FunctionResult:
     """The result of a BAML function call.
@@ -18,6 +18,12 @@ class FunctionResult:
     # Returns True if the function call was successful, False otherwise
     def is_ok(self) -> bool: ...
     def cast_to(self, enum_module: Any, class_module: Any) -> Any: ...
+    # The HTTP request and response of every attempt, oldest first.
+    # Credentials in request headers are redacted.
+    def raw_http(self) -> List[RawHttpAttempt]: ...
+    # The flags the parser applied to coerce the response into the return
+    # type, and their total score. Raises if the response wasn't parsed.
+    def parse_report(self) -> ParseReport: ...
 
     # This is a debug function that returns the internal representation of the response
     # This is not to be relied upon and is subject to change
@@ -75,8 +81,34 @@ class BamlAudioPy:
     def as_url(self) -> str: ...
     def as_base64(self) -> Tuple[str, str]: ...
 
+class BamlPdfPy:
+    @staticmethod
+    def from_url(url: str) -> BamlPdfPy: ...
+    @staticmethod
+    def from_base64(media_type: str, base64: str) -> BamlPdfPy: ...
+    def is_url(self) -> bool: ...
+    def is_base64(self) -> bool: ...
+    def as_url(self) -> str: ...
+    def as_base64(self) -> Tuple[str, str]: ...
+
+class BamlVideoPy:
+    @staticmethod
+    def from_url(url: str) -> BamlVideoPy: ...
+    @staticmethod
+    def from_base64(media_type: str, base64: str) -> BamlVideoPy: ...
+    def is_url(self) -> bool: ...
+    def is_base64(self) -> bool: ...
+    def as_url(self) -> str: ...
+    def as_base64(self) -> Tuple[str, str]: ...
+
 class RuntimeContextManager:
     def upsert_tags(self, tags: Dict[str, Any]) -> None: ...
+    def set_variant(self, function_name: str, variant: Optional[str]) -> None: ...
+    def set_parse_thresholds(
+        self,
+        max_score: Optional[int] = None,
+        forbidden_flags: Optional[List[str]] = None,
+    ) -> None: ...
     def deep_clone(self) -> RuntimeContextManager: ...
     def context_depth(self) -> int: ...
 
@@ -116,12 +148,71 @@ class BamlRuntime:
         tb: Optional[TypeBuilder],
         cr: Optional[ClientRegistry],
     ) -> SyncFunctionResultStream: ...
+    # Builds the HTTP request the call would send, without sending it.
+    # Credentials in request headers are kept.
+    async def build_request(
+        self,
+        function_name: str,
+        args: Dict[str, Any],
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+        stream: bool,
+    ) -> RawHttpRequest: ...
+    def build_request_sync(
+        self,
+        function_name: str,
+        args: Dict[str, Any],
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+        stream: bool,
+    ) -> RawHttpRequest: ...
+    def parse_response(
+        self,
+        function_name: str,
+        raw_text: str,
+        partial: bool,
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+    ) -> FunctionResult: ...
+    async def submit_batch(
+        self,
+        function_name: str,
+        args: List[Dict[str, Any]],
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+    ) -> BatchJob: ...
+    async def batch_status(
+        self,
+        job: BatchJob,
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+    ) -> BatchJobStatus: ...
+    async def batch_results(
+        self,
+        job: BatchJob,
+        ctx: RuntimeContextManager,
+        tb: Optional[TypeBuilder],
+        cr: Optional[ClientRegistry],
+    ) -> List[FunctionResult]: ...
     def create_context_manager(self) -> RuntimeContextManager: ...
     def flush(self) -> None: ...
     def drain_stats(self) -> TraceStats: ...
     def set_log_event_callback(
         self, handler: Optional[Callable[[BamlLogEvent], None]]
     ) -> None: ...
+    def add_request_hook(
+        self,
+        on_request: Optional[
+            Callable[[HookRequest], Optional[HookRequest]]
+        ] = None,
+        on_response: Optional[Callable[[HookResponse], None]] = None,
+    ) -> None: ...
+    def clear_request_hooks(self) -> None: ...
 
 class LogEventMetadata:
     event_id: str
@@ -188,6 +279,70 @@ class TypeBuilder:
     def optional(self, inner_type: FieldType) -> FieldType: ...
     def map(self, key_type: FieldType, value_type: FieldType) -> FieldType: ...
     def union(self, *types: FieldType) -> FieldType: ...
+    def add_baml(self, baml: str, rt: BamlRuntime) -> None: ...
+
+class HookRequest(TypedDict):
+    client: str
+    url: str
+    headers: Dict[str, str]
+    body: Any
+
+class HookResponse(TypedDict):
+    client: str
+    url: str
+    status: int
+    headers: Dict[str, str]
+    body: Optional[str]
+
+class RawHttpRequest(TypedDict):
+    method: str
+    url: str
+    headers: Dict[str, str]
+    body: Any
+
+class RawHttpResponse(TypedDict):
+    status: int
+    headers: Dict[str, str]
+    # The data of each server-sent event for streamed responses; None if the
+    # body was not captured.
+    body: Union[str, List[str], None]
+
+class RawHttpAttempt(TypedDict):
+    client: str
+    request: Optional[RawHttpRequest]
+    response: Optional[RawHttpResponse]
+
+class ParseFlag(TypedDict):
+    path: str
+    flag: str
+    description: str
+    original: Optional[str]
+    score: int
+
+class ParseReport(TypedDict):
+    score: int
+    flags: List[ParseFlag]
+
+class BatchJob:
+    @property
+    def id(self) -> str: ...
+    @property
+    def function_name(self) -> str: ...
+    @property
+    def client(self) -> str: ...
+    def __len__(self) -> int: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> BatchJob: ...
+
+class BatchJobStatus:
+    @property
+    def status(
+        self,
+    ) -> Literal["in_progress", "completed", "failed", "cancelled", "expired"]: ...
+    @property
+    def message(self) -> Optional[str]: ...
+    def is_done(self) -> bool: ...
 
 class ClientRegistry:
     def __init__(self) -> None: ...
@@ -207,6 +362,7 @@ class FieldType:
 class EnumBuilder:
     def value(self, name: str) -> EnumValueBuilder: ...
     def alias(self, alias: Optional[str]) -> EnumBuilder: ...
+    def remove_value(self, name: str) -> EnumBuilder: ...
     def field(self) -> FieldType: ...
 
 class EnumValueBuilder:
@@ -217,6 +373,7 @@ class EnumValueBuilder:
 class ClassBuilder:
     def field(self) -> FieldType: ...
     def property(self, name: str) -> ClassPropertyBuilder: ...
+    def reorder_properties(self, *names: str) -> ClassBuilder: ...
 
 class ClassPropertyBuilder:
     def type(self, field_type: FieldType) -> ClassPropertyBuilder: ...
@@ -66,6 +66,18 @@ class CtxManager:
         mngr = self.__ctx()
         mngr.upsert_tags(tags)
 
+    def set_variant(self, function_name: str, variant: typing.Optional[str]) -> None:
+        mngr = self.__ctx()
+        mngr.set_variant(function_name, variant)
+
+    def set_parse_thresholds(
+        self,
+        max_score: typing.Optional[int] = None,
+        forbidden_flags: typing.Optional[typing.List[str]] = None,
+    ) -> None:
+        mngr = self.__ctx()
+        mngr.set_parse_thresholds(max_score, forbidden_flags)
+
     def get(self) -> RuntimeContextManager:
         return self.__ctx()
 
@@ -3,6 +3,7 @@ from .baml_py import (
     ClassBuilder,
     EnumBuilder,
     FieldType,
+    BamlRuntime,
     ClassPropertyBuilder as _ClassPropertyBuilder,
     EnumValueBuilder,
     TypeBuilder as _TypeBuilder,
@@ -10,10 +11,16 @@ from .baml_py import (
 
 
 class TypeBuilder:
-    def __init__(self, classes: typing.Set[str], enums: typing.Set[str]):
+    def __init__(
+        self,
+        classes: typing.Set[str],
+        enums: typing.Set[str],
+        runtime: typing.Optional[BamlRuntime] = None,
+    ):
         self.__classes = classes
         self.__enums = enums
         self.__tb = _TypeBuilder()
+        self.__runtime = runtime
 
     @property
     def _tb(self) -> _TypeBuilder:
@@ -68,6 +75,11 @@ class TypeBuilder:
         self.__enums.add(name)
         return NewEnumBuilder(self._tb, name)
 
+    def add_baml(self, baml: str) -> None:
+        if self.__runtime is None:
+            raise ValueError("add_baml needs a TypeBuilder created from a generated baml_client.")
+        self._tb.add_baml(baml, self.__runtime)
+
 
 class NewClassBuilder:
     def __init__(self, tb: _TypeBuilder, name: str):
@@ -91,6 +103,10 @@ class NewClassBuilder:
         # correct fix is to implement this logic in rust, not python
         return ClassPropertyBuilder(self.__bldr.property(name).type(type))
 
+    def reorder_properties(self, *names: str) -> "NewClassBuilder":
+        self.__bldr.reorder_properties(*names)
+        return self
+
     @property
     def props(self) -> "NewClassProperties":
         return self.__props
@@ -100,6 +116,10 @@ class ClassPropertyBuilder:
     def __init__(self, bldr: _ClassPropertyBuilder):
         self.__bldr = bldr
 
+    def type(self, type: FieldType):
+        self.__bldr.type(type)
+        return self
+
     def alias(self, alias: typing.Optional[str]):
         self.__bldr.alias(alias)
         return self
@@ -143,6 +163,13 @@ class NewEnumBuilder:
         # NOTE(sam): why is this inconsistent between classes and enums?
         return self.__bldr.value(name)
 
+    def remove_value(self, name: str) -> "NewEnumBuilder":
+        if name not in self.__values:
+            raise ValueError(f"Value {name} not found.")
+        self.__values.discard(name)
+        self.__bldr.remove_value(name)
+        return self
+
 
 class NewEnumValues:
     def __init__(self, enum_bldr: EnumBuilder, values: typing.Set[str]):
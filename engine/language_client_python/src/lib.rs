@@ -59,6 +59,8 @@ fn baml_py(m: Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<types::SyncFunctionResultStream>()?;
     m.add_class::<types::BamlImagePy>()?;
     m.add_class::<types::BamlAudioPy>()?;
+    m.add_class::<types::BamlPdfPy>()?;
+    m.add_class::<types::BamlVideoPy>()?;
     m.add_class::<types::RuntimeContextManager>()?;
     m.add_class::<types::BamlSpan>()?;
     m.add_class::<types::TypeBuilder>()?;
@@ -68,6 +70,8 @@ fn baml_py(m: Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<types::ClassPropertyBuilder>()?;
     m.add_class::<types::FieldType>()?;
     m.add_class::<types::ClientRegistry>()?;
+    m.add_class::<types::BatchJob>()?;
+    m.add_class::<types::BatchJobStatus>()?;
 
     m.add_class::<runtime::BamlLogEvent>()?;
     m.add_class::<runtime::LogEventMetadata>()?;
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-use baml_types::{BamlMap, BamlValue};
+use baml_types::{iso8601::Duration, BamlMap, BamlValue};
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError},
     prelude::{PyAnyMethods, PyTypeMethods},
@@ -9,7 +9,7 @@ use pyo3::{
     PyErr, PyObject, PyResult, Python, ToPyObject,
 };
 
-use crate::types::{BamlAudioPy, BamlImagePy};
+use crate::types::{BamlAudioPy, BamlImagePy, BamlPdfPy, BamlVideoPy};
 
 struct SerializationError {
     position: Vec<String>,
@@ -215,6 +215,9 @@ pub fn parse_py_type(
         let base_model = py
             .import_bound("pydantic")
             .and_then(|m| m.getattr("BaseModel"))?;
+        let datetime = py.import_bound("datetime")?;
+        let date_type = datetime.getattr("date")?;
+        let timedelta_type = datetime.getattr("timedelta")?;
 
         let mut get_type = |py: Python<'_>,
                             any: PyObject,
@@ -285,6 +288,23 @@ pub fn parse_py_type(
                 //     log::info!("  {}: {}", key, repr_str);
                 // }
                 Ok(MappedPyType::Class(name, fields))
+            } else if t.is_subclass(&date_type).unwrap_or(false) {
+                // Dates and datetimes (a subclass of date) are passed as ISO 8601.
+                let iso = any.call_method0(py, "isoformat")?.extract::<String>(py)?;
+                Ok(MappedPyType::String(iso))
+            } else if t.is_subclass(&timedelta_type).unwrap_or(false) {
+                let days = any.getattr(py, "days")?.extract::<i64>(py)?;
+                let seconds = any.getattr(py, "seconds")?.extract::<f64>(py)?;
+                let microseconds = any.getattr(py, "microseconds")?.extract::<f64>(py)?;
+                if days < 0 {
+                    anyhow::bail!("Negative durations are not supported");
+                }
+                let duration = Duration {
+                    months: 0,
+                    days: days as u64,
+                    seconds: seconds + microseconds / 1e6,
+                };
+                Ok(MappedPyType::String(duration.to_string()))
                 // use downcast only
             } else if let Ok(list) = any.downcast_bound::<PyList>(py) {
                 let mut items = vec![];
@@ -313,6 +333,12 @@ pub fn parse_py_type(
             } else if let Ok(b) = any.downcast_bound::<BamlAudioPy>(py) {
                 let b = b.borrow();
                 Ok(MappedPyType::BamlMedia(b.inner.clone()))
+            } else if let Ok(b) = any.downcast_bound::<BamlPdfPy>(py) {
+                let b = b.borrow();
+                Ok(MappedPyType::BamlMedia(b.inner.clone()))
+            } else if let Ok(b) = any.downcast_bound::<BamlVideoPy>(py) {
+                let b = b.borrow();
+                Ok(MappedPyType::BamlMedia(b.inner.clone()))
             } else {
                 if matches!(unknown_type_handler, UnknownTypeHandler::SerializeAsStr) {
                     // Call the __str__ method on the object
@@ -1,5 +1,6 @@
 use crate::errors::{BamlError, BamlInvalidArgumentError};
 use crate::parse_py_type::parse_py_type;
+use crate::types::batch::{BatchJob, BatchJobStatus};
 use crate::types::function_results::FunctionResult;
 use crate::types::trace_stats::TraceStats;
 
@@ -7,10 +8,12 @@ use crate::types::function_result_stream::{FunctionResultStream, SyncFunctionRes
 use crate::types::runtime_ctx_manager::RuntimeContextManager;
 use crate::types::type_builder::TypeBuilder;
 use crate::types::ClientRegistry;
+use baml_runtime::request_hook::{HookRequest, HookResponse, RequestHook};
 use baml_runtime::runtime_interface::ExperimentalTracingInterface;
 use baml_runtime::BamlRuntime as CoreBamlRuntime;
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::{pyclass, PyObject, Python, ToPyObject};
+use pythonize::{depythonize_bound, pythonize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -191,6 +194,223 @@ impl BamlRuntime {
             .map_err(BamlError::from_anyhow)
     }
 
+    /// Builds the HTTP request that calling the function would send, without
+    /// sending it. Credentials in request headers are kept.
+    #[pyo3(signature = (function_name, args, ctx, tb, cb, stream))]
+    fn build_request(
+        &self,
+        py: Python<'_>,
+        function_name: String,
+        args: PyObject,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+        stream: bool,
+    ) -> PyResult<PyObject> {
+        let Some(args) = parse_py_type(args, false)? else {
+            return Err(BamlInvalidArgumentError::new_err(
+                "Failed to parse args, perhaps you used a non-serializable type?",
+            ));
+        };
+        let Some(args_map) = args.as_map_owned() else {
+            return Err(BamlInvalidArgumentError::new_err(
+                "Failed to parse args as a map",
+            ));
+        };
+
+        let baml_runtime = self.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let request = baml_runtime
+                .build_request(
+                    &function_name,
+                    &args_map,
+                    &ctx_mng,
+                    tb.as_ref(),
+                    cb.as_ref(),
+                    stream,
+                )
+                .await
+                .map_err(BamlError::from_anyhow)?;
+            Python::with_gil(|py| Ok(pythonize(py, &request)?))
+        })
+        .map(|f| f.into())
+    }
+
+    #[pyo3(signature = (function_name, args, ctx, tb, cb, stream))]
+    fn build_request_sync(
+        &self,
+        py: Python<'_>,
+        function_name: String,
+        args: PyObject,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+        stream: bool,
+    ) -> PyResult<PyObject> {
+        let Some(args) = parse_py_type(args, false)? else {
+            return Err(BamlInvalidArgumentError::new_err(
+                "Failed to parse args, perhaps you used a non-serializable type?",
+            ));
+        };
+        let Some(args_map) = args.as_map_owned() else {
+            return Err(BamlInvalidArgumentError::new_err(
+                "Failed to parse args as a map",
+            ));
+        };
+
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        let request = self
+            .inner
+            .async_runtime
+            .block_on(self.inner.build_request(
+                &function_name,
+                &args_map,
+                &ctx_mng,
+                tb.as_ref(),
+                cb.as_ref(),
+                stream,
+            ))
+            .map_err(BamlError::from_anyhow)?;
+        Ok(pythonize(py, &request)?)
+    }
+
+    /// Parses the text of a response to a request from `build_request`.
+    #[pyo3(signature = (function_name, raw_text, partial, ctx, tb, cb))]
+    fn parse_response(
+        &self,
+        function_name: String,
+        raw_text: String,
+        partial: bool,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> PyResult<FunctionResult> {
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        self.inner
+            .parse_response(
+                &function_name,
+                &raw_text,
+                partial,
+                &ctx.inner,
+                tb.as_ref(),
+                cb.as_ref(),
+            )
+            .map(FunctionResult::from)
+            .map_err(BamlError::from_anyhow)
+    }
+
+    /// Submits one call per entry of `args` to the client's batch API.
+    #[pyo3(signature = (function_name, args, ctx, tb, cb))]
+    fn submit_batch(
+        &self,
+        py: Python<'_>,
+        function_name: String,
+        args: Vec<PyObject>,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> PyResult<PyObject> {
+        let mut args_maps = Vec::with_capacity(args.len());
+        for args in args {
+            let Some(args) = parse_py_type(args, false)? else {
+                return Err(BamlInvalidArgumentError::new_err(
+                    "Failed to parse args, perhaps you used a non-serializable type?",
+                ));
+            };
+            let Some(args_map) = args.as_map_owned() else {
+                return Err(BamlInvalidArgumentError::new_err(
+                    "Failed to parse args. Expect a list of kwargs",
+                ));
+            };
+            args_maps.push(args_map);
+        }
+
+        let baml_runtime = self.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            baml_runtime
+                .submit_batch(
+                    function_name,
+                    &args_maps,
+                    &ctx_mng,
+                    tb.as_ref(),
+                    cb.as_ref(),
+                )
+                .await
+                .map(BatchJob::from)
+                .map_err(BamlError::from_anyhow)
+        })
+        .map(|f| f.into())
+    }
+
+    #[pyo3(signature = (job, ctx, tb, cb))]
+    fn batch_status(
+        &self,
+        py: Python<'_>,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> PyResult<PyObject> {
+        let baml_runtime = self.inner.clone();
+        let job = job.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            baml_runtime
+                .batch_status(&job, &ctx_mng, tb.as_ref(), cb.as_ref())
+                .await
+                .map(BatchJobStatus::from)
+                .map_err(BamlError::from_anyhow)
+        })
+        .map(|f| f.into())
+    }
+
+    /// Returns one result per submitted call, in submission order.
+    #[pyo3(signature = (job, ctx, tb, cb))]
+    fn batch_results(
+        &self,
+        py: Python<'_>,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> PyResult<PyObject> {
+        let baml_runtime = self.inner.clone();
+        let job = job.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            baml_runtime
+                .batch_results(&job, &ctx_mng, tb.as_ref(), cb.as_ref())
+                .await
+                .map(|results| {
+                    results
+                        .into_iter()
+                        .map(FunctionResult::from)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(BamlError::from_anyhow)
+        })
+        .map(|f| f.into())
+    }
+
     #[pyo3(signature = (function_name, args, on_event, ctx, tb, cb))]
     fn stream_function(
         &self,
@@ -283,6 +503,27 @@ impl BamlRuntime {
         self.inner.drain_stats().into()
     }
 
+    /// Registers callables to run around every HTTP request sent to an LLM
+    /// provider. `on_request` receives the request as a dict and may modify it
+    /// in place or return a new one; `on_response` receives the raw response.
+    #[pyo3(signature = (on_request = None, on_response = None))]
+    fn add_request_hook(
+        &self,
+        on_request: Option<PyObject>,
+        on_response: Option<PyObject>,
+    ) -> PyResult<()> {
+        self.inner
+            .add_request_hook(std::sync::Arc::new(PyRequestHook {
+                on_request,
+                on_response,
+            }));
+        Ok(())
+    }
+
+    fn clear_request_hooks(&self) {
+        self.inner.clear_request_hooks();
+    }
+
     #[pyo3()]
     fn set_log_event_callback(&self, callback: Option<PyObject>) -> PyResult<()> {
         let callback = callback.clone();
@@ -324,3 +565,38 @@ impl BamlRuntime {
         }
     }
 }
+
+struct PyRequestHook {
+    on_request: Option<PyObject>,
+    on_response: Option<PyObject>,
+}
+
+impl RequestHook for PyRequestHook {
+    fn on_request<'a>(
+        &'a self,
+        request: &'a mut HookRequest,
+    ) -> futures::future::BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let Some(callback) = &self.on_request else {
+                return Ok(());
+            };
+            Python::with_gil(|py| {
+                let arg = pythonize(py, &*request)?;
+                let result = callback.call1(py, (arg.clone_ref(py),))?;
+                let updated = if result.is_none(py) { arg } else { result };
+                *request = depythonize_bound(updated.into_bound(py))?;
+                Ok(())
+            })
+        })
+    }
+
+    fn on_response(&self, response: &HookResponse) -> anyhow::Result<()> {
+        let Some(callback) = &self.on_response else {
+            return Ok(());
+        };
+        Python::with_gil(|py| {
+            callback.call1(py, (pythonize(py, response)?,))?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use pyo3::prelude::{pymethods, PyResult};
+
+use crate::errors::BamlError;
+
+crate::lang_wrapper!(BatchJob, baml_runtime::BatchJob);
+
+#[pymethods]
+impl BatchJob {
+    #[getter]
+    pub fn id(&self) -> String {
+        self.inner.id.clone()
+    }
+
+    #[getter]
+    pub fn function_name(&self) -> String {
+        self.inner.function_name.clone()
+    }
+
+    #[getter]
+    pub fn client(&self) -> String {
+        self.inner.client.clone()
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.inner.custom_ids.len()
+    }
+
+    /// Serializes the job so it can be resumed with `BatchJob.from_json`.
+    pub fn to_json(&self) -> PyResult<String> {
+        self.inner.to_json().map_err(BamlError::from_anyhow)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        Ok(baml_runtime::BatchJob::from_json(json)
+            .map_err(BamlError::from_anyhow)?
+            .into())
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "BatchJob(id={:?}, function_name={:?}, client={:?}, calls={})",
+            self.inner.id,
+            self.inner.function_name,
+            self.inner.client,
+            self.inner.custom_ids.len()
+        )
+    }
+}
+
+crate::lang_wrapper!(BatchJobStatus, baml_runtime::BatchJobStatus);
+
+#[pymethods]
+impl BatchJobStatus {
+    /// One of "in_progress", "completed", "failed", "cancelled" or "expired".
+    #[getter]
+    pub fn status(&self) -> &'static str {
+        match self.inner {
+            baml_runtime::BatchJobStatus::InProgress => "in_progress",
+            baml_runtime::BatchJobStatus::Completed => "completed",
+            baml_runtime::BatchJobStatus::Failed { .. } => "failed",
+            baml_runtime::BatchJobStatus::Cancelled => "cancelled",
+            baml_runtime::BatchJobStatus::Expired => "expired",
+        }
+    }
+
+    #[getter]
+    pub fn message(&self) -> Option<String> {
+        match &self.inner {
+            baml_runtime::BatchJobStatus::Failed { message } => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.inner.is_done()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("BatchJobStatus({})", self.inner)
+    }
+}
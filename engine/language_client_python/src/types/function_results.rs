@@ -2,10 +2,11 @@ use baml_types::{BamlValueWithMeta, ResponseCheck};
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::types::{PyAnyMethods, PyDict, PyModule, PyTuple, PyType};
 use pyo3::{Bound, IntoPy, PyAny, PyObject, Python};
+use pythonize::pythonize;
 
 use crate::errors::BamlError;
 
-use super::{BamlAudioPy, BamlImagePy};
+use super::{BamlAudioPy, BamlImagePy, BamlPdfPy, BamlVideoPy};
 
 crate::lang_wrapper!(FunctionResult, baml_runtime::FunctionResult);
 
@@ -25,6 +26,19 @@ impl FunctionResult {
         serde_json::json!(self.inner.llm_response()).to_string()
     }
 
+    /// The HTTP request and response of every attempt, oldest first.
+    /// Credentials in request headers are redacted.
+    fn raw_http(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.raw_http())?)
+    }
+
+    /// The flags the parser applied to coerce the response into the return
+    /// type, and their total score.
+    fn parse_report(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let report = self.inner.parse_report().map_err(BamlError::from_anyhow)?;
+        Ok(pythonize(py, &report)?)
+    }
+
     // Cast the parsed value to a specific type
     // the module is the module that the type is defined in
     fn cast_to(
@@ -99,6 +113,10 @@ fn pythonize_strict(
             baml_types::BamlMediaType::Audio => {
                 Ok(BamlAudioPy::from(baml_media.clone()).into_py(py))
             }
+            baml_types::BamlMediaType::Pdf => Ok(BamlPdfPy::from(baml_media.clone()).into_py(py)),
+            baml_types::BamlMediaType::Video => {
+                Ok(BamlVideoPy::from(baml_media.clone()).into_py(py))
+            }
         },
         BamlValueWithMeta::Enum(enum_name, ref value, _) => {
             let enum_type = match enum_module.getattr(enum_name.as_str()) {
@@ -21,6 +21,25 @@ impl RuntimeContextManager {
         Ok(true)
     }
 
+    #[pyo3(signature = (function_name, variant))]
+    fn set_variant(&self, function_name: &str, variant: Option<&str>) {
+        match variant {
+            Some(variant) => self.inner.set_variant(function_name, variant),
+            None => self.inner.clear_variant(function_name),
+        }
+    }
+
+    #[pyo3(signature = (max_score = None, forbidden_flags = None))]
+    fn set_parse_thresholds(
+        &self,
+        max_score: Option<i32>,
+        forbidden_flags: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        self.inner
+            .set_parse_thresholds(max_score, forbidden_flags.unwrap_or_default())
+            .map_err(BamlError::from_anyhow)
+    }
+
     #[pyo3()]
     fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -7,6 +7,8 @@ use pyo3::{
     Bound, PyResult,
 };
 
+use crate::{errors::BamlError, runtime::BamlRuntime};
+
 crate::lang_wrapper!(TypeBuilder, type_builder::TypeBuilder);
 crate::lang_wrapper!(EnumBuilder, type_builder::EnumBuilder, sync_thread_safe, name: String);
 crate::lang_wrapper!(ClassBuilder, type_builder::ClassBuilder, sync_thread_safe, name: String);
@@ -93,6 +95,12 @@ impl TypeBuilder {
         .into()
     }
 
+    pub fn add_baml(&self, baml: &str, rt: &BamlRuntime) -> PyResult<()> {
+        self.inner
+            .add_baml(baml, &rt.inner)
+            .map_err(BamlError::from_anyhow)
+    }
+
     #[pyo3(signature = (*types))]
     pub fn union<'py>(&self, types: &Bound<'_, PyTuple>) -> PyResult<FieldType> {
         let mut rs_types = vec![];
@@ -132,6 +140,14 @@ impl EnumBuilder {
         self.inner.clone().into()
     }
 
+    pub fn remove_value(&self, name: &str) -> Self {
+        self.inner.lock().unwrap().remove_value(name);
+        EnumBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        }
+    }
+
     pub fn field(&self) -> FieldType {
         baml_types::FieldType::r#enum(&self.name).into()
     }
@@ -178,6 +194,16 @@ impl ClassBuilder {
     pub fn property(&self, name: &str) -> ClassPropertyBuilder {
         self.inner.lock().unwrap().property(name).into()
     }
+
+    #[pyo3(signature = (*names))]
+    pub fn reorder_properties(&self, names: &Bound<'_, PyTuple>) -> PyResult<Self> {
+        let names = names.extract::<Vec<String>>()?;
+        self.inner.lock().unwrap().reorder_properties(names);
+        Ok(ClassBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        })
+    }
 }
 
 #[pymethods]
@@ -53,6 +53,21 @@ impl FunctionResult {
         }
     }
 
+    /// The HTTP request and response of every attempt, oldest first.
+    /// Credentials in request headers are redacted.
+    pub fn raw_http(rb_self: &FunctionResult) -> Result<Value> {
+        serde_magnus::serialize(&rb_self.inner.raw_http())
+    }
+
+    /// The flags the parser applied to coerce the response into the return
+    /// type, and their total score.
+    pub fn parse_report(ruby: &Ruby, rb_self: &FunctionResult) -> Result<Value> {
+        match rb_self.inner.parse_report() {
+            Ok(report) => serde_magnus::serialize(&report),
+            Err(e) => Err(Error::new(ruby.exception_runtime_error(), e.to_string())),
+        }
+    }
+
     /// For usage in magnus::init
     ///
     /// TODO: use traits and macros to implement this
@@ -63,6 +78,8 @@ impl FunctionResult {
             "parsed_using_types",
             method!(FunctionResult::parsed_using_types, 1),
         )?;
+        cls.define_method("raw_http", method!(FunctionResult::raw_http, 0))?;
+        cls.define_method("parse_report", method!(FunctionResult::parse_report, 0))?;
 
         Ok(())
     }
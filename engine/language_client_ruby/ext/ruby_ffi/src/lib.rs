@@ -1,6 +1,6 @@
 use baml_runtime::BamlRuntime;
 use baml_types::BamlValue;
-use magnus::{class, function, method, prelude::*, Error, RHash, Ruby};
+use magnus::{class, function, method, prelude::*, Error, RHash, Ruby, Value};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -183,6 +183,81 @@ impl BamlRuntimeFfi {
 
         retval
     }
+
+    /// Builds the HTTP request that calling the function would send, without
+    /// sending it. Credentials in request headers are kept.
+    pub fn build_request(
+        ruby: &Ruby,
+        rb_self: &BamlRuntimeFfi,
+        function_name: String,
+        args: RHash,
+        ctx: &RuntimeContextManager,
+        type_registry: Option<&types::type_builder::TypeBuilder>,
+        client_registry: Option<&types::client_registry::ClientRegistry>,
+        stream: bool,
+    ) -> Result<Value> {
+        let args = match ruby_to_json::RubyToJson::convert_hash_to_json(args) {
+            Ok(args) => args.into_iter().collect(),
+            Err(e) => {
+                return Err(Error::new(
+                    ruby.exception_syntax_error(),
+                    format!("error while parsing build_request args:\n{}", e),
+                ));
+            }
+        };
+
+        match rb_self.t.block_on(rb_self.inner.build_request(
+            &function_name,
+            &args,
+            &ctx.inner,
+            type_registry.map(|t| &t.inner),
+            client_registry.map(|c| c.inner.borrow_mut()).as_deref(),
+            stream,
+        )) {
+            Ok(request) => serde_magnus::serialize(&request),
+            Err(e) => Err(Error::new(
+                ruby.exception_runtime_error(),
+                format!(
+                    "{:?}",
+                    e.context(format!(
+                        "error while building the request for {function_name}"
+                    ))
+                ),
+            )),
+        }
+    }
+
+    /// Parses the text of a response to a request from `build_request`.
+    pub fn parse_response(
+        ruby: &Ruby,
+        rb_self: &BamlRuntimeFfi,
+        function_name: String,
+        raw_text: String,
+        partial: bool,
+        ctx: &RuntimeContextManager,
+        type_registry: Option<&types::type_builder::TypeBuilder>,
+        client_registry: Option<&types::client_registry::ClientRegistry>,
+    ) -> Result<FunctionResult> {
+        match rb_self.inner.parse_response(
+            &function_name,
+            &raw_text,
+            partial,
+            &ctx.inner,
+            type_registry.map(|t| &t.inner),
+            client_registry.map(|c| c.inner.borrow_mut()).as_deref(),
+        ) {
+            Ok(res) => Ok(FunctionResult::new(res)),
+            Err(e) => Err(Error::new(
+                ruby.exception_runtime_error(),
+                format!(
+                    "{:?}",
+                    e.context(format!(
+                        "error while parsing the response of {function_name}"
+                    ))
+                ),
+            )),
+        }
+    }
 }
 
 fn invoke_runtime_cli(ruby: &Ruby, argv0: String, argv: Vec<String>) -> Result<()> {
@@ -253,6 +328,8 @@ fn init(ruby: &Ruby) -> Result<()> {
         "stream_function",
         method!(BamlRuntimeFfi::stream_function, 5),
     )?;
+    runtime_class.define_method("build_request", method!(BamlRuntimeFfi::build_request, 6))?;
+    runtime_class.define_method("parse_response", method!(BamlRuntimeFfi::parse_response, 6))?;
 
     FunctionResult::define_in_ruby(&module)?;
     FunctionResultStream::define_in_ruby(&module)?;
@@ -268,6 +345,8 @@ fn init(ruby: &Ruby) -> Result<()> {
 
     types::client_registry::ClientRegistry::define_in_ruby(&module)?;
     types::media::Audio::define_in_ruby(&module)?;
+    types::media::Pdf::define_in_ruby(&module)?;
+    types::media::Video::define_in_ruby(&module)?;
     types::media::Image::define_in_ruby(&module)?;
 
     // everything below this is for our own testing purposes
@@ -8,7 +8,7 @@ use std::result::Result;
 
 use crate::types::{
     self,
-    media::{Audio, Image},
+    media::{Audio, Image, Pdf, Video},
 };
 
 struct SerializationError {
@@ -223,6 +223,19 @@ impl<'rb> RubyToJson<'rb> {
             }
         }
 
+        // Date, Time and DateTime are passed as ISO 8601 strings.
+        if any.respond_to("iso8601", false).unwrap_or(false) {
+            return any
+                .funcall::<_, _, String>("iso8601", ())
+                .map(BamlValue::String)
+                .map_err(|e| {
+                    vec![SerializationError {
+                        position: field_pos,
+                        message: format!("failed to convert date to ISO 8601: {e}"),
+                    }]
+                });
+        }
+
         if self.is_type::<Audio>(any) {
             return self.to_type::<Audio>(any, field_pos);
         }
@@ -231,6 +244,14 @@ impl<'rb> RubyToJson<'rb> {
             return self.to_type::<Image>(any, field_pos);
         }
 
+        if self.is_type::<Pdf>(any) {
+            return self.to_type::<Pdf>(any, field_pos);
+        }
+
+        if self.is_type::<Video>(any) {
+            return self.to_type::<Video>(any, field_pos);
+        }
+
         Err(vec![SerializationError {
             position: field_pos,
             message: format!(
@@ -1,4 +1,4 @@
-use magnus::{class, prelude::*, RModule};
+use magnus::{class, method, prelude::*, Error, RModule, Ruby};
 
 use crate::Result;
 
@@ -7,11 +7,27 @@ pub struct RuntimeContextManager {
     pub inner: baml_runtime::RuntimeContextManager,
 }
 impl RuntimeContextManager {
+    pub fn set_parse_thresholds(
+        ruby: &Ruby,
+        rb_self: &RuntimeContextManager,
+        max_score: Option<i32>,
+        forbidden_flags: Option<Vec<String>>,
+    ) -> Result<()> {
+        rb_self
+            .inner
+            .set_parse_thresholds(max_score, forbidden_flags.unwrap_or_default())
+            .map_err(|e| Error::new(ruby.exception_arg_error(), e.to_string()))
+    }
+
     pub fn define_in_ruby(module: &RModule) -> Result<()> {
-        module.define_class("RuntimeContextManager", class::object())?;
+        let cls = module.define_class("RuntimeContextManager", class::object())?;
 
         //cls.define_method("upsert_tags", method!(RuntimeContextManager::upsert_tags, 1))?;
         //cls.define_method("deep_clone", method!(RuntimeContextManager::deep_clone, 0))?;
+        cls.define_method(
+            "set_parse_thresholds",
+            method!(RuntimeContextManager::set_parse_thresholds, 2),
+        )?;
 
         Ok(())
     }
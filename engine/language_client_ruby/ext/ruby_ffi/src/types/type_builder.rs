@@ -2,8 +2,8 @@ use crate::Result;
 use baml_runtime::type_builder::{self, WithMeta};
 use baml_types::BamlValue;
 use magnus::{
-    class, function, method, scan_args::scan_args, try_convert::TryConvertOwned, Module, Object,
-    RModule, Value,
+    class, function, method, scan_args::scan_args, try_convert::TryConvertOwned, Error, Module,
+    Object, RModule, Ruby, Value,
 };
 
 #[magnus::wrap(class = "Baml::Ffi::TypeBuilder", free_immediately, size)]
@@ -113,6 +113,23 @@ impl TypeBuilder {
         .into())
     }
 
+    pub fn add_baml(
+        ruby: &Ruby,
+        rb_self: &TypeBuilder,
+        baml: String,
+        rt: &crate::BamlRuntimeFfi,
+    ) -> Result<()> {
+        rb_self.inner.add_baml(&baml, &rt.inner).map_err(|e| {
+            Error::new(
+                ruby.exception_runtime_error(),
+                format!(
+                    "{:?}",
+                    e.context("error while adding BAML to the TypeBuilder")
+                ),
+            )
+        })
+    }
+
     pub fn define_in_ruby(module: &RModule) -> Result<()> {
         let cls = module.define_class("TypeBuilder", class::object())?;
 
@@ -132,6 +149,7 @@ impl TypeBuilder {
         cls.define_method("literal_string", method!(TypeBuilder::literal_string, 1))?;
         cls.define_method("literal_int", method!(TypeBuilder::literal_int, 1))?;
         cls.define_method("literal_bool", method!(TypeBuilder::literal_bool, 1))?;
+        cls.define_method("add_baml", method!(TypeBuilder::add_baml, 2))?;
 
         Ok(())
     }
@@ -176,6 +194,14 @@ impl EnumBuilder {
         self.inner.clone().into()
     }
 
+    pub fn remove_value(&self, name: String) -> Self {
+        self.inner.lock().unwrap().remove_value(name.as_str());
+        EnumBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        }
+    }
+
     pub fn field(&self) -> FieldType {
         baml_types::FieldType::r#enum(&self.name).into()
     }
@@ -185,6 +211,7 @@ impl EnumBuilder {
 
         cls.define_method("value", method!(EnumBuilder::value, 1))?;
         cls.define_method("alias", method!(EnumBuilder::alias, 1))?;
+        cls.define_method("remove_value", method!(EnumBuilder::remove_value, 1))?;
         cls.define_method("field", method!(EnumBuilder::field, 0))?;
 
         Ok(())
@@ -237,11 +264,24 @@ impl ClassBuilder {
         self.inner.lock().unwrap().property(name.as_str()).into()
     }
 
+    pub fn reorder_properties(&self, args: &[Value]) -> Result<Self> {
+        let args = scan_args::<(), (), Vec<String>, (), (), ()>(args)?;
+        self.inner.lock().unwrap().reorder_properties(args.splat);
+        Ok(ClassBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        })
+    }
+
     pub fn define_in_ruby(module: &RModule) -> Result<()> {
         let cls = module.define_class("ClassBuilder", class::object())?;
 
         cls.define_method("field", method!(ClassBuilder::field, 0))?;
         cls.define_method("property", method!(ClassBuilder::property, 1))?;
+        cls.define_method(
+            "reorder_properties",
+            method!(ClassBuilder::reorder_properties, -1),
+        )?;
 
         Ok(())
     }
@@ -13,6 +13,8 @@ module Baml
   ClientRegistry = Baml::Ffi::ClientRegistry
   Image = Baml::Ffi::Image
   Audio = Baml::Ffi::Audio
+  Pdf = Baml::Ffi::Pdf
+  Video = Baml::Ffi::Video
 
   # Reexport Checked types.
   Checked = Baml::Checks::Checked
@@ -1,10 +1,47 @@
 # This file should NOT be imported from baml.rb; we don't want
 # to introduce a hard dependency on Sorbet for the baml gem.
+require "date"
 require "ostruct"
+require "time"
 #require "pp"
 
 module Baml
   module Sorbet
+    # BAML returns dates and datetimes as ISO 8601 strings; this turns the
+    # ones in a value of the given Sorbet type into Dates and Times.
+    def self.revive_dates(value, type)
+      return value if value.nil?
+
+      type = T::Utils.coerce(type)
+      case type
+      when T::Types::Simple
+        return value unless value.is_a?(String)
+        begin
+          if type.raw_type == Date
+            Date.iso8601(value)
+          elsif type.raw_type == Time
+            Time.iso8601(value)
+          else
+            value
+          end
+        rescue ArgumentError
+          value
+        end
+      when T::Types::TypedArray
+        value.is_a?(Array) ? value.map { |item| revive_dates(item, type.type) } : value
+      when T::Types::TypedHash
+        value.is_a?(Hash) ? value.transform_values { |item| revive_dates(item, type.values) } : value
+      when T::Types::Union
+        # A string in a union that also allows other strings is kept.
+        return value if value.is_a?(String) && type.types.any? { |t| t.is_a?(T::Types::Simple) && t.raw_type == String }
+        # Dates read datetimes too, so datetimes go first.
+        types = type.types.sort_by { |t| t.is_a?(T::Types::Simple) && t.raw_type == Date ? 1 : 0 }
+        types.reduce(value) { |v, t| revive_dates(v, t) }
+      else
+        value
+      end
+    end
+
     # Provides dynamic properties on statically defined classes
     module Struct
       # Needed to allow accessing dynamic types
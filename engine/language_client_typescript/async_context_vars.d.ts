@@ -6,6 +6,8 @@ export declare class BamlCtxManager {
     allowResets(): boolean;
     reset(): void;
     upsertTags(tags: Record<string, string>): void;
+    setVariant(functionName: string, variant: string | null): void;
+    setParseThresholds(maxScore: number | null, forbiddenFlags?: string[]): void;
     cloneContext(): RuntimeContextManager;
     startTrace(name: string, args: Record<string, any>): [RuntimeContextManager, BamlSpan];
     endTrace(span: BamlSpan, response: any): void;
@@ -32,6 +32,14 @@ class BamlCtxManager {
         const manager = this.ctx.getStore();
         manager.upsertTags(tags);
     }
+    setVariant(functionName, variant) {
+        const manager = this.ctx.getStore();
+        manager.setVariant(functionName, variant);
+    }
+    setParseThresholds(maxScore, forbiddenFlags = []) {
+        const manager = this.ctx.getStore();
+        manager.setParseThresholds(maxScore, forbiddenFlags);
+    }
     cloneContext() {
         let store = this.ctx.getStore();
         if (store === undefined) {
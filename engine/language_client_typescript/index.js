@@ -1,6 +1,6 @@
 "use strict";
 Object.defineProperty(exports, "__esModule", { value: true });
-exports.createBamlValidationError = exports.BamlValidationError = exports.BamlCtxManager = exports.BamlStream = exports.BamlLogEvent = exports.ClientRegistry = exports.invoke_runtime_cli = exports.Audio = exports.ClientBuilder = exports.Image = exports.FunctionResultStream = exports.FunctionResult = exports.BamlRuntime = void 0;
+exports.createBamlValidationError = exports.BamlValidationError = exports.BamlCtxManager = exports.BamlStream = exports.HookResponse = exports.HookRequest = exports.BatchJobStatus = exports.BatchJob = exports.BamlLogEvent = exports.ClientRegistry = exports.invoke_runtime_cli = exports.Video = exports.Pdf = exports.Audio = exports.ClientBuilder = exports.Image = exports.FunctionResultStream = exports.FunctionResult = exports.BamlRuntime = void 0;
 var native_1 = require("./native");
 Object.defineProperty(exports, "BamlRuntime", { enumerable: true, get: function () { return native_1.BamlRuntime; } });
 Object.defineProperty(exports, "FunctionResult", { enumerable: true, get: function () { return native_1.FunctionResult; } });
@@ -8,9 +8,15 @@ Object.defineProperty(exports, "FunctionResultStream", { enumerable: true, get:
 Object.defineProperty(exports, "Image", { enumerable: true, get: function () { return native_1.BamlImage; } });
 Object.defineProperty(exports, "ClientBuilder", { enumerable: true, get: function () { return native_1.ClientBuilder; } });
 Object.defineProperty(exports, "Audio", { enumerable: true, get: function () { return native_1.BamlAudio; } });
+Object.defineProperty(exports, "Pdf", { enumerable: true, get: function () { return native_1.BamlPdf; } });
+Object.defineProperty(exports, "Video", { enumerable: true, get: function () { return native_1.BamlVideo; } });
 Object.defineProperty(exports, "invoke_runtime_cli", { enumerable: true, get: function () { return native_1.invoke_runtime_cli; } });
 Object.defineProperty(exports, "ClientRegistry", { enumerable: true, get: function () { return native_1.ClientRegistry; } });
 Object.defineProperty(exports, "BamlLogEvent", { enumerable: true, get: function () { return native_1.BamlLogEvent; } });
+Object.defineProperty(exports, "BatchJob", { enumerable: true, get: function () { return native_1.BatchJob; } });
+Object.defineProperty(exports, "BatchJobStatus", { enumerable: true, get: function () { return native_1.BatchJobStatus; } });
+Object.defineProperty(exports, "HookRequest", { enumerable: true, get: function () { return native_1.HookRequest; } });
+Object.defineProperty(exports, "HookResponse", { enumerable: true, get: function () { return native_1.HookResponse; } });
 var stream_1 = require("./stream");
 Object.defineProperty(exports, "BamlStream", { enumerable: true, get: function () { return stream_1.BamlStream; } });
 var async_context_vars_1 = require("./async_context_vars");
@@ -18,6 +18,15 @@ export declare class BamlImage {
   toJSON(): any
 }
 
+export declare class BamlPdf {
+  static fromUrl(url: string): BamlPdf
+  static fromBase64(mediaType: string, base64: string): BamlPdf
+  isUrl(): boolean
+  asUrl(): string
+  asBase64(): [string, string]
+  toJSON(): any
+}
+
 export declare class BamlRuntime {
   static fromDirectory(directory: string, envVars: Record<string, string>): BamlRuntime
   static fromFiles(rootPath: string, files: Record<string, string>, envVars: Record<string, string>): BamlRuntime
@@ -25,9 +34,29 @@ export declare class BamlRuntime {
   createContextManager(): RuntimeContextManager
   callFunction(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): Promise<FunctionResult>
   callFunctionSync(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): FunctionResult
+  /**
+   * Builds the HTTP request that calling the function would send, without
+   * sending it. Credentials in request headers are kept.
+   */
+  buildRequest(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb: TypeBuilder | undefined | null, cb: ClientRegistry | undefined | null, stream: boolean): Promise<{ method: string, url: string, headers: Record<string, string>, body: any }>
+  buildRequestSync(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb: TypeBuilder | undefined | null, cb: ClientRegistry | undefined | null, stream: boolean): { method: string, url: string, headers: Record<string, string>, body: any }
+  /** Parses the text of a response to a request from `buildRequest`. */
+  parseResponse(functionName: string, rawText: string, partial: boolean, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): FunctionResult
+  /** Submits one call per entry of `args` to the client's batch API. */
+  submitBatch(functionName: string, args: { [string]: any }[], ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): Promise<BatchJob>
+  batchStatus(job: BatchJob, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): Promise<BatchJobStatus>
+  /** Returns one result per submitted call, in submission order. */
+  batchResults(job: BatchJob, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): Promise<FunctionResult[]>
   streamFunction(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null): FunctionResultStream
   streamFunctionSync(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null): FunctionResultStream
   setLogEventCallback(func?: undefined | ((err: any, param: BamlLogEvent) => void)): void
+  /**
+   * Registers functions to run around every HTTP request sent to an LLM
+   * provider. `onRequest` may return a modified request, or a Promise of
+   * one; `onResponse` receives the raw response.
+   */
+  addRequestHook(onRequest?: (request: HookRequest) => HookRequest | undefined | Promise<HookRequest | undefined>, onResponse?: (response: HookResponse) => void): void
+  clearRequestHooks(): void
   flush(): void
   drainStats(): TraceStats
 }
@@ -37,9 +66,34 @@ export declare class BamlSpan {
   finish(result: any, ctx: RuntimeContextManager): any
 }
 
+export declare class BamlVideo {
+  static fromUrl(url: string): BamlVideo
+  static fromBase64(mediaType: string, base64: string): BamlVideo
+  isUrl(): boolean
+  asUrl(): string
+  asBase64(): [string, string]
+  toJSON(): any
+}
+
+export declare class BatchJob {
+  get id(): string
+  get functionName(): string
+  get client(): string
+  /** Serializes the job so it can be resumed with `BatchJob.fromJson`. */
+  toJson(): string
+  static fromJson(json: string): BatchJob
+}
+
+export declare class BatchJobStatus {
+  get status(): 'in_progress' | 'completed' | 'failed' | 'cancelled' | 'expired'
+  get message(): string | null
+  isDone(): boolean
+}
+
 export declare class ClassBuilder {
   field(): FieldType
   property(name: string): ClassPropertyBuilder
+  reorderProperties(names: Array<string>): ClassBuilder
 }
 
 export declare class ClassPropertyBuilder {
@@ -57,6 +111,7 @@ export declare class ClientRegistry {
 export declare class EnumBuilder {
   value(name: string): EnumValueBuilder
   alias(alias?: string | undefined | null): EnumBuilder
+  removeValue(name: string): EnumBuilder
   field(): FieldType
 }
 
@@ -74,6 +129,16 @@ export declare class FieldType {
 export declare class FunctionResult {
   isOk(): boolean
   parsed(): any
+  /**
+   * The HTTP request and response of every attempt, oldest first.
+   * Credentials in request headers are redacted.
+   */
+  rawHttp(): any
+  /**
+   * The flags the parser applied to coerce the response into the return
+   * type, and their total score.
+   */
+  parseReport(): { score: number, flags: Array<{ path: string, flag: string, description: string, original: string | null, score: number }> }
 }
 
 export declare class FunctionResultStream {
@@ -83,6 +148,8 @@ export declare class FunctionResultStream {
 
 export declare class RuntimeContextManager {
   upsertTags(tags: any): void
+  setVariant(functionName: string, variant?: string | undefined | null): void
+  setParseThresholds(maxScore?: number | undefined | null, forbiddenFlags?: Array<string> | undefined | null): void
   deepClone(): RuntimeContextManager
   contextDepth(): number
 }
@@ -113,6 +180,7 @@ export declare class TypeBuilder {
   null(): FieldType
   map(key: FieldType, value: FieldType): FieldType
   union(types: Array<FieldType>): FieldType
+  addBaml(baml: string, rt: BamlRuntime): void
 }
 
 export interface BamlLogEvent {
@@ -123,6 +191,21 @@ export interface BamlLogEvent {
   startTime: string
 }
 
+export interface HookRequest {
+  client: string
+  url: string
+  headers: Record<string, string>
+  body: any
+}
+
+export interface HookResponse {
+  client: string
+  url: string
+  status: number
+  headers: Record<string, string>
+  body?: string
+}
+
 export declare export declare function invoke_runtime_cli(params: Array<string>): void
 
 export interface LogEventMetadata {
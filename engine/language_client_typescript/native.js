@@ -363,8 +363,12 @@ if (!nativeBinding) {
 
 module.exports.BamlAudio = nativeBinding.BamlAudio
 module.exports.BamlImage = nativeBinding.BamlImage
+module.exports.BamlPdf = nativeBinding.BamlPdf
 module.exports.BamlRuntime = nativeBinding.BamlRuntime
 module.exports.BamlSpan = nativeBinding.BamlSpan
+module.exports.BamlVideo = nativeBinding.BamlVideo
+module.exports.BatchJob = nativeBinding.BatchJob
+module.exports.BatchJobStatus = nativeBinding.BatchJobStatus
 module.exports.ClassBuilder = nativeBinding.ClassBuilder
 module.exports.ClassPropertyBuilder = nativeBinding.ClassPropertyBuilder
 module.exports.ClientRegistry = nativeBinding.ClientRegistry
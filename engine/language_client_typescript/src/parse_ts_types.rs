@@ -13,6 +13,8 @@ use napi::NapiRaw;
 
 use crate::types::audio::BamlAudio;
 use crate::types::image::BamlImage;
+use crate::types::pdf::BamlPdf;
+use crate::types::video::BamlVideo;
 
 struct SerializationError {
     position: Vec<String>,
@@ -104,10 +106,20 @@ pub fn js_object_to_baml_value(env: Env, kwargs: JsObject) -> napi::Result<BamlV
         }
         Ok(BamlValue::List(args))
     } else if kwargs.is_date()? {
+        // Dates are sent at the local UTC offset, so that a `date` argument
+        // is the day that JS shows for it, not the day in UTC.
+        let get_timezone_offset: JsFunction = kwargs.get_named_property("getTimezoneOffset")?;
+        let minutes_behind_utc = get_timezone_offset
+            .call_without_args(Some(&kwargs))?
+            .coerce_to_number()?
+            .get_double()?;
         let date: JsDate = unsafe { kwargs.into_unknown().cast() };
         let timestamp = date.value_of()?;
-        // TODO: Convert timestamp to a DateTime
-        Ok(BamlValue::Float(timestamp))
+        let offset_seconds = -(minutes_behind_utc * 60.0) as i32;
+        match baml_types::iso8601::format_timestamp_millis(timestamp as i64, offset_seconds) {
+            Some(iso) => Ok(BamlValue::String(iso)),
+            None => Err(Error::new(Status::InvalidArg, "Invalid Date".to_owned())),
+        }
     } else {
         let mut args = BamlMap::new();
 
@@ -197,6 +209,10 @@ pub fn jsunknown_to_baml_value(
                 BamlValue::Media(img.inner.clone())
             } else if let Ok(audio) = env.get_value_external::<BamlAudio>(&external) {
                 BamlValue::Media(audio.inner.clone())
+            } else if let Ok(pdf) = env.get_value_external::<BamlPdf>(&external) {
+                BamlValue::Media(pdf.inner.clone())
+            } else if let Ok(video) = env.get_value_external::<BamlVideo>(&external) {
+                BamlValue::Media(video.inner.clone())
             } else {
                 if skip_unsupported {
                     return Ok(None);
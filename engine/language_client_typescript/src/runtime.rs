@@ -1,17 +1,24 @@
 use crate::errors::{from_anyhow_error, invalid_argument_error};
 use crate::parse_ts_types;
+use crate::types::batch::{BatchJob, BatchJobStatus};
 use crate::types::client_registry::ClientRegistry;
 use crate::types::function_result_stream::FunctionResultStream;
 use crate::types::function_results::FunctionResult;
+use crate::types::request_hook::{HookRequest, HookResponse};
 use crate::types::runtime_ctx_manager::RuntimeContextManager;
 use crate::types::trace_stats::TraceStats;
 use crate::types::type_builder::TypeBuilder;
 use baml_runtime::on_log_event::LogEvent;
+use baml_runtime::request_hook::{
+    HookRequest as CoreHookRequest, HookResponse as CoreHookResponse, RequestHook,
+};
 use baml_runtime::runtime_interface::ExperimentalTracingInterface;
 use baml_runtime::BamlRuntime as CoreRuntime;
 use baml_types::BamlValue;
-use napi::bindgen_prelude::ObjectFinalize;
-use napi::threadsafe_function::{ThreadSafeCallContext, ThreadsafeFunctionCallMode};
+use napi::bindgen_prelude::{Either, ObjectFinalize, Promise};
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
 use napi::JsFunction;
 use napi::JsObject;
 use napi::{Env, JsUndefined};
@@ -165,6 +172,226 @@ impl BamlRuntime {
             .map_err(|e| from_anyhow_error(e))
     }
 
+    /// Builds the HTTP request that calling the function would send, without
+    /// sending it. Credentials in request headers are kept.
+    #[napi(
+        ts_return_type = "Promise<{ method: string, url: string, headers: Record<string, string>, body: any }>"
+    )]
+    pub fn build_request(
+        &self,
+        env: Env,
+        function_name: String,
+        #[napi(ts_arg_type = "{ [string]: any }")] args: JsObject,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+        stream: bool,
+    ) -> napi::Result<JsObject> {
+        let args = parse_ts_types::js_object_to_baml_value(env, args)?;
+
+        if !args.is_map() {
+            return Err(invalid_argument_error(&format!(
+                "Expected a map of arguments, got: {}",
+                args.r#type()
+            )));
+        }
+        let args_map = args.as_map_owned().unwrap();
+
+        let baml_runtime = self.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        let fut = async move {
+            let request = baml_runtime
+                .build_request(
+                    &function_name,
+                    &args_map,
+                    &ctx_mng,
+                    tb.as_ref(),
+                    cb.as_ref(),
+                    stream,
+                )
+                .await
+                .map_err(|e| from_anyhow_error(e))?;
+            Ok(serde_json::to_value(request)?)
+        };
+
+        env.execute_tokio_future(fut, |&mut _, data| Ok(data))
+    }
+
+    #[napi(
+        ts_return_type = "{ method: string, url: string, headers: Record<string, string>, body: any }"
+    )]
+    pub fn build_request_sync(
+        &self,
+        env: Env,
+        function_name: String,
+        #[napi(ts_arg_type = "{ [string]: any }")] args: JsObject,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+        stream: bool,
+    ) -> napi::Result<serde_json::Value> {
+        let args = parse_ts_types::js_object_to_baml_value(env, args)?;
+
+        if !args.is_map() {
+            return Err(invalid_argument_error(&format!(
+                "Expected a map of arguments, got: {}",
+                args.r#type()
+            )));
+        }
+        let args_map = args.as_map_owned().unwrap();
+
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+        let request = self
+            .inner
+            .async_runtime
+            .block_on(self.inner.build_request(
+                &function_name,
+                &args_map,
+                &ctx_mng,
+                tb.as_ref(),
+                cb.as_ref(),
+                stream,
+            ))
+            .map_err(|e| from_anyhow_error(e))?;
+
+        Ok(serde_json::to_value(request)?)
+    }
+
+    /// Parses the text of a response to a request from `buildRequest`.
+    #[napi]
+    pub fn parse_response(
+        &self,
+        function_name: String,
+        raw_text: String,
+        partial: bool,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> napi::Result<FunctionResult> {
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        self.inner
+            .parse_response(
+                &function_name,
+                &raw_text,
+                partial,
+                &ctx.inner,
+                tb.as_ref(),
+                cb.as_ref(),
+            )
+            .map(FunctionResult::from)
+            .map_err(|e| from_anyhow_error(e))
+    }
+
+    /// Submits one call per entry of `args` to the client's batch API.
+    #[napi(ts_return_type = "Promise<BatchJob>")]
+    pub fn submit_batch(
+        &self,
+        env: Env,
+        function_name: String,
+        #[napi(ts_arg_type = "{ [string]: any }[]")] args: Vec<JsObject>,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> napi::Result<JsObject> {
+        let mut args_maps = Vec::with_capacity(args.len());
+        for args in args {
+            let args = parse_ts_types::js_object_to_baml_value(env, args)?;
+            if !args.is_map() {
+                return Err(invalid_argument_error(&format!(
+                    "Expected a map of arguments, got: {}",
+                    args.r#type()
+                )));
+            }
+            args_maps.push(args.as_map_owned().unwrap());
+        }
+
+        let baml_runtime = self.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        let fut = async move {
+            baml_runtime
+                .submit_batch(
+                    function_name,
+                    &args_maps,
+                    &ctx_mng,
+                    tb.as_ref(),
+                    cb.as_ref(),
+                )
+                .await
+                .map(BatchJob::from)
+                .map_err(|e| from_anyhow_error(e))
+        };
+
+        env.execute_tokio_future(fut, |&mut _, data| Ok(data))
+    }
+
+    #[napi(ts_return_type = "Promise<BatchJobStatus>")]
+    pub fn batch_status(
+        &self,
+        env: Env,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> napi::Result<JsObject> {
+        let baml_runtime = self.inner.clone();
+        let job = job.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        let fut = async move {
+            baml_runtime
+                .batch_status(&job, &ctx_mng, tb.as_ref(), cb.as_ref())
+                .await
+                .map(BatchJobStatus::from)
+                .map_err(|e| from_anyhow_error(e))
+        };
+
+        env.execute_tokio_future(fut, |&mut _, data| Ok(data))
+    }
+
+    /// Returns one result per submitted call, in submission order.
+    #[napi(ts_return_type = "Promise<FunctionResult[]>")]
+    pub fn batch_results(
+        &self,
+        env: Env,
+        job: &BatchJob,
+        ctx: &RuntimeContextManager,
+        tb: Option<&TypeBuilder>,
+        cb: Option<&ClientRegistry>,
+    ) -> napi::Result<JsObject> {
+        let baml_runtime = self.inner.clone();
+        let job = job.inner.clone();
+        let ctx_mng = ctx.inner.clone();
+        let tb = tb.map(|tb| tb.inner.clone());
+        let cb = cb.map(|cb| cb.inner.clone());
+
+        let fut = async move {
+            baml_runtime
+                .batch_results(&job, &ctx_mng, tb.as_ref(), cb.as_ref())
+                .await
+                .map(|results| {
+                    results
+                        .into_iter()
+                        .map(FunctionResult::from)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(|e| from_anyhow_error(e))
+        };
+
+        env.execute_tokio_future(fut, |&mut _, data| Ok(data))
+    }
+
     #[napi]
     pub fn stream_function(
         &self,
@@ -345,6 +572,55 @@ impl BamlRuntime {
         env.get_undefined()
     }
 
+    /// Registers functions to run around every HTTP request sent to an LLM
+    /// provider. `onRequest` may return a modified request, or a Promise of
+    /// one; `onResponse` receives the raw response.
+    #[napi(
+        ts_args_type = "onRequest?: (request: HookRequest) => HookRequest | undefined | Promise<HookRequest | undefined>, onResponse?: (response: HookResponse) => void"
+    )]
+    pub fn add_request_hook(
+        &self,
+        env: Env,
+        on_request: Option<JsFunction>,
+        on_response: Option<JsFunction>,
+    ) -> napi::Result<()> {
+        let on_request = match on_request {
+            Some(func) => {
+                let mut tsfn: ThreadsafeFunction<HookRequest, ErrorStrategy::Fatal> = func
+                    .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<HookRequest>| {
+                        Ok(vec![ctx.value])
+                    })?;
+                tsfn.unref(&env)?;
+                Some(tsfn)
+            }
+            None => None,
+        };
+        let on_response = match on_response {
+            Some(func) => {
+                let mut tsfn: ThreadsafeFunction<HookResponse, ErrorStrategy::Fatal> = func
+                    .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<HookResponse>| {
+                        Ok(vec![ctx.value])
+                    })?;
+                tsfn.unref(&env)?;
+                Some(tsfn)
+            }
+            None => None,
+        };
+
+        self.inner
+            .add_request_hook(std::sync::Arc::new(JsRequestHook {
+                on_request,
+                on_response,
+                js_thread: std::thread::current().id(),
+            }));
+        Ok(())
+    }
+
+    #[napi]
+    pub fn clear_request_hooks(&self) {
+        self.inner.clear_request_hooks();
+    }
+
     #[napi]
     pub fn flush(&mut self, _env: Env) -> napi::Result<()> {
         self.inner.flush().map_err(|e| from_anyhow_error(e))
@@ -356,6 +632,73 @@ impl BamlRuntime {
     }
 }
 
+type HookReturn = Either<Promise<Option<HookRequest>>, Option<HookRequest>>;
+
+struct JsRequestHook {
+    on_request: Option<ThreadsafeFunction<HookRequest, ErrorStrategy::Fatal>>,
+    on_response: Option<ThreadsafeFunction<HookResponse, ErrorStrategy::Fatal>>,
+    /// The thread the hook was registered on. JS functions can only run there.
+    js_thread: std::thread::ThreadId,
+}
+
+impl RequestHook for JsRequestHook {
+    fn on_request<'a>(
+        &'a self,
+        request: &'a mut CoreHookRequest,
+    ) -> futures::future::BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let Some(tsfn) = &self.on_request else {
+                return Ok(());
+            };
+            // Sync calls block the JS thread until they return, so the hook
+            // could never run.
+            if std::thread::current().id() == self.js_thread {
+                anyhow::bail!(
+                    "onRequest hooks can't run during sync calls. Use the async client instead."
+                );
+            }
+            let (tx, rx) = futures::channel::oneshot::channel();
+            let status = tsfn.call_with_return_value(
+                HookRequest::from(&*request),
+                ThreadsafeFunctionCallMode::NonBlocking,
+                move |updated: HookReturn| {
+                    let _ = tx.send(updated);
+                    Ok(())
+                },
+            );
+            if status != napi::Status::Ok {
+                anyhow::bail!("Failed to call onRequest hook: {:?}", status);
+            }
+            let updated = match rx.await {
+                Ok(Either::A(promise)) => promise
+                    .await
+                    .map_err(|e| anyhow::anyhow!("onRequest hook rejected: {}", e.reason))?,
+                Ok(Either::B(updated)) => updated,
+                Err(futures::channel::oneshot::Canceled) => {
+                    anyhow::bail!("onRequest hook threw or returned an invalid request")
+                }
+            };
+            if let Some(updated) = updated {
+                updated.apply(request);
+            }
+            Ok(())
+        })
+    }
+
+    fn on_response(&self, response: &CoreHookResponse) -> anyhow::Result<()> {
+        if let Some(tsfn) = &self.on_response {
+            let status = tsfn.call(
+                HookResponse::from(response),
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+            if status != napi::Status::Ok {
+                anyhow::bail!("Failed to call onResponse hook: {:?}", status);
+            }
+        }
+        Ok(())
+    }
+}
+
 impl ObjectFinalize for BamlRuntime {
     fn finalize(mut self, env: Env) -> napi::Result<()> {
         if let Some(mut cb) = self.callback.take() {
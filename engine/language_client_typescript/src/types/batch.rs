@@ -0,0 +1,69 @@
+use napi::bindgen_prelude::External;
+use napi_derive::napi;
+
+use crate::errors::from_anyhow_error;
+
+crate::lang_wrapper!(BatchJob, baml_runtime::BatchJob);
+
+#[napi]
+impl BatchJob {
+    #[napi(getter)]
+    pub fn get_id(&self) -> String {
+        self.inner.id.clone()
+    }
+
+    #[napi(getter)]
+    pub fn get_function_name(&self) -> String {
+        self.inner.function_name.clone()
+    }
+
+    #[napi(getter)]
+    pub fn get_client(&self) -> String {
+        self.inner.client.clone()
+    }
+
+    /// Serializes the job so it can be resumed with `BatchJob.fromJson`.
+    #[napi]
+    pub fn to_json(&self) -> napi::Result<String> {
+        self.inner.to_json().map_err(from_anyhow_error)
+    }
+
+    #[napi(ts_return_type = "BatchJob")]
+    pub fn from_json(json: String) -> napi::Result<External<BatchJob>> {
+        let inner = baml_runtime::BatchJob::from_json(&json).map_err(from_anyhow_error)?;
+        Ok(External::new(BatchJob { inner }))
+    }
+}
+
+crate::lang_wrapper!(BatchJobStatus, baml_runtime::BatchJobStatus);
+
+#[napi]
+impl BatchJobStatus {
+    #[napi(
+        getter,
+        ts_return_type = "'in_progress' | 'completed' | 'failed' | 'cancelled' | 'expired'"
+    )]
+    pub fn get_status(&self) -> String {
+        match self.inner {
+            baml_runtime::BatchJobStatus::InProgress => "in_progress",
+            baml_runtime::BatchJobStatus::Completed => "completed",
+            baml_runtime::BatchJobStatus::Failed { .. } => "failed",
+            baml_runtime::BatchJobStatus::Cancelled => "cancelled",
+            baml_runtime::BatchJobStatus::Expired => "expired",
+        }
+        .to_string()
+    }
+
+    #[napi(getter)]
+    pub fn get_message(&self) -> Option<String> {
+        match &self.inner {
+            baml_runtime::BatchJobStatus::Failed { message } => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    #[napi]
+    pub fn is_done(&self) -> bool {
+        self.inner.is_done()
+    }
+}
@@ -24,4 +24,25 @@ impl FunctionResult {
 
         Ok(serde_json::to_value(parsed)?)
     }
+
+    /// The HTTP request and response of every attempt, oldest first.
+    /// Credentials in request headers are redacted.
+    #[napi]
+    pub fn raw_http(&self) -> napi::Result<serde_json::Value> {
+        Ok(serde_json::to_value(self.inner.raw_http())?)
+    }
+
+    /// The flags the parser applied to coerce the response into the return
+    /// type, and their total score.
+    #[napi(
+        ts_return_type = "{ score: number, flags: Array<{ path: string, flag: string, description: string, original: string | null, score: number }> }"
+    )]
+    pub fn parse_report(&self) -> napi::Result<serde_json::Value> {
+        let report = self
+            .inner
+            .parse_report()
+            .map_err(|e| from_anyhow_error(e))?;
+
+        Ok(serde_json::to_value(report)?)
+    }
 }
@@ -1,11 +1,15 @@
 mod lang_wrappers;
 
 pub mod audio;
+pub(crate) mod batch;
 pub(crate) mod client_registry;
 pub(crate) mod function_result_stream;
 pub(crate) mod function_results;
 pub mod image;
+pub mod pdf;
+pub(crate) mod request_hook;
 pub(crate) mod runtime_ctx_manager;
 pub(crate) mod span;
 pub(crate) mod trace_stats;
 pub(crate) mod type_builder;
+pub mod video;
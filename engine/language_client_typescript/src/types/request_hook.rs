@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use napi_derive::napi;
+
+#[napi(object)]
+pub struct HookRequest {
+    pub client: String,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub body: serde_json::Value,
+}
+
+#[napi(object)]
+pub struct HookResponse {
+    pub client: String,
+    pub url: String,
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+}
+
+impl From<&baml_runtime::request_hook::HookRequest> for HookRequest {
+    fn from(request: &baml_runtime::request_hook::HookRequest) -> Self {
+        Self {
+            client: request.client.clone(),
+            url: request.url.clone(),
+            headers: request.headers.clone().into_iter().collect(),
+            body: request.body.clone(),
+        }
+    }
+}
+
+impl From<&baml_runtime::request_hook::HookResponse> for HookResponse {
+    fn from(response: &baml_runtime::request_hook::HookResponse) -> Self {
+        Self {
+            client: response.client.clone(),
+            url: response.url.clone(),
+            status: response.status,
+            headers: response.headers.clone().into_iter().collect(),
+            body: response.body.clone(),
+        }
+    }
+}
+
+impl HookRequest {
+    /// Writes the fields a hook may change back into `request`.
+    pub(crate) fn apply(self, request: &mut baml_runtime::request_hook::HookRequest) {
+        request.url = self.url;
+        request.headers = self.headers.into_iter().collect();
+        request.body = self.body;
+    }
+}
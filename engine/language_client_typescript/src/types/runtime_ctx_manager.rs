@@ -23,6 +23,25 @@ impl RuntimeContextManager {
         Ok(())
     }
 
+    #[napi]
+    pub fn set_variant(&self, function_name: String, variant: Option<String>) {
+        match variant {
+            Some(variant) => self.inner.set_variant(&function_name, &variant),
+            None => self.inner.clear_variant(&function_name),
+        }
+    }
+
+    #[napi]
+    pub fn set_parse_thresholds(
+        &self,
+        max_score: Option<i32>,
+        forbidden_flags: Option<Vec<String>>,
+    ) -> napi::Result<()> {
+        self.inner
+            .set_parse_thresholds(max_score, forbidden_flags.unwrap_or_default())
+            .map_err(|e| invalid_argument_error(&e.to_string()))
+    }
+
     #[napi]
     pub fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -2,6 +2,8 @@ use baml_runtime::type_builder::{self, WithMeta};
 use baml_types::BamlValue;
 use napi_derive::napi;
 
+use crate::{errors::from_anyhow_error, runtime::BamlRuntime};
+
 crate::lang_wrapper!(TypeBuilder, type_builder::TypeBuilder);
 crate::lang_wrapper!(EnumBuilder, type_builder::EnumBuilder, sync_thread_safe, name: String);
 crate::lang_wrapper!(ClassBuilder, type_builder::ClassBuilder, sync_thread_safe, name: String);
@@ -99,6 +101,13 @@ impl TypeBuilder {
         .into()
     }
 
+    #[napi]
+    pub fn add_baml(&self, baml: String, rt: &BamlRuntime) -> napi::Result<()> {
+        self.inner
+            .add_baml(&baml, &rt.inner)
+            .map_err(from_anyhow_error)
+    }
+
     #[napi]
     pub fn union(&self, types: Vec<&FieldType>) -> FieldType {
         baml_types::FieldType::union(
@@ -142,6 +151,15 @@ impl EnumBuilder {
         self.inner.clone().into()
     }
 
+    #[napi]
+    pub fn remove_value(&self, name: String) -> Self {
+        self.inner.lock().unwrap().remove_value(&name);
+        EnumBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        }
+    }
+
     #[napi]
     pub fn field(&self) -> FieldType {
         baml_types::FieldType::r#enum(&self.name).into()
@@ -193,6 +211,15 @@ impl ClassBuilder {
     pub fn property(&self, name: String) -> ClassPropertyBuilder {
         self.inner.lock().unwrap().property(&name).into()
     }
+
+    #[napi]
+    pub fn reorder_properties(&self, names: Vec<String>) -> Self {
+        self.inner.lock().unwrap().reorder_properties(names);
+        ClassBuilder {
+            inner: self.inner.clone(),
+            name: self.name.clone(),
+        }
+    }
 }
 
 #[napi]
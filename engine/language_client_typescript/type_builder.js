@@ -6,9 +6,11 @@ class TypeBuilder {
     tb;
     classes;
     enums;
-    constructor({ classes, enums }) {
+    runtime;
+    constructor({ classes, enums, runtime }) {
         this.classes = classes;
         this.enums = enums;
+        this.runtime = runtime;
         this.tb = new native_1.TypeBuilder();
     }
     _tb() {
@@ -73,6 +75,12 @@ class TypeBuilder {
         this.enums.add(name);
         return new EnumBuilder(this.tb, name);
     }
+    addBaml(baml) {
+        if (!this.runtime) {
+            throw new Error('addBaml needs a TypeBuilder created from a generated baml_client.');
+        }
+        this.tb.addBaml(baml, this.runtime);
+    }
 }
 exports.TypeBuilder = TypeBuilder;
 class ClassBuilder {
@@ -101,6 +109,10 @@ class ClassBuilder {
         }
         return new ClassPropertyBuilder(this.bldr.property(name));
     }
+    reorderProperties(names) {
+        this.bldr.reorderProperties(names);
+        return this;
+    }
 }
 exports.ClassBuilder = ClassBuilder;
 class ClassPropertyBuilder {
@@ -108,6 +120,10 @@ class ClassPropertyBuilder {
     constructor(bldr) {
         this.bldr = bldr;
     }
+    type(type) {
+        this.bldr.setType(type);
+        return this;
+    }
     alias(alias) {
         this.bldr.alias(alias);
         return this;
@@ -143,5 +159,13 @@ class EnumBuilder {
         this.values.add(name);
         return this.bldr.value(name);
     }
+    removeValue(name) {
+        if (!this.values.has(name)) {
+            throw new Error(`Value ${name} not found.`);
+        }
+        this.values.delete(name);
+        this.bldr.removeValue(name);
+        return this;
+    }
 }
 exports.EnumBuilder = EnumBuilder;
@@ -36,6 +36,16 @@ export class BamlCtxManager {
     manager.upsertTags(tags)
   }
 
+  setVariant(functionName: string, variant: string | null): void {
+    const manager = this.ctx.getStore()!
+    manager.setVariant(functionName, variant)
+  }
+
+  setParseThresholds(maxScore: number | null, forbiddenFlags: string[] = []): void {
+    const manager = this.ctx.getStore()!
+    manager.setParseThresholds(maxScore, forbiddenFlags)
+  }
+
   cloneContext(): RuntimeContextManager {
     let store = this.ctx.getStore()
     if (store === undefined) {
@@ -5,9 +5,15 @@ export {
   BamlImage as Image,
   ClientBuilder,
   BamlAudio as Audio,
+  BamlPdf as Pdf,
+  BamlVideo as Video,
   invoke_runtime_cli,
   ClientRegistry,
   BamlLogEvent,
+  BatchJob,
+  BatchJobStatus,
+  HookRequest,
+  HookResponse,
 } from './native'
 export { BamlStream } from './stream'
 export { BamlCtxManager } from './async_context_vars'
@@ -1,4 +1,5 @@
 import {
+  BamlRuntime,
   ClassBuilder as _ClassBuilder,
   EnumBuilder as _EnumBuilder,
   ClassPropertyBuilder as _ClassPropertyBuilder,
@@ -21,10 +22,12 @@ export class TypeBuilder {
   private tb: _TypeBuilder
   protected classes: Set<string>
   protected enums: Set<string>
+  private runtime?: BamlRuntime
 
-  constructor({ classes, enums }: { classes: Set<string>; enums: Set<string> }) {
+  constructor({ classes, enums, runtime }: { classes: Set<string>; enums: Set<string>; runtime?: BamlRuntime }) {
     this.classes = classes
     this.enums = enums
+    this.runtime = runtime
     this.tb = new _TypeBuilder()
   }
 
@@ -108,6 +111,13 @@ export class TypeBuilder {
     this.enums.add(name)
     return new EnumBuilder(this.tb, name)
   }
+
+  addBaml(baml: string): void {
+    if (!this.runtime) {
+      throw new Error('addBaml needs a TypeBuilder created from a generated baml_client.')
+    }
+    this.tb.addBaml(baml, this.runtime)
+  }
 }
 
 export class ClassBuilder<ClassName extends string, Properties extends string = string> {
@@ -143,6 +153,11 @@ export class ClassBuilder<ClassName extends string, Properties extends string =
     }
     return new ClassPropertyBuilder(this.bldr.property(name))
   }
+
+  reorderProperties(names: Array<Properties | string>): ClassBuilder<ClassName, Properties> {
+    this.bldr.reorderProperties(names)
+    return this
+  }
 }
 
 class ClassPropertyBuilder {
@@ -152,6 +167,11 @@ class ClassPropertyBuilder {
     this.bldr = bldr
   }
 
+  type(type: FieldType): ClassPropertyBuilder {
+    this.bldr.setType(type)
+    return this
+  }
+
   alias(alias: string | null): ClassPropertyBuilder {
     this.bldr.alias(alias)
     return this
@@ -196,4 +216,13 @@ export class EnumBuilder<EnumName extends string, T extends string = string> {
     this.values.add(name)
     return this.bldr.value(name)
   }
+
+  removeValue(name: T | string): EnumBuilder<EnumName, T> {
+    if (!this.values.has(name)) {
+      throw new Error(`Value ${name} not found.`)
+    }
+    this.values.delete(name)
+    this.bldr.removeValue(name)
+    return this
+  }
 }
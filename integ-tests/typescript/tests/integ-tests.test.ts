@@ -351,6 +351,43 @@ describe('Integ tests', () => {
     expect(res.toLowerCase()).toContain('donkey')
   })
 
+  it('request hooks fail fast in sync calls', async () => {
+    DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.addRequestHook((request) => request)
+    try {
+      const start = Date.now()
+      expect(() => b_sync.TestOpenAIShorthand('Dr. Pepper')).toThrow('sync calls')
+      expect(Date.now() - start).toBeLessThan(5000)
+    } finally {
+      DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.clearRequestHooks()
+    }
+  })
+
+  it('request hooks can return a promise', async () => {
+    let resolved = false
+    DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.addRequestHook(async (request) => {
+      await new Promise((resolve) => setTimeout(resolve, 10))
+      request.headers['x-baml-test'] = 'promise'
+      resolved = true
+      return request
+    })
+    try {
+      const res = await b.TestOpenAIShorthand('Dr. Pepper')
+      expect(res.length).toBeGreaterThan(0)
+      expect(resolved).toBe(true)
+    } finally {
+      DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.clearRequestHooks()
+    }
+
+    DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.addRequestHook(async () => {
+      throw new Error('hook rejected')
+    })
+    try {
+      await expect(b.TestOpenAIShorthand('Dr. Pepper')).rejects.toThrow('hook rejected')
+    } finally {
+      DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME.clearRequestHooks()
+    }
+  })
+
   it('supports tracing sync', async () => {
     const blah = 'blah'
 
@@ -155,6 +155,20 @@ export const selectedFunctionAtom = atom(
   },
 )
 
+// null means the function's own prompt and client
+const rawSelectedVariantAtom = atom<string | null>(null)
+export const selectedVariantAtom = atom(
+  (get) => {
+    const func = get(selectedFunctionAtom)
+    const variant = get(rawSelectedVariantAtom)
+    return func?.variants.find((v) => v === variant) ?? null
+  },
+  (get, set, variant: string | null) => {
+    set(rawSelectedVariantAtom, variant)
+    set(orchIndexAtom, 0)
+  },
+)
+
 const rawSelectedTestCaseAtom = atom<string | null>(null)
 export const selectedTestCaseAtom = atom(
   (get) => {
@@ -399,6 +413,7 @@ const rawCurlAtomAsync = atom(async (get) => {
 
   const wasmCallContext = new wasm.WasmCallContext()
   wasmCallContext.node_index = orch_index
+  wasmCallContext.variant = get(selectedVariantAtom) ?? undefined
 
   return await func.render_raw_curl_for_test(
     runtime,
@@ -426,6 +441,7 @@ const renderPromptAtomAsync = atom(async (get) => {
 
   const wasmCallContext = new wasm.WasmCallContext()
   wasmCallContext.node_index = orch_index
+  wasmCallContext.variant = get(selectedVariantAtom) ?? undefined
 
   try {
     return await func.render_prompt_for_test(runtime, test_case.name, wasmCallContext, async (path: string) => {
@@ -1,7 +1,7 @@
 import { atom, useAtomValue } from 'jotai'
 import { atomFamily, useAtomCallback } from 'jotai/utils'
 import React, { useCallback } from 'react'
-import { selectedFunctionAtom, selectedRuntimeAtom } from '../EventListener'
+import { selectedFunctionAtom, selectedRuntimeAtom, selectedVariantAtom, wasmAtom } from '../EventListener'
 import type { WasmFunctionResponse, WasmTestResponse } from '@gloo-ai/baml-schema-wasm-web/baml_schema_build'
 import { vscode } from '../../utils/vscode'
 
@@ -89,9 +89,10 @@ export const useRunHooks = () => {
   const runTest = useAtomCallback(
     useCallback(
       async (get, set, testNames: string[]) => {
+        const wasm = get(wasmAtom)
         const runtime = get(selectedRuntimeAtom)
         const func = get(selectedFunctionAtom)
-        if (!runtime || !func) {
+        if (!wasm || !runtime || !func) {
           // Refuse to run a test if no runtime is selected
           return
         }
@@ -153,11 +154,14 @@ export const useRunHooks = () => {
               if (!func || !runtime) {
                 return Promise.reject(new Error('Code potentially modified while running tests'))
               }
+              const wasmCallContext = new wasm.WasmCallContext()
+              wasmCallContext.variant = get(selectedVariantAtom) ?? undefined
               let now = new Date().getTime()
               return func
                 .run_test(
                   runtime,
                   testName,
+                  wasmCallContext,
                   (intermediate: WasmFunctionResponse) => {
                     set(testStatusAtom(testName), {
                       status: 'running',
@@ -132,7 +132,7 @@ const CurlSnippet: React.FC = () => {
   )
 }
 
-const WebviewMedia: React.FC<{ bamlMediaType: 'image' | 'audio'; media: WasmChatMessagePartMedia }> = ({
+const WebviewMedia: React.FC<{ bamlMediaType: 'image' | 'audio' | 'pdf' | 'video'; media: WasmChatMessagePartMedia }> = ({
   bamlMediaType,
   media,
 }) => {
@@ -193,6 +193,19 @@ const WebviewMedia: React.FC<{ bamlMediaType: 'image' | 'audio'; media: WasmChat
                 Your browser does not support the audio element.
               </audio>
             )
+          case 'pdf':
+            return (
+              <a href={mediaUrl} target='_blank' rel='noopener noreferrer'>
+                Open PDF
+              </a>
+            )
+          case 'video':
+            return (
+              <video controls className='max-h-[400px] max-w-[400px]'>
+                <source src={mediaUrl} />
+                Your browser does not support the video element.
+              </video>
+            )
         }
       })()}
     </div>
@@ -236,8 +249,24 @@ const PromptPreview: React.FC = () => {
     return <CurlSnippet />
   }
 
+  const tokenCount = promptPreview.token_count()
+  const maxInputTokens = promptPreview.max_input_tokens()
+
   return (
     <div className='flex flex-col gap-4 px-2 w-full h-full'>
+      {tokenCount !== undefined && (
+        <div
+          className={
+            maxInputTokens !== undefined && tokenCount > maxInputTokens
+              ? 'text-xs text-red-500'
+              : 'text-xs text-muted-foreground'
+          }
+        >
+          {promptPreview.token_count_is_exact() ? '' : '~'}
+          {tokenCount} tokens
+          {maxInputTokens !== undefined && ` (max_input_tokens: ${maxInputTokens})`}
+        </div>
+      )}
       {promptPreview.as_chat()?.map((chat, idx) => (
         <div key={idx} className='flex flex-col'>
           <div className='flex flex-row gap-2'>{chat.role}</div>
@@ -274,6 +303,20 @@ const PromptPreview: React.FC = () => {
                   return <div key={idx}>Error loading audio: {media.content}</div>
                 return <WebviewMedia key={idx} bamlMediaType='audio' media={media} />
               }
+              if (part.is_pdf()) {
+                const media = part.as_media()
+                if (!media) return <div key={idx}>Error loading pdf: this chat message part is not media</div>
+                if (media.type === wasm?.WasmChatMessagePartMediaType.Error)
+                  return <div key={idx}>Error loading pdf: {media.content}</div>
+                return <WebviewMedia key={idx} bamlMediaType='pdf' media={media} />
+              }
+              if (part.is_video()) {
+                const media = part.as_media()
+                if (!media) return <div key={idx}>Error loading video: this chat message part is not media</div>
+                if (media.type === wasm?.WasmChatMessagePartMediaType.Error)
+                  return <div key={idx}>Error loading video: {media.content}</div>
+                return <WebviewMedia key={idx} bamlMediaType='video' media={media} />
+              }
               return null
             }
 
@@ -7,6 +7,7 @@ import {
   orchIndexAtom,
   selectedFunctionAtom,
   selectedTestCaseAtom,
+  selectedVariantAtom,
   availableClientsAtom,
   currentClientsAtom,
 } from '../baml_wasm_web/EventListener'
@@ -84,6 +85,43 @@ const FunctionDropdown: React.FC = () => {
   )
 }
 
+const VariantDropdown: React.FC = () => {
+  const [open, setOpen] = useState(false)
+  const variants = useAtomValue(selectedFunctionAtom)?.variants ?? []
+  const [selected, setSelected] = useAtom(selectedVariantAtom)
+
+  // Every function has its own prompt and client as the first variant.
+  if (variants.length < 2) {
+    return null
+  }
+
+  return (
+    <div className='flex flex-col-reverse items-start gap-0.5'>
+      <span className='pl-2 text-xs text-muted-foreground'>Variant</span>
+      <Popover open={open} onOpenChange={setOpen}>
+        <PopoverTrigger asChild>
+          <div className='max-w-[300px] justify-between items-center flex hover:bg-vscode-button-hoverBackground h-fit rounded-md text-vscode-foreground cursor-pointer'>
+            <span className='px-2 py-1 w-full text-left truncate'>{selected ?? 'default'}</span>
+            <ChevronDown className='ml-1 w-4 h-4 opacity-50 shrink-0' />
+          </div>
+        </PopoverTrigger>
+        <PopoverContent className='w-1/3 min-w-[300px] p-0'>
+          <SearchBarWithSelector
+            options={variants.map((variant, i) => ({
+              value: variant,
+              content: <span>{i === 0 ? 'default' : variant}</span>,
+            }))}
+            onChange={(value) => {
+              setSelected(value === variants[0] ? null : value)
+              setOpen(false)
+            }}
+          />
+        </PopoverContent>
+      </Popover>
+    </div>
+  )
+}
+
 const TestDropdown: React.FC = () => {
   const [open, setOpen] = useState(false)
   const tests = useAtomValue(selectedFunctionAtom)?.test_cases
@@ -191,6 +229,7 @@ export const ViewSelector: React.FC = () => {
     <div className='flex overflow-x-auto flex-row justify-between w-full'>
       <div className='flex overflow-x-auto flex-row gap-4 items-center px-2 py-1'>
         <FunctionDropdown />
+        <VariantDropdown />
         <div>
           <ChevronRight className='w-4 h-4' />
         </div>
@@ -140,7 +140,7 @@ export function startServer(options?: LSOptions): void {
       capabilities: {
         textDocumentSync: TextDocumentSyncKind.Full,
         definitionProvider: true,
-        documentFormattingProvider: false,
+        documentFormattingProvider: true,
         completionProvider: {
           resolveProvider: false,
           triggerCharacters: ['@', '"', '.'],
@@ -598,23 +598,25 @@ export function startServer(options?: LSOptions): void {
     }
   })
 
-  // connection.onDocumentFormatting((params: DocumentFormattingParams) => {
-  //   const doc = getDocument(params.textDocument.uri)
-  //   if (doc) {
-  //     return MessageHandler.handleDocumentFormatting(params, doc, showErrorToast)
-  //   }
-  // })
-
-  // connection.onDocumentFormatting((params: DocumentFormattingParams): TextEdit[] => {
-  //   const { textDocument, options } = params
-  //   const document = documents.get(textDocument.uri)
-
-  //   if (!document) {
-  //     return []
-  //   }
+  connection.onDocumentFormatting((params: DocumentFormattingParams): TextEdit[] => {
+    const doc = getDocument(params.textDocument.uri)
+    if (!doc) {
+      return []
+    }
 
-  //   return formatDocument(document, options)
-  // })
+    try {
+      const formatted = BamlWasm.format_document(doc.getText())
+      if (formatted === doc.getText()) {
+        return []
+      }
+      const fullRange = Range.create(Position.create(0, 0), doc.positionAt(doc.getText().length))
+      return [TextEdit.replace(fullRange, formatted)]
+    } catch (e) {
+      // Files with syntax errors are left alone; the diagnostics already point at the problem.
+      console.log(`Skipping formatting for ${params.textDocument.uri}: ${e}`)
+      return []
+    }
+  })
 
   // connection.onCodeAction((params: CodeActionParams) => {
   //   const doc = getDocument(params.textDocument.uri)